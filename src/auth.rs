@@ -1,7 +1,8 @@
+use std::path::Path;
 use std::str::FromStr;
 
 use alloy::providers::ProviderBuilder;
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use polymarket_client_sdk::auth::state::Authenticated;
 use polymarket_client_sdk::auth::{LocalSigner, Normal, Signer as _};
 use polymarket_client_sdk::clob::types::SignatureType;
@@ -19,6 +20,56 @@ fn parse_signature_type(s: &str) -> SignatureType {
     }
 }
 
+/// Reads the key behind `--private-key-file`, trimming surrounding whitespace
+/// and newlines. On Unix a world-readable file gets a warning, or is rejected
+/// when `strict_permissions` is set.
+pub fn read_private_key_file(path: &Path, strict_permissions: bool) -> Result<String> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(path)
+            .with_context(|| format!("Failed to read private key file {}", path.display()))?
+            .permissions()
+            .mode();
+        if mode & 0o004 != 0 {
+            let msg = format!(
+                "private key file {} is readable by every user; run `chmod 600` on it",
+                path.display()
+            );
+            if strict_permissions {
+                bail!("{msg}");
+            }
+            tracing::warn!("{msg}");
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = strict_permissions;
+    let key = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read private key file {}", path.display()))?;
+    let key = key.trim();
+    if key.is_empty() {
+        bail!("private key file {} is empty", path.display());
+    }
+    Ok(key.to_string())
+}
+
+/// The key given on the command line: `--private-key`, else the contents of
+/// `--private-key-file`. `None` lets [`resolve_signer`] fall back to the env
+/// var and then the config file.
+pub fn cli_private_key(
+    inline: Option<String>,
+    file: Option<&Path>,
+    strict_permissions: bool,
+) -> Result<Option<String>> {
+    match (inline, file) {
+        (Some(key), _) => Ok(Some(key)),
+        (None, Some(path)) => read_private_key_file(path, strict_permissions).map(Some),
+        (None, None) => Ok(None),
+    }
+}
+
+/// Signer for `private_key` (the `--private-key` / `--private-key-file`
+/// value), falling back to the env var and then the config file.
 pub fn resolve_signer(
     private_key: Option<&str>,
 ) -> Result<impl polymarket_client_sdk::auth::Signer> {
@@ -43,10 +94,10 @@ pub async fn authenticate_with_signer(
 ) -> Result<clob::Client<Authenticated<Normal>>> {
     let sig_type = parse_signature_type(&config::resolve_signature_type(signature_type_flag));
 
-    clob::Client::default()
+    let builder = clob::Client::default()
         .authentication_builder(signer)
-        .signature_type(sig_type)
-        .authenticate()
+        .signature_type(sig_type);
+    crate::net::timed(builder.authenticate())
         .await
         .context("Failed to authenticate with Polymarket CLOB")
 }
@@ -77,6 +128,48 @@ pub async fn create_provider(
 mod tests {
     use super::*;
 
+    const TEST_KEY: &str = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    fn key_file(name: &str, contents: &str) -> std::path::PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("polymarket-key-{}-{name}", std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600)).unwrap();
+        }
+        path
+    }
+
+    #[test]
+    fn key_file_resolves_to_same_signer_as_inline_key() {
+        let path = key_file("same", &format!("  {TEST_KEY}\n"));
+        let from_file = cli_private_key(None, Some(&path), true).unwrap();
+        assert_eq!(from_file.as_deref(), Some(TEST_KEY));
+        assert_eq!(
+            resolve_signer(from_file.as_deref()).unwrap().address(),
+            resolve_signer(Some(TEST_KEY)).unwrap().address()
+        );
+
+        let inline = cli_private_key(Some("0xinline".into()), Some(&path), true).unwrap();
+        assert_eq!(inline.as_deref(), Some("0xinline"));
+        assert_eq!(cli_private_key(None, None, true).unwrap(), None);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn world_readable_key_file_is_rejected_when_strict() {
+        use std::os::unix::fs::PermissionsExt;
+        let path = key_file("open", TEST_KEY);
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+        let err = read_private_key_file(&path, true).unwrap_err();
+        assert!(err.to_string().contains("readable by every user"));
+        assert_eq!(read_private_key_file(&path, false).unwrap(), TEST_KEY);
+        std::fs::remove_file(&path).unwrap();
+    }
+
     #[test]
     fn parse_signature_type_proxy() {
         assert_eq!(parse_signature_type("proxy"), SignatureType::Proxy);
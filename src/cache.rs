@@ -0,0 +1,163 @@
+//! Opt-in on-disk cache for read-only Gamma queries (`--cache-ttl-secs`).
+//! Each response is stored as JSON under `<config dir>/cache/`, keyed by a
+//! hash of the endpoint name and the request.
+
+use std::fmt::Debug;
+use std::fs;
+use std::future::Future;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// `--cache-ttl-secs`; 0 (the default, and `--no-cache`) disables the cache.
+/// Atomic because the shell sets it per command.
+static TTL_SECS: AtomicU64 = AtomicU64::new(0);
+
+pub fn set_ttl_secs(secs: u64) {
+    TTL_SECS.store(secs, Ordering::Relaxed);
+}
+
+pub fn cache_dir() -> Result<PathBuf> {
+    Ok(crate::config::config_dir()?.join("cache"))
+}
+
+#[derive(Serialize, serde::Deserialize)]
+struct Entry<T> {
+    stored_at: DateTime<Utc>,
+    value: T,
+}
+
+fn entry_path(dir: &Path, key: &str) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    dir.join(format!("{:016x}.json", hasher.finish()))
+}
+
+/// Returns the cached response for `endpoint` + `request` when one younger
+/// than the TTL exists, otherwise runs `fetch` and stores its result. Cache
+/// read or write problems never fail the command; they just mean a fetch.
+pub async fn cached<T, R, F, Fut>(endpoint: &str, request: &R, fetch: F) -> Result<T>
+where
+    T: Serialize + DeserializeOwned,
+    R: Debug,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let ttl = TTL_SECS.load(Ordering::Relaxed);
+    let Some(dir) = (ttl > 0).then(cache_dir).and_then(Result::ok) else {
+        return fetch().await;
+    };
+    cached_at(
+        &dir,
+        &format!("{endpoint}:{request:?}"),
+        ttl,
+        Utc::now(),
+        fetch,
+    )
+    .await
+}
+
+async fn cached_at<T, F, Fut>(
+    dir: &Path,
+    key: &str,
+    ttl_secs: u64,
+    now: DateTime<Utc>,
+    fetch: F,
+) -> Result<T>
+where
+    T: Serialize + DeserializeOwned,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let path = entry_path(dir, key);
+    if let Some(value) = read_fresh(&path, ttl_secs, now) {
+        return Ok(value);
+    }
+    let value = fetch().await?;
+    if let Err(e) = write_entry(&path, &value, now) {
+        tracing::warn!("could not write response cache: {e}");
+    }
+    Ok(value)
+}
+
+fn read_fresh<T: DeserializeOwned>(path: &Path, ttl_secs: u64, now: DateTime<Utc>) -> Option<T> {
+    let entry: Entry<T> = serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+    let age = now.signed_duration_since(entry.stored_at).num_seconds();
+    (0..i64::try_from(ttl_secs).unwrap_or(i64::MAX))
+        .contains(&age)
+        .then_some(entry.value)
+}
+
+fn write_entry<T: Serialize>(path: &Path, value: &T, now: DateTime<Utc>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let entry = Entry {
+        stored_at: now,
+        value,
+    };
+    fs::write(path, serde_json::to_string(&entry)?)?;
+    Ok(())
+}
+
+/// Deletes every cached response; returns how many were removed.
+pub fn clear() -> Result<usize> {
+    let dir = cache_dir()?;
+    if !dir.exists() {
+        return Ok(0);
+    }
+    let mut removed = 0;
+    for entry in fs::read_dir(&dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|e| e == "json") {
+            fs::remove_file(&path)?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[tokio::test]
+    async fn second_call_within_ttl_is_served_from_disk() {
+        let dir = std::env::temp_dir().join(format!("polymarket-cache-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let count = Cell::new(0);
+        let fetches = &count;
+        let fetch = move || async move {
+            fetches.set(fetches.get() + 1);
+            Ok::<_, anyhow::Error>(vec![fetches.get()])
+        };
+        let t0 = DateTime::parse_from_rfc3339("2026-03-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let first: Vec<i32> = cached_at(&dir, "markets:req", 60, t0, fetch).await.unwrap();
+        let later = t0 + chrono::Duration::seconds(59);
+        let second: Vec<i32> = cached_at(&dir, "markets:req", 60, later, fetch)
+            .await
+            .unwrap();
+        assert_eq!(fetches.get(), 1);
+        assert_eq!(first, second);
+
+        let other: Vec<i32> = cached_at(&dir, "tags:req", 60, later, fetch).await.unwrap();
+        assert_eq!((fetches.get(), other), (2, vec![2]));
+
+        let expired = t0 + chrono::Duration::seconds(60);
+        let third: Vec<i32> = cached_at(&dir, "markets:req", 60, expired, fetch)
+            .await
+            .unwrap();
+        assert_eq!((fetches.get(), third), (3, vec![3]));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -3,14 +3,17 @@
 
 use alloy::primitives::U256;
 use alloy::sol;
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use clap::{Args, Subcommand};
-use polymarket_client_sdk::types::{Address, address};
+use polymarket_client_sdk::types::{Address, Decimal, address};
 use polymarket_client_sdk::{POLYGON, contract_config};
 
 use crate::auth;
 use crate::output::OutputFormat;
-use crate::output::approve::{ApprovalStatus, print_approval_status, print_tx_result};
+use crate::output::approve::{
+    AllowanceRequirement, ApprovalStatus, format_usdc, print_allowance_requirement,
+    print_approval_status, print_tx_result,
+};
 
 const USDC_ADDRESS: Address = address!("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174");
 
@@ -19,6 +22,7 @@ sol! {
     interface IERC20 {
         function approve(address spender, uint256 value) external returns (bool);
         function allowance(address owner, address spender) external view returns (uint256);
+        function balanceOf(address account) external view returns (uint256);
     }
 
     #[sol(rpc)]
@@ -40,6 +44,12 @@ pub enum ApproveCommand {
     Check {
         /// Wallet address to check (defaults to configured wallet)
         address: Option<String>,
+        /// Only check the USDC allowance granted to this spender
+        #[arg(long, requires = "min_amount")]
+        spender: Option<String>,
+        /// Fail unless the spender's allowance is at least this many USDC
+        #[arg(long, requires = "spender")]
+        min_amount: Option<Decimal>,
     },
     /// Approve all required contracts for trading (sends on-chain transactions)
     Set,
@@ -82,22 +92,99 @@ pub async fn execute(
     private_key: Option<&str>,
 ) -> Result<()> {
     match args.command {
-        ApproveCommand::Check { address } => check(address.as_deref(), private_key, output).await,
+        ApproveCommand::Check {
+            address,
+            spender,
+            min_amount,
+        } => match (spender, min_amount) {
+            (Some(spender), Some(min_amount)) => {
+                check_requirement(
+                    address.as_deref(),
+                    &spender,
+                    min_amount,
+                    private_key,
+                    output,
+                )
+                .await
+            }
+            _ => check(address.as_deref(), private_key, output).await,
+        },
         ApproveCommand::Set => set(private_key, output).await,
     }
 }
 
-async fn check(
+fn resolve_owner(address_arg: Option<&str>, private_key: Option<&str>) -> Result<Address> {
+    if let Some(addr) = address_arg {
+        super::parse_address(addr)
+    } else {
+        let signer = auth::resolve_signer(private_key)?;
+        Ok(polymarket_client_sdk::auth::Signer::address(&signer))
+    }
+}
+
+/// On-chain USDC balance of `owner`, in base units.
+pub(crate) async fn usdc_balance(owner: Address) -> Result<U256> {
+    let provider = auth::create_readonly_provider().await?;
+    IERC20::new(USDC_ADDRESS, provider)
+        .balanceOf(owner)
+        .call()
+        .await
+        .context("Failed to read USDC balance")
+}
+
+/// Converts a USDC amount to base units (6 decimals), dropping sub-unit dust.
+fn usdc_to_base_units(amount: Decimal) -> Result<U256> {
+    if amount.is_sign_negative() {
+        bail!("--min-amount must not be negative");
+    }
+    let raw = (amount * Decimal::from(1_000_000)).trunc();
+    raw.to_string().parse().context("--min-amount is too large")
+}
+
+async fn check_requirement(
     address_arg: Option<&str>,
+    spender: &str,
+    min_amount: Decimal,
     private_key: Option<&str>,
     output: OutputFormat,
 ) -> Result<()> {
-    let owner: Address = if let Some(addr) = address_arg {
-        super::parse_address(addr)?
-    } else {
-        let signer = auth::resolve_signer(private_key)?;
-        polymarket_client_sdk::auth::Signer::address(&signer)
+    let spender = super::parse_address(spender)?;
+    let required = usdc_to_base_units(min_amount)?;
+    let owner = resolve_owner(address_arg, private_key)?;
+
+    let provider = auth::create_readonly_provider().await?;
+    let usdc = IERC20::new(USDC_ADDRESS, provider);
+    let current = usdc
+        .allowance(owner, spender)
+        .call()
+        .await
+        .context("Failed to read USDC allowance")?;
+
+    let requirement = AllowanceRequirement {
+        spender: format!("{spender}"),
+        current,
+        required,
     };
+    print_allowance_requirement(&requirement, &output)?;
+    if !requirement.sufficient() {
+        // The requirement printed above already carries `sufficient: false`,
+        // so json/yaml output stays a single document.
+        return Err(crate::output::ReportedFailure(format!(
+            "USDC allowance for {spender} is {} short of the required {}",
+            format_usdc(requirement.gap()),
+            format_usdc(required)
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+async fn check(
+    address_arg: Option<&str>,
+    private_key: Option<&str>,
+    output: OutputFormat,
+) -> Result<()> {
+    let owner = resolve_owner(address_arg, private_key)?;
 
     let provider = auth::create_readonly_provider().await?;
     let config = contract_config(POLYGON, false).context("No contract config for Polygon")?;
@@ -168,7 +255,7 @@ async fn set(private_key: Option<&str>, output: OutputFormat) -> Result<()> {
 
         match output {
             OutputFormat::Table => print_tx_result(step, total, &label, tx_hash),
-            OutputFormat::Json => results.push(serde_json::json!({
+            OutputFormat::Json | OutputFormat::Yaml => results.push(serde_json::json!({
                 "step": step,
                 "type": "erc20",
                 "contract": target.name,
@@ -192,7 +279,7 @@ async fn set(private_key: Option<&str>, output: OutputFormat) -> Result<()> {
 
         match output {
             OutputFormat::Table => print_tx_result(step, total, &label, tx_hash),
-            OutputFormat::Json => results.push(serde_json::json!({
+            OutputFormat::Json | OutputFormat::Yaml => results.push(serde_json::json!({
                 "step": step,
                 "type": "erc1155",
                 "contract": target.name,
@@ -205,8 +292,8 @@ async fn set(private_key: Option<&str>, output: OutputFormat) -> Result<()> {
         OutputFormat::Table => {
             println!("\nAll contracts approved. You're ready to trade.");
         }
-        OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(&results)?);
+        OutputFormat::Json | OutputFormat::Yaml => {
+            crate::output::print_json(&results)?;
         }
     }
 
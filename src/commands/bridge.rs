@@ -1,6 +1,12 @@
 use super::parse_address;
+use crate::net::timed;
 use crate::output::OutputFormat;
-use crate::output::bridge::{print_deposit, print_status, print_supported_assets};
+use crate::output::bridge::{
+    all_terminal, print_deposit, print_status, print_supported_assets, print_track_summary,
+    status_signature,
+};
+use std::time::{Duration, Instant};
+
 use anyhow::Result;
 use clap::{Args, Subcommand};
 use polymarket_client_sdk::bridge::{
@@ -23,15 +29,34 @@ pub enum BridgeCommand {
     },
 
     /// List supported chains and tokens for deposits
-    SupportedAssets,
+    #[command(visible_alias = "assets")]
+    SupportedAssets {
+        /// Only show assets on this chain (chain ID or name, e.g. 137 or polygon)
+        #[arg(long)]
+        chain: Option<String>,
+        /// Only show this token symbol (e.g. USDC)
+        #[arg(long)]
+        token: Option<String>,
+    },
 
     /// Check deposit transaction status for an address
     Status {
         /// Deposit address (EVM, Solana, or Bitcoin)
         address: String,
     },
+
+    /// Poll deposit status until every transaction is Completed or Failed
+    Track {
+        /// Deposit address (EVM, Solana, or Bitcoin)
+        address: String,
+        /// Give up after this many seconds
+        #[arg(long, default_value = "900", value_parser = clap::value_parser!(u64).range(1..))]
+        timeout_secs: u64,
+    },
 }
 
+const TRACK_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
 pub async fn execute(
     client: &bridge::Client,
     args: BridgeArgs,
@@ -43,23 +68,74 @@ pub async fn execute(
                 .address(parse_address(&address)?)
                 .build();
 
-            let response = client.deposit(&request).await?;
+            let response = timed(client.deposit(&request)).await?;
             print_deposit(&response, &output)?;
         }
 
-        BridgeCommand::SupportedAssets => {
-            let response = client.supported_assets().await?;
-            print_supported_assets(&response, &output)?;
+        BridgeCommand::SupportedAssets { chain, token } => {
+            let response = timed(client.supported_assets()).await?;
+            print_supported_assets(&response, chain.as_deref(), token.as_deref(), &output)?;
         }
 
         BridgeCommand::Status { address } => {
             anyhow::ensure!(!address.trim().is_empty(), "Address cannot be empty");
             let request = StatusRequest::builder().address(&address).build();
 
-            let response = client.status(&request).await?;
+            let response = timed(client.status(&request)).await?;
             print_status(&response, &output)?;
         }
+
+        BridgeCommand::Track {
+            address,
+            timeout_secs,
+        } => {
+            anyhow::ensure!(!address.trim().is_empty(), "Address cannot be empty");
+            track_status(client, &address, Duration::from_secs(timeout_secs), &output).await?;
+        }
     }
 
     Ok(())
 }
+
+/// Re-polls `status` until every transaction is terminal or `timeout` passes.
+/// Table output redraws whenever a status changes; JSON prints one summary at
+/// the end. Poll errors are reported and retried rather than ending tracking.
+async fn track_status(
+    client: &bridge::Client,
+    address: &str,
+    timeout: Duration,
+    output: &OutputFormat,
+) -> Result<()> {
+    let request = StatusRequest::builder().address(address).build();
+    let deadline = Instant::now() + timeout;
+    let mut last = None;
+    let mut shown_signature = None;
+    loop {
+        match timed(client.status(&request)).await {
+            Ok(response) => {
+                let signature = status_signature(&response);
+                if matches!(output, OutputFormat::Table)
+                    && shown_signature.as_ref() != Some(&signature)
+                {
+                    if std::io::IsTerminal::is_terminal(&std::io::stdout()) {
+                        print!("\x1b[2J\x1b[H");
+                    }
+                    println!("Tracking deposits for {address} (Ctrl-C to stop)");
+                    print_status(&response, output)?;
+                    shown_signature = Some(signature);
+                }
+                let done = all_terminal(&response);
+                last = Some(response);
+                if done {
+                    break;
+                }
+            }
+            Err(e) => tracing::warn!("could not fetch bridge status: {e}"),
+        }
+        if Instant::now() + TRACK_POLL_INTERVAL > deadline {
+            break;
+        }
+        tokio::time::sleep(TRACK_POLL_INTERVAL).await;
+    }
+    print_track_summary(address, last.as_ref(), output)
+}
@@ -0,0 +1,31 @@
+use anyhow::Result;
+use clap::{Args, Subcommand};
+
+use crate::output::{OutputFormat, print_json};
+
+#[derive(Args)]
+pub struct CacheArgs {
+    #[command(subcommand)]
+    pub command: CacheCommand,
+}
+
+#[derive(Subcommand)]
+pub enum CacheCommand {
+    /// Delete every cached Gamma response
+    Clear,
+}
+
+pub fn execute(args: &CacheArgs, output: OutputFormat) -> Result<()> {
+    match args.command {
+        CacheCommand::Clear => {
+            let removed = crate::cache::clear()?;
+            match output {
+                OutputFormat::Table => println!("Removed {removed} cached response(s)."),
+                OutputFormat::Json | OutputFormat::Yaml => {
+                    print_json(&serde_json::json!({ "removed": removed }))?;
+                }
+            }
+            Ok(())
+        }
+    }
+}
@@ -1,6 +1,8 @@
+use std::path::PathBuf;
 use std::str::FromStr;
+use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use chrono::NaiveDate;
 use clap::{Args, Subcommand};
 use polymarket_client_sdk::clob;
@@ -12,21 +14,29 @@ use polymarket_client_sdk::clob::types::{
         PriceHistoryRequest, PriceRequest, SpreadRequest, TradesRequest, UserRewardsEarningRequest,
     },
 };
+use polymarket_client_sdk::gamma::{
+    self,
+    types::request::{MarketBySlugRequest, MarketsRequest},
+};
 use polymarket_client_sdk::types::{Decimal, U256};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
 
-use super::parse_condition_id;
+use super::{normalize_slug_input, parse_condition_id};
 use crate::auth;
+use crate::net::{retry, timed};
 use crate::output::OutputFormat;
 use crate::output::clob::{
-    print_account_status, print_api_keys, print_balance, print_batch_prices, print_cancel_result,
-    print_clob_market, print_clob_markets, print_create_api_key, print_current_rewards,
-    print_delete_api_key, print_earnings, print_fee_rate, print_geoblock, print_last_trade,
-    print_last_trades_prices, print_market_reward, print_midpoint, print_midpoints, print_neg_risk,
-    print_notifications, print_ok, print_order_book, print_order_books, print_order_detail,
-    print_order_scoring, print_orders, print_orders_scoring, print_post_order_result,
-    print_post_orders_result, print_price, print_price_history, print_reward_percentages,
-    print_rewards, print_server_time, print_simplified_markets, print_spread, print_spreads,
-    print_tick_size, print_trades, print_user_earnings_markets,
+    print_account_fees, print_account_status, print_api_keys, print_balance, print_batch_prices,
+    print_book_ladder, print_cancel_result, print_clob_market, print_clob_markets,
+    print_create_api_key, print_current_rewards, print_delete_api_key, print_depth, print_earnings,
+    print_fee_rate, print_geoblock, print_last_trade, print_last_trades_prices,
+    print_market_reward, print_midpoint, print_midpoints, print_neg_risk, print_notifications,
+    print_ok, print_order_books, print_order_detail, print_order_scoring, print_orders,
+    print_orders_scoring, print_post_order_result, print_post_orders_result, print_price,
+    print_price_history, print_price_list, print_reward_percentages, print_rewards,
+    print_server_time, print_simplified_markets, print_spread, print_spreads, print_tick_size,
+    print_trades, print_user_earnings_markets, with_cumulative,
 };
 
 #[derive(Args)]
@@ -43,10 +53,17 @@ pub enum ClobCommand {
     /// Get price for a token
     Price {
         /// Token ID (numeric string)
-        token_id: String,
+        #[arg(
+            required_unless_present = "tokens_file",
+            conflicts_with = "tokens_file"
+        )]
+        token_id: Option<String>,
         /// Side: buy or sell
         #[arg(long)]
         side: CliSide,
+        /// File with one token ID or market slug per line; prices are fetched concurrently
+        #[arg(long)]
+        tokens_file: Option<PathBuf>,
     },
 
     /// Get prices for specific tokens (batch)
@@ -89,6 +106,27 @@ pub enum ClobCommand {
     Book {
         /// Token ID (numeric string)
         token_id: String,
+        /// Show the best N levels per side (0 for the whole book)
+        #[arg(long, visible_alias = "levels", default_value_t = 10)]
+        depth: usize,
+        /// Add running size and notional columns per side
+        #[arg(long, visible_alias = "json-levels")]
+        cumulative: bool,
+    },
+
+    /// Order-book depth, imbalance and fill-price metrics for a token
+    Depth {
+        /// Token ID (numeric string)
+        token_id: String,
+        /// Count resting size within this many ticks of the midpoint
+        #[arg(long, default_value_t = 5)]
+        ticks: u32,
+        /// Size in shares to estimate an average fill price for
+        #[arg(long)]
+        size: Option<Decimal>,
+        /// Side that would fill --size (buy walks asks, sell walks bids)
+        #[arg(long, default_value = "buy")]
+        side: CliSide,
     },
 
     /// Get order books for multiple tokens
@@ -391,6 +429,17 @@ pub enum ClobCommand {
 
     /// Check account status (authenticated)
     AccountStatus,
+
+    /// Show the maker/taker fee rate this account pays on a market (authenticated)
+    #[command(group(clap::ArgGroup::new("target").required(true).args(["token", "market"])))]
+    Fees {
+        /// Token ID (numeric string)
+        #[arg(long)]
+        token: Option<String>,
+        /// Market slug (uses the market's first outcome token)
+        #[arg(long)]
+        market: Option<String>,
+    },
 }
 
 #[derive(Clone, Debug, clap::ValueEnum)]
@@ -474,10 +523,285 @@ impl From<CliAssetType> for AssetType {
     }
 }
 
-fn parse_token_id(s: &str) -> Result<U256> {
+/// Result of walking one side of an order book from the best price outward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BookFill {
+    pub shares: Decimal,
+    pub notional: Decimal,
+    pub complete: bool,
+}
+
+impl BookFill {
+    pub fn avg_price(&self) -> Option<Decimal> {
+        (self.shares > Decimal::ZERO).then(|| self.notional / self.shares)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum FillTarget {
+    /// Spend up to this much USDC (notional).
+    Notional(Decimal),
+    /// Fill up to this many shares.
+    Shares(Decimal),
+}
+
+/// Walks `(price, size)` levels best-first until `target` is filled. Asks are
+/// consumed lowest price first (`best_is_lowest`), bids highest price first.
+pub fn walk_book(
+    levels: &[(Decimal, Decimal)],
+    best_is_lowest: bool,
+    target: FillTarget,
+) -> BookFill {
+    let mut sorted: Vec<(Decimal, Decimal)> = levels
+        .iter()
+        .copied()
+        .filter(|(price, size)| *price > Decimal::ZERO && *size > Decimal::ZERO)
+        .collect();
+    if best_is_lowest {
+        sorted.sort_by_key(|l| l.0);
+    } else {
+        sorted.sort_by_key(|l| std::cmp::Reverse(l.0));
+    }
+
+    let mut remaining = match target {
+        FillTarget::Notional(v) | FillTarget::Shares(v) => v,
+    };
+    let mut shares = Decimal::ZERO;
+    let mut notional = Decimal::ZERO;
+    for (price, size) in sorted {
+        if remaining <= Decimal::ZERO {
+            break;
+        }
+        match target {
+            FillTarget::Notional(_) => {
+                let take = (size * price).min(remaining);
+                shares += take / price;
+                notional += take;
+                remaining -= take;
+            }
+            FillTarget::Shares(_) => {
+                let take = size.min(remaining);
+                shares += take;
+                notional += take * price;
+                remaining -= take;
+            }
+        }
+    }
+
+    BookFill {
+        shares,
+        notional,
+        complete: remaining <= Decimal::ZERO,
+    }
+}
+
+/// Orders `(price, size)` levels best-first (lowest price first for asks),
+/// keeping at most `depth` of them; 0 keeps the whole side.
+fn best_levels(
+    levels: &[(Decimal, Decimal)],
+    best_is_lowest: bool,
+    depth: usize,
+) -> Vec<(Decimal, Decimal)> {
+    let mut sorted = levels.to_vec();
+    if best_is_lowest {
+        sorted.sort_by_key(|l| l.0);
+    } else {
+        sorted.sort_by_key(|l| std::cmp::Reverse(l.0));
+    }
+    if depth > 0 {
+        sorted.truncate(depth);
+    }
+    sorted
+}
+
+#[derive(Debug)]
+pub struct DepthMetrics {
+    pub mid: Option<Decimal>,
+    pub ticks: u32,
+    pub bid_depth: Decimal,
+    pub ask_depth: Decimal,
+    /// (bid - ask) / (bid + ask), in [-1, 1]; positive means bid-heavy.
+    pub imbalance: Option<Decimal>,
+    pub size: Option<Decimal>,
+    pub side: &'static str,
+    pub fill: Option<BookFill>,
+}
+
+fn depth_metrics(
+    bids: &[(Decimal, Decimal)],
+    asks: &[(Decimal, Decimal)],
+    tick: Decimal,
+    ticks: u32,
+    size: Option<Decimal>,
+    buy: bool,
+) -> DepthMetrics {
+    let best_bid = bids.iter().map(|l| l.0).max();
+    let best_ask = asks.iter().map(|l| l.0).min();
+    let mid = match (best_bid, best_ask) {
+        (Some(b), Some(a)) => Some((b + a) / Decimal::TWO),
+        _ => None,
+    };
+    let band = tick * Decimal::from(ticks);
+    let within = |price: Decimal| mid.is_none_or(|m| (price - m).abs() <= band);
+    let bid_depth: Decimal = bids.iter().filter(|l| within(l.0)).map(|l| l.1).sum();
+    let ask_depth: Decimal = asks.iter().filter(|l| within(l.0)).map(|l| l.1).sum();
+    let total = bid_depth + ask_depth;
+    let imbalance = (total > Decimal::ZERO).then(|| (bid_depth - ask_depth) / total);
+    let fill = size.map(|shares| {
+        if buy {
+            walk_book(asks, true, FillTarget::Shares(shares))
+        } else {
+            walk_book(bids, false, FillTarget::Shares(shares))
+        }
+    });
+
+    DepthMetrics {
+        mid,
+        ticks,
+        bid_depth,
+        ask_depth,
+        imbalance,
+        size,
+        side: if buy { "buy" } else { "sell" },
+        fill,
+    }
+}
+
+pub(crate) fn parse_token_id(s: &str) -> Result<U256> {
     U256::from_str(s).map_err(|_| anyhow::anyhow!("Invalid token ID: {s}"))
 }
 
+async fn resolve_market_token(slug: &str) -> Result<U256> {
+    let slug = normalize_slug_input(slug);
+    let request = MarketBySlugRequest::builder().slug(&slug).build();
+    let client = gamma::Client::default();
+    let market = retry(|| client.market_by_slug(&request))
+        .await
+        .with_context(|| format!("Market not found: {slug}"))?;
+    market
+        .clob_token_ids
+        .and_then(|ids| ids.into_iter().next())
+        .ok_or_else(|| anyhow::anyhow!("Market {slug} has no CLOB token IDs"))
+}
+
+/// The token `clob fees` quotes and its market's maker base fee, from either
+/// `--token` or `--market`. A token with no gamma market has no maker fee.
+async fn fee_market(token: Option<String>, market: Option<String>) -> Result<(U256, Option<i32>)> {
+    let gamma = gamma::Client::default();
+    match (token, market) {
+        (Some(token), _) => {
+            let token_id = parse_token_id(&token)?;
+            let request = MarketsRequest::builder()
+                .clob_token_ids(vec![token_id])
+                .build();
+            let markets = retry(|| gamma.markets(&request)).await?;
+            Ok((token_id, markets.first().and_then(|m| m.maker_base_fee)))
+        }
+        (None, Some(slug)) => {
+            let slug = normalize_slug_input(&slug);
+            let request = MarketBySlugRequest::builder().slug(&slug).build();
+            let market = retry(|| gamma.market_by_slug(&request))
+                .await
+                .with_context(|| format!("Market not found: {slug}"))?;
+            let token_id = market
+                .clob_token_ids
+                .as_ref()
+                .and_then(|ids| ids.first().copied())
+                .ok_or_else(|| anyhow::anyhow!("Market {slug} has no CLOB token IDs"))?;
+            Ok((token_id, market.maker_base_fee))
+        }
+        (None, None) => unreachable!("clap requires --token or --market"),
+    }
+}
+
+/// Token IDs or market slugs from a `--tokens-file`: one per line, blank
+/// lines and `#` comments skipped.
+fn parse_tokens_file(contents: &str) -> Vec<String> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(str::to_string)
+        .collect()
+}
+
+async fn resolve_token_input(input: &str) -> Result<U256> {
+    if input.chars().all(|c| c.is_ascii_digit()) {
+        parse_token_id(input)
+    } else {
+        resolve_market_token(input).await
+    }
+}
+
+const MAX_CONCURRENT_PRICE_REQUESTS: usize = 8;
+
+/// Fetches one price per input with at most `MAX_CONCURRENT_PRICE_REQUESTS`
+/// in flight. Results keep the input order; a failure only affects its line.
+async fn fetch_prices(inputs: &[String], side: Side) -> Vec<Result<Decimal>> {
+    let permits = Arc::new(Semaphore::new(MAX_CONCURRENT_PRICE_REQUESTS));
+    let mut tasks = JoinSet::new();
+    for (i, input) in inputs.iter().cloned().enumerate() {
+        let permits = Arc::clone(&permits);
+        tasks.spawn(async move {
+            let _permit = permits.acquire_owned().await;
+            let result = async {
+                let token_id = resolve_token_input(&input).await?;
+                let request = PriceRequest::builder()
+                    .token_id(token_id)
+                    .side(side)
+                    .build();
+                let response = timed(clob::Client::default().price(&request)).await?;
+                Ok(response.price)
+            }
+            .await;
+            (i, result)
+        });
+    }
+
+    let mut results: Vec<Result<Decimal>> = inputs
+        .iter()
+        .map(|_| Err(anyhow::anyhow!("price request did not complete")))
+        .collect();
+    while let Some(joined) = tasks.join_next().await {
+        if let Ok((i, result)) = joined {
+            results[i] = result;
+        }
+    }
+    results
+}
+
+impl ClobCommand {
+    /// Whether the command signs or authenticates, so needs the private key.
+    pub(crate) fn needs_wallet(&self) -> bool {
+        !matches!(
+            self,
+            ClobCommand::Ok
+                | ClobCommand::Price { .. }
+                | ClobCommand::BatchPrices { .. }
+                | ClobCommand::Midpoint { .. }
+                | ClobCommand::Midpoints { .. }
+                | ClobCommand::Spread { .. }
+                | ClobCommand::Spreads { .. }
+                | ClobCommand::Book { .. }
+                | ClobCommand::Depth { .. }
+                | ClobCommand::Books { .. }
+                | ClobCommand::LastTrade { .. }
+                | ClobCommand::LastTrades { .. }
+                | ClobCommand::Market { .. }
+                | ClobCommand::Markets { .. }
+                | ClobCommand::SamplingMarkets { .. }
+                | ClobCommand::SimplifiedMarkets { .. }
+                | ClobCommand::SamplingSimpMarkets { .. }
+                | ClobCommand::TickSize { .. }
+                | ClobCommand::FeeRate { .. }
+                | ClobCommand::NegRisk { .. }
+                | ClobCommand::PriceHistory { .. }
+                | ClobCommand::Time
+                | ClobCommand::Geoblock
+        )
+    }
+}
+
 fn parse_token_ids(s: &str) -> Result<Vec<U256>> {
     s.split(',').map(|t| parse_token_id(t.trim())).collect()
 }
@@ -503,6 +827,7 @@ pub async fn execute(
         | ClobCommand::Spread { .. }
         | ClobCommand::Spreads { .. }
         | ClobCommand::Book { .. }
+        | ClobCommand::Depth { .. }
         | ClobCommand::Books { .. }
         | ClobCommand::LastTrade { .. }
         | ClobCommand::LastTrades { .. }
@@ -552,7 +877,8 @@ pub async fn execute(
         ClobCommand::ApiKeys
         | ClobCommand::DeleteApiKey
         | ClobCommand::CreateApiKey
-        | ClobCommand::AccountStatus => {
+        | ClobCommand::AccountStatus
+        | ClobCommand::Fees { .. } => {
             execute_account(args.command, &output, private_key, signature_type).await
         }
     }
@@ -562,17 +888,30 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
     match command {
         ClobCommand::Ok => {
             let client = clob::Client::default();
-            let result = client.ok().await?;
+            let result = timed(client.ok()).await?;
             print_ok(&result, output)?;
         }
 
-        ClobCommand::Price { token_id, side } => {
+        ClobCommand::Price {
+            token_id,
+            side,
+            tokens_file,
+        } => {
+            if let Some(path) = tokens_file {
+                let contents = std::fs::read_to_string(&path)
+                    .with_context(|| format!("Could not read {}", path.display()))?;
+                let inputs = parse_tokens_file(&contents);
+                let results = fetch_prices(&inputs, Side::from(side)).await;
+                print_price_list(&inputs, &results, output)?;
+                return Ok(());
+            }
+            let token_id = token_id.expect("clap requires token_id without --tokens-file");
             let client = clob::Client::default();
             let request = PriceRequest::builder()
                 .token_id(parse_token_id(&token_id)?)
                 .side(Side::from(side))
                 .build();
-            let result = client.price(&request).await?;
+            let result = timed(client.price(&request)).await?;
             print_price(&result, output)?;
         }
 
@@ -587,7 +926,7 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
                         .build()
                 })
                 .collect();
-            let result = client.prices(&requests).await?;
+            let result = timed(client.prices(&requests)).await?;
             print_batch_prices(&result, output)?;
         }
 
@@ -596,7 +935,7 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
             let request = MidpointRequest::builder()
                 .token_id(parse_token_id(&token_id)?)
                 .build();
-            let result = client.midpoint(&request).await?;
+            let result = timed(client.midpoint(&request)).await?;
             print_midpoint(&result, output)?;
         }
 
@@ -606,7 +945,7 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
                 .into_iter()
                 .map(|id| MidpointRequest::builder().token_id(id).build())
                 .collect();
-            let result = client.midpoints(&requests).await?;
+            let result = timed(client.midpoints(&requests)).await?;
             print_midpoints(&result, output)?;
         }
 
@@ -616,7 +955,7 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
                 .token_id(parse_token_id(&token_id)?)
                 .maybe_side(side.map(Side::from))
                 .build();
-            let result = client.spread(&request).await?;
+            let result = timed(client.spread(&request)).await?;
             print_spread(&result, output)?;
         }
 
@@ -626,17 +965,53 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
                 .into_iter()
                 .map(|id| SpreadRequest::builder().token_id(id).build())
                 .collect();
-            let result = client.spreads(&requests).await?;
+            let result = timed(client.spreads(&requests)).await?;
             print_spreads(&result, output)?;
         }
 
-        ClobCommand::Book { token_id } => {
+        ClobCommand::Book {
+            token_id,
+            depth,
+            cumulative,
+        } => {
+            let client = clob::Client::default();
+            let request = OrderBookSummaryRequest::builder()
+                .token_id(parse_token_id(&token_id)?)
+                .build();
+            let result = timed(client.order_book(&request)).await?;
+            let bids: Vec<_> = result.bids.iter().map(|o| (o.price, o.size)).collect();
+            let asks: Vec<_> = result.asks.iter().map(|o| (o.price, o.size)).collect();
+            print_book_ladder(
+                &result,
+                &with_cumulative(&best_levels(&bids, false, depth)),
+                &with_cumulative(&best_levels(&asks, true, depth)),
+                cumulative,
+                output,
+            )?;
+        }
+
+        ClobCommand::Depth {
+            token_id,
+            ticks,
+            size,
+            side,
+        } => {
             let client = clob::Client::default();
             let request = OrderBookSummaryRequest::builder()
                 .token_id(parse_token_id(&token_id)?)
                 .build();
-            let result = client.order_book(&request).await?;
-            print_order_book(&result, output)?;
+            let book = timed(client.order_book(&request)).await?;
+            let bids: Vec<_> = book.bids.iter().map(|o| (o.price, o.size)).collect();
+            let asks: Vec<_> = book.asks.iter().map(|o| (o.price, o.size)).collect();
+            let metrics = depth_metrics(
+                &bids,
+                &asks,
+                book.tick_size.as_decimal(),
+                ticks,
+                size,
+                matches!(side, CliSide::Buy),
+            );
+            print_depth(&metrics, output)?;
         }
 
         ClobCommand::Books { token_ids } => {
@@ -645,7 +1020,7 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
                 .into_iter()
                 .map(|id| OrderBookSummaryRequest::builder().token_id(id).build())
                 .collect();
-            let result = client.order_books(&requests).await?;
+            let result = timed(client.order_books(&requests)).await?;
             print_order_books(&result, output)?;
         }
 
@@ -654,7 +1029,7 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
             let request = LastTradePriceRequest::builder()
                 .token_id(parse_token_id(&token_id)?)
                 .build();
-            let result = client.last_trade_price(&request).await?;
+            let result = timed(client.last_trade_price(&request)).await?;
             print_last_trade(&result, output)?;
         }
 
@@ -664,55 +1039,55 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
                 .into_iter()
                 .map(|id| LastTradePriceRequest::builder().token_id(id).build())
                 .collect();
-            let result = client.last_trades_prices(&requests).await?;
+            let result = timed(client.last_trades_prices(&requests)).await?;
             print_last_trades_prices(&result, output)?;
         }
 
         ClobCommand::Market { condition_id } => {
             let client = clob::Client::default();
-            let result = client.market(&condition_id).await?;
+            let result = timed(client.market(&condition_id)).await?;
             print_clob_market(&result, output)?;
         }
 
         ClobCommand::Markets { cursor } => {
             let client = clob::Client::default();
-            let result = client.markets(cursor).await?;
+            let result = timed(client.markets(cursor)).await?;
             print_clob_markets(&result, output)?;
         }
 
         ClobCommand::SamplingMarkets { cursor } => {
             let client = clob::Client::default();
-            let result = client.sampling_markets(cursor).await?;
+            let result = timed(client.sampling_markets(cursor)).await?;
             print_clob_markets(&result, output)?;
         }
 
         ClobCommand::SimplifiedMarkets { cursor } => {
             let client = clob::Client::default();
-            let result = client.simplified_markets(cursor).await?;
+            let result = timed(client.simplified_markets(cursor)).await?;
             print_simplified_markets(&result, output)?;
         }
 
         ClobCommand::SamplingSimpMarkets { cursor } => {
             let client = clob::Client::default();
-            let result = client.sampling_simplified_markets(cursor).await?;
+            let result = timed(client.sampling_simplified_markets(cursor)).await?;
             print_simplified_markets(&result, output)?;
         }
 
         ClobCommand::TickSize { token_id } => {
             let client = clob::Client::default();
-            let result = client.tick_size(parse_token_id(&token_id)?).await?;
+            let result = timed(client.tick_size(parse_token_id(&token_id)?)).await?;
             print_tick_size(&result, output)?;
         }
 
         ClobCommand::FeeRate { token_id } => {
             let client = clob::Client::default();
-            let result = client.fee_rate_bps(parse_token_id(&token_id)?).await?;
+            let result = timed(client.fee_rate_bps(parse_token_id(&token_id)?)).await?;
             print_fee_rate(&result, output)?;
         }
 
         ClobCommand::NegRisk { token_id } => {
             let client = clob::Client::default();
-            let result = client.neg_risk(parse_token_id(&token_id)?).await?;
+            let result = timed(client.neg_risk(parse_token_id(&token_id)?)).await?;
             print_neg_risk(&result, output)?;
         }
 
@@ -727,19 +1102,19 @@ async fn execute_read(command: ClobCommand, output: &OutputFormat) -> Result<()>
                 .time_range(TimeRange::from_interval(Interval::from(interval)))
                 .maybe_fidelity(fidelity)
                 .build();
-            let result = client.price_history(&request).await?;
+            let result = timed(client.price_history(&request)).await?;
             print_price_history(&result, output)?;
         }
 
         ClobCommand::Time => {
             let client = clob::Client::default();
-            let result = client.server_time().await?;
+            let result = timed(client.server_time()).await?;
             print_server_time(result, output)?;
         }
 
         ClobCommand::Geoblock => {
             let client = clob::Client::default();
-            let result = client.check_geoblock().await?;
+            let result = timed(client.check_geoblock()).await?;
             print_geoblock(&result, output)?;
         }
 
@@ -766,13 +1141,13 @@ async fn execute_trade(
                 .maybe_market(market.map(|m| parse_condition_id(&m)).transpose()?)
                 .maybe_asset_id(asset.map(|a| parse_token_id(&a)).transpose()?)
                 .build();
-            let result = client.orders(&request, cursor).await?;
+            let result = timed(client.orders(&request, cursor)).await?;
             print_orders(&result, output)?;
         }
 
         ClobCommand::Order { order_id } => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
-            let result = client.order(&order_id).await?;
+            let result = timed(client.order(&order_id)).await?;
             print_order_detail(&result, output)?;
         }
 
@@ -803,7 +1178,7 @@ async fn execute_trade(
                 .build()
                 .await?;
             let order = client.sign(&signer, order).await?;
-            let result = client.post_order(order).await?;
+            let result = timed(client.post_order(order)).await?;
             print_post_order_result(&result, output)?;
         }
 
@@ -851,7 +1226,7 @@ async fn execute_trade(
                 signed_orders.push(client.sign(&signer, order).await?);
             }
 
-            let results = client.post_orders(signed_orders).await?;
+            let results = timed(client.post_orders(signed_orders)).await?;
             print_post_orders_result(&results, output)?;
         }
 
@@ -882,26 +1257,26 @@ async fn execute_trade(
                 .build()
                 .await?;
             let order = client.sign(&signer, order).await?;
-            let result = client.post_order(order).await?;
+            let result = timed(client.post_order(order)).await?;
             print_post_order_result(&result, output)?;
         }
 
         ClobCommand::Cancel { order_id } => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
-            let result = client.cancel_order(&order_id).await?;
+            let result = timed(client.cancel_order(&order_id)).await?;
             print_cancel_result(&result, output)?;
         }
 
         ClobCommand::CancelOrders { order_ids } => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
             let ids: Vec<&str> = order_ids.split(',').map(str::trim).collect();
-            let result = client.cancel_orders(&ids).await?;
+            let result = timed(client.cancel_orders(&ids)).await?;
             print_cancel_result(&result, output)?;
         }
 
         ClobCommand::CancelAll => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
-            let result = client.cancel_all_orders().await?;
+            let result = timed(client.cancel_all_orders()).await?;
             print_cancel_result(&result, output)?;
         }
 
@@ -911,7 +1286,7 @@ async fn execute_trade(
                 .maybe_market(market.map(|m| parse_condition_id(&m)).transpose()?)
                 .maybe_asset_id(asset.map(|a| parse_token_id(&a)).transpose()?)
                 .build();
-            let result = client.cancel_market_orders(&request).await?;
+            let result = timed(client.cancel_market_orders(&request)).await?;
             print_cancel_result(&result, output)?;
         }
 
@@ -925,7 +1300,7 @@ async fn execute_trade(
                 .maybe_market(market.map(|m| parse_condition_id(&m)).transpose()?)
                 .maybe_asset_id(asset.map(|a| parse_token_id(&a)).transpose()?)
                 .build();
-            let result = client.trades(&request, cursor).await?;
+            let result = timed(client.trades(&request, cursor)).await?;
             print_trades(&result, output)?;
         }
 
@@ -936,7 +1311,7 @@ async fn execute_trade(
                 .asset_type(AssetType::from(asset_type))
                 .maybe_token_id(token.map(|t| parse_token_id(&t)).transpose()?)
                 .build();
-            let result = client.balance_allowance(request).await?;
+            let result = timed(client.balance_allowance(request)).await?;
             print_balance(&result, is_collateral, output)?;
         }
 
@@ -946,18 +1321,18 @@ async fn execute_trade(
                 .asset_type(AssetType::from(asset_type))
                 .maybe_token_id(token.map(|t| parse_token_id(&t)).transpose()?)
                 .build();
-            client.update_balance_allowance(request).await?;
+            timed(client.update_balance_allowance(request)).await?;
             match output {
                 OutputFormat::Table => println!("Balance allowance updated."),
-                OutputFormat::Json => {
-                    println!("{}", serde_json::json!({"success": true}));
+                OutputFormat::Json | OutputFormat::Yaml => {
+                    crate::output::print_json(&serde_json::json!({"success": true}))?;
                 }
             }
         }
 
         ClobCommand::Notifications => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
-            let result = client.notifications().await?;
+            let result = timed(client.notifications()).await?;
             print_notifications(&result, output)?;
         }
 
@@ -968,11 +1343,11 @@ async fn execute_trade(
             let request = DeleteNotificationsRequest::builder()
                 .notification_ids(notification_ids)
                 .build();
-            client.delete_notifications(&request).await?;
+            timed(client.delete_notifications(&request)).await?;
             match output {
                 OutputFormat::Table => println!("Notifications deleted."),
-                OutputFormat::Json => {
-                    println!("{}", serde_json::json!({"success": true}));
+                OutputFormat::Json | OutputFormat::Yaml => {
+                    crate::output::print_json(&serde_json::json!({"success": true}))?;
                 }
             }
         }
@@ -992,17 +1367,14 @@ async fn execute_rewards(
     match command {
         ClobCommand::Rewards { date, cursor } => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
-            let result = client
-                .earnings_for_user_for_day(parse_date(&date)?, cursor)
-                .await?;
+            let result =
+                timed(client.earnings_for_user_for_day(parse_date(&date)?, cursor)).await?;
             print_rewards(&result, output)?;
         }
 
         ClobCommand::Earnings { date } => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
-            let result = client
-                .total_earnings_for_user_for_day(parse_date(&date)?)
-                .await?;
+            let result = timed(client.total_earnings_for_user_for_day(parse_date(&date)?)).await?;
             print_earnings(&result, output)?;
         }
 
@@ -1011,21 +1383,19 @@ async fn execute_rewards(
             let request = UserRewardsEarningRequest::builder()
                 .date(parse_date(&date)?)
                 .build();
-            let result = client
-                .user_earnings_and_markets_config(&request, cursor)
-                .await?;
+            let result = timed(client.user_earnings_and_markets_config(&request, cursor)).await?;
             print_user_earnings_markets(&result, output)?;
         }
 
         ClobCommand::RewardPercentages => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
-            let result = client.reward_percentages().await?;
+            let result = timed(client.reward_percentages()).await?;
             print_reward_percentages(&result, output)?;
         }
 
         ClobCommand::CurrentRewards { cursor } => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
-            let result = client.current_rewards(cursor).await?;
+            let result = timed(client.current_rewards(cursor)).await?;
             print_current_rewards(&result, output)?;
         }
 
@@ -1034,20 +1404,20 @@ async fn execute_rewards(
             cursor,
         } => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
-            let result = client.raw_rewards_for_market(&condition_id, cursor).await?;
+            let result = timed(client.raw_rewards_for_market(&condition_id, cursor)).await?;
             print_market_reward(&result, output)?;
         }
 
         ClobCommand::OrderScoring { order_id } => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
-            let result = client.is_order_scoring(&order_id).await?;
+            let result = timed(client.is_order_scoring(&order_id)).await?;
             print_order_scoring(&result, output)?;
         }
 
         ClobCommand::OrdersScoring { order_ids } => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
             let ids: Vec<&str> = order_ids.split(',').map(str::trim).collect();
-            let result = client.are_orders_scoring(&ids).await?;
+            let result = timed(client.are_orders_scoring(&ids)).await?;
             print_orders_scoring(&result, output)?;
         }
 
@@ -1066,29 +1436,36 @@ async fn execute_account(
     match command {
         ClobCommand::ApiKeys => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
-            let result = client.api_keys().await?;
+            let result = timed(client.api_keys()).await?;
             print_api_keys(&result, output)?;
         }
 
         ClobCommand::DeleteApiKey => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
-            let result = client.delete_api_key().await?;
+            let result = timed(client.delete_api_key()).await?;
             print_delete_api_key(&result, output)?;
         }
 
         ClobCommand::CreateApiKey => {
             let signer = auth::resolve_signer(private_key)?;
             let client = clob::Client::default();
-            let result = client.create_or_derive_api_key(&signer, None).await?;
+            let result = timed(client.create_or_derive_api_key(&signer, None)).await?;
             print_create_api_key(&result, output)?;
         }
 
         ClobCommand::AccountStatus => {
             let client = auth::authenticated_clob_client(private_key, signature_type).await?;
-            let result = client.closed_only_mode().await?;
+            let result = timed(client.closed_only_mode()).await?;
             print_account_status(&result, output)?;
         }
 
+        ClobCommand::Fees { token, market } => {
+            let (token_id, maker_bps) = fee_market(token, market).await?;
+            let client = auth::authenticated_clob_client(private_key, signature_type).await?;
+            let result = timed(client.fee_rate_bps(token_id)).await?;
+            print_account_fees(maker_bps, &result, output)?;
+        }
+
         _ => unreachable!(),
     }
 
@@ -1127,6 +1504,12 @@ mod tests {
         assert!(parse_token_id("-1").is_err());
     }
 
+    #[test]
+    fn parse_tokens_file_skips_blanks_and_comments() {
+        let inputs = parse_tokens_file("123\n\n  # basket legs\n will-it-rain \n456\n");
+        assert_eq!(inputs, ["123", "will-it-rain", "456"]);
+    }
+
     #[test]
     fn parse_token_ids_single() {
         let ids = parse_token_ids("100").unwrap();
@@ -1156,6 +1539,57 @@ mod tests {
         assert!(parse_token_ids("1,abc,3").is_err());
     }
 
+    fn dd(v: &str) -> Decimal {
+        Decimal::from_str(v).unwrap()
+    }
+
+    #[test]
+    fn walk_book_buy_notional_consumes_cheapest_asks_first() {
+        let asks = vec![(dd("0.60"), dd("100")), (dd("0.50"), dd("10"))];
+        let fill = walk_book(&asks, true, FillTarget::Notional(dd("11")));
+        assert!(fill.complete);
+        assert_eq!(fill.notional, dd("11"));
+        assert_eq!(fill.shares, dd("10") + dd("6") / dd("0.60"));
+    }
+
+    #[test]
+    fn walk_book_sell_shares_reports_partial_fill() {
+        let bids = vec![(dd("0.40"), dd("5")), (dd("0.45"), dd("5"))];
+        let fill = walk_book(&bids, false, FillTarget::Shares(dd("20")));
+        assert!(!fill.complete);
+        assert_eq!(fill.shares, dd("10"));
+        assert_eq!(fill.notional, dd("4.25"));
+    }
+
+    #[test]
+    fn depth_metrics_counts_levels_near_mid_and_imbalance() {
+        let bids = vec![(dd("0.49"), dd("30")), (dd("0.40"), dd("1000"))];
+        let asks = vec![(dd("0.51"), dd("10")), (dd("0.52"), dd("20"))];
+        let m = depth_metrics(&bids, &asks, dd("0.01"), 2, Some(dd("25")), true);
+        assert_eq!(m.mid, Some(dd("0.50")));
+        assert_eq!(m.bid_depth, dd("30"));
+        assert_eq!(m.ask_depth, dd("30"));
+        assert_eq!(m.imbalance, Some(Decimal::ZERO));
+        let fill = m.fill.unwrap();
+        assert!(fill.complete);
+        assert_eq!(fill.avg_price(), Some(dd("12.9") / dd("25")));
+    }
+
+    #[test]
+    fn best_levels_orders_best_first_and_caps_depth() {
+        let bids = vec![
+            (dd("0.40"), dd("5")),
+            (dd("0.45"), dd("10")),
+            (dd("0.30"), dd("1")),
+        ];
+        let top = best_levels(&bids, false, 2);
+        assert_eq!(top, vec![(dd("0.45"), dd("10")), (dd("0.40"), dd("5"))]);
+
+        let asks = vec![(dd("0.61"), dd("2")), (dd("0.555"), dd("3"))];
+        let all = best_levels(&asks, true, 0);
+        assert_eq!(all, vec![(dd("0.555"), dd("3")), (dd("0.61"), dd("2"))]);
+    }
+
     #[test]
     fn parse_date_valid() {
         let d = parse_date("2024-06-15").unwrap();
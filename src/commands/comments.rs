@@ -1,5 +1,6 @@
 use super::parse_address;
-use crate::output::comments::{print_comment_detail, print_comments_table};
+use crate::net::{fetch_all, timed};
+use crate::output::comments::{print_comment_detail, print_comment_thread, print_comments_table};
 use crate::output::{OutputFormat, print_json};
 use anyhow::Result;
 use clap::{Args, Subcommand};
@@ -8,6 +9,7 @@ use polymarket_client_sdk::gamma::{
     types::{
         ParentEntityType,
         request::{CommentsByIdRequest, CommentsByUserAddressRequest, CommentsRequest},
+        response::Comment,
     },
 };
 
@@ -44,6 +46,19 @@ pub enum CommentsCommand {
         /// Sort ascending instead of descending
         #[arg(long)]
         ascending: bool,
+
+        /// Follow pagination and fetch every page (--limit is the page size)
+        #[arg(long)]
+        all: bool,
+
+        /// Stop --all after this many pages
+        #[arg(long, default_value_t = crate::net::DEFAULT_MAX_PAGES, requires = "all")]
+        max_pages: u32,
+
+        /// Show replies nested under their parent comment (use with --all so
+        /// parents on other pages aren't shown as top-level comments)
+        #[arg(long)]
+        tree: bool,
     },
 
     /// Get a comment by ID
@@ -105,27 +120,50 @@ pub async fn execute(
             offset,
             order,
             ascending,
+            all,
+            max_pages,
+            tree,
         } => {
-            let request = CommentsRequest::builder()
-                .parent_entity_type(ParentEntityType::from(entity_type))
-                .parent_entity_id(entity_id)
-                .limit(limit)
-                .maybe_offset(offset)
-                .maybe_order(order)
-                .maybe_ascending(if ascending { Some(true) } else { None })
-                .build();
+            let request_at = |offset: Option<i32>| {
+                CommentsRequest::builder()
+                    .parent_entity_type(ParentEntityType::from(entity_type.clone()))
+                    .parent_entity_id(entity_id.clone())
+                    .limit(limit)
+                    .maybe_offset(offset)
+                    .maybe_order(order.clone())
+                    .maybe_ascending(if ascending { Some(true) } else { None })
+                    .build()
+            };
 
-            let comments = client.comments(&request).await?;
+            let comments = if all {
+                let page = |offset| {
+                    let request = request_at(Some(offset));
+                    async move { timed(client.comments(&request)).await }
+                };
+                fetch_all(
+                    offset.unwrap_or(0),
+                    limit,
+                    max_pages,
+                    |c: &Comment| c.id.clone(),
+                    page,
+                )
+                .await?
+            } else {
+                timed(client.comments(&request_at(offset))).await?
+            };
 
+            if tree {
+                return print_comment_thread(&comments, output);
+            }
             match output {
                 OutputFormat::Table => print_comments_table(&comments),
-                OutputFormat::Json => print_json(&comments)?,
+                OutputFormat::Json | OutputFormat::Yaml => print_json(&comments)?,
             }
         }
 
         CommentsCommand::Get { id } => {
             let req = CommentsByIdRequest::builder().id(id).build();
-            let comments = client.comments_by_id(&req).await?;
+            let comments = timed(client.comments_by_id(&req)).await?;
 
             let Some(comment) = comments.first() else {
                 anyhow::bail!("Comment not found");
@@ -133,7 +171,7 @@ pub async fn execute(
 
             match output {
                 OutputFormat::Table => print_comment_detail(comment),
-                OutputFormat::Json => print_json(&comment)?,
+                OutputFormat::Json | OutputFormat::Yaml => print_json(&comment)?,
             }
         }
 
@@ -153,11 +191,11 @@ pub async fn execute(
                 .maybe_ascending(if ascending { Some(true) } else { None })
                 .build();
 
-            let comments = client.comments_by_user_address(&request).await?;
+            let comments = timed(client.comments_by_user_address(&request)).await?;
 
             match output {
                 OutputFormat::Table => print_comments_table(&comments),
-                OutputFormat::Json => print_json(&comments)?,
+                OutputFormat::Json | OutputFormat::Yaml => print_json(&comments)?,
             }
         }
     }
@@ -1,10 +1,9 @@
 use std::{
-    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
     fs,
     hash::{Hash, Hasher},
     io::{Read, Write},
-    net::{TcpListener, TcpStream},
-    path::PathBuf,
+    path::{Path, PathBuf},
     sync::Arc,
     time::Duration,
 };
@@ -14,13 +13,18 @@ use chrono::Utc;
 use clap::{Args, Subcommand, ValueEnum};
 use polymarket_client_sdk::data::types::ActivityType;
 use rust_decimal::Decimal;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tokio::sync::Mutex;
+use tracing::Instrument as _;
 
+use crate::commands::clob::{FillTarget, walk_book};
 use crate::output::OutputFormat;
 use polymarket_client_sdk::auth::Signer as _;
-use polymarket_client_sdk::clob::types::request::OrderBookSummaryRequest;
-use polymarket_client_sdk::clob::types::{Amount, OrderType, Side as ClobSide};
+use polymarket_client_sdk::clob::types::request::{
+    BalanceAllowanceRequest, OrderBookSummaryRequest,
+};
+use polymarket_client_sdk::clob::types::{Amount, AssetType, OrderType, Side as ClobSide};
 use polymarket_client_sdk::data::types::request::{
     ActivityRequest, ClosedPositionsRequest, TradesRequest, ValueRequest,
 };
@@ -39,9 +43,178 @@ pub enum CopyCommand {
     Plan(PlanArgs),
     Record(RecordArgs),
     Settle(SettleArgs),
-    Dashboard,
+    Dashboard(DashboardArgs),
     /// Local web UI with near-real-time updates and controls
     Ui(UiArgs),
+    /// Run the monitor in the foreground without the web UI (stop with Ctrl-C)
+    Monitor,
+    /// Prune settled movements older than the retention window from the local history
+    Compact(CompactArgs),
+    /// Read back the settlements log (including rotated files)
+    Settlements(SettlementsArgs),
+    /// Dump the movement history as CSV
+    Export(ExportArgs),
+    /// Merge movements from another machine's history (db JSONL or `copy export` CSV)
+    Import(ImportArgs),
+    /// Re-run sizing over the recorded history with hypothetical settings (nothing is saved)
+    Replay(ReplayArgs),
+    /// Show what one monitor pass would copy from the leaders' recent trades (no orders, nothing saved)
+    Preview,
+    /// Read back the monitor's sizing decisions, including trades it chose not to copy
+    Decisions(DecisionsArgs),
+    /// Wipe the local movement history and state (the configuration is kept)
+    Reset(ResetArgs),
+}
+
+impl CopyCommand {
+    /// Whether the command may sign orders or look up the executing wallet,
+    /// so needs the private key.
+    pub(crate) fn needs_wallet(&self) -> bool {
+        match self {
+            CopyCommand::Ui(_) | CopyCommand::Monitor => true,
+            CopyCommand::Record(record) => record.execute,
+            CopyCommand::Settle(settle) => settle.auto,
+            _ => false,
+        }
+    }
+}
+
+/// The executing wallet from the global `--private-key` / `--private-key-file`
+/// and `--signature-type` flags. Unset fields fall back to the env var and
+/// then the config file, as for every other signing command.
+#[derive(Clone, Default)]
+struct CopyWallet {
+    private_key: Option<String>,
+    signature_type: Option<String>,
+}
+
+impl CopyWallet {
+    /// The account that holds the copies' positions: the signer, or its
+    /// proxy / Safe wallet per the signature type.
+    fn trading_address(&self) -> Result<alloy::primitives::Address> {
+        crate::commands::wallet::trading_address(
+            self.private_key.as_deref(),
+            self.signature_type.as_deref(),
+        )
+    }
+}
+
+#[derive(Args)]
+pub struct DecisionsArgs {
+    /// Show only the most recent N decisions
+    #[arg(long, default_value_t = 50)]
+    pub limit: usize,
+    /// Only decisions that led to a recorded copy
+    #[arg(long)]
+    pub acted_only: bool,
+}
+
+#[derive(Args)]
+pub struct ReplayArgs {
+    /// Which history to replay (defaults to the configured mode)
+    #[arg(long, value_enum)]
+    pub mode: Option<HistoryMode>,
+    /// Leader portfolio value to size against; the history doesn't record it
+    #[arg(long)]
+    pub leader_positions_value: Decimal,
+    /// The hypothetical config, given with the same flags as `copy configure`
+    #[command(flatten)]
+    pub config: ConfigureArgs,
+}
+
+#[derive(Args)]
+pub struct ExportArgs {
+    /// Which history to export (defaults to the configured mode)
+    #[arg(long, value_enum)]
+    pub mode: Option<HistoryMode>,
+    /// Only include settled movements
+    #[arg(long)]
+    pub settled_only: bool,
+    /// Write to this file instead of stdout
+    #[arg(long)]
+    pub out: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct ImportArgs {
+    /// The db file (`copy_trader_*_db.jsonl`) or CSV export to merge
+    pub path: PathBuf,
+    /// Which history to merge into (defaults to the configured mode)
+    #[arg(long, value_enum)]
+    pub mode: Option<HistoryMode>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum HistoryMode {
+    Real,
+    Sim,
+}
+
+#[derive(Args)]
+pub struct ResetArgs {
+    /// Which history to clear
+    #[arg(long, value_enum)]
+    pub mode: ResetMode,
+    /// Also rotate the copy, settlements and decisions logs to `.1`
+    #[arg(long)]
+    pub rotate_logs: bool,
+    /// Skip the confirmation prompt
+    #[arg(long)]
+    pub yes: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ResetMode {
+    Real,
+    Sim,
+    All,
+}
+
+impl ResetMode {
+    fn storage_modes(self) -> &'static [StorageMode] {
+        match self {
+            Self::Real => &[StorageMode::Real],
+            Self::Sim => &[StorageMode::Simulation],
+            Self::All => &[StorageMode::Real, StorageMode::Simulation],
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct SettlementsArgs {
+    /// Only entries settled on or after this UTC date (YYYY-MM-DD)
+    #[arg(long, value_parser = parse_since_date)]
+    pub since: Option<chrono::NaiveDate>,
+    #[arg(long, value_enum, default_value_t = SettlementSort::Time)]
+    pub sort: SettlementSort,
+    /// Group entries and print totals per group
+    #[arg(long, value_enum)]
+    pub group_by: Option<SettlementGroupBy>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum SettlementSort {
+    /// Oldest first
+    Time,
+    /// Largest PnL first
+    Pnl,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum SettlementGroupBy {
+    Market,
+}
+
+fn parse_since_date(s: &str) -> Result<chrono::NaiveDate> {
+    chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .map_err(|_| anyhow!("Invalid date: expected YYYY-MM-DD format"))
+}
+
+#[derive(Args)]
+pub struct CompactArgs {
+    /// Retention window in days (defaults to the configured history-retention-days)
+    #[arg(long)]
+    pub retention_days: Option<u64>,
 }
 
 #[derive(Args)]
@@ -50,36 +223,172 @@ pub struct UiArgs {
     pub host: String,
     #[arg(long, default_value_t = 8787)]
     pub port: u16,
+    /// Listen on this Unix domain socket instead of a TCP port (same token auth)
+    #[arg(long, conflicts_with_all = ["host", "port"])]
+    pub socket: Option<PathBuf>,
+    /// Serve this HTML file instead of the UI embedded at build time
+    #[arg(long)]
+    pub ui_template: Option<PathBuf>,
+    /// Re-read the template on every request (development; defaults to the source copy_ui.html)
+    #[arg(long, default_value_t = false)]
+    pub reload_template: bool,
 }
 
 #[derive(Args, Serialize, Deserialize)]
 pub struct ConfigureArgs {
-    #[arg(long)]
-    pub leader: String,
+    /// Leader address to copy, optionally with a weight (ADDR[:WEIGHT], default 1).
+    /// Repeat or comma-separate to follow several; funds are split by weight.
+    #[arg(
+        long = "leader",
+        required = true,
+        value_delimiter = ',',
+        value_parser = parse_leader_spec
+    )]
+    #[serde(alias = "leader", deserialize_with = "deserialize_leaders")]
+    pub leaders: Vec<LeaderConfig>,
     #[arg(long)]
     pub allocated_funds: Decimal,
-    #[arg(long, default_value_t = Decimal::from_i128_with_scale(500, 2))]
-    pub max_trade_pct: Decimal,
-    #[arg(long, default_value_t = Decimal::from_i128_with_scale(7000, 2))]
-    pub max_total_exposure_pct: Decimal,
-    #[arg(long, default_value_t = Decimal::ONE)]
-    pub min_copy_usd: Decimal,
-    #[arg(long, default_value_t = 2)]
-    pub poll_interval_secs: u64,
+    /// Start from a named set of defaults; any flag given explicitly overrides it
+    #[arg(long, value_enum)]
+    #[serde(default)]
+    pub preset: Option<CopyPreset>,
+    /// Max size of one copy, % of funds [default: 5, or the preset's]
+    #[arg(long)]
+    pub max_trade_pct: Option<Decimal>,
+    /// Max open exposure, % of funds [default: 70, or the preset's]
+    #[arg(long)]
+    pub max_total_exposure_pct: Option<Decimal>,
+    /// Skip copies smaller than this many USD [default: 1, or the preset's]
+    #[arg(long)]
+    pub min_copy_usd: Option<Decimal>,
+    /// Polling interval in seconds [default: 2, or the preset's]
+    #[arg(long)]
+    pub poll_interval_secs: Option<u64>,
     /// Optional polling interval in milliseconds (min 500ms). Overrides poll-interval-secs when set.
     #[arg(long)]
     pub poll_interval_ms: Option<u64>,
-    #[arg(long, value_enum, default_value_t = RiskLevel::Balanced)]
-    pub risk_level: RiskLevel,
+    /// [default: balanced, or the preset's]
+    #[arg(long, value_enum)]
+    pub risk_level: Option<RiskLevel>,
     #[arg(long, default_value_t = false)]
     pub execute_orders: bool,
+    /// Run the real monitor but never post orders; movements are recorded as paper
+    #[arg(long, default_value_t = false)]
+    #[serde(default)]
+    pub paper_mode: bool,
     #[arg(long, default_value_t = false)]
     pub realtime_mode: bool,
     #[arg(long, default_value_t = false)]
     pub simulation_mode: bool,
+    /// Only copy trades on this outcome side
+    #[arg(long, alias = "outcome-filter", value_enum, default_value_t = CopyOutcome::Both)]
+    #[serde(default)]
+    pub copy_outcome: CopyOutcome,
+    /// `both` copies the leader's buys and closes copies when they sell;
+    /// `buy` copies buys but ignores their sells (copies close on resolution,
+    /// closed positions or exit thresholds). `sell` is rejected.
+    #[arg(long, value_enum, default_value_t = CopySides::Both)]
+    #[serde(default)]
+    pub copy_sides: CopySides,
+    /// Only copy markets whose normalized slug contains one of these
+    /// (case-insensitive; comma-separated or repeated)
+    #[arg(long, value_delimiter = ',')]
+    #[serde(default)]
+    pub slug_allowlist: Vec<String>,
+    /// Never copy markets whose normalized slug contains one of these
+    /// (case-insensitive; comma-separated or repeated)
+    #[arg(long, value_delimiter = ',')]
+    #[serde(default)]
+    pub slug_denylist: Vec<String>,
+    /// Prune settled movements older than this many days from the local history (archived first)
+    #[arg(long)]
+    #[serde(default)]
+    pub history_retention_days: Option<u64>,
+    /// Cap each leader's open exposure at this percent of funds (default: only the global cap)
+    #[arg(long)]
+    #[serde(default)]
+    pub per_leader_max_exposure_pct: Option<Decimal>,
+    /// Don't write settlements to the text log (the db still records them)
+    #[arg(long, default_value_t = false)]
+    #[serde(default)]
+    pub no_settlement_log: bool,
+    /// Rotate the settlements log once it reaches this many bytes [default: 5 MiB]
+    #[arg(long)]
+    #[serde(default)]
+    pub settlement_log_max_bytes: Option<u64>,
+    /// Stop copying after this many movements in a UTC day (default: unlimited)
+    #[arg(long)]
+    #[serde(default)]
+    pub max_daily_trades: Option<usize>,
+    /// Reserve estimated round-trip fees out of the available exposure when sizing copies
+    #[arg(long, default_value_t = false)]
+    #[serde(default)]
+    pub include_fees: bool,
+    /// Which monitor events trigger notifications
+    #[arg(long, value_enum, default_value_t = NotifyOn::Settled)]
+    #[serde(default)]
+    pub notify_on: NotifyOn,
+    /// POST each notified movement as JSON to this URL
+    #[arg(long)]
+    #[serde(default)]
+    pub notify_webhook_url: Option<String>,
+    /// Run this shell command for each notified movement, with its JSON on stdin
+    #[arg(long)]
+    #[serde(default)]
+    pub notify_command: Option<String>,
+    /// Exit and settle an open copy once it's down this percent from its entry price
+    #[arg(long)]
+    #[serde(default)]
+    pub stop_loss_pct: Option<Decimal>,
+    /// Exit and settle an open copy once it's up this percent from its entry price
+    #[arg(long)]
+    #[serde(default)]
+    pub take_profit_pct: Option<Decimal>,
+    /// After a rate-limit slowdown, step polling back toward the configured
+    /// interval after this many consecutive successful API calls [default: 5]
+    #[arg(long)]
+    #[serde(default)]
+    pub backoff_recovery_successes: Option<u32>,
+    /// Stop opening new copies once today's settled net loss (UTC day) reaches this many USD
+    #[arg(long)]
+    #[serde(default)]
+    pub daily_loss_limit_usd: Option<Decimal>,
+    /// Stop opening new copies while this many copied movements are still unsettled
+    #[arg(long)]
+    #[serde(default)]
+    pub max_open_positions: Option<usize>,
+    /// Stop opening copies of a leader once the settled net PnL of their copies
+    /// reaches -N USD, until re-enabled from the UI (their sells still close copies)
+    #[arg(long)]
+    #[serde(default)]
+    pub per_leader_disable_loss_usd: Option<Decimal>,
+    /// Apply max-total-exposure-pct to all open copies, or separately to each
+    /// market family (slugs equal up to a rolling numeric suffix)
+    #[arg(long, value_enum, default_value_t = ExposureMode::Global)]
+    #[serde(default)]
+    pub exposure_mode: ExposureMode,
+    /// Size copies in proportion to the leader's movement, or at a flat
+    /// fixed-copy-usd per copied trade
+    #[arg(long, value_enum, default_value_t = SizingMode::Proportional)]
+    #[serde(default)]
+    pub sizing_mode: SizingMode,
+    /// USD per copied trade with --sizing-mode fixed, still subject to
+    /// max-trade-pct, available exposure and min-copy-usd
+    #[arg(long)]
+    #[serde(default)]
+    pub fixed_copy_usd: Option<Decimal>,
+    /// Only copy when the book holds this many times the copy size (within
+    /// max-slippage-pct when set) [default: 1, when max-slippage-pct is set]
+    #[arg(long)]
+    #[serde(default)]
+    pub liquidity_multiple: Option<Decimal>,
+    /// Only count book levels within this percent of the leader's fill price as depth
+    #[arg(long)]
+    #[serde(default)]
+    pub max_slippage_pct: Option<Decimal>,
 }
 
-#[derive(Clone, Copy, Debug, Serialize, Deserialize, ValueEnum)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema, ValueEnum)]
 #[serde(rename_all = "kebab-case")]
 pub enum RiskLevel {
     Conservative,
@@ -87,12 +396,199 @@ pub enum RiskLevel {
     Aggressive,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum CopyPreset {
+    /// 2% per trade, 40% exposure, $2 minimum, 5s polling, conservative risk
+    Conservative,
+    /// 10% per trade, 90% exposure, $1 minimum, 1s polling, aggressive risk
+    Aggressive,
+    /// 1% per trade, 30% exposure, $1 minimum, 200ms realtime polling, aggressive risk
+    Scalper,
+}
+
+/// The tunables a preset fills in. Without a preset these are the plain
+/// `copy configure` defaults.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct PresetValues {
+    max_trade_pct: Decimal,
+    max_total_exposure_pct: Decimal,
+    min_copy_usd: Decimal,
+    poll_interval_ms: u64,
+    risk_level: RiskLevel,
+    realtime_mode: bool,
+}
+
+fn preset_values(preset: Option<CopyPreset>) -> PresetValues {
+    let pct = |v: i64| Decimal::from(v);
+    match preset {
+        None => PresetValues {
+            max_trade_pct: pct(5),
+            max_total_exposure_pct: pct(70),
+            min_copy_usd: Decimal::ONE,
+            poll_interval_ms: 2000,
+            risk_level: RiskLevel::Balanced,
+            realtime_mode: false,
+        },
+        Some(CopyPreset::Conservative) => PresetValues {
+            max_trade_pct: pct(2),
+            max_total_exposure_pct: pct(40),
+            min_copy_usd: Decimal::TWO,
+            poll_interval_ms: 5000,
+            risk_level: RiskLevel::Conservative,
+            realtime_mode: false,
+        },
+        Some(CopyPreset::Aggressive) => PresetValues {
+            max_trade_pct: pct(10),
+            max_total_exposure_pct: pct(90),
+            min_copy_usd: Decimal::ONE,
+            poll_interval_ms: 1000,
+            risk_level: RiskLevel::Aggressive,
+            realtime_mode: false,
+        },
+        Some(CopyPreset::Scalper) => PresetValues {
+            max_trade_pct: pct(1),
+            max_total_exposure_pct: pct(30),
+            min_copy_usd: Decimal::ONE,
+            poll_interval_ms: 200,
+            risk_level: RiskLevel::Aggressive,
+            realtime_mode: true,
+        },
+    }
+}
+
+impl ConfigureArgs {
+    /// Preset (or default) values with every explicitly given flag applied on
+    /// top. A preset's realtime polling is dropped in simulation mode.
+    fn resolved(&self) -> PresetValues {
+        let base = preset_values(self.preset);
+        PresetValues {
+            max_trade_pct: self.max_trade_pct.unwrap_or(base.max_trade_pct),
+            max_total_exposure_pct: self
+                .max_total_exposure_pct
+                .unwrap_or(base.max_total_exposure_pct),
+            min_copy_usd: self.min_copy_usd.unwrap_or(base.min_copy_usd),
+            poll_interval_ms: self
+                .poll_interval_ms
+                .or(self.poll_interval_secs.map(|s| s.saturating_mul(1000)))
+                .unwrap_or(base.poll_interval_ms),
+            risk_level: self.risk_level.unwrap_or(base.risk_level),
+            realtime_mode: self.realtime_mode || (base.realtime_mode && !self.simulation_mode),
+        }
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema, ValueEnum,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum CopyOutcome {
+    Yes,
+    No,
+    #[default]
+    Both,
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema, ValueEnum,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum CopySides {
+    Buy,
+    Sell,
+    #[default]
+    Both,
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema, ValueEnum,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum NotifyOn {
+    /// Every copied movement as soon as it is recorded
+    Detected,
+    /// Only when a movement settles
+    #[default]
+    Settled,
+    Both,
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema, ValueEnum,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum ExposureMode {
+    /// One cap over every open copy
+    #[default]
+    Global,
+    /// One cap per normalized market slug, so `-updown-5m-<ts>` rollovers
+    /// share a budget instead of each getting the full one
+    ByNormalizedMarket,
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema, ValueEnum,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum SizingMode {
+    /// Scale the leader's movement by our funds relative to theirs
+    #[default]
+    Proportional,
+    /// The same `fixed_copy_usd` for every copied trade
+    Fixed,
+}
+
+/// Monitor events that can be notified; gated by `CopyConfig::notify_on`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum NotifyEvent {
+    Detected,
+    Settled,
+}
+
+fn should_notify(cfg: &CopyConfig, event: NotifyEvent) -> bool {
+    match cfg.notify_on {
+        NotifyOn::Both => true,
+        NotifyOn::Detected => event == NotifyEvent::Detected,
+        NotifyOn::Settled => event == NotifyEvent::Settled,
+    }
+}
+
+#[derive(Args)]
+pub struct DashboardArgs {
+    /// Only print aggregate stats and the last 7 days of PnL
+    #[arg(long)]
+    pub summary_only: bool,
+    /// How to draw the PnL series in table output
+    #[arg(long, value_enum, default_value_t = DashboardChart::Sparkline)]
+    pub chart: DashboardChart,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum DashboardChart {
+    /// One block character per bucket, scaled to the series' min/max
+    Sparkline,
+    /// `+`/`-` bars, one per whole dollar (capped at 40)
+    Bars,
+    /// Numbers only
+    None,
+}
+
 #[derive(Args)]
 pub struct PlanArgs {
     #[arg(long)]
     pub leader_positions_value: Decimal,
     #[arg(long)]
     pub leader_movement_value: Decimal,
+    /// Show every cap's value and mark the one that bound the copy size
+    #[arg(long)]
+    pub explain: bool,
+    /// Market slug of the movement, used to estimate fees when include-fees is configured
+    /// and to pick the exposure budget under exposure-mode by-normalized-market
+    #[arg(long)]
+    pub market: Option<String>,
+    /// Size against this leader's share of the funds [default: the first configured leader]
+    #[arg(long)]
+    pub leader: Option<String>,
 }
 
 #[derive(Args)]
@@ -107,19 +603,119 @@ pub struct RecordArgs {
     pub copied_value: Decimal,
     #[arg(long, default_value_t = Decimal::ZERO)]
     pub diff_pct: Decimal,
+    /// Also place the order for `--copied-value` before recording it
+    #[arg(long, requires_all = ["token_id", "side", "price"])]
+    pub execute: bool,
+    /// Outcome token to trade (with --execute)
+    #[arg(long, requires = "execute")]
+    pub token_id: Option<String>,
+    /// Order side (with --execute)
+    #[arg(long, value_enum, requires = "execute")]
+    pub side: Option<crate::commands::clob::CliSide>,
+    /// Leader's fill price, used to size sells and recorded with the movement
+    #[arg(long, requires = "execute")]
+    pub price: Option<Decimal>,
+    /// Skip the confirmation prompt
+    #[arg(long, requires = "execute")]
+    pub yes: bool,
 }
 
 #[derive(Args)]
 pub struct SettleArgs {
-    #[arg(long)]
-    pub movement_id: String,
-    #[arg(long)]
-    pub pnl: Decimal,
+    #[arg(long, required_unless_present = "auto")]
+    pub movement_id: Option<String>,
+    #[arg(long, required_unless_present = "auto", allow_hyphen_values = true)]
+    pub pnl: Option<Decimal>,
+    /// Reconcile all open movements against closed positions fetched once
+    #[arg(long, conflicts_with_all = ["movement_id", "pnl"])]
+    pub auto: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct LeaderConfig {
+    pub address: String,
+    /// Relative share of the allocated funds; weights need not sum to 1.
+    #[serde(default = "default_leader_weight")]
+    pub weight: Decimal,
+}
+
+#[cfg(test)]
+impl LeaderConfig {
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            address: address.into(),
+            weight: Decimal::ONE,
+        }
+    }
+}
+
+fn default_leader_weight() -> Decimal {
+    Decimal::ONE
+}
+
+/// Parses `ADDR` or `ADDR:WEIGHT`.
+fn parse_leader_spec(s: &str) -> Result<LeaderConfig> {
+    let s = s.trim();
+    let (address, weight) = match s.split_once(':') {
+        Some((address, weight)) => (
+            address.trim(),
+            weight
+                .trim()
+                .parse::<Decimal>()
+                .map_err(|_| anyhow!("invalid leader weight in '{s}'"))?,
+        ),
+        None => (s, Decimal::ONE),
+    };
+    if address.is_empty() {
+        bail!("empty leader address");
+    }
+    Ok(LeaderConfig {
+        address: address.to_string(),
+        weight,
+    })
+}
+
+/// Accepts the current `leaders` list as well as the old single `leader`
+/// string (or a comma-separated `ADDR[:WEIGHT]` list, as the UI sends).
+fn deserialize_leaders<'de, D>(deserializer: D) -> std::result::Result<Vec<LeaderConfig>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum LeaderInput {
+        Spec(String),
+        Full(LeaderConfig),
+    }
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum LeadersInput {
+        One(String),
+        Many(Vec<LeaderInput>),
+    }
+
+    let specs: Vec<LeaderInput> = match LeadersInput::deserialize(deserializer)? {
+        LeadersInput::One(s) => s
+            .split(',')
+            .filter(|part| !part.trim().is_empty())
+            .map(|part| LeaderInput::Spec(part.to_string()))
+            .collect(),
+        LeadersInput::Many(list) => list,
+    };
+    specs
+        .into_iter()
+        .map(|input| match input {
+            LeaderInput::Spec(spec) => parse_leader_spec(&spec).map_err(serde::de::Error::custom),
+            LeaderInput::Full(leader) => Ok(leader),
+        })
+        .collect()
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Clone)]
 pub struct CopyConfig {
-    pub leader: String,
+    #[serde(alias = "leader", deserialize_with = "deserialize_leaders")]
+    pub leaders: Vec<LeaderConfig>,
     pub allocated_funds: Decimal,
     pub max_trade_pct: Decimal,
     pub max_total_exposure_pct: Decimal,
@@ -130,15 +726,97 @@ pub struct CopyConfig {
     pub risk_level: RiskLevel,
     pub execute_orders: bool,
     #[serde(default)]
+    pub paper_mode: bool,
+    #[serde(default)]
     pub realtime_mode: bool,
     #[serde(default)]
     pub simulation_mode: bool,
+    #[serde(default)]
+    pub copy_outcome: CopyOutcome,
+    #[serde(default)]
+    pub history_retention_days: Option<u64>,
+    #[serde(default)]
+    pub per_leader_max_exposure_pct: Option<Decimal>,
+    #[serde(default)]
+    pub no_settlement_log: bool,
+    #[serde(default)]
+    pub settlement_log_max_bytes: Option<u64>,
+    #[serde(default)]
+    pub max_daily_trades: Option<usize>,
+    #[serde(default)]
+    pub include_fees: bool,
+    #[serde(default)]
+    pub notify_on: NotifyOn,
+    #[serde(default)]
+    pub notify_webhook_url: Option<String>,
+    #[serde(default)]
+    pub notify_command: Option<String>,
+    #[serde(default)]
+    pub stop_loss_pct: Option<Decimal>,
+    #[serde(default)]
+    pub take_profit_pct: Option<Decimal>,
+    #[serde(default)]
+    pub backoff_recovery_successes: Option<u32>,
+    #[serde(default)]
+    pub daily_loss_limit_usd: Option<Decimal>,
+    #[serde(default)]
+    pub max_open_positions: Option<usize>,
+    #[serde(default)]
+    pub exposure_mode: ExposureMode,
+    #[serde(default)]
+    pub sizing_mode: SizingMode,
+    #[serde(default)]
+    pub fixed_copy_usd: Option<Decimal>,
+    #[serde(default)]
+    pub liquidity_multiple: Option<Decimal>,
+    #[serde(default)]
+    pub max_slippage_pct: Option<Decimal>,
+    #[serde(default)]
+    pub copy_sides: CopySides,
+    #[serde(default)]
+    pub slug_allowlist: Vec<String>,
+    #[serde(default)]
+    pub slug_denylist: Vec<String>,
+    #[serde(default)]
+    pub per_leader_disable_loss_usd: Option<Decimal>,
+}
+
+impl CopyConfig {
+    /// The first configured leader; untagged (legacy) movements belong to it.
+    pub fn primary_leader(&self) -> &str {
+        self.leaders.first().map_or("", |l| l.address.as_str())
+    }
+
+    /// `leader`'s weight over the sum of all weights; 0 for unknown leaders.
+    fn leader_share(&self, leader: &str) -> Decimal {
+        let total: Decimal = self.leaders.iter().map(|l| l.weight).sum();
+        if total <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+        self.leaders
+            .iter()
+            .filter(|l| l.address.eq_ignore_ascii_case(leader))
+            .map(|l| l.weight)
+            .sum::<Decimal>()
+            / total
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Clone)]
 pub struct MovementRecord {
     pub movement_id: String,
+    /// Leader address the movement was copied from. Empty for movements
+    /// recorded before leaders were tagged; those count as the configured one.
+    #[serde(default)]
+    pub leader: String,
+    /// Recorded by the real monitor in paper mode: no order was posted.
+    #[serde(default)]
+    pub paper: bool,
     pub market: String,
+    /// Outcome token id of the copied trade, used to price open movements for
+    /// stop-loss/take-profit. Empty for movements recorded before it was kept.
+    #[serde(default)]
+    pub asset: String,
     pub timestamp: String,
     pub leader_value: Decimal,
     #[serde(default)]
@@ -159,19 +837,49 @@ pub struct MovementRecord {
     pub estimated_total_fee_usd: Decimal,
     pub settled: bool,
     pub pnl: Decimal,
+    /// Part of `quantity` still open after the leader partially closed the
+    /// position; `None` until the first partial close.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remaining_quantity: Option<Decimal>,
+    /// Timestamp of the last closed position applied as a partial close, so a
+    /// re-fetched closed position isn't applied twice.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_partial_close_ts: Option<i64>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Default)]
 pub struct CopyState {
     pub movements: Vec<MovementRecord>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct PlanResult {
     pub proportional_size: Decimal,
     pub capped_size: Decimal,
     pub available_funds: Decimal,
     pub reason: String,
+    /// Every bound `compute_plan` checked; only serialized for `copy plan --explain`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constraints: Option<PlanConstraints>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct PlanConstraints {
+    pub effective_funds: Decimal,
+    pub proportional_size: Decimal,
+    /// The flat size proposed instead of `proportional_size`; absent unless sizing-mode is fixed.
+    pub fixed_copy_usd: Option<Decimal>,
+    pub max_trade: Decimal,
+    pub available_exposure: Decimal,
+    /// Absent when no per-leader cap is configured.
+    pub per_leader_available: Option<Decimal>,
+    /// Exposure held back for round-trip fees; absent unless include-fees is on.
+    pub fee_reserve: Option<Decimal>,
+    pub min_copy_usd: Decimal,
+    /// Unsettled movements counted against max-open-positions; absent when no cap is set.
+    pub open_positions: Option<usize>,
+    /// Name of the field above that determined the planned size.
+    pub binding: String,
 }
 
 fn default_poll_interval_ms() -> u64 {
@@ -193,20 +901,84 @@ fn normalize_poll_ms(poll_ms: u64, realtime_mode: bool, simulation_mode: bool) -
 const FAST_MARKET_FEE_BPS: u32 = 70;
 const BPS_DENOMINATOR: u32 = 10_000;
 
-fn is_fast_market_with_fee(slug: &str) -> bool {
-    let normalized = normalize_market_slug(slug);
-    normalized.contains("-updown-5m") || normalized.contains("-updown-15m")
+/// Per-side taker fees keyed by market slug suffix (matched after the numeric
+/// timestamp is stripped, e.g. `-updown-5m`). Read from `fees.json` in the
+/// config dir as a plain `{"<suffix>": <bps>}` object, which replaces the
+/// built-in table entirely; the longest matching suffix wins.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+struct FeeSchedule {
+    rules: BTreeMap<String, u32>,
+}
+
+impl Default for FeeSchedule {
+    fn default() -> Self {
+        Self {
+            rules: BTreeMap::from([
+                ("-updown-5m".to_string(), FAST_MARKET_FEE_BPS),
+                ("-updown-15m".to_string(), FAST_MARKET_FEE_BPS),
+            ]),
+        }
+    }
+}
+
+impl FeeSchedule {
+    /// Fee in bps for `slug`, or `None` when no rule matches (or it's zero).
+    fn fee_bps(&self, slug: &str) -> Option<u32> {
+        let normalized = normalize_market_slug(slug);
+        self.rules
+            .iter()
+            .filter(|(suffix, _)| normalized.ends_with(suffix.as_str()))
+            .max_by_key(|(suffix, _)| suffix.len())
+            .map(|(_, bps)| *bps)
+            .filter(|bps| *bps > 0)
+    }
+}
+
+fn fees_path() -> Result<PathBuf> {
+    Ok(base_dir()?.join("fees.json"))
+}
+
+fn load_fee_schedule() -> Result<FeeSchedule> {
+    let path = fees_path()?;
+    if !path.exists() {
+        return Ok(FeeSchedule::default());
+    }
+    let data = fs::read_to_string(&path)?;
+    serde_json::from_str(&data).with_context(|| format!("Invalid fee table {}", path.display()))
 }
 
-fn trading_fee_impact_for_movement(
+/// The active schedule, read once per process. A broken `fees.json` falls
+/// back to the built-in table with a warning rather than stopping the monitor.
+fn fee_schedule() -> &'static FeeSchedule {
+    static SCHEDULE: std::sync::OnceLock<FeeSchedule> = std::sync::OnceLock::new();
+    SCHEDULE.get_or_init(|| {
+        load_fee_schedule().unwrap_or_else(|e| {
+            tracing::warn!("{e:#}; using built-in fee table");
+            FeeSchedule::default()
+        })
+    })
+}
+
+/// Round-trip fee as a fraction of the copied value (zero for fee-free markets).
+fn round_trip_fee_rate(schedule: &FeeSchedule, market: &str) -> Decimal {
+    match schedule.fee_bps(market) {
+        Some(bps) => Decimal::from(bps * 2) / Decimal::from(BPS_DENOMINATOR),
+        None => Decimal::ZERO,
+    }
+}
+
+fn trading_fee_impact(
+    schedule: &FeeSchedule,
     market: &str,
     copied_value: Decimal,
 ) -> Option<TradingFeeImpact> {
-    if !is_fast_market_with_fee(market) || copied_value <= Decimal::ZERO {
+    let fee_bps = schedule.fee_bps(market)?;
+    if copied_value <= Decimal::ZERO {
         return None;
     }
 
-    let fee_rate = Decimal::from(FAST_MARKET_FEE_BPS) / Decimal::from(BPS_DENOMINATOR);
+    let fee_rate = Decimal::from(fee_bps) / Decimal::from(BPS_DENOMINATOR);
     let entry_fee_usd = copied_value * fee_rate;
     let round_trip_fee_usd = entry_fee_usd * Decimal::from(2);
     let max_gross_profit_usd =
@@ -214,7 +986,7 @@ fn trading_fee_impact_for_movement(
     let max_net_profit_usd = max_gross_profit_usd - round_trip_fee_usd;
 
     Some(TradingFeeImpact {
-        fee_bps: FAST_MARKET_FEE_BPS,
+        fee_bps,
         entry_fee_usd,
         round_trip_fee_usd,
         max_gross_profit_usd,
@@ -222,31 +994,108 @@ fn trading_fee_impact_for_movement(
     })
 }
 
-pub async fn execute(args: CopyArgs, output: OutputFormat) -> Result<()> {
-    match args.command {
+fn config_from_args(cfg: ConfigureArgs) -> CopyConfig {
+    let r = cfg.resolved();
+    CopyConfig {
+        leaders: cfg.leaders,
+        allocated_funds: cfg.allocated_funds,
+        max_trade_pct: r.max_trade_pct,
+        max_total_exposure_pct: r.max_total_exposure_pct,
+        min_copy_usd: r.min_copy_usd,
+        poll_interval_secs: cfg
+            .poll_interval_secs
+            .unwrap_or((r.poll_interval_ms / 1000).max(1)),
+        poll_interval_ms: normalize_poll_ms(
+            r.poll_interval_ms,
+            r.realtime_mode,
+            cfg.simulation_mode,
+        ),
+        risk_level: r.risk_level,
+        execute_orders: cfg.execute_orders,
+        paper_mode: cfg.paper_mode,
+        realtime_mode: r.realtime_mode,
+        simulation_mode: cfg.simulation_mode,
+        copy_outcome: cfg.copy_outcome,
+        history_retention_days: cfg.history_retention_days,
+        per_leader_max_exposure_pct: cfg.per_leader_max_exposure_pct,
+        no_settlement_log: cfg.no_settlement_log,
+        settlement_log_max_bytes: cfg.settlement_log_max_bytes,
+        max_daily_trades: cfg.max_daily_trades,
+        include_fees: cfg.include_fees,
+        notify_on: cfg.notify_on,
+        notify_webhook_url: cfg.notify_webhook_url.clone(),
+        notify_command: cfg.notify_command.clone(),
+        stop_loss_pct: cfg.stop_loss_pct,
+        take_profit_pct: cfg.take_profit_pct,
+        backoff_recovery_successes: cfg.backoff_recovery_successes,
+        daily_loss_limit_usd: cfg.daily_loss_limit_usd,
+        max_open_positions: cfg.max_open_positions,
+        exposure_mode: cfg.exposure_mode,
+        sizing_mode: cfg.sizing_mode,
+        fixed_copy_usd: cfg.fixed_copy_usd,
+        liquidity_multiple: cfg.liquidity_multiple,
+        max_slippage_pct: cfg.max_slippage_pct,
+        copy_sides: cfg.copy_sides,
+        slug_allowlist: slug_patterns(&cfg.slug_allowlist),
+        slug_denylist: slug_patterns(&cfg.slug_denylist),
+        per_leader_disable_loss_usd: cfg.per_leader_disable_loss_usd,
+    }
+}
+
+/// Trims and lowercases filter patterns, dropping empty ones.
+fn slug_patterns(patterns: &[String]) -> Vec<String> {
+    patterns
+        .iter()
+        .map(|p| p.trim().to_lowercase())
+        .filter(|p| !p.is_empty())
+        .collect()
+}
+
+/// The only switch for posting real orders: paper and simulation runs never
+/// reach the order path, even if `execute_orders` is also set.
+fn posts_orders(cfg: &CopyConfig) -> bool {
+    cfg.execute_orders && !cfg.paper_mode && !cfg.simulation_mode
+}
+
+/// "execute" posts orders, "paper" and "dry-run" only record (paper rows are
+/// flagged), "simulation" uses the separate simulated history.
+fn run_mode_label(cfg: &CopyConfig) -> &'static str {
+    if cfg.simulation_mode {
+        "simulation"
+    } else if posts_orders(cfg) {
+        "execute"
+    } else if cfg.paper_mode {
+        "paper"
+    } else {
+        "dry-run"
+    }
+}
+
+fn outcome_allowed(filter: CopyOutcome, outcome: &str) -> bool {
+    match filter {
+        CopyOutcome::Both => true,
+        CopyOutcome::Yes => outcome.trim().eq_ignore_ascii_case("yes"),
+        CopyOutcome::No => outcome.trim().eq_ignore_ascii_case("no"),
+    }
+}
+
+pub async fn execute(
+    args: CopyArgs,
+    output: OutputFormat,
+    private_key: Option<&str>,
+    signature_type: Option<&str>,
+) -> Result<()> {
+    let wallet = CopyWallet {
+        private_key: private_key.map(str::to_string),
+        signature_type: signature_type.map(str::to_string),
+    };
+    match args.command {
         CopyCommand::Configure(cfg) => {
             validate_config(&cfg)?;
-            let c = CopyConfig {
-                leader: cfg.leader,
-                allocated_funds: cfg.allocated_funds,
-                max_trade_pct: cfg.max_trade_pct,
-                max_total_exposure_pct: cfg.max_total_exposure_pct,
-                min_copy_usd: cfg.min_copy_usd,
-                poll_interval_secs: cfg.poll_interval_secs,
-                poll_interval_ms: normalize_poll_ms(
-                    cfg.poll_interval_ms
-                        .unwrap_or(cfg.poll_interval_secs.saturating_mul(1000)),
-                    cfg.realtime_mode,
-                    cfg.simulation_mode,
-                ),
-                risk_level: cfg.risk_level,
-                execute_orders: cfg.execute_orders,
-                realtime_mode: cfg.realtime_mode,
-                simulation_mode: cfg.simulation_mode,
-            };
+            let c = config_from_args(cfg);
             save_config(&c)?;
             init_db(StorageMode::Real)?;
-            if matches!(output, OutputFormat::Json) {
+            if matches!(output, OutputFormat::Json | OutputFormat::Yaml) {
                 crate::output::print_json(&serde_json::json!({"status": "configured"}))?;
             } else {
                 println!("Copy-trader configured successfully.");
@@ -261,77 +1110,360 @@ pub async fn execute(args: CopyArgs, output: OutputFormat) -> Result<()> {
         CopyCommand::Plan(plan_args) => {
             let config = load_config()?;
             let state = load_state()?;
-            let result = compute_plan(
+            let leader = plan_args
+                .leader
+                .as_deref()
+                .unwrap_or_else(|| config.primary_leader());
+            if !config
+                .leaders
+                .iter()
+                .any(|l| l.address.eq_ignore_ascii_case(leader))
+            {
+                bail!("{leader} is not a configured leader");
+            }
+            let market = plan_args.market.as_deref().unwrap_or_default();
+            let mut result = compute_plan(
                 &config,
                 &state,
+                leader,
+                market,
                 plan_args.leader_positions_value,
                 plan_args.leader_movement_value,
+                plan_args
+                    .market
+                    .as_deref()
+                    .map_or(Decimal::ZERO, |m| round_trip_fee_rate(fee_schedule(), m)),
             )?;
+            if !plan_args.explain {
+                result.constraints = None;
+            }
             crate::output::copy::print_plan(&result, output)
         }
         CopyCommand::Record(record) => {
+            // Checked before any order goes out, so a retried command can't
+            // place a second order for the same movement.
+            let mode = current_mode_from_disk();
             let mut state = load_state()?;
+            if state
+                .movements
+                .iter()
+                .any(|m| m.movement_id == record.movement_id)
+                || db_has_movement(&read_db_rows(mode)?, &record.movement_id)
+            {
+                bail!("movement {} is already recorded", record.movement_id);
+            }
+            let mut copied_value = record.copied_value;
+            let mut leader_price = Decimal::ZERO;
+            let mut quantity = Decimal::ZERO;
+            let mut copy_side = "unknown".to_string();
+            let mut executed = false;
+            if record.execute {
+                let (Some(token), Some(side), Some(price)) =
+                    (&record.token_id, record.side.clone(), record.price)
+                else {
+                    bail!("--execute requires --token-id, --side and --price");
+                };
+                if price <= Decimal::ZERO || price >= Decimal::ONE {
+                    bail!("--price must be between 0 and 1 (exclusive)");
+                }
+                let token_id = crate::commands::clob::parse_token_id(token)?;
+                let side = ClobSide::from(side);
+                let is_sell = matches!(side, ClobSide::Sell);
+                let (usd, shares) = rounded_order_size(copied_value, price, is_sell);
+                let side_label = if is_sell { "SELL" } else { "BUY" };
+                if is_sell && !state.movements.iter().any(|m| is_open_buy_of(m, token_id)) {
+                    bail!(
+                        "no open BUY on token {token} to close; a SELL is recorded by settling the copies it exits"
+                    );
+                }
+                if !record.yes {
+                    use std::io::BufRead;
+                    print!(
+                        "Place {side_label} of {shares} shares (${usd}) on token {token}? [y/N] "
+                    );
+                    std::io::stdout().flush()?;
+                    let mut input = String::new();
+                    std::io::stdin().lock().read_line(&mut input)?;
+                    if !matches!(input.trim().to_lowercase().as_str(), "y" | "yes") {
+                        println!("Aborted.");
+                        return Ok(());
+                    }
+                }
+                copied_value =
+                    execute_copy_order(&wallet, token_id, side, price, copied_value, true).await?;
+                if is_sell {
+                    return record_manual_sell(
+                        &mut state,
+                        mode,
+                        token_id,
+                        price,
+                        copied_value,
+                        output,
+                    );
+                }
+                leader_price = price;
+                quantity = shares;
+                copy_side = side_label.to_string();
+                executed = true;
+            }
             let entry = MovementRecord {
                 movement_id: record.movement_id,
+                leader: String::new(),
+                paper: false,
                 market: record.market,
+                asset: record.token_id.clone().unwrap_or_default(),
                 timestamp: Utc::now().to_rfc3339(),
                 leader_value: record.leader_value,
-                leader_price: Decimal::ZERO,
-                copied_value: record.copied_value,
+                leader_price,
+                copied_value,
                 simulated_copy_price: Decimal::ZERO,
-                quantity: Decimal::ZERO,
-                copy_side: "unknown".to_string(),
+                quantity,
+                copy_side,
                 outcome: String::new(),
                 resolved_outcome: String::new(),
                 diff_pct: record.diff_pct,
                 estimated_total_fee_usd: Decimal::ZERO,
                 settled: false,
                 pnl: Decimal::ZERO,
+                remaining_quantity: None,
+                last_partial_close_ts: None,
             };
             state.movements.push(entry.clone());
             save_state(&state)?;
-            append_db_movement(current_mode_from_disk(), &entry)?;
-            if matches!(output, OutputFormat::Json) {
-                crate::output::print_json(&serde_json::json!({"status": "recorded"}))?;
+            append_db_movement(mode, &entry)?;
+            if matches!(output, OutputFormat::Json | OutputFormat::Yaml) {
+                crate::output::print_json(&serde_json::json!({
+                    "status": "recorded",
+                    "executed": executed,
+                    "copied_value": copied_value,
+                }))?;
+            } else if executed {
+                println!("Order placed (${copied_value}) and movement recorded.");
             } else {
                 println!("Movement recorded.");
             }
             Ok(())
         }
         CopyCommand::Settle(settle) => {
+            if settle.auto {
+                return settle_auto(output, &wallet).await;
+            }
+            let (Some(movement_id), Some(pnl)) = (settle.movement_id, settle.pnl) else {
+                bail!("--movement-id and --pnl are required unless --auto is set");
+            };
             let mut state = load_state()?;
             let movement = state
                 .movements
                 .iter_mut()
-                .find(|m| m.movement_id == settle.movement_id)
-                .ok_or_else(|| anyhow!("movement not found: {}", settle.movement_id))?;
+                .find(|m| m.movement_id == movement_id)
+                .ok_or_else(|| anyhow!("movement not found: {movement_id}"))?;
             movement.settled = true;
-            movement.pnl = settle.pnl;
+            movement.pnl = pnl;
             let movement_for_log = movement.clone();
             save_state(&state)?;
-            let mode = current_mode_from_disk();
-            settle_db_movement(mode, &settle.movement_id, settle.pnl)?;
-            if let Err(e) = append_settlement_log(mode, &movement_for_log) {
-                eprintln!("warning: could not append settlement log: {e}");
+            let cfg = load_config().ok();
+            let mode = cfg.as_ref().map_or(StorageMode::Real, mode_from_config);
+            settle_db_movement(mode, &movement_id, pnl)?;
+            if let Err(e) = append_settlement_log(cfg.as_ref(), mode, &movement_for_log) {
+                tracing::warn!("could not append settlement log: {e}");
             }
-            if matches!(output, OutputFormat::Json) {
+            if matches!(output, OutputFormat::Json | OutputFormat::Yaml) {
                 crate::output::print_json(&serde_json::json!({"status": "settled"}))?;
             } else {
                 println!("Movement settled and funds released.");
             }
             Ok(())
         }
-        CopyCommand::Dashboard => {
+        CopyCommand::Dashboard(dashboard) => {
             let state = load_state()?;
-            crate::output::copy::print_dashboard(&state, output)
+            let allocated_funds = load_config().ok().map(|c| c.allocated_funds);
+            crate::output::copy::print_dashboard(
+                &state,
+                allocated_funds,
+                dashboard.summary_only,
+                dashboard.chart,
+                output,
+            )
+        }
+        CopyCommand::Ui(ui) => run_ui(ui, wallet).await,
+        CopyCommand::Monitor => run_monitor(wallet).await,
+        CopyCommand::Compact(compact) => {
+            let retention_days = match compact.retention_days {
+                Some(days) => days,
+                None => load_config()?.history_retention_days.ok_or_else(|| {
+                    anyhow!(
+                        "No retention window configured. Pass --retention-days or configure --history-retention-days"
+                    )
+                })?,
+            };
+            let mode = current_mode_from_disk();
+            let pruned = compact_db(mode, retention_days)?;
+            if matches!(output, OutputFormat::Json | OutputFormat::Yaml) {
+                crate::output::print_json(&serde_json::json!({
+                    "status": "compacted",
+                    "pruned": pruned,
+                    "retention_days": retention_days,
+                }))?;
+            } else {
+                println!("Pruned {pruned} settled movement(s) older than {retention_days} day(s).");
+            }
+            Ok(())
+        }
+        CopyCommand::Export(args) => {
+            let mode = history_mode(args.mode);
+            let mut rows = read_db_rows(mode)?;
+            if args.settled_only {
+                rows.retain(|r| r.settled);
+            }
+            let csv = db_rows_csv(&rows);
+            match args.out {
+                Some(path) => {
+                    fs::write(&path, csv)
+                        .with_context(|| format!("Failed to write {}", path.display()))?;
+                    if matches!(output, OutputFormat::Json | OutputFormat::Yaml) {
+                        crate::output::print_json(&serde_json::json!({
+                            "status": "exported",
+                            "rows": rows.len(),
+                            "path": path.display().to_string(),
+                        }))?;
+                    } else {
+                        println!("Exported {} movement(s) to {}", rows.len(), path.display());
+                    }
+                }
+                None => print!("{csv}"),
+            }
+            Ok(())
+        }
+        CopyCommand::Import(args) => {
+            let mode = history_mode(args.mode);
+            let raw = fs::read_to_string(&args.path)
+                .with_context(|| format!("Failed to read {}", args.path.display()))?;
+            let (incoming, malformed) = parse_import_rows(&raw);
+            if malformed > 0 {
+                tracing::warn!(
+                    "skipped {malformed} malformed line(s) in {}",
+                    args.path.display()
+                );
+            }
+            let mut rows = read_db_rows(mode)?;
+            let archived = read_archived_movement_ids(mode)?;
+            let (added, duplicates, open) =
+                merge_db_rows(&mut rows, incoming, &archived, read_db_seq(mode));
+            if added > 0 {
+                write_db_rows(mode, &rows)?;
+                if let Some(last) = rows.last() {
+                    write_db_seq(mode, last.id)?;
+                }
+            }
+            if matches!(output, OutputFormat::Json | OutputFormat::Yaml) {
+                crate::output::print_json(&serde_json::json!({
+                    "status": "imported",
+                    "added": added,
+                    "duplicates": duplicates,
+                    "open_skipped": open,
+                    "malformed": malformed,
+                }))?;
+            } else {
+                println!(
+                    "Imported {added} movement(s) from {} ({duplicates} already present, {open} still open skipped, {malformed} malformed skipped)",
+                    args.path.display()
+                );
+            }
+            Ok(())
+        }
+        CopyCommand::Replay(args) => {
+            validate_config(&args.config)?;
+            let mode = history_mode(args.mode);
+            let cfg = config_from_args(args.config);
+            let history = load_state_from_db(mode)?;
+            let summary = replay_history(
+                &cfg,
+                fee_schedule(),
+                &history.movements,
+                args.leader_positions_value,
+            )?;
+            crate::output::copy::print_replay(&summary, output)
+        }
+        CopyCommand::Preview => {
+            let config = load_config()?;
+            let rows = preview_trades(&config).await?;
+            crate::output::copy::print_preview(&rows, output)
+        }
+        CopyCommand::Decisions(args) => {
+            let decisions = read_decisions(&decisions_path()?)?;
+            let decisions = latest_decisions(decisions, args.limit, args.acted_only);
+            crate::output::copy::print_decisions(&decisions, output)
+        }
+        CopyCommand::Reset(args) => {
+            let label = match args.mode {
+                ResetMode::Real => "real",
+                ResetMode::Sim => "simulation",
+                ResetMode::All => "real and simulation",
+            };
+            if !args.yes {
+                use std::io::BufRead;
+                print!("Delete the {label} copy-trading history and state? [y/N] ");
+                std::io::stdout().flush()?;
+                let mut input = String::new();
+                std::io::stdin().lock().read_line(&mut input)?;
+                if !matches!(input.trim().to_lowercase().as_str(), "y" | "yes") {
+                    println!("Aborted.");
+                    return Ok(());
+                }
+            }
+            let cleared = reset_history(args.mode.storage_modes(), args.rotate_logs)?;
+            if matches!(output, OutputFormat::Json | OutputFormat::Yaml) {
+                crate::output::print_json(&serde_json::json!({
+                    "status": "reset",
+                    "mode": label,
+                    "cleared": cleared,
+                    "logs_rotated": args.rotate_logs,
+                }))?;
+            } else {
+                println!(
+                    "Cleared {cleared} movement(s) from the {label} history. Configuration kept."
+                );
+            }
+            Ok(())
+        }
+        CopyCommand::Settlements(args) => {
+            let mut entries = read_settlement_log(&settlement_log_path()?)?;
+            if let Some(since) = args.since {
+                entries.retain(|e| e.timestamp.date_naive() >= since);
+            }
+            sort_settlements(&mut entries, args.sort);
+            match args.group_by {
+                Some(SettlementGroupBy::Market) => crate::output::copy::print_settlement_groups(
+                    &group_settlements_by_market(entries),
+                    output,
+                ),
+                None => crate::output::copy::print_settlements(&entries, output),
+            }
         }
-        CopyCommand::Ui(ui) => run_ui(ui).await,
     }
 }
 
 #[derive(Clone)]
 struct UiAppState {
     runtime: Arc<Mutex<RuntimeState>>,
+    /// Held by `monitor_loop` for the whole of each tick, so shutdown can
+    /// wait for the tick in flight instead of cutting a db write short.
+    tick: Arc<Mutex<()>>,
+    /// Closed-position and resolved-market syncs spawned by the ticks; they
+    /// write movements too, so shutdown drains them after the tick.
+    sync_tasks: Arc<Mutex<tokio::task::JoinSet<()>>>,
+    /// Signs the monitor's orders and names the account whose closed
+    /// positions settle them.
+    wallet: Arc<CopyWallet>,
+}
+
+impl UiAppState {
+    /// Runs a background sync on `sync_tasks`, reaping the ones that finished.
+    async fn spawn_sync(&self, task: impl Future<Output = ()> + Send + 'static) {
+        let mut tasks = self.sync_tasks.lock().await;
+        while tasks.try_join_next().is_some() {}
+        tasks.spawn(task);
+    }
 }
 
 #[derive(Default)]
@@ -339,9 +1471,19 @@ struct RuntimeState {
     config: Option<CopyConfig>,
     monitoring: bool,
     current_poll_interval_ms: u64,
+    /// Successful API calls since the last error; drives backoff decay.
+    consecutive_successes: u32,
     warning: Option<String>,
-    last_seen_trade_keys_real: HashSet<String>,
-    last_seen_trade_keys_sim: HashSet<String>,
+    /// When the monitor last hit an API or order error, for `/metrics`.
+    last_error_at_ms: Option<i64>,
+    /// Lowercased leaders whose trades are skipped after hitting
+    /// `per_leader_disable_loss_usd`.
+    disabled_leaders: BTreeSet<String>,
+    /// When each leader was re-enabled from the UI; only movements opened
+    /// since then count towards disabling it again.
+    leader_reenabled_at: HashMap<String, String>,
+    last_seen_trade_keys_real: SeenTradeKeys,
+    last_seen_trade_keys_sim: SeenTradeKeys,
     simulation_tick: u64,
     next_closed_sync_real_at_ms: i64,
     next_closed_sync_sim_at_ms: i64,
@@ -357,6 +1499,111 @@ struct RuntimeState {
     market_sync_sim_in_flight: bool,
     simulation_bootstrap_done: bool,
     simulation_bootstrap_next_retry_at_ms: i64,
+    next_compaction_at_ms: i64,
+}
+
+/// The part of `RuntimeState` that survives a restart of `copy ui`, stored in
+/// `copy_trader_monitor.json` next to the config.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+struct MonitorState {
+    monitoring: bool,
+    current_poll_interval_ms: u64,
+    /// Mode the monitor was running in, so a config switched between real and
+    /// simulation while stopped is not resumed in the other mode.
+    #[serde(default)]
+    simulation_mode: bool,
+    /// Leaders auto-disabled for losses, kept so a restart does not resume
+    /// copying them until they are re-enabled from the UI.
+    #[serde(default)]
+    disabled_leaders: BTreeSet<String>,
+    /// When each leader was last re-enabled; losses before it no longer count.
+    #[serde(default)]
+    leader_reenabled_at: HashMap<String, String>,
+}
+
+impl MonitorState {
+    fn from_runtime(runtime: &RuntimeState) -> Self {
+        Self {
+            monitoring: runtime.monitoring,
+            current_poll_interval_ms: runtime.current_poll_interval_ms,
+            simulation_mode: runtime.config.as_ref().is_some_and(|c| c.simulation_mode),
+            disabled_leaders: runtime.disabled_leaders.clone(),
+            leader_reenabled_at: runtime.leader_reenabled_at.clone(),
+        }
+    }
+
+    fn restore_leaders(&self, runtime: &mut RuntimeState) {
+        runtime.disabled_leaders = self.disabled_leaders.clone();
+        runtime.leader_reenabled_at = self.leader_reenabled_at.clone();
+    }
+
+    /// Whether a UI starting with `config` should resume monitoring.
+    fn should_resume(&self, config: Option<&CopyConfig>) -> bool {
+        self.monitoring && config.is_some_and(|c| c.simulation_mode == self.simulation_mode)
+    }
+}
+
+pub(crate) const MAX_SEEN_TRADE_KEYS: usize = 5_000;
+const COMPACTION_INTERVAL_MS: i64 = 3_600_000;
+
+/// Insertion-ordered set of seen trade keys that evicts the oldest entries once
+/// it grows past `MAX_SEEN_TRADE_KEYS`, so long-running monitors stay bounded.
+/// Also remembers which leaders have been primed (lowercased addresses).
+#[derive(Default)]
+struct SeenTradeKeys {
+    keys: HashSet<String>,
+    order: VecDeque<String>,
+    primed_leaders: HashSet<String>,
+}
+
+impl SeenTradeKeys {
+    fn contains(&self, key: &str) -> bool {
+        self.keys.contains(key)
+    }
+
+    fn insert(&mut self, key: String) {
+        if self.keys.insert(key.clone()) {
+            self.order.push_back(key);
+        }
+        while self.order.len() > MAX_SEEN_TRADE_KEYS {
+            if let Some(oldest) = self.order.pop_front() {
+                self.keys.remove(&oldest);
+            }
+        }
+    }
+
+    /// Returns false for a fill already handled. Buys are marked seen right
+    /// away; sells only once they settle a copy, are copied or are skipped,
+    /// so a failed order or settlement is retried on the next sweep.
+    fn claim(&mut self, key: &str, is_sell: bool) -> bool {
+        if self.contains(key) {
+            return false;
+        }
+        if !is_sell {
+            self.insert(key.to_string());
+        }
+        true
+    }
+
+    /// On the first successful fetch for `leader` marks every fetched trade as
+    /// seen so its history isn't copied, and returns true; later sweeps return
+    /// false. Tracked per leader so one added while monitoring, or whose first
+    /// fetch failed, is primed too instead of having its history copied.
+    fn prime_leader(&mut self, leader: &str, keys: impl IntoIterator<Item = String>) -> bool {
+        if !self.primed_leaders.insert(leader.to_ascii_lowercase()) {
+            return false;
+        }
+        for key in keys {
+            self.insert(key);
+        }
+        true
+    }
+
+    fn clear(&mut self) {
+        self.keys.clear();
+        self.order.clear();
+        self.primed_leaders.clear();
+    }
 }
 
 const CLOSED_SYNC_BASE_MS: u64 = 30_000;
@@ -365,15 +1612,19 @@ const MARKET_SYNC_BASE_MS: u64 = 30_000;
 const MARKET_SYNC_MAX_BACKOFF_MS: u64 = 120_000;
 const SIM_BOOTSTRAP_RETRY_MS: u64 = 300_000;
 
-#[derive(Serialize)]
-struct UiStateResponse {
+#[derive(Serialize, JsonSchema)]
+pub(crate) struct UiStateResponse {
     configured: bool,
     monitoring: bool,
     config: Option<CopyConfig>,
     current_poll_interval_ms: u64,
     warning: Option<String>,
     active_mode: String,
+    /// execute, paper, dry-run or simulation (see `run_mode_label`).
+    run_mode: String,
     movement_count: usize,
+    /// Leaders skipped after reaching `per_leader_disable_loss_usd`.
+    disabled_leaders: Vec<String>,
     initial_allocated_funds: Decimal,
     current_equity: Decimal,
     used_exposure: Decimal,
@@ -383,17 +1634,23 @@ struct UiStateResponse {
     recent_movements: Vec<DbMovement>,
 }
 
-#[derive(Serialize)]
-struct UpdatesResponse {
+#[derive(Serialize, JsonSchema)]
+pub(crate) struct UpdatesResponse {
     latest_id: i64,
     movements: Vec<DbMovement>,
 }
 
-#[derive(Serialize, Clone)]
-struct DbMovement {
+#[derive(Serialize, JsonSchema, Clone)]
+pub(crate) struct DbMovement {
     id: i64,
     movement_id: String,
+    #[serde(default)]
+    leader: String,
+    #[serde(default)]
+    paper: bool,
     market: String,
+    #[serde(default)]
+    asset: String,
     timestamp: String,
     leader_value: String,
     #[serde(default)]
@@ -416,7 +1673,7 @@ struct DbMovement {
     pnl: String,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 struct TradingFeeImpact {
     fee_bps: u32,
     entry_fee_usd: Decimal,
@@ -425,63 +1682,411 @@ struct TradingFeeImpact {
     max_net_profit_usd: Decimal,
 }
 
-async fn run_ui(ui: UiArgs) -> Result<()> {
+async fn run_ui(ui: UiArgs, wallet: CopyWallet) -> Result<()> {
     if ui.host != "127.0.0.1" && ui.host != "localhost" {
         bail!("For security, UI host must be 127.0.0.1 or localhost");
     }
 
     init_db(StorageMode::Real)?;
     let token = generate_api_token()?;
-    let addr = format!("{}:{}", ui.host, ui.port);
-    println!("Copy UI running at http://{addr}");
-    println!("UI API token: {token}");
+
+    let template = Arc::new(resolve_ui_template(ui.ui_template, ui.reload_template)?);
+    for problem in ui_template_problems(&ui_html(&template)) {
+        tracing::warn!("UI template looks broken: {problem}");
+    }
 
     let app_state = UiAppState {
-        runtime: Arc::new(Mutex::new(RuntimeState {
-            config: load_config().ok(),
-            monitoring: false,
-            current_poll_interval_ms: load_config()
-                .ok()
-                .map(|c| normalize_poll_ms(c.poll_interval_ms, c.realtime_mode, c.simulation_mode))
-                .unwrap_or(default_poll_interval_ms()),
-            warning: None,
-            last_seen_trade_keys_real: HashSet::new(),
-            last_seen_trade_keys_sim: HashSet::new(),
-            simulation_tick: 0,
-            next_closed_sync_real_at_ms: 0,
-            next_closed_sync_sim_at_ms: 0,
-            closed_sync_backoff_real_ms: CLOSED_SYNC_BASE_MS,
-            closed_sync_backoff_sim_ms: CLOSED_SYNC_BASE_MS,
-            closed_sync_real_in_flight: false,
-            closed_sync_sim_in_flight: false,
-            next_market_sync_real_at_ms: 0,
-            next_market_sync_sim_at_ms: 0,
-            market_sync_backoff_real_ms: MARKET_SYNC_BASE_MS,
-            market_sync_backoff_sim_ms: MARKET_SYNC_BASE_MS,
-            market_sync_real_in_flight: false,
-            market_sync_sim_in_flight: false,
-            simulation_bootstrap_done: false,
-            simulation_bootstrap_next_retry_at_ms: 0,
-        })),
+        runtime: Arc::new(Mutex::new(new_runtime_state(load_config().ok()))),
+        tick: Arc::default(),
+        sync_tasks: Arc::default(),
+        wallet: Arc::new(wallet),
+    };
+    resume_monitoring(&app_state).await;
+
+    if let Some(path) = ui.socket {
+        serve_unix_socket(&path, &app_state, &token, &template).await?;
+    } else {
+        let addr = format!("{}:{}", ui.host, ui.port);
+        let listener = tokio::net::TcpListener::bind(&addr).await?;
+        println!("Copy UI running at http://{addr}");
+        println!("UI API token: {token}");
+        let shutdown = tokio::signal::ctrl_c();
+        tokio::pin!(shutdown);
+        loop {
+            tokio::select! {
+                accepted = listener.accept() => {
+                    let (stream, peer) = accepted?;
+                    let stream = stream.into_std()?;
+                    stream.set_nonblocking(false)?;
+                    stream.set_read_timeout(Some(HTTP_READ_TIMEOUT))?;
+                    stream.set_write_timeout(Some(HTTP_WRITE_TIMEOUT))?;
+                    let local = peer.ip().is_loopback();
+                    spawn_connection(stream, local, &app_state, &token, &template);
+                }
+                _ = &mut shutdown => break,
+            }
+        }
+    }
+    shutdown_monitor(&app_state, true).await;
+    Ok(())
+}
+
+/// How long Ctrl-C waits for the monitor tick in flight before exiting anyway.
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(30);
+
+/// Ctrl-C handling for `copy ui` and `copy monitor`: keeps the monitor from
+/// starting another tick and waits for the current one, so the process never
+/// exits halfway through a db write. `copy ui` also saves the monitor state
+/// first, so its next start resumes monitoring as before.
+async fn shutdown_monitor(app: &UiAppState, save_monitor_state: bool) {
+    log_copy_event(
+        "core",
+        "apagado solicitado; esperando a que termine el ciclo en curso",
+    );
+    {
+        let mut runtime = app.runtime.lock().await;
+        if save_monitor_state {
+            persist_monitor_state(&runtime);
+        }
+        runtime.monitoring = false;
+    }
+    let drained = tokio::time::timeout(SHUTDOWN_GRACE, async {
+        // Holding the tick keeps a new one from spawning more syncs meanwhile.
+        let _tick = app.tick.lock().await;
+        let mut tasks = app.sync_tasks.lock().await;
+        while tasks.join_next().await.is_some() {}
+    })
+    .await;
+    match drained {
+        Ok(()) => log_copy_event("core", "apagado completado"),
+        Err(_) => log_copy_event(
+            "core",
+            format!(
+                "apagado sin esperar: el ciclo o la sincronizacion en curso no termino en {}s",
+                SHUTDOWN_GRACE.as_secs()
+            ),
+        ),
+    }
+}
+
+/// Restarts `monitor_loop` if the previous UI process was monitoring when it
+/// exited and the config on disk is still in the same real/simulation mode.
+async fn resume_monitoring(app: &UiAppState) {
+    let saved = match load_monitor_state() {
+        Ok(saved) => saved,
+        Err(e) => {
+            tracing::warn!("ignoring monitor state: {e}");
+            return;
+        }
     };
+    {
+        let mut runtime = app.runtime.lock().await;
+        saved.restore_leaders(&mut runtime);
+        if !saved.should_resume(runtime.config.as_ref()) {
+            if saved.monitoring {
+                tracing::warn!(
+                    "not resuming monitoring: copy-trader config is missing or changed mode"
+                );
+            }
+            return;
+        }
+        start_monitoring(&mut runtime);
+        if saved.current_poll_interval_ms > 0 {
+            runtime.current_poll_interval_ms = saved.current_poll_interval_ms;
+        }
+    }
+    println!("Resuming copy monitoring from previous session");
+    let app = app.clone();
+    tokio::spawn(
+        async move {
+            if let Err(e) = monitor_loop(app).await {
+                log_copy_event("core", format!("monitor loop finalizado con error: {e}"));
+            }
+        }
+        .instrument(monitor_span()),
+    );
+}
+
+/// `local` is whether the peer is on this machine (loopback or the Unix
+/// socket); only local peers may read `/metrics` without the token.
+fn spawn_connection<S>(
+    stream: S,
+    local: bool,
+    app: &UiAppState,
+    token: &str,
+    template: &Arc<UiTemplate>,
+) where
+    S: Read + Write + Send + 'static,
+{
+    let app = app.clone();
+    let token = token.to_string();
+    let template = Arc::clone(template);
+    tokio::spawn(async move {
+        let _ = handle_http(stream, local, app, &token, &template).await;
+    });
+}
 
-    let listener = TcpListener::bind(&addr)?;
+/// Serves the UI on a Unix socket until Ctrl-C, then removes the socket file.
+#[cfg(unix)]
+async fn serve_unix_socket(
+    path: &Path,
+    app: &UiAppState,
+    token: &str,
+    template: &Arc<UiTemplate>,
+) -> Result<()> {
+    use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+    use tokio::net::UnixListener;
+
+    // A socket file left behind by a previous run would make bind fail; never
+    // remove anything that isn't a socket.
+    if let Ok(meta) = fs::symlink_metadata(path) {
+        if !meta.file_type().is_socket() {
+            bail!("{} exists and is not a socket", path.display());
+        }
+        fs::remove_file(path)
+            .with_context(|| format!("Failed to remove stale socket {}", path.display()))?;
+    }
+    let listener = UnixListener::bind(path)
+        .with_context(|| format!("Failed to bind UI socket {}", path.display()))?;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+    println!("Copy UI listening on unix:{}", path.display());
+    println!("UI API token: {token}");
+    let shutdown = tokio::signal::ctrl_c();
+    tokio::pin!(shutdown);
     loop {
-        let (stream, _) = listener.accept()?;
-        let app = app_state.clone();
-        let token = token.clone();
-        tokio::spawn(async move {
-            let _ = handle_http(stream, app, &token).await;
-        });
+        tokio::select! {
+            accepted = listener.accept() => {
+                let stream = accepted?.0.into_std()?;
+                stream.set_nonblocking(false)?;
+                stream.set_read_timeout(Some(HTTP_READ_TIMEOUT))?;
+                stream.set_write_timeout(Some(HTTP_WRITE_TIMEOUT))?;
+                spawn_connection(stream, true, app, token, template);
+            }
+            _ = &mut shutdown => break,
+        }
+    }
+    let _ = fs::remove_file(path);
+    Ok(())
+}
+
+#[cfg(not(unix))]
+async fn serve_unix_socket(
+    _path: &Path,
+    _app: &UiAppState,
+    _token: &str,
+    _template: &Arc<UiTemplate>,
+) -> Result<()> {
+    bail!("--socket is only supported on Unix platforms")
+}
+
+const EMBEDDED_UI_HTML: &str = include_str!("../output/copy_ui.html");
+const UI_REQUIRED_ENDPOINTS: [&str; 5] = [
+    "/api/state",
+    "/api/updates",
+    "/api/configure",
+    "/api/start",
+    "/api/stop",
+];
+
+enum UiTemplate {
+    Embedded,
+    Loaded(String),
+    Reload(PathBuf),
+}
+
+fn resolve_ui_template(path: Option<PathBuf>, reload: bool) -> Result<UiTemplate> {
+    match (path, reload) {
+        (None, false) => Ok(UiTemplate::Embedded),
+        (Some(path), false) => {
+            let html = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read UI template {}", path.display()))?;
+            Ok(UiTemplate::Loaded(html))
+        }
+        (path, true) => {
+            let path = path.unwrap_or_else(|| {
+                PathBuf::from(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/src/output/copy_ui.html"
+                ))
+            });
+            println!(
+                "Reloading UI template from {} on every request",
+                path.display()
+            );
+            Ok(UiTemplate::Reload(path))
+        }
+    }
+}
+
+fn ui_html(template: &UiTemplate) -> std::borrow::Cow<'_, str> {
+    match template {
+        UiTemplate::Embedded => EMBEDDED_UI_HTML.into(),
+        UiTemplate::Loaded(html) => html.as_str().into(),
+        UiTemplate::Reload(path) => match fs::read_to_string(path) {
+            Ok(html) => html.into(),
+            Err(e) => {
+                tracing::warn!(
+                    "could not read UI template {} ({e}); serving embedded copy",
+                    path.display()
+                );
+                EMBEDDED_UI_HTML.into()
+            }
+        },
+    }
+}
+
+/// Cheap sanity check that the HTML we are about to serve is the copy UI:
+/// non-empty and wired to every API endpoint the server exposes.
+fn ui_template_problems(html: &str) -> Vec<String> {
+    if html.trim().is_empty() {
+        return vec!["template is empty".to_string()];
+    }
+    UI_REQUIRED_ENDPOINTS
+        .iter()
+        .filter(|endpoint| !html.contains(*endpoint))
+        .map(|endpoint| format!("missing reference to {endpoint}"))
+        .collect()
+}
+
+/// One-shot version of the monitor's closed-position reconciliation: fetches
+/// closed positions once (the executing wallet's when `execute_orders` is set,
+/// otherwise every leader's) and settles every matching open movement.
+async fn settle_auto(output: OutputFormat, wallet: &CopyWallet) -> Result<()> {
+    let config = load_config()?;
+    let mode = mode_from_config(&config);
+    let users = if posts_orders(&config) {
+        vec![wallet.trading_address()?]
+    } else {
+        leader_addresses(&config)?
+    };
+    let scope = match mode {
+        StorageMode::Real => "real",
+        StorageMode::Simulation => "sim",
+    };
+
+    let data_client = polymarket_client_sdk::data::Client::default();
+    let mut settled = 0;
+    let mut closed_count = 0;
+    for user in users {
+        let closed_positions = fetch_closed_positions_paginated(&data_client, user, scope).await?;
+        closed_count += closed_positions.len();
+        settled += apply_settlements_from_closed_positions(
+            &config,
+            mode,
+            scope,
+            &closed_positions,
+            settlement_leader(&config, user),
+        )?;
+    }
+
+    if matches!(output, OutputFormat::Json | OutputFormat::Yaml) {
+        crate::output::print_json(&serde_json::json!({
+            "status": "settled",
+            "settled": settled,
+            "closed_positions": closed_count,
+        }))?;
+    } else {
+        println!("Settled {settled} open movement(s) from {closed_count} closed position(s).");
+    }
+    Ok(())
+}
+
+async fn run_monitor(wallet: CopyWallet) -> Result<()> {
+    let config = load_config()?;
+    init_db(mode_from_config(&config))?;
+
+    let app_state = UiAppState {
+        runtime: Arc::new(Mutex::new(new_runtime_state(Some(config)))),
+        tick: Arc::default(),
+        sync_tasks: Arc::default(),
+        wallet: Arc::new(wallet),
+    };
+    {
+        let mut runtime = app_state.runtime.lock().await;
+        match load_monitor_state() {
+            Ok(saved) => saved.restore_leaders(&mut runtime),
+            Err(e) => tracing::warn!("ignoring monitor state: {e}"),
+        }
+        start_monitoring(&mut runtime);
+    }
+    println!("Copy monitor running without UI. Press Ctrl-C to stop.");
+    // Spawned so the tick in flight keeps running while Ctrl-C waits for it.
+    let monitor = tokio::spawn(monitor_loop(app_state.clone()).instrument(monitor_span()));
+    tokio::select! {
+        joined = monitor => joined.context("copy monitor task failed")?,
+        _ = tokio::signal::ctrl_c() => {
+            shutdown_monitor(&app_state, false).await;
+            Ok(())
+        }
+    }
+}
+
+fn new_runtime_state(config: Option<CopyConfig>) -> RuntimeState {
+    let current_poll_interval_ms = config
+        .as_ref()
+        .map(|c| normalize_poll_ms(c.poll_interval_ms, c.realtime_mode, c.simulation_mode))
+        .unwrap_or(default_poll_interval_ms());
+    RuntimeState {
+        config,
+        monitoring: false,
+        current_poll_interval_ms,
+        consecutive_successes: 0,
+        warning: None,
+        last_error_at_ms: None,
+        disabled_leaders: BTreeSet::new(),
+        leader_reenabled_at: HashMap::new(),
+        last_seen_trade_keys_real: SeenTradeKeys::default(),
+        last_seen_trade_keys_sim: SeenTradeKeys::default(),
+        simulation_tick: 0,
+        next_closed_sync_real_at_ms: 0,
+        next_closed_sync_sim_at_ms: 0,
+        closed_sync_backoff_real_ms: CLOSED_SYNC_BASE_MS,
+        closed_sync_backoff_sim_ms: CLOSED_SYNC_BASE_MS,
+        closed_sync_real_in_flight: false,
+        closed_sync_sim_in_flight: false,
+        next_market_sync_real_at_ms: 0,
+        next_market_sync_sim_at_ms: 0,
+        market_sync_backoff_real_ms: MARKET_SYNC_BASE_MS,
+        market_sync_backoff_sim_ms: MARKET_SYNC_BASE_MS,
+        market_sync_real_in_flight: false,
+        market_sync_sim_in_flight: false,
+        simulation_bootstrap_done: false,
+        simulation_bootstrap_next_retry_at_ms: 0,
+        next_compaction_at_ms: 0,
     }
 }
 
-async fn handle_http(mut stream: TcpStream, app: UiAppState, token: &str) -> Result<()> {
+/// Marks the runtime as monitoring and resets per-run bookkeeping. Shared by
+/// `copy monitor` and the UI's `/api/start` so both start the loop identically.
+fn start_monitoring(runtime: &mut RuntimeState) {
+    runtime.monitoring = true;
+    runtime.simulation_bootstrap_done = false;
+    runtime.simulation_bootstrap_next_retry_at_ms = 0;
+    runtime.last_seen_trade_keys_real.clear();
+    runtime.last_seen_trade_keys_sim.clear();
+    let mode = runtime
+        .config
+        .as_ref()
+        .map(|c| if c.simulation_mode { "sim" } else { "real" })
+        .unwrap_or("real");
+    log_copy_event(mode, "monitor iniciado");
+}
+
+async fn handle_http<S: Read + Write>(
+    mut stream: S,
+    local: bool,
+    app: UiAppState,
+    token: &str,
+    template: &UiTemplate,
+) -> Result<()> {
     let request = read_http_request(&mut stream)?;
     let (method, path, query) = parse_request_line(&request)?;
     let headers = parse_headers(&request);
     let body = request.split("\r\n\r\n").nth(1).unwrap_or("");
-    if path.starts_with("/api/") && !is_authorized(&headers, query, token) {
+    // Prometheus scrapers can't easily send the token header, so /metrics is
+    // open to local peers only.
+    let needs_token = path.starts_with("/api/") || (path == "/metrics" && !local);
+    if needs_token && !is_authorized(&headers, query, token) {
         write_response(
             &mut stream,
             "401 Unauthorized",
@@ -496,7 +2101,7 @@ async fn handle_http(mut stream: TcpStream, app: UiAppState, token: &str) -> Res
             &mut stream,
             "200 OK",
             "text/html; charset=utf-8",
-            include_str!("../output/copy_ui.html"),
+            &ui_html(template),
         )?,
         ("GET", "/api/state") => {
             let runtime = app.runtime.lock().await;
@@ -507,18 +2112,8 @@ async fn handle_http(mut stream: TcpStream, app: UiAppState, token: &str) -> Res
                 .as_ref()
                 .map(|c| c.allocated_funds)
                 .unwrap_or(Decimal::ZERO);
-            let settled_pnl_after_fees: Decimal = db_state
-                .movements
-                .iter()
-                .filter(|m| m.settled)
-                .map(|m| m.pnl - m.estimated_total_fee_usd)
-                .sum();
-            let used_exposure: Decimal = db_state
-                .movements
-                .iter()
-                .filter(|m| !m.settled)
-                .map(|m| m.copied_value)
-                .sum();
+            let settled_pnl_after_fees = settled_pnl_after_fees(&db_state.movements);
+            let used_exposure = open_exposure(&db_state.movements);
             let current_equity = initial_allocated_funds + settled_pnl_after_fees;
             let available_to_copy = (current_equity - used_exposure).max(Decimal::ZERO);
 
@@ -545,7 +2140,13 @@ async fn handle_http(mut stream: TcpStream, app: UiAppState, token: &str) -> Res
                     })
                     .unwrap_or("real")
                     .to_string(),
+                run_mode: runtime
+                    .config
+                    .as_ref()
+                    .map_or("dry-run", run_mode_label)
+                    .to_string(),
                 movement_count: db_state.movements.len(),
+                disabled_leaders: runtime.disabled_leaders.iter().cloned().collect(),
                 initial_allocated_funds,
                 current_equity,
                 used_exposure,
@@ -556,6 +2157,16 @@ async fn handle_http(mut stream: TcpStream, app: UiAppState, token: &str) -> Res
             })?;
             write_response(&mut stream, "200 OK", "application/json", &payload)?;
         }
+        ("GET", "/metrics") => {
+            let runtime = app.runtime.lock().await;
+            let db_state = load_state_from_db(current_mode_from_runtime(&runtime))?;
+            write_response(
+                &mut stream,
+                "200 OK",
+                "text/plain; version=0.0.4; charset=utf-8",
+                &prometheus_metrics(&db_state, &runtime),
+            )?;
+        }
         ("GET", "/api/updates") => {
             let since = parse_since(query);
             let runtime = app.runtime.lock().await;
@@ -567,27 +2178,14 @@ async fn handle_http(mut stream: TcpStream, app: UiAppState, token: &str) -> Res
             })?;
             write_response(&mut stream, "200 OK", "application/json", &payload)?;
         }
+        ("GET", "/api/stream") => {
+            let events = movement_events().subscribe();
+            stream_movements(&mut stream, &app, events).await?;
+        }
         ("POST", "/api/configure") => {
             let cfg: ConfigureArgs = serde_json::from_str(body).context("invalid json")?;
             validate_config(&cfg)?;
-            let config = CopyConfig {
-                leader: cfg.leader,
-                allocated_funds: cfg.allocated_funds,
-                max_trade_pct: cfg.max_trade_pct,
-                max_total_exposure_pct: cfg.max_total_exposure_pct,
-                min_copy_usd: cfg.min_copy_usd,
-                poll_interval_secs: cfg.poll_interval_secs,
-                poll_interval_ms: normalize_poll_ms(
-                    cfg.poll_interval_ms
-                        .unwrap_or(cfg.poll_interval_secs.saturating_mul(1000)),
-                    cfg.realtime_mode,
-                    cfg.simulation_mode,
-                ),
-                risk_level: cfg.risk_level,
-                execute_orders: cfg.execute_orders,
-                realtime_mode: cfg.realtime_mode,
-                simulation_mode: cfg.simulation_mode,
-            };
+            let config = config_from_args(cfg);
             save_config(&config)?;
             let mut runtime = app.runtime.lock().await;
             runtime.current_poll_interval_ms = config.poll_interval_ms;
@@ -606,24 +2204,39 @@ async fn handle_http(mut stream: TcpStream, app: UiAppState, token: &str) -> Res
                     )?;
                     return Ok(());
                 }
-                runtime.monitoring = true;
-                runtime.simulation_bootstrap_done = false;
-                runtime.simulation_bootstrap_next_retry_at_ms = 0;
-                runtime.last_seen_trade_keys_real.clear();
-                runtime.last_seen_trade_keys_sim.clear();
-                let mode = runtime
-                    .config
-                    .as_ref()
-                    .map(|c| if c.simulation_mode { "sim" } else { "real" })
-                    .unwrap_or("real");
-                log_copy_event(mode, "monitor iniciado");
+                start_monitoring(&mut runtime);
+                persist_monitor_state(&runtime);
             }
             let app_clone = app.clone();
-            tokio::spawn(async move {
-                if let Err(e) = monitor_loop(app_clone).await {
-                    log_copy_event("core", format!("monitor loop finalizado con error: {e}"));
+            tokio::spawn(
+                async move {
+                    if let Err(e) = monitor_loop(app_clone).await {
+                        log_copy_event("core", format!("monitor loop finalizado con error: {e}"));
+                    }
                 }
-            });
+                .instrument(monitor_span()),
+            );
+            write_response(&mut stream, "200 OK", "application/json", "{\"ok\":true}")?;
+        }
+        ("POST", "/api/leaders/enable") => {
+            let body: serde_json::Value = serde_json::from_str(body).context("invalid json")?;
+            let Some(leader) = body["leader"].as_str().map(str::to_lowercase) else {
+                write_response(
+                    &mut stream,
+                    "400 Bad Request",
+                    "application/json",
+                    "{\"error\":\"leader is required\"}",
+                )?;
+                return Ok(());
+            };
+            let mut runtime = app.runtime.lock().await;
+            if runtime.disabled_leaders.remove(&leader) {
+                runtime
+                    .leader_reenabled_at
+                    .insert(leader.clone(), Utc::now().to_rfc3339());
+                log_copy_event("core", format!("lider {leader} reactivado desde la UI"));
+                persist_leader_state(&runtime);
+            }
             write_response(&mut stream, "200 OK", "application/json", "{\"ok\":true}")?;
         }
         ("POST", "/api/stop") => {
@@ -635,6 +2248,7 @@ async fn handle_http(mut stream: TcpStream, app: UiAppState, token: &str) -> Res
                 .map(|c| if c.simulation_mode { "sim" } else { "real" })
                 .unwrap_or("real");
             log_copy_event(mode, "monitor detenido");
+            persist_monitor_state(&runtime);
             write_response(&mut stream, "200 OK", "application/json", "{\"ok\":true}")?;
         }
         _ => write_response(&mut stream, "404 Not Found", "text/plain", "not found")?,
@@ -645,11 +2259,16 @@ async fn handle_http(mut stream: TcpStream, app: UiAppState, token: &str) -> Res
 
 fn log_copy_event(mode: &str, message: impl AsRef<str>) {
     let msg = message.as_ref();
-    println!("[copy:{mode}] {msg}");
-
     if !should_persist_copy_log_message(msg) {
+        // Polling heartbeats are only worth seeing with -v.
+        tracing::debug!(%mode, "{msg}");
         return;
     }
+    if msg.to_ascii_lowercase().contains("error") {
+        tracing::error!(%mode, "{msg}");
+    } else {
+        tracing::info!(%mode, "{msg}");
+    }
 
     let ts = Utc::now().to_rfc3339();
     let line = format!(
@@ -764,6 +2383,12 @@ fn schedule_market_sync_backoff(runtime: &mut RuntimeState, mode: StorageMode) {
     }
 }
 
+/// Every event the monitor logs carries this span, so `-v` output can be
+/// told apart from the UI server's own lines.
+fn monitor_span() -> tracing::Span {
+    tracing::info_span!("copy_monitor")
+}
+
 async fn monitor_loop(app: UiAppState) -> Result<()> {
     let data_client = polymarket_client_sdk::data::Client::default();
     let clob_client = polymarket_client_sdk::clob::Client::default();
@@ -796,6 +2421,7 @@ async fn monitor_loop(app: UiAppState) -> Result<()> {
         let Some(cfg) = cfg else {
             break;
         };
+        let tick = app.tick.lock().await;
 
         log_copy_event(
             "core",
@@ -806,6 +2432,49 @@ async fn monitor_loop(app: UiAppState) -> Result<()> {
             ),
         );
 
+        if let Some(retention_days) = cfg.history_retention_days {
+            let due = {
+                let mut runtime = app.runtime.lock().await;
+                let due = closed_sync_due(runtime.next_compaction_at_ms);
+                if due {
+                    runtime.next_compaction_at_ms = now_ms() + COMPACTION_INTERVAL_MS;
+                }
+                due
+            };
+            if due {
+                let mode = mode_from_config(&cfg);
+                match compact_db(mode, retention_days) {
+                    Ok(0) => {}
+                    Ok(pruned) => log_copy_event(
+                        "core",
+                        format!(
+                            "compactacion: {pruned} movimientos liquidados con mas de {retention_days} dias archivados"
+                        ),
+                    ),
+                    Err(e) => log_copy_event("core", format!("error compactando historial: {e}")),
+                }
+            }
+        }
+
+        let exit_mode = mode_from_config(&cfg);
+        if let Err(e) = apply_exit_thresholds(&app, &cfg, &clob_client, exit_mode).await {
+            log_copy_event(
+                "core",
+                format!("error revisando stop-loss/take-profit: {e}"),
+            );
+        }
+        if cfg.per_leader_disable_loss_usd.is_some() {
+            match load_state_from_db(exit_mode) {
+                Ok(state) => {
+                    let mut runtime = app.runtime.lock().await;
+                    if refresh_disabled_leaders(&mut runtime, &cfg, &state.movements) {
+                        persist_leader_state(&runtime);
+                    }
+                }
+                Err(e) => log_copy_event("core", format!("error revisando pnl por lider: {e}")),
+            }
+        }
+
         if cfg.simulation_mode {
             log_copy_event("sim", format!("tick simulacion (poll={}ms)", poll_ms));
             if let Err(e) = simulation_step(&app, &cfg, &data_client, &clob_client).await {
@@ -817,47 +2486,43 @@ async fn monitor_loop(app: UiAppState) -> Result<()> {
                 "core",
                 format!("ciclo monitor #{loop_tick} finalizado; esperando {poll_ms}ms"),
             );
+            drop(tick);
             tokio::time::sleep(Duration::from_millis(poll_ms)).await;
             continue;
         }
 
-        let leader = match crate::commands::parse_address(&cfg.leader) {
-            Ok(addr) => addr,
+        let leaders = match leader_addresses(&cfg) {
+            Ok(addrs) => addrs,
             Err(e) => {
                 let mut runtime = app.runtime.lock().await;
                 runtime.warning = Some(format!("Leader inválido: {e}"));
                 log_copy_event("real", format!("error parseando leader: {e}"));
+                drop(tick);
                 tokio::time::sleep(Duration::from_millis(poll_ms)).await;
                 continue;
             }
         };
-        let value_req = ValueRequest::builder().user(leader).build();
-        let leader_value = data_client
-            .value(&value_req)
-            .await
-            .ok()
-            .and_then(|v| v.first().map(|x| x.value))
-            .unwrap_or(Decimal::ONE);
 
-        let settlement_user = if cfg.execute_orders {
-            match crate::auth::resolve_signer(None) {
-                Ok(signer) => signer.address(),
+        let settlement_users = if posts_orders(&cfg) {
+            match app.wallet.trading_address() {
+                Ok(address) => vec![address],
                 Err(e) => {
                     let mut runtime = app.runtime.lock().await;
                     runtime.warning = Some(format!(
                         "execute-orders activo pero no hay wallet configurada: {e}"
                     ));
-                    leader
+                    leaders.clone()
                 }
             }
         } else {
-            leader
+            leaders.clone()
         };
 
-        let mut remaining_wallet_value_usd = if cfg.execute_orders {
+        let mut remaining_wallet_value_usd = if posts_orders(&cfg) {
+            let settlement_user = settlement_users[0];
             let wallet_value_req = ValueRequest::builder().user(settlement_user).build();
             match tokio::time::timeout(
-                Duration::from_secs(15),
+                crate::net::request_timeout(),
                 data_client.value(&wallet_value_req),
             )
             .await
@@ -894,8 +2559,9 @@ async fn monitor_loop(app: UiAppState) -> Result<()> {
                     log_copy_event(
                         "real",
                         format!(
-                            "timeout consultando valor wallet ejecutora {} (15s)",
-                            settlement_user
+                            "timeout consultando valor wallet ejecutora {} ({}s)",
+                            settlement_user,
+                            crate::net::request_timeout().as_secs()
                         ),
                     );
                     None
@@ -917,9 +2583,15 @@ async fn monitor_loop(app: UiAppState) -> Result<()> {
                 runtime.closed_sync_real_in_flight = true;
             }
             let app_bg = app.clone();
-            tokio::spawn(async move {
-                run_closed_sync_task(app_bg, settlement_user, StorageMode::Real, "real").await;
-            });
+            let users = settlement_users.clone();
+            app.spawn_sync(run_closed_sync_task(
+                app_bg,
+                cfg.clone(),
+                users,
+                StorageMode::Real,
+                "real",
+            ))
+            .await;
         }
 
         let should_sync_market = {
@@ -934,20 +2606,38 @@ async fn monitor_loop(app: UiAppState) -> Result<()> {
                 runtime.market_sync_real_in_flight = true;
             }
             let app_bg = app.clone();
-            tokio::spawn(async move {
-                run_market_closed_sync_task(app_bg, settlement_user, StorageMode::Real, "real")
-                    .await;
-            });
+            let users = settlement_users.clone();
+            app.spawn_sync(run_market_closed_sync_task(
+                app_bg,
+                cfg.clone(),
+                users,
+                StorageMode::Real,
+                "real",
+            ))
+            .await;
         }
 
-        log_copy_event(
-            "real",
-            format!("consultando ultimos movimientos de la cuenta a copiar ({leader})"),
-        );
-        let trades_req = TradesRequest::builder().user(leader).limit(20)?.build();
-        let trades =
-            match tokio::time::timeout(Duration::from_secs(15), data_client.trades(&trades_req))
+        // Each trade is tagged with the leader it came from and that leader's
+        // positions value, which sizes the copy against the leader's slice.
+        let mut trades = Vec::new();
+        for (leader_cfg, leader) in cfg.leaders.iter().zip(leaders.iter().copied()) {
+            let value_req = ValueRequest::builder().user(leader).build();
+            let leader_value = crate::net::timed(data_client.value(&value_req))
                 .await
+                .ok()
+                .and_then(|v| v.first().map(|x| x.value))
+                .unwrap_or(Decimal::ONE);
+
+            log_copy_event(
+                "real",
+                format!("consultando ultimos movimientos de la cuenta a copiar ({leader})"),
+            );
+            let trades_req = TradesRequest::builder().user(leader).limit(20)?.build();
+            let fetched = match tokio::time::timeout(
+                crate::net::request_timeout(),
+                data_client.trades(&trades_req),
+            )
+            .await
             {
                 Ok(Ok(trades)) => {
                     log_copy_event(
@@ -956,16 +2646,19 @@ async fn monitor_loop(app: UiAppState) -> Result<()> {
                     );
                     let mut runtime = app.runtime.lock().await;
                     runtime.warning = None;
-                    trades
+                    record_api_success(&mut runtime);
+                    Some(trades)
                 }
                 Ok(Err(e)) => {
                     let mut runtime = app.runtime.lock().await;
+                    record_api_error(&mut runtime);
                     let msg = e.to_string();
                     if is_rate_limit_error(&msg) {
-                        runtime.current_poll_interval_ms = runtime
-                            .current_poll_interval_ms
-                            .saturating_add(250)
-                            .max(500);
+                        runtime.current_poll_interval_ms = adjust_poll_interval(
+                            runtime.current_poll_interval_ms,
+                            poll_baseline_ms(runtime.config.as_ref()),
+                            PollEvent::RateLimited,
+                        );
                         runtime.warning = Some(format!(
                             "Rate limit detectado. Aumentando polling a {} ms",
                             runtime.current_poll_interval_ms
@@ -974,52 +2667,60 @@ async fn monitor_loop(app: UiAppState) -> Result<()> {
                         runtime.warning = Some(format!("Error consultando trades: {msg}"));
                     }
                     log_copy_event("real", format!("error consultando trades recientes: {msg}"));
-                    Vec::new()
+                    None
                 }
                 Err(_) => {
                     let mut runtime = app.runtime.lock().await;
+                    record_api_error(&mut runtime);
                     runtime.warning = Some("Timeout consultando trades recientes".to_string());
-                    log_copy_event("real", "timeout consultando ultimos movimientos (15s)");
-                    Vec::new()
+                    log_copy_event(
+                        "real",
+                        format!(
+                            "timeout consultando ultimos movimientos ({}s)",
+                            crate::net::request_timeout().as_secs()
+                        ),
+                    );
+                    None
                 }
             };
-
-        let prime_only = {
-            let mut runtime = app.runtime.lock().await;
-            if runtime.last_seen_trade_keys_real.is_empty() {
-                for t in &trades {
-                    runtime.last_seen_trade_keys_real.insert(trade_event_key(t));
-                }
-                true
-            } else {
-                false
+            let Some(fetched) = fetched else {
+                continue;
+            };
+            // A leader's first successful fetch only primes its history; the
+            // loop keeps polling and later sweeps copy what is new.
+            let primed = {
+                let mut runtime = app.runtime.lock().await;
+                runtime
+                    .last_seen_trade_keys_real
+                    .prime_leader(&leader_cfg.address, fetched.iter().map(trade_event_key))
+            };
+            if primed {
+                log_copy_event(
+                    "real",
+                    format!(
+                        "primer barrido ({leader}): {} trades marcados como vistos (sin copiar histórico)",
+                        fetched.len()
+                    ),
+                );
+                continue;
             }
-        };
-
-        if prime_only {
-            log_copy_event(
-                "real",
-                format!(
-                    "primer barrido: {} trades marcados como vistos (sin copiar histórico)",
-                    trades.len()
-                ),
+            trades.extend(
+                fetched
+                    .into_iter()
+                    .map(|t| (leader_cfg.address.clone(), leader_value, t)),
             );
-            return Ok(());
         }
 
-        for t in trades {
+        for (leader, leader_value, t) in trades {
             let tx_hash = t.transaction_hash.to_string();
             let trade_key = trade_event_key(&t);
             let movement_id = format!("real-{trade_key}");
             let is_sell = t.side.to_string().eq_ignore_ascii_case("sell");
             {
                 let mut runtime = app.runtime.lock().await;
-                if runtime.last_seen_trade_keys_real.contains(&trade_key) {
+                if !runtime.last_seen_trade_keys_real.claim(&trade_key, is_sell) {
                     continue;
                 }
-                if !is_sell {
-                    runtime.last_seen_trade_keys_real.insert(trade_key.clone());
-                }
             }
 
             let mut state = load_state()?;
@@ -1028,18 +2729,13 @@ async fn monitor_loop(app: UiAppState) -> Result<()> {
             }
 
             if is_sell {
-                let settled_from_sell =
-                    settle_open_buys_from_sell_trade(&mut state, &t.slug, &t.outcome, t.price);
+                let settled_from_sell = settle_from_leader_sell(
+                    &cfg, &mut state, &leader, &t.slug, &t.outcome, t.price,
+                );
                 if !settled_from_sell.is_empty() {
                     save_state(&state)?;
                     for movement in settled_from_sell {
-                        settle_db_movement_from_record(StorageMode::Real, &movement)?;
-                        if let Err(e) = append_settlement_log(StorageMode::Real, &movement) {
-                            log_copy_event(
-                                "real",
-                                format!("error escribiendo log de settlement: {e}"),
-                            );
-                        }
+                        record_settlement(&cfg, StorageMode::Real, "real", &movement)?;
                         log_copy_event(
                             "real",
                             format!(
@@ -1058,61 +2754,46 @@ async fn monitor_loop(app: UiAppState) -> Result<()> {
                 }
             }
 
-            let plan = compute_plan(&cfg, &state, leader_value, t.size * t.price)?;
-            if plan.capped_size <= Decimal::ZERO {
-                log_copy_event(
-                    "real",
-                    format!(
-                        "trade detectado {} ({}) sin copia (motivo: {})",
-                        t.slug, tx_hash, plan.reason
-                    ),
-                );
-                continue;
-            }
-
-            if t.side.to_string().eq_ignore_ascii_case("sell") {
-                let required_sell_shares = copied_shares_from_notional(plan.capped_size, t.price);
-                if !has_enough_inventory_for_sell(&state, &t.slug, &t.outcome, required_sell_shares)
-                {
+            // A disabled leader's sells still close its open copies above;
+            // only new positions are skipped.
+            if leader_disabled(&app, &leader).await {
+                if !is_sell {
                     log_copy_event(
                         "real",
-                        format!(
-                            "sell {} ({}) descartado: no hay buy abierto conciliable (outcome={}, required_shares={})",
-                            t.slug, tx_hash, t.outcome, required_sell_shares
-                        ),
+                        format!("lider {leader} desactivado: no se copia {tx_hash}"),
                     );
-                    continue;
                 }
-
-                // If this path is reached, sell did not close previous buys via immediate settlement.
-                // Avoid creating open SELL rows; SELL must always close an existing BUY.
-                log_copy_event(
-                    "real",
-                    format!(
-                        "sell {} ({}) descartado: no se pudo conciliar cierre inmediato; evitando SELL abierto",
-                        t.slug, tx_hash
-                    ),
-                );
                 continue;
             }
 
-            let fee_impact = trading_fee_impact_for_movement(&t.slug, plan.capped_size);
-            if let Some(impact) = fee_impact
-                && impact.max_net_profit_usd <= Decimal::ZERO
-            {
+            let candidate = TradeCandidate::from_trade(&leader, leader_value, &t);
+            let TradeEvaluation {
+                mut plan,
+                fee_impact,
+                skip,
+            } = evaluate_trade(&cfg, fee_schedule(), &state, &candidate, &Utc::now())?;
+            if let Some(skip) = skip {
+                if skip == TradeSkip::DailyLossLimit {
+                    app.runtime.lock().await.warning = Some(
+                        "Límite de pérdida diaria alcanzado: sin nuevas copias hasta medianoche UTC"
+                            .to_string(),
+                    );
+                }
                 log_copy_event(
                     "real",
-                    format!(
-                        "trade {} ({}) descartado por fees ({} bps): profit_max_neto={} (gross_max={} fee_entry={} fees_rt={})",
-                        t.slug,
-                        tx_hash,
-                        impact.fee_bps,
-                        impact.max_net_profit_usd,
-                        impact.max_gross_profit_usd,
-                        impact.entry_fee_usd,
-                        impact.round_trip_fee_usd,
-                    ),
+                    skip_log_message(false, &skip, &plan, &candidate, &tx_hash),
                 );
+                record_decision(&DecisionRecord::new(
+                    "real",
+                    &candidate,
+                    &plan,
+                    fee_impact,
+                    skip.label(&plan),
+                    false,
+                ));
+                // A skipped sell would be re-evaluated (and re-logged) every poll.
+                let mut runtime = app.runtime.lock().await;
+                runtime.last_seen_trade_keys_real.insert(trade_key.clone());
                 continue;
             }
 
@@ -1134,8 +2815,13 @@ async fn monitor_loop(app: UiAppState) -> Result<()> {
             );
 
             let (estimated_sim_price, has_full_liquidity) =
-                match estimate_simulated_copy_price_from_book(&clob_client, &t, plan.capped_size)
-                    .await
+                match estimate_simulated_copy_price_from_book(
+                    &cfg,
+                    &clob_client,
+                    &t,
+                    plan.capped_size,
+                )
+                .await
                 {
                     Ok((Some(px), full_fill)) => {
                         if full_fill {
@@ -1157,6 +2843,24 @@ async fn monitor_loop(app: UiAppState) -> Result<()> {
                         }
                         (Some(px), full_fill)
                     }
+                    Ok((None, _)) if requires_book_depth(&cfg) => {
+                        log_copy_event(
+                            "real",
+                            format!(
+                                "copia descartada: profundidad del libro insuficiente para {} ({})",
+                                t.slug, tx_hash
+                            ),
+                        );
+                        record_decision(&DecisionRecord::new(
+                            "real",
+                            &candidate,
+                            &plan,
+                            fee_impact,
+                            "insufficient book depth",
+                            false,
+                        ));
+                        continue;
+                    }
                     Ok((None, _)) => {
                         log_copy_event(
                             "real",
@@ -1179,7 +2883,7 @@ async fn monitor_loop(app: UiAppState) -> Result<()> {
                     }
                 };
 
-            if cfg.execute_orders {
+            if posts_orders(&cfg) {
                 let Some(wallet_available) = remaining_wallet_value_usd else {
                     log_copy_event(
                         "real",
@@ -1188,6 +2892,14 @@ async fn monitor_loop(app: UiAppState) -> Result<()> {
                             tx_hash
                         ),
                     );
+                    record_decision(&DecisionRecord::new(
+                        "real",
+                        &candidate,
+                        &plan,
+                        fee_impact,
+                        "wallet balance unavailable",
+                        false,
+                    ));
                     continue;
                 };
 
@@ -1204,14 +2916,34 @@ async fn monitor_loop(app: UiAppState) -> Result<()> {
                             tx_hash, wallet_available, plan.capped_size
                         ),
                     );
+                    record_decision(&DecisionRecord::new(
+                        "real",
+                        &candidate,
+                        &plan,
+                        fee_impact,
+                        "insufficient wallet funds",
+                        false,
+                    ));
                     continue;
                 }
 
-                if let Err(e) = execute_copy_order_from_trade(&t, plan.capped_size).await {
-                    let mut runtime = app.runtime.lock().await;
-                    runtime.warning = Some(format!("Error ejecutando orden en wallet: {e}"));
-                    log_copy_event("real", format!("error copiando orden {}: {e}", tx_hash));
-                    continue;
+                match execute_copy_order_from_trade(&app.wallet, &t, plan.capped_size).await {
+                    Ok(submitted) => plan.capped_size = submitted,
+                    Err(e) => {
+                        let mut runtime = app.runtime.lock().await;
+                        runtime.warning = Some(format!("Error ejecutando orden en wallet: {e}"));
+                        runtime.last_error_at_ms = Some(now_ms());
+                        log_copy_event("real", format!("error copiando orden {}: {e}", tx_hash));
+                        record_decision(&DecisionRecord::new(
+                            "real",
+                            &candidate,
+                            &plan,
+                            fee_impact,
+                            format!("order failed: {e}"),
+                            false,
+                        ));
+                        continue;
+                    }
                 }
 
                 remaining_wallet_value_usd =
@@ -1228,7 +2960,10 @@ async fn monitor_loop(app: UiAppState) -> Result<()> {
 
             let record = MovementRecord {
                 movement_id: movement_id.clone(),
-                market: t.slug,
+                leader: leader.clone(),
+                paper: cfg.paper_mode,
+                market: t.slug.clone(),
+                asset: t.asset.to_string(),
                 timestamp: Utc::now().to_rfc3339(),
                 leader_value: t.size * t.price,
                 leader_price: t.price,
@@ -1244,16 +2979,28 @@ async fn monitor_loop(app: UiAppState) -> Result<()> {
                     .unwrap_or(Decimal::ZERO),
                 settled: false,
                 pnl: Decimal::ZERO,
+                remaining_quantity: None,
+                last_partial_close_ts: None,
             };
             let mut updated = state;
             updated.movements.push(record.clone());
             save_state(&updated)?;
-            append_db_movement(StorageMode::Real, &record)?;
+            if let Some(movement) = append_db_movement(StorageMode::Real, &record)? {
+                notify_movement(&cfg, StorageMode::Real, NotifyEvent::Detected, &movement);
+            }
+            record_decision(&DecisionRecord::new(
+                "real",
+                &candidate,
+                &plan,
+                fee_impact,
+                plan.reason.clone(),
+                true,
+            ));
             if is_sell {
                 let mut runtime = app.runtime.lock().await;
                 runtime.last_seen_trade_keys_real.insert(trade_key.clone());
             }
-            if cfg.execute_orders {
+            if posts_orders(&cfg) {
                 log_copy_event(
                     "real",
                     format!(
@@ -1270,7 +3017,8 @@ async fn monitor_loop(app: UiAppState) -> Result<()> {
                 log_copy_event(
                     "real",
                     format!(
-                        "orden registrada (dry-run) {} side={} outcome={} leader_price={} sim_price={} cantidad={}",
+                        "orden registrada ({}) {} side={} outcome={} leader_price={} sim_price={} cantidad={}",
+                        run_mode_label(&cfg),
                         record.movement_id,
                         record.copy_side,
                         record.outcome,
@@ -1286,46 +3034,133 @@ async fn monitor_loop(app: UiAppState) -> Result<()> {
             "core",
             format!("ciclo monitor #{loop_tick} finalizado; esperando {poll_ms}ms"),
         );
+        drop(tick);
         tokio::time::sleep(Duration::from_millis(poll_ms)).await;
     }
     log_copy_event("core", "monitor loop finalizado");
     Ok(())
 }
 
+/// Posts the copy as a FOK market order and returns the USD size actually
+/// submitted, which can be slightly below `copied_value_usd` after rounding
+/// to the exchange's size increment.
 async fn execute_copy_order_from_trade(
+    wallet: &CopyWallet,
     trade: &polymarket_client_sdk::data::types::response::Trade,
     copied_value_usd: Decimal,
-) -> Result<()> {
-    let signer = crate::auth::resolve_signer(None)?;
-    let client = crate::auth::authenticate_with_signer(&signer, None).await?;
-
+) -> Result<Decimal> {
     let side = if trade.side.to_string().eq_ignore_ascii_case("buy") {
         ClobSide::Buy
     } else {
         ClobSide::Sell
     };
+    // Route to the exact outcome token the leader traded; this is what keeps
+    // categorical (3+ outcome) markets correct without any Yes/No mapping.
+    execute_copy_order(
+        wallet,
+        trade.asset,
+        side,
+        trade.price,
+        copied_value_usd,
+        false,
+    )
+    .await
+}
 
-    let amount = if matches!(side, ClobSide::Sell) {
-        if trade.price <= Decimal::ZERO {
-            bail!("invalid leader trade price for sell copy: {}", trade.price);
+/// Places a FOK market order for a copy and returns the USD amount actually
+/// submitted. With `verify_balance`, bails before signing if the wallet holds
+/// less collateral (buys) or fewer outcome shares (sells) than the order needs.
+async fn execute_copy_order(
+    wallet: &CopyWallet,
+    token_id: polymarket_client_sdk::types::U256,
+    side: ClobSide,
+    price: Decimal,
+    copied_value_usd: Decimal,
+    verify_balance: bool,
+) -> Result<Decimal> {
+    let signer = crate::auth::resolve_signer(wallet.private_key.as_deref())?;
+    let client = crate::net::timed(crate::auth::authenticate_with_signer(
+        &signer,
+        wallet.signature_type.as_deref(),
+    ))
+    .await?;
+
+    let is_sell = matches!(side, ClobSide::Sell);
+    if is_sell && price <= Decimal::ZERO {
+        bail!("invalid leader trade price for sell copy: {price}");
+    }
+    let (usd, shares) = rounded_order_size(copied_value_usd, price, is_sell);
+    if usd <= Decimal::ZERO {
+        bail!("copy size {copied_value_usd} rounds to zero at the exchange's size increment");
+    }
+    if verify_balance {
+        let request = BalanceAllowanceRequest::builder()
+            .asset_type(if is_sell {
+                AssetType::Conditional
+            } else {
+                AssetType::Collateral
+            })
+            .maybe_token_id(is_sell.then_some(token_id))
+            .build();
+        let raw = crate::net::timed(client.balance_allowance(request))
+            .await?
+            .balance;
+        let available = raw / Decimal::from(1_000_000u64);
+        let needed = if is_sell { shares } else { usd };
+        if available < needed {
+            let unit = if is_sell { "shares" } else { "USDC" };
+            bail!("insufficient balance: {available} {unit} available, {needed} needed");
         }
-        let shares = copied_value_usd / trade.price;
+    }
+    let amount = if is_sell {
         Amount::shares(shares)?
     } else {
-        Amount::usdc(copied_value_usd)?
+        Amount::usdc(usd)?
     };
 
-    let order = client
-        .market_order()
-        .token_id(trade.asset)
-        .side(side)
-        .amount(amount)
-        .order_type(OrderType::FOK)
-        .build()
-        .await?;
+    let order = crate::net::timed(
+        client
+            .market_order()
+            .token_id(token_id)
+            .side(side)
+            .amount(amount)
+            .order_type(OrderType::FOK)
+            .build(),
+    )
+    .await?;
     let signed_order = client.sign(&signer, order).await?;
-    let _ = client.post_order(signed_order).await?;
-    Ok(())
+    let _ = crate::net::timed(client.post_order(signed_order)).await?;
+    Ok(usd)
+}
+
+/// Market orders are sized in cents of USDC (buys) or hundredths of a share
+/// (sells); the exchange rejects anything finer.
+const ORDER_SIZE_INCREMENT: Decimal = Decimal::from_parts(1, 0, 0, false, 2);
+
+fn floor_to_increment(value: Decimal, increment: Decimal) -> Decimal {
+    if increment <= Decimal::ZERO {
+        return value;
+    }
+    (value / increment).floor() * increment
+}
+
+/// Floors a copy to a valid order size and returns `(usd, shares)`. Buys are
+/// rounded in USDC, sells in shares, matching how each is submitted.
+fn rounded_order_size(
+    copied_value_usd: Decimal,
+    price: Decimal,
+    is_sell: bool,
+) -> (Decimal, Decimal) {
+    if is_sell {
+        let shares = floor_to_increment(
+            copied_shares_from_notional(copied_value_usd, price),
+            ORDER_SIZE_INCREMENT,
+        );
+        (shares * price, shares)
+    } else {
+        let usd = floor_to_increment(copied_value_usd.max(Decimal::ZERO), ORDER_SIZE_INCREMENT);
+        (usd, copied_shares_from_notional(usd, price))
+    }
 }
 
 async fn fetch_trades_paginated(
@@ -1360,7 +3195,7 @@ async fn fetch_trades_paginated(
             .map_err(|e| anyhow!("error construyendo offset de trades: {e}"))?
             .build();
 
-        let batch = tokio::time::timeout(Duration::from_secs(8), data_client.trades(&req))
+        let batch = tokio::time::timeout(crate::net::request_timeout(), data_client.trades(&req))
             .await
             .map_err(|_| anyhow!("timeout consultando trades"))??;
 
@@ -1404,8 +3239,8 @@ async fn simulation_step(
         runtime.simulation_tick = runtime.simulation_tick.saturating_add(1);
     }
 
-    let leader = match crate::commands::parse_address(&cfg.leader) {
-        Ok(addr) => addr,
+    let leaders = match leader_addresses(cfg) {
+        Ok(addrs) => addrs,
         Err(e) => {
             let mut runtime = app.runtime.lock().await;
             runtime.warning = Some(format!("Leader inválido en simulación: {e}"));
@@ -1413,13 +3248,6 @@ async fn simulation_step(
             return Ok(());
         }
     };
-    let value_req = ValueRequest::builder().user(leader).build();
-    let leader_value = data_client
-        .value(&value_req)
-        .await
-        .ok()
-        .and_then(|v| v.first().map(|x| x.value))
-        .unwrap_or(Decimal::ONE);
 
     let should_sync_closed = {
         let runtime = app.runtime.lock().await;
@@ -1432,9 +3260,15 @@ async fn simulation_step(
             runtime.closed_sync_sim_in_flight = true;
         }
         let app_bg = app.clone();
-        tokio::spawn(async move {
-            run_closed_sync_task(app_bg, leader, StorageMode::Simulation, "sim").await;
-        });
+        let users = leaders.clone();
+        app.spawn_sync(run_closed_sync_task(
+            app_bg,
+            cfg.clone(),
+            users,
+            StorageMode::Simulation,
+            "sim",
+        ))
+        .await;
     }
 
     let should_sync_market = {
@@ -1448,111 +3282,140 @@ async fn simulation_step(
             runtime.market_sync_sim_in_flight = true;
         }
         let app_bg = app.clone();
-        tokio::spawn(async move {
-            run_market_closed_sync_task(app_bg, leader, StorageMode::Simulation, "sim").await;
-        });
+        let users = leaders.clone();
+        app.spawn_sync(run_market_closed_sync_task(
+            app_bg,
+            cfg.clone(),
+            users,
+            StorageMode::Simulation,
+            "sim",
+        ))
+        .await;
     }
 
-    log_copy_event(
-        "sim",
-        format!("consultando ultimos movimientos de la cuenta a copiar ({leader})"),
-    );
     let bootstrap_needed = {
         let runtime = app.runtime.lock().await;
         !runtime.simulation_bootstrap_done
             && closed_sync_due(runtime.simulation_bootstrap_next_retry_at_ms)
     };
 
-    let trades = if bootstrap_needed {
+    let mut trades = Vec::new();
+    for (leader_cfg, leader) in cfg.leaders.iter().zip(leaders.iter().copied()) {
+        let value_req = ValueRequest::builder().user(leader).build();
+        let leader_value = crate::net::timed(data_client.value(&value_req))
+            .await
+            .ok()
+            .and_then(|v| v.first().map(|x| x.value))
+            .unwrap_or(Decimal::ONE);
+
         log_copy_event(
             "sim",
-            "bootstrap simulación: descargando historial acotado de trades para evitar throttle",
+            format!("consultando ultimos movimientos de la cuenta a copiar ({leader})"),
         );
-        match fetch_trades_paginated(data_client, leader, 200, 6, "sim").await {
-            Ok(mut t) => {
-                t.sort_by_key(|x| x.timestamp);
-                let mut runtime = app.runtime.lock().await;
-                runtime.simulation_bootstrap_done = true;
-                t
+        let fetched = if bootstrap_needed {
+            log_copy_event(
+                "sim",
+                "bootstrap simulación: descargando historial acotado de trades para evitar throttle",
+            );
+            match fetch_trades_paginated(data_client, leader, 200, 6, "sim").await {
+                Ok(mut t) => {
+                    t.sort_by_key(|x| x.timestamp);
+                    let mut runtime = app.runtime.lock().await;
+                    runtime.simulation_bootstrap_done = true;
+                    Some(t)
+                }
+                Err(e) => {
+                    let mut runtime = app.runtime.lock().await;
+                    runtime.warning = Some(format!(
+                        "Error bootstrap simulación consultando trades: {e}"
+                    ));
+                    runtime.simulation_bootstrap_next_retry_at_ms =
+                        now_ms() + i64::try_from(SIM_BOOTSTRAP_RETRY_MS).unwrap_or(300_000);
+                    log_copy_event(
+                        "sim",
+                        format!(
+                            "error bootstrap consultando trades: {e}; próximo reintento en ~{}s",
+                            SIM_BOOTSTRAP_RETRY_MS / 1000
+                        ),
+                    );
+                    None
+                }
             }
-            Err(e) => {
-                let mut runtime = app.runtime.lock().await;
-                runtime.warning = Some(format!(
-                    "Error bootstrap simulación consultando trades: {e}"
-                ));
-                runtime.simulation_bootstrap_next_retry_at_ms =
-                    now_ms() + i64::try_from(SIM_BOOTSTRAP_RETRY_MS).unwrap_or(300_000);
-                log_copy_event(
-                    "sim",
-                    format!(
-                        "error bootstrap consultando trades: {e}; próximo reintento en ~{}s",
-                        SIM_BOOTSTRAP_RETRY_MS / 1000
-                    ),
-                );
-                Vec::new()
+        } else {
+            let trades_req = TradesRequest::builder().user(leader).limit(20)?.build();
+            match tokio::time::timeout(
+                crate::net::request_timeout(),
+                data_client.trades(&trades_req),
+            )
+            .await
+            {
+                Ok(Ok(mut trades)) => {
+                    log_copy_event(
+                        "sim",
+                        format!("consulta trades completada: {} movimientos", trades.len()),
+                    );
+                    trades.sort_by_key(|x| x.timestamp);
+                    Some(trades)
+                }
+                Ok(Err(e)) => {
+                    let mut runtime = app.runtime.lock().await;
+                    runtime.warning = Some(format!("Error simulación consultando trades: {e}"));
+                    runtime.last_error_at_ms = Some(now_ms());
+                    log_copy_event("sim", format!("error consultando trades recientes: {e}"));
+                    None
+                }
+                Err(_) => {
+                    let mut runtime = app.runtime.lock().await;
+                    runtime.warning = Some("Timeout simulación consultando trades".to_string());
+                    runtime.last_error_at_ms = Some(now_ms());
+                    log_copy_event(
+                        "sim",
+                        format!(
+                            "timeout consultando ultimos movimientos ({}s)",
+                            crate::net::request_timeout().as_secs()
+                        ),
+                    );
+                    None
+                }
             }
+        };
+        let Some(fetched) = fetched else {
+            continue;
+        };
+        let primed = {
+            let mut runtime = app.runtime.lock().await;
+            runtime
+                .last_seen_trade_keys_sim
+                .prime_leader(&leader_cfg.address, fetched.iter().map(trade_event_key))
+        };
+        if primed {
+            log_copy_event(
+                "sim",
+                format!(
+                    "primer barrido sim ({leader}): {} trades marcados como vistos (sin copiar histórico)",
+                    fetched.len()
+                ),
+            );
+            continue;
         }
-    } else {
-        let trades_req = TradesRequest::builder().user(leader).limit(20)?.build();
-        match tokio::time::timeout(Duration::from_secs(15), data_client.trades(&trades_req)).await {
-            Ok(Ok(mut trades)) => {
-                log_copy_event(
-                    "sim",
-                    format!("consulta trades completada: {} movimientos", trades.len()),
-                );
-                trades.sort_by_key(|x| x.timestamp);
-                trades
-            }
-            Ok(Err(e)) => {
-                let mut runtime = app.runtime.lock().await;
-                runtime.warning = Some(format!("Error simulación consultando trades: {e}"));
-                log_copy_event("sim", format!("error consultando trades recientes: {e}"));
-                Vec::new()
-            }
-            Err(_) => {
-                let mut runtime = app.runtime.lock().await;
-                runtime.warning = Some("Timeout simulación consultando trades".to_string());
-                log_copy_event("sim", "timeout consultando ultimos movimientos (15s)");
-                Vec::new()
-            }
-        }
-    };
-
-    let prime_only = {
-        let mut runtime = app.runtime.lock().await;
-        if runtime.last_seen_trade_keys_sim.is_empty() {
-            for t in &trades {
-                runtime.last_seen_trade_keys_sim.insert(trade_event_key(t));
-            }
-            true
-        } else {
-            false
-        }
-    };
-
-    if prime_only {
-        log_copy_event(
-            "sim",
-            format!(
-                "primer barrido sim: {} trades marcados como vistos (sin copiar histórico)",
-                trades.len()
-            ),
+        trades.extend(
+            fetched
+                .into_iter()
+                .map(|t| (leader_cfg.address.clone(), leader_value, t)),
         );
-        return Ok(());
     }
+    // Leaders' histories are fetched separately; copy in time order across them.
+    trades.sort_by_key(|(_, _, t)| t.timestamp);
 
-    for t in trades {
+    for (leader, leader_value, t) in trades {
         let tx_hash = t.transaction_hash.to_string();
         let trade_key = trade_event_key(&t);
         let is_sell = t.side.to_string().eq_ignore_ascii_case("sell");
         {
             let mut runtime = app.runtime.lock().await;
-            if runtime.last_seen_trade_keys_sim.contains(&trade_key) {
+            if !runtime.last_seen_trade_keys_sim.claim(&trade_key, is_sell) {
                 continue;
             }
-            if !is_sell {
-                runtime.last_seen_trade_keys_sim.insert(trade_key.clone());
-            }
         }
 
         let mut state = load_state()?;
@@ -1563,14 +3426,11 @@ async fn simulation_step(
 
         if is_sell {
             let settled_from_sell =
-                settle_open_buys_from_sell_trade(&mut state, &t.slug, &t.outcome, t.price);
+                settle_from_leader_sell(cfg, &mut state, &leader, &t.slug, &t.outcome, t.price);
             if !settled_from_sell.is_empty() {
                 save_state(&state)?;
                 for movement in settled_from_sell {
-                    settle_db_movement_from_record(StorageMode::Simulation, &movement)?;
-                    if let Err(e) = append_settlement_log(StorageMode::Simulation, &movement) {
-                        log_copy_event("sim", format!("error escribiendo log de settlement: {e}"));
-                    }
+                    record_settlement(cfg, StorageMode::Simulation, "sim", &movement)?;
                     log_copy_event(
                         "sim",
                         format!(
@@ -1589,58 +3449,46 @@ async fn simulation_step(
             }
         }
 
-        let plan = compute_plan(cfg, &state, leader_value, t.size * t.price)?;
-        if plan.capped_size <= Decimal::ZERO {
-            log_copy_event(
-                "sim",
-                format!(
-                    "trade detectado {} ({}) sin simulacion (motivo: {})",
-                    t.slug, tx_hash, plan.reason
-                ),
-            );
-            continue;
-        }
-
-        if t.side.to_string().eq_ignore_ascii_case("sell") {
-            let required_sell_shares = copied_shares_from_notional(plan.capped_size, t.price);
-            if !has_enough_inventory_for_sell(&state, &t.slug, &t.outcome, required_sell_shares) {
+        // A disabled leader's sells still close its open copies above; only
+        // new positions are skipped.
+        if leader_disabled(app, &leader).await {
+            if !is_sell {
                 log_copy_event(
                     "sim",
-                    format!(
-                        "simulacion sell {} ({}) descartada: no hay buy abierto conciliable (outcome={}, required_shares={})",
-                        t.slug, tx_hash, t.outcome, required_sell_shares
-                    ),
+                    format!("lider {leader} desactivado: no se copia {tx_hash}"),
                 );
-                continue;
             }
-
-            log_copy_event(
-                "sim",
-                format!(
-                    "simulacion sell {} ({}) descartada: no se pudo conciliar cierre inmediato; evitando SELL abierto",
-                    t.slug, tx_hash
-                ),
-            );
             continue;
         }
 
-        let fee_impact = trading_fee_impact_for_movement(&t.slug, plan.capped_size);
-        if let Some(impact) = fee_impact
-            && impact.max_net_profit_usd <= Decimal::ZERO
-        {
+        let candidate = TradeCandidate::from_trade(&leader, leader_value, &t);
+        let TradeEvaluation {
+            plan,
+            fee_impact,
+            skip,
+        } = evaluate_trade(cfg, fee_schedule(), &state, &candidate, &Utc::now())?;
+        if let Some(skip) = skip {
+            if skip == TradeSkip::DailyLossLimit {
+                app.runtime.lock().await.warning = Some(
+                    "Límite de pérdida diaria alcanzado: sin nuevas simulaciones hasta medianoche UTC"
+                        .to_string(),
+                );
+            }
             log_copy_event(
                 "sim",
-                format!(
-                    "simulacion descartada por fees {} ({}) ({} bps): profit_max_neto={} (gross_max={} fee_entry={} fees_rt={})",
-                    t.slug,
-                    tx_hash,
-                    impact.fee_bps,
-                    impact.max_net_profit_usd,
-                    impact.max_gross_profit_usd,
-                    impact.entry_fee_usd,
-                    impact.round_trip_fee_usd,
-                ),
+                skip_log_message(true, &skip, &plan, &candidate, &tx_hash),
             );
+            record_decision(&DecisionRecord::new(
+                "sim",
+                &candidate,
+                &plan,
+                fee_impact,
+                skip.label(&plan),
+                false,
+            ));
+            // A skipped sell would be re-evaluated (and re-logged) every poll.
+            let mut runtime = app.runtime.lock().await;
+            runtime.last_seen_trade_keys_sim.insert(trade_key.clone());
             continue;
         }
 
@@ -1662,7 +3510,9 @@ async fn simulation_step(
         );
 
         let (estimated_sim_price, has_full_liquidity) =
-            match estimate_simulated_copy_price_from_book(clob_client, &t, plan.capped_size).await {
+            match estimate_simulated_copy_price_from_book(cfg, clob_client, &t, plan.capped_size)
+                .await
+            {
                 Ok(v) => v,
                 Err(e) => {
                     let mut runtime = app.runtime.lock().await;
@@ -1671,6 +3521,14 @@ async fn simulation_step(
                         "sim",
                         format!("error chequeando liquidez {} ({}): {e}", t.slug, tx_hash),
                     );
+                    record_decision(&DecisionRecord::new(
+                        "sim",
+                        &candidate,
+                        &plan,
+                        fee_impact,
+                        format!("liquidity check failed: {e}"),
+                        false,
+                    ));
                     continue;
                 }
             };
@@ -1702,6 +3560,14 @@ async fn simulation_step(
                     t.slug, tx_hash
                 ),
             );
+            record_decision(&DecisionRecord::new(
+                "sim",
+                &candidate,
+                &plan,
+                fee_impact,
+                "no liquidity",
+                false,
+            ));
             continue;
         }
 
@@ -1715,7 +3581,10 @@ async fn simulation_step(
 
         let record = MovementRecord {
             movement_id,
-            market: t.slug,
+            leader: leader.clone(),
+            paper: false,
+            market: t.slug.clone(),
+            asset: t.asset.to_string(),
             timestamp: Utc::now().to_rfc3339(),
             leader_value: t.size * t.price,
             leader_price: t.price,
@@ -1731,11 +3600,28 @@ async fn simulation_step(
                 .unwrap_or(Decimal::ZERO),
             settled: false,
             pnl: Decimal::ZERO,
+            remaining_quantity: None,
+            last_partial_close_ts: None,
         };
         let mut updated = state;
         updated.movements.push(record.clone());
         save_state(&updated)?;
-        append_db_movement(StorageMode::Simulation, &record)?;
+        if let Some(movement) = append_db_movement(StorageMode::Simulation, &record)? {
+            notify_movement(
+                cfg,
+                StorageMode::Simulation,
+                NotifyEvent::Detected,
+                &movement,
+            );
+        }
+        record_decision(&DecisionRecord::new(
+            "sim",
+            &candidate,
+            &plan,
+            fee_impact,
+            plan.reason.clone(),
+            true,
+        ));
         if is_sell {
             let mut runtime = app.runtime.lock().await;
             runtime.last_seen_trade_keys_sim.insert(trade_key.clone());
@@ -1776,11 +3662,16 @@ fn unsettled_market_slugs(state: &CopyState) -> Vec<String> {
     out
 }
 
+/// Settles open movements against one account's closed positions. With
+/// `leader` set they are that leader's and only settle its copies; `None`
+/// (the executing wallet's own positions) settles any copy.
 fn apply_settlements_from_closed_positions(
+    cfg: &CopyConfig,
     mode: StorageMode,
     log_scope: &'static str,
     closed_positions: &[polymarket_client_sdk::data::types::response::ClosedPosition],
-) -> Result<()> {
+    leader: Option<&str>,
+) -> Result<usize> {
     let closed_keys = closed_slug_keys(closed_positions);
     if let Some((oldest_movement_id, oldest_market)) = oldest_unsettled_from_db(mode)?
         && is_market_closed(&closed_keys, &oldest_market)
@@ -1795,28 +3686,50 @@ fn apply_settlements_from_closed_positions(
     }
 
     let mut state = load_state()?;
-    let settled = settle_open_movements_from_closed_positions(&mut state, closed_positions);
-    if !settled.is_empty() {
+    let partial_before: Vec<Option<i64>> = state
+        .movements
+        .iter()
+        .map(|m| m.last_partial_close_ts)
+        .collect();
+    let settled = settle_open_movements_from_closed_positions(&mut state, closed_positions, |m| {
+        leader.is_none_or(|l| movement_leader_is(m, cfg, l))
+    });
+    let settled_count = settled.len();
+    let partially_closed: Vec<&MovementRecord> = state
+        .movements
+        .iter()
+        .zip(&partial_before)
+        .filter(|(m, before)| !m.settled && m.last_partial_close_ts != **before)
+        .map(|(m, _)| m)
+        .collect();
+    for movement in &partially_closed {
+        log_copy_event(
+            log_scope,
+            format!(
+                "cierre parcial {} (mercado={}): quedan {} de {}, pnl acumulado={}",
+                movement.movement_id,
+                movement.market,
+                movement.remaining_quantity.unwrap_or_default(),
+                movement.quantity,
+                movement.pnl
+            ),
+        );
+    }
+    if !settled.is_empty() || !partially_closed.is_empty() {
         save_state(&state)?;
-        for movement in settled {
-            log_copy_event(
-                log_scope,
-                format!(
-                    "resuelta {} (mercado={}) pnl={} -> fondos liberados",
-                    movement.movement_id, movement.market, movement.pnl
-                ),
-            );
-            settle_db_movement_from_record(mode, &movement)?;
-            if let Err(e) = append_settlement_log(mode, &movement) {
-                log_copy_event(
-                    log_scope,
-                    format!("error escribiendo log de settlement: {e}"),
-                );
-            }
-        }
+    }
+    for movement in settled {
+        log_copy_event(
+            log_scope,
+            format!(
+                "resuelta {} (mercado={}) pnl={} -> fondos liberados",
+                movement.movement_id, movement.market, movement.pnl
+            ),
+        );
+        record_settlement(cfg, mode, log_scope, &movement)?;
     }
 
-    Ok(())
+    Ok(settled_count)
 }
 
 fn settle_open_buys_from_resolved_markets(
@@ -1840,12 +3753,15 @@ fn settle_open_buys_from_resolved_markets(
             continue;
         }
 
-        let payout_per_share = if movement.outcome == *resolved_outcome {
+        let payout_per_share = if outcomes_match(&movement.outcome, resolved_outcome) {
             Decimal::ONE
         } else {
             Decimal::ZERO
         };
-        movement.pnl = (shares * payout_per_share) - movement.copied_value;
+        apply_remainder_pnl(
+            movement,
+            (shares * payout_per_share) - movement.copied_value,
+        );
         movement.copy_side = "sell".to_string();
         movement.resolved_outcome = resolved_outcome.clone();
         movement.settled = true;
@@ -1856,6 +3772,7 @@ fn settle_open_buys_from_resolved_markets(
 }
 
 fn apply_settlements_from_resolved_markets(
+    cfg: &CopyConfig,
     mode: StorageMode,
     log_scope: &'static str,
     resolved_outcomes: &HashMap<String, String>,
@@ -1872,13 +3789,7 @@ fn apply_settlements_from_resolved_markets(
 
     save_state(&state)?;
     for movement in settled {
-        settle_db_movement_from_record(mode, &movement)?;
-        if let Err(e) = append_settlement_log(mode, &movement) {
-            log_copy_event(
-                log_scope,
-                format!("error escribiendo log de settlement: {e}"),
-            );
-        }
+        record_settlement(cfg, mode, log_scope, &movement)?;
         log_copy_event(
             log_scope,
             format!(
@@ -1929,9 +3840,10 @@ async fn fetch_closed_markets_from_gamma(
             .slug(chunk.to_vec())
             .closed(true)
             .build();
-        let markets = tokio::time::timeout(Duration::from_secs(15), gamma_client.markets(&req))
-            .await
-            .map_err(|_| anyhow!("timeout consultando mercados cerrados"))??;
+        let markets =
+            tokio::time::timeout(crate::net::request_timeout(), gamma_client.markets(&req))
+                .await
+                .map_err(|_| anyhow!("timeout consultando mercados cerrados"))??;
 
         for market in markets {
             if market.closed.unwrap_or(false)
@@ -1961,7 +3873,8 @@ async fn fetch_closed_markets_from_gamma(
 
 async fn run_market_closed_sync_task(
     app: UiAppState,
-    user: alloy::primitives::Address,
+    cfg: CopyConfig,
+    users: Vec<alloy::primitives::Address>,
     mode: StorageMode,
     log_scope: &'static str,
 ) {
@@ -1988,17 +3901,25 @@ async fn run_market_closed_sync_task(
         log_copy_event(
             log_scope,
             format!(
-                "mercado reporta cierres para {} slugs; forzando conciliación por cuenta {}",
+                "mercado reporta cierres para {} slugs; forzando conciliación por {} cuenta(s)",
                 closed_market_slugs.len(),
-                user
+                users.len()
             ),
         );
 
         let data_client = polymarket_client_sdk::data::Client::default();
-        let closed_positions =
-            fetch_closed_positions_paginated(&data_client, user, log_scope).await?;
-        apply_settlements_from_closed_positions(mode, log_scope, &closed_positions)?;
-        apply_settlements_from_resolved_markets(mode, log_scope, &resolved_outcomes)
+        for &user in &users {
+            let closed_positions =
+                fetch_closed_positions_paginated(&data_client, user, log_scope).await?;
+            apply_settlements_from_closed_positions(
+                &cfg,
+                mode,
+                log_scope,
+                &closed_positions,
+                settlement_leader(&cfg, user),
+            )?;
+        }
+        apply_settlements_from_resolved_markets(&cfg, mode, log_scope, &resolved_outcomes)
     }
     .await;
 
@@ -2022,6 +3943,7 @@ async fn run_market_closed_sync_task(
 fn settle_open_buys_from_activities(
     state: &mut CopyState,
     activities: &[polymarket_client_sdk::data::types::response::Activity],
+    owns: impl Fn(&MovementRecord) -> bool,
 ) -> Vec<MovementRecord> {
     let mut settled = Vec::new();
 
@@ -2043,7 +3965,7 @@ fn settle_open_buys_from_activities(
             exit_price = a.usdc_size / a.size;
         }
 
-        for movement in state.movements.iter_mut().filter(|m| !m.settled) {
+        for movement in state.movements.iter_mut().filter(|m| !m.settled && owns(m)) {
             if !movement.copy_side.eq_ignore_ascii_case("buy") {
                 continue;
             }
@@ -2051,7 +3973,8 @@ fn settle_open_buys_from_activities(
             if movement.market != *slug && movement_norm != normalized_slug {
                 continue;
             }
-            if !activity_outcome.is_empty() && movement.outcome != activity_outcome {
+            if !activity_outcome.is_empty() && !outcomes_match(&movement.outcome, activity_outcome)
+            {
                 continue;
             }
 
@@ -2063,7 +3986,7 @@ fn settle_open_buys_from_activities(
 
             if exit_price > Decimal::ZERO && entry_price > Decimal::ZERO {
                 let roi = (exit_price - entry_price) / entry_price;
-                movement.pnl = movement.copied_value * roi;
+                apply_remainder_pnl(movement, movement.copied_value * roi);
             }
             movement.copy_side = "sell".to_string();
             if !activity_outcome.is_empty() {
@@ -2077,26 +4000,26 @@ fn settle_open_buys_from_activities(
     settled
 }
 
+/// Activity counterpart of [`apply_settlements_from_closed_positions`], with
+/// the same `leader` scoping.
 fn apply_settlements_from_activity(
+    cfg: &CopyConfig,
     mode: StorageMode,
     log_scope: &'static str,
     activities: &[polymarket_client_sdk::data::types::response::Activity],
+    leader: Option<&str>,
 ) -> Result<()> {
     let mut state = load_state()?;
-    let settled = settle_open_buys_from_activities(&mut state, activities);
+    let settled = settle_open_buys_from_activities(&mut state, activities, |m| {
+        leader.is_none_or(|l| movement_leader_is(m, cfg, l))
+    });
     if settled.is_empty() {
         return Ok(());
     }
 
     save_state(&state)?;
     for movement in settled {
-        settle_db_movement_from_record(mode, &movement)?;
-        if let Err(e) = append_settlement_log(mode, &movement) {
-            log_copy_event(
-                log_scope,
-                format!("error escribiendo log de settlement: {e}"),
-            );
-        }
+        record_settlement(cfg, mode, log_scope, &movement)?;
         log_copy_event(
             log_scope,
             format!(
@@ -2129,7 +4052,7 @@ async fn fetch_activity_paginated(
             .map_err(|e| anyhow!("error construyendo offset de activity: {e}"))?
             .build();
 
-        let batch = tokio::time::timeout(Duration::from_secs(15), data_client.activity(&req))
+        let batch = tokio::time::timeout(crate::net::request_timeout(), data_client.activity(&req))
             .await
             .map_err(|_| anyhow!("timeout consultando activity"))??;
 
@@ -2150,21 +4073,29 @@ async fn fetch_activity_paginated(
 
 async fn run_closed_sync_task(
     app: UiAppState,
-    user: alloy::primitives::Address,
+    cfg: CopyConfig,
+    users: Vec<alloy::primitives::Address>,
     mode: StorageMode,
     log_scope: &'static str,
 ) {
-    log_copy_event(
-        log_scope,
-        format!("consultando cierres/resoluciones de la cuenta a copiar ({user})"),
-    );
-
     let data_client = polymarket_client_sdk::data::Client::default();
-    let result = fetch_closed_positions_paginated(&data_client, user, log_scope).await;
+    let result: Result<Vec<_>> = async {
+        let mut closed_by_user = Vec::new();
+        for &user in &users {
+            log_copy_event(
+                log_scope,
+                format!("consultando cierres/resoluciones de la cuenta a copiar ({user})"),
+            );
+            let closed = fetch_closed_positions_paginated(&data_client, user, log_scope).await?;
+            closed_by_user.push((user, closed));
+        }
+        Ok(closed_by_user)
+    }
+    .await;
 
     match result {
-        Ok(closed_positions) => {
-            if closed_positions.is_empty() {
+        Ok(closed_by_user) => {
+            if closed_by_user.iter().all(|(_, closed)| closed.is_empty()) {
                 let mut runtime = app.runtime.lock().await;
                 runtime.warning = Some(match mode {
                     StorageMode::Real => {
@@ -2177,14 +4108,33 @@ async fn run_closed_sync_task(
                 });
                 schedule_closed_sync_backoff(&mut runtime, mode);
             } else {
-                let settle_result =
-                    apply_settlements_from_closed_positions(mode, log_scope, &closed_positions);
+                record_api_success(&mut *app.runtime.lock().await);
+                // Each account's closures settle only the copies it owns, so
+                // one leader exiting a market never closes another's copy.
+                let settle_result = closed_by_user.iter().try_for_each(|(user, closed)| {
+                    apply_settlements_from_closed_positions(
+                        &cfg,
+                        mode,
+                        log_scope,
+                        closed,
+                        settlement_leader(&cfg, *user),
+                    )
+                    .map(|_| ())
+                });
 
                 if settle_result.is_ok() {
-                    if let Ok(activities) =
-                        fetch_activity_paginated(&data_client, user, log_scope).await
-                    {
-                        let _ = apply_settlements_from_activity(mode, log_scope, &activities);
+                    for &user in &users {
+                        if let Ok(activities) =
+                            fetch_activity_paginated(&data_client, user, log_scope).await
+                        {
+                            let _ = apply_settlements_from_activity(
+                                &cfg,
+                                mode,
+                                log_scope,
+                                &activities,
+                                settlement_leader(&cfg, user),
+                            );
+                        }
                     }
                 }
 
@@ -2193,6 +4143,7 @@ async fn run_closed_sync_task(
                     Ok(_) => schedule_closed_sync_success(&mut runtime, mode),
                     Err(e) => {
                         runtime.warning = Some(format!("Error conciliando cierres: {e}"));
+                        runtime.last_error_at_ms = Some(now_ms());
                         schedule_closed_sync_backoff(&mut runtime, mode);
                     }
                 }
@@ -2200,6 +4151,7 @@ async fn run_closed_sync_task(
         }
         Err(e) => {
             let mut runtime = app.runtime.lock().await;
+            record_api_error(&mut runtime);
             runtime.warning = Some(match mode {
                 StorageMode::Real => format!("Error consultando posiciones cerradas: {e}"),
                 StorageMode::Simulation => format!("Error simulación consultando cerradas: {e}"),
@@ -2242,41 +4194,43 @@ async fn fetch_closed_positions_paginated(
             }
         };
 
-        let batch =
-            match tokio::time::timeout(Duration::from_secs(15), data_client.closed_positions(&req))
-                .await
-            {
-                Ok(Ok(v)) => v,
-                Ok(Err(e)) => {
-                    log_copy_event(
-                        log_scope,
-                        format!(
-                            "error consultando cierres paginados (page={}, offset={}): {}",
-                            page, offset, e
-                        ),
-                    );
-                    return Err(anyhow!(
+        let batch = match tokio::time::timeout(
+            crate::net::request_timeout(),
+            data_client.closed_positions(&req),
+        )
+        .await
+        {
+            Ok(Ok(v)) => v,
+            Ok(Err(e)) => {
+                log_copy_event(
+                    log_scope,
+                    format!(
                         "error consultando cierres paginados (page={}, offset={}): {}",
-                        page,
-                        offset,
-                        e
-                    ));
-                }
-                Err(_) => {
-                    log_copy_event(
-                        log_scope,
-                        format!(
-                            "timeout consultando cierres paginados (page={}, offset={})",
-                            page, offset
-                        ),
-                    );
-                    return Err(anyhow!(
+                        page, offset, e
+                    ),
+                );
+                return Err(anyhow!(
+                    "error consultando cierres paginados (page={}, offset={}): {}",
+                    page,
+                    offset,
+                    e
+                ));
+            }
+            Err(_) => {
+                log_copy_event(
+                    log_scope,
+                    format!(
                         "timeout consultando cierres paginados (page={}, offset={})",
-                        page,
-                        offset
-                    ));
-                }
-            };
+                        page, offset
+                    ),
+                );
+                return Err(anyhow!(
+                    "timeout consultando cierres paginados (page={}, offset={})",
+                    page,
+                    offset
+                ));
+            }
+        };
 
         let batch_len = batch.len();
         out.extend(batch);
@@ -2299,6 +4253,7 @@ async fn fetch_closed_positions_paginated(
 }
 
 async fn estimate_simulated_copy_price_from_book(
+    cfg: &CopyConfig,
     clob_client: &polymarket_client_sdk::clob::Client,
     trade: &polymarket_client_sdk::data::types::response::Trade,
     copied_value_usd: Decimal,
@@ -2306,108 +4261,269 @@ async fn estimate_simulated_copy_price_from_book(
     let req = OrderBookSummaryRequest::builder()
         .token_id(trade.asset)
         .build();
-    let book = clob_client.order_book(&req).await?;
-
-    if trade.side.to_string().eq_ignore_ascii_case("buy") {
-        let mut remaining_usdc = copied_value_usd;
-        let mut filled_usdc = Decimal::ZERO;
-        let mut filled_shares = Decimal::ZERO;
-        for ask in &book.asks {
-            if remaining_usdc <= Decimal::ZERO {
-                break;
-            }
-            let level_notional = ask.size * ask.price;
-            let take_notional = if level_notional >= remaining_usdc {
-                remaining_usdc
-            } else {
-                level_notional
-            };
-            if ask.price > Decimal::ZERO {
-                filled_shares += take_notional / ask.price;
-            }
-            filled_usdc += take_notional;
-            remaining_usdc -= take_notional;
-        }
-        if filled_shares <= Decimal::ZERO {
-            return Ok((None, false));
-        }
-        Ok((
-            Some(filled_usdc / filled_shares),
-            remaining_usdc <= Decimal::ZERO,
-        ))
+    let book = crate::net::timed(clob_client.order_book(&req)).await?;
+
+    let is_buy = trade.side.to_string().eq_ignore_ascii_case("buy");
+    let levels: Vec<_> = if is_buy { &book.asks } else { &book.bids }
+        .iter()
+        .map(|o| (o.price, o.size))
+        .collect();
+    Ok(simulated_fill(
+        cfg,
+        &levels,
+        is_buy,
+        trade.price,
+        copied_value_usd,
+    ))
+}
+
+/// Average price and full-fill flag for copying `copied_value_usd` against
+/// one side of the book (asks for buys, bids for sells). `None` means skip:
+/// nothing fills, or the book is thinner than `has_required_depth` demands.
+fn simulated_fill(
+    cfg: &CopyConfig,
+    levels: &[(Decimal, Decimal)],
+    is_buy: bool,
+    leader_price: Decimal,
+    copied_value_usd: Decimal,
+) -> (Option<Decimal>, bool) {
+    if !is_buy && leader_price <= Decimal::ZERO {
+        return (None, false);
+    }
+    if !has_required_depth(cfg, levels, is_buy, leader_price, copied_value_usd) {
+        return (None, false);
+    }
+    let target = if is_buy {
+        FillTarget::Notional(copied_value_usd)
     } else {
-        if trade.price <= Decimal::ZERO {
-            return Ok((None, false));
-        }
-        let mut remaining_shares = copied_value_usd / trade.price;
-        let mut sold_shares = Decimal::ZERO;
-        let mut received_usdc = Decimal::ZERO;
-        for bid in &book.bids {
-            if remaining_shares <= Decimal::ZERO {
-                break;
-            }
-            let take_shares = if bid.size >= remaining_shares {
-                remaining_shares
-            } else {
-                bid.size
-            };
-            sold_shares += take_shares;
-            received_usdc += take_shares * bid.price;
-            remaining_shares -= take_shares;
-        }
-        if sold_shares <= Decimal::ZERO {
-            return Ok((None, false));
-        }
-        Ok((
-            Some(received_usdc / sold_shares),
-            remaining_shares <= Decimal::ZERO,
-        ))
+        FillTarget::Shares(copied_value_usd / leader_price)
+    };
+    let fill = walk_book(levels, is_buy, target);
+    match fill.avg_price() {
+        Some(px) => (Some(px), fill.complete),
+        None => (None, false),
     }
 }
 
-fn is_rate_limit_error(msg: &str) -> bool {
-    let m = msg.to_ascii_lowercase();
-    m.contains("429") || m.contains("too many") || m.contains("rate limit")
+/// Whether a depth margin is configured; the real monitor then skips copies
+/// the book can't back instead of copying on a partial or unknown fill.
+fn requires_book_depth(cfg: &CopyConfig) -> bool {
+    cfg.liquidity_multiple.is_some() || cfg.max_slippage_pct.is_some()
 }
 
-fn is_authorized(
-    headers: &std::collections::HashMap<String, String>,
-    query: &str,
-    token: &str,
+/// With `liquidity_multiple` or `max_slippage_pct` configured, requires
+/// `liquidity_multiple` (default 1) times the copy to be fillable using only
+/// levels within `max_slippage_pct` of the leader's price. Always true when
+/// neither is set, so only the exact copy size has to fill.
+fn has_required_depth(
+    cfg: &CopyConfig,
+    levels: &[(Decimal, Decimal)],
+    is_buy: bool,
+    leader_price: Decimal,
+    copied_value_usd: Decimal,
 ) -> bool {
-    let header_ok = headers
-        .get("x-api-key")
-        .is_some_and(|v| constant_time_eq(v.as_bytes(), token.as_bytes()));
-    let query_ok = query
-        .split('&')
-        .find_map(|kv| kv.split_once('='))
-        .is_some_and(|(k, v)| k == "token" && constant_time_eq(v.as_bytes(), token.as_bytes()));
+    if !requires_book_depth(cfg) {
+        return true;
+    }
+    let required = copied_value_usd * cfg.liquidity_multiple.unwrap_or(Decimal::ONE);
+    let band = cfg
+        .max_slippage_pct
+        .map(|pct| leader_price * pct / Decimal::from(100));
+    let within: Vec<_> = levels
+        .iter()
+        .copied()
+        .filter(|(price, _)| {
+            band.is_none_or(|band| {
+                if is_buy {
+                    *price <= leader_price + band
+                } else {
+                    *price >= leader_price - band
+                }
+            })
+        })
+        .collect();
+    let target = if is_buy {
+        FillTarget::Notional(required)
+    } else if leader_price > Decimal::ZERO {
+        FillTarget::Shares(required / leader_price)
+    } else {
+        return false;
+    };
+    walk_book(&within, is_buy, target).complete
+}
 
-    header_ok || query_ok
+const POLL_BACKOFF_STEP_MS: u64 = 250;
+const DEFAULT_BACKOFF_RECOVERY_SUCCESSES: u32 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PollEvent {
+    /// The API answered 429 / "too many requests".
+    RateLimited,
+    /// Enough consecutive calls succeeded to step back toward the baseline.
+    Recovered,
 }
 
-fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
-    if a.len() != b.len() {
-        return false;
-    }
-    let mut x = 0u8;
-    for (aa, bb) in a.iter().zip(b.iter()) {
-        x |= aa ^ bb;
+/// Next polling interval: one step slower on a rate limit, one step back
+/// toward (never below) `baseline` on recovery.
+fn adjust_poll_interval(current: u64, baseline: u64, event: PollEvent) -> u64 {
+    match event {
+        PollEvent::RateLimited => current.saturating_add(POLL_BACKOFF_STEP_MS).max(500),
+        PollEvent::Recovered => current.saturating_sub(POLL_BACKOFF_STEP_MS).max(baseline),
     }
-    x == 0
 }
 
-fn generate_api_token() -> Result<String> {
-    let mut buf = [0u8; 32];
+/// The configured interval, clamped by `min_poll_ms` for the config's mode.
+fn poll_baseline_ms(cfg: Option<&CopyConfig>) -> u64 {
+    cfg.map_or(default_poll_interval_ms(), |c| {
+        normalize_poll_ms(c.poll_interval_ms, c.realtime_mode, c.simulation_mode)
+    })
+}
 
-    if let Ok(mut f) = fs::File::open("/dev/urandom") {
-        if f.read_exact(&mut buf).is_ok() {
-            return Ok(buf.iter().map(|b| format!("{b:02x}")).collect());
-        }
+/// Resets the success streak after a failed `trades`/`closed_positions` call
+/// and stamps the error time reported by `/metrics`.
+fn record_api_error(runtime: &mut RuntimeState) {
+    runtime.consecutive_successes = 0;
+    runtime.last_error_at_ms = Some(now_ms());
+}
+
+/// Counts a successful `trades`/`closed_positions` call and, once enough have
+/// succeeded in a row while backed off, steps the interval down one notch.
+fn record_api_success(runtime: &mut RuntimeState) {
+    runtime.consecutive_successes = runtime.consecutive_successes.saturating_add(1);
+    let baseline = poll_baseline_ms(runtime.config.as_ref());
+    let threshold = runtime
+        .config
+        .as_ref()
+        .and_then(|c| c.backoff_recovery_successes)
+        .unwrap_or(DEFAULT_BACKOFF_RECOVERY_SUCCESSES);
+    if runtime.current_poll_interval_ms <= baseline || runtime.consecutive_successes < threshold {
+        return;
     }
+    runtime.consecutive_successes = 0;
+    runtime.current_poll_interval_ms = adjust_poll_interval(
+        runtime.current_poll_interval_ms,
+        baseline,
+        PollEvent::Recovered,
+    );
+    log_copy_event(
+        "core",
+        format!(
+            "API estable: reduciendo polling a {} ms",
+            runtime.current_poll_interval_ms
+        ),
+    );
+}
 
-    // Cross-platform fallback when /dev/urandom is unavailable (e.g. Windows).
-    // Token is only used for local UI auth and remains process-local.
+fn is_rate_limit_error(msg: &str) -> bool {
+    let m = msg.to_ascii_lowercase();
+    m.contains("429") || m.contains("too many") || m.contains("rate limit")
+}
+
+/// Realized PnL net of estimated fees, over settled movements.
+fn settled_pnl_after_fees(movements: &[MovementRecord]) -> Decimal {
+    movements
+        .iter()
+        .filter(|m| m.settled)
+        .map(|m| m.pnl - m.estimated_total_fee_usd)
+        .sum()
+}
+
+/// USD still committed to unsettled movements.
+fn open_exposure(movements: &[MovementRecord]) -> Decimal {
+    movements
+        .iter()
+        .filter(|m| !m.settled)
+        .map(|m| m.copied_value)
+        .sum()
+}
+
+/// `/metrics` body in the Prometheus text exposition format.
+fn prometheus_metrics(state: &CopyState, runtime: &RuntimeState) -> String {
+    let metrics = [
+        (
+            "polymarket_copy_movements",
+            "gauge",
+            "Movements in the history of the active mode (drops on reset or compaction)",
+            state.movements.len().to_string(),
+        ),
+        (
+            "polymarket_copy_open_exposure_usd",
+            "gauge",
+            "USD committed to unsettled copied movements",
+            open_exposure(&state.movements).to_string(),
+        ),
+        (
+            "polymarket_copy_realized_pnl_usd",
+            "gauge",
+            "Realized PnL of settled movements after estimated fees",
+            settled_pnl_after_fees(&state.movements).to_string(),
+        ),
+        (
+            "polymarket_copy_poll_interval_seconds",
+            "gauge",
+            "Current poll interval, including any rate-limit backoff",
+            (Decimal::from(runtime.current_poll_interval_ms) / Decimal::from(1000)).to_string(),
+        ),
+        (
+            "polymarket_copy_last_error_timestamp_seconds",
+            "gauge",
+            "Unix time of the monitor's last API or order error (0 if none)",
+            (Decimal::from(runtime.last_error_at_ms.unwrap_or(0)) / Decimal::from(1000))
+                .to_string(),
+        ),
+        (
+            "polymarket_copy_monitoring",
+            "gauge",
+            "1 while the monitor loop is running",
+            u8::from(runtime.monitoring).to_string(),
+        ),
+    ];
+    metrics
+        .into_iter()
+        .map(|(name, kind, help, value)| {
+            format!("# HELP {name} {help}\n# TYPE {name} {kind}\n{name} {value}\n")
+        })
+        .collect()
+}
+
+fn is_authorized(
+    headers: &std::collections::HashMap<String, String>,
+    query: &str,
+    token: &str,
+) -> bool {
+    let header_ok = headers
+        .get("x-api-key")
+        .is_some_and(|v| constant_time_eq(v.as_bytes(), token.as_bytes()));
+    let query_ok = query
+        .split('&')
+        .find_map(|kv| kv.split_once('='))
+        .is_some_and(|(k, v)| k == "token" && constant_time_eq(v.as_bytes(), token.as_bytes()));
+
+    header_ok || query_ok
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut x = 0u8;
+    for (aa, bb) in a.iter().zip(b.iter()) {
+        x |= aa ^ bb;
+    }
+    x == 0
+}
+
+fn generate_api_token() -> Result<String> {
+    let mut buf = [0u8; 32];
+
+    if let Ok(mut f) = fs::File::open("/dev/urandom")
+        && f.read_exact(&mut buf).is_ok()
+    {
+        return Ok(buf.iter().map(|b| format!("{b:02x}")).collect());
+    }
+
+    // Cross-platform fallback when /dev/urandom is unavailable (e.g. Windows).
+    // Token is only used for local UI auth and remains process-local.
     for i in 0..4u64 {
         let mut hasher = std::collections::hash_map::DefaultHasher::new();
         std::time::SystemTime::now()
@@ -2425,10 +4541,62 @@ fn generate_api_token() -> Result<String> {
     Ok(buf.iter().map(|b| format!("{b:02x}")).collect())
 }
 
-fn read_http_request(stream: &mut TcpStream) -> Result<String> {
-    let mut buf = vec![0_u8; 1024 * 64];
-    let n = stream.read(&mut buf)?;
-    Ok(String::from_utf8_lossy(&buf[..n]).to_string())
+const HTTP_MAX_HEADER_BYTES: usize = 64 * 1024;
+const HTTP_MAX_BODY_BYTES: usize = 1024 * 1024;
+const HTTP_READ_TIMEOUT: Duration = Duration::from_secs(10);
+/// Connections use blocking writes from async tasks, so a client that stops
+/// reading (e.g. a stalled `/api/stream` tab) must not hold a worker forever:
+/// the write fails after this and the connection is dropped.
+const HTTP_WRITE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Reads one request: headers up to the blank line, then exactly
+/// `Content-Length` body bytes, however many reads that takes.
+fn read_http_request(stream: &mut impl Read) -> Result<String> {
+    let mut buf = Vec::new();
+    let mut chunk = [0_u8; 8192];
+    let header_end = loop {
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+        if buf.len() > HTTP_MAX_HEADER_BYTES {
+            bail!("request headers exceed {HTTP_MAX_HEADER_BYTES} bytes");
+        }
+        let n = stream.read(&mut chunk)?;
+        if n == 0 {
+            if buf.is_empty() {
+                bail!("empty request");
+            }
+            // No blank line before EOF: treat what arrived as a bodiless request.
+            return Ok(String::from_utf8_lossy(&buf).to_string());
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    };
+
+    let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+    let content_length = match parse_headers(&head).get("content-length") {
+        Some(v) => v
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| anyhow!("invalid Content-Length: {v}"))?,
+        None => 0,
+    };
+    if content_length > HTTP_MAX_BODY_BYTES {
+        bail!("request body of {content_length} bytes exceeds {HTTP_MAX_BODY_BYTES}");
+    }
+
+    let total = header_end + content_length;
+    while buf.len() < total {
+        let n = stream.read(&mut chunk)?;
+        if n == 0 {
+            bail!(
+                "connection closed after {} of {content_length} body bytes",
+                buf.len() - header_end
+            );
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+    buf.truncate(total);
+    Ok(String::from_utf8_lossy(&buf).to_string())
 }
 
 fn parse_request_line(request: &str) -> Result<(&str, &str, &str)> {
@@ -2459,13 +4627,56 @@ fn parse_headers(request: &str) -> std::collections::HashMap<String, String> {
 fn parse_since(query: &str) -> i64 {
     query
         .split('&')
-        .find_map(|kv| kv.split_once('='))
-        .and_then(|(k, v)| if k == "since" { v.parse().ok() } else { None })
+        .filter_map(|kv| kv.split_once('='))
+        .find(|(k, _)| *k == "since")
+        .and_then(|(_, v)| v.parse().ok())
         .unwrap_or(0)
 }
 
+const SSE_KEEPALIVE: Duration = Duration::from_secs(15);
+
+/// Serves `GET /api/stream`: a Server-Sent Events response with one
+/// `data: {movement}` frame per row appended to the active mode's db. Returns
+/// once the client goes away (the next frame or keepalive fails to write, or
+/// blocks past `HTTP_WRITE_TIMEOUT`) or the channel closes, so the connection
+/// task never outlives its client.
+async fn stream_movements(
+    stream: &mut impl Write,
+    app: &UiAppState,
+    mut events: tokio::sync::broadcast::Receiver<MovementEvent>,
+) -> Result<()> {
+    use tokio::sync::broadcast::error::RecvError;
+
+    stream.write_all(
+        b"HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-store\r\nConnection: keep-alive\r\n\r\n",
+    )?;
+    stream.flush()?;
+    loop {
+        let frame = match tokio::time::timeout(SSE_KEEPALIVE, events.recv()).await {
+            Ok(Ok(event)) => {
+                let active = current_mode_from_runtime(&*app.runtime.lock().await);
+                if event.mode != active {
+                    continue;
+                }
+                format!("data: {}\n\n", serde_json::to_string(&event.movement)?)
+            }
+            // Missed frames are picked up by the UI's periodic full refresh.
+            Ok(Err(RecvError::Lagged(_))) => continue,
+            Ok(Err(RecvError::Closed)) => return Ok(()),
+            Err(_) => ": keepalive\n\n".to_string(),
+        };
+        if stream
+            .write_all(frame.as_bytes())
+            .and_then(|()| stream.flush())
+            .is_err()
+        {
+            return Ok(());
+        }
+    }
+}
+
 fn write_response(
-    stream: &mut TcpStream,
+    stream: &mut impl Write,
     status: &str,
     content_type: &str,
     body: &str,
@@ -2483,22 +4694,111 @@ fn validate_config(cfg: &ConfigureArgs) -> Result<()> {
     if cfg.allocated_funds <= Decimal::ZERO {
         bail!("allocated-funds must be > 0");
     }
+    if cfg.leaders.is_empty() {
+        bail!("at least one --leader is required");
+    }
+    let mut seen = HashSet::new();
+    for leader in &cfg.leaders {
+        crate::commands::parse_address(&leader.address)
+            .with_context(|| format!("invalid leader '{}'", leader.address))?;
+        if leader.weight <= Decimal::ZERO {
+            bail!(
+                "leader weight must be > 0 (got {} for {})",
+                leader.weight,
+                leader.address
+            );
+        }
+        if !seen.insert(leader.address.to_lowercase()) {
+            bail!("leader {} is listed more than once", leader.address);
+        }
+    }
+    let r = cfg.resolved();
     for (name, v) in [
-        ("max-trade-pct", cfg.max_trade_pct),
-        ("max-total-exposure-pct", cfg.max_total_exposure_pct),
+        ("max-trade-pct", r.max_trade_pct),
+        ("max-total-exposure-pct", r.max_total_exposure_pct),
+        (
+            "per-leader-max-exposure-pct",
+            cfg.per_leader_max_exposure_pct
+                .unwrap_or(Decimal::from(100)),
+        ),
     ] {
         if v <= Decimal::ZERO || v > Decimal::from(100) {
             bail!("{name} must be between 0 and 100");
         }
     }
-    if cfg.min_copy_usd < Decimal::ZERO {
+    if r.min_copy_usd < Decimal::ZERO {
         bail!("min-copy-usd cannot be negative");
     }
-    if cfg.realtime_mode && cfg.simulation_mode {
+    if r.realtime_mode && cfg.simulation_mode {
         bail!("realtime-mode and simulation-mode are mutually exclusive");
     }
+    if cfg.paper_mode && (cfg.execute_orders || cfg.simulation_mode) {
+        bail!("paper-mode cannot be combined with execute-orders or simulation-mode");
+    }
+    if cfg.copy_sides == CopySides::Sell {
+        bail!(
+            "--copy-sides sell is not supported: leader sells only close open copies, \
+             so there would be nothing to copy"
+        );
+    }
+    if cfg.max_daily_trades == Some(0) {
+        bail!("max-daily-trades must be > 0");
+    }
+    if cfg.settlement_log_max_bytes == Some(0) {
+        bail!("settlement-log-max-bytes must be > 0");
+    }
+    if cfg
+        .stop_loss_pct
+        .is_some_and(|v| v <= Decimal::ZERO || v > Decimal::from(100))
+    {
+        bail!("stop-loss-pct must be between 0 and 100");
+    }
+    if cfg.take_profit_pct.is_some_and(|v| v <= Decimal::ZERO) {
+        bail!("take-profit-pct must be > 0");
+    }
+    if cfg.backoff_recovery_successes == Some(0) {
+        bail!("backoff-recovery-successes must be > 0");
+    }
+    if cfg.daily_loss_limit_usd.is_some_and(|v| v <= Decimal::ZERO) {
+        bail!("daily-loss-limit-usd must be > 0");
+    }
+    if cfg.max_open_positions == Some(0) {
+        bail!("max-open-positions must be > 0");
+    }
+    if cfg
+        .per_leader_disable_loss_usd
+        .is_some_and(|v| v <= Decimal::ZERO)
+    {
+        bail!("per-leader-disable-loss-usd must be > 0");
+    }
+    match (cfg.sizing_mode, cfg.fixed_copy_usd) {
+        (SizingMode::Fixed, None) => bail!("--sizing-mode fixed requires --fixed-copy-usd"),
+        (_, Some(v)) if v <= Decimal::ZERO => bail!("fixed-copy-usd must be > 0"),
+        _ => {}
+    }
+    if cfg.liquidity_multiple.is_some_and(|v| v < Decimal::ONE) {
+        bail!("liquidity-multiple must be >= 1");
+    }
+    if cfg
+        .max_slippage_pct
+        .is_some_and(|v| v < Decimal::ZERO || v > Decimal::from(100))
+    {
+        bail!("max-slippage-pct must be between 0 and 100");
+    }
+    if let Some(url) = &cfg.notify_webhook_url
+        && !(url.starts_with("http://") || url.starts_with("https://"))
+    {
+        bail!("notify-webhook-url must be an http(s) URL");
+    }
+    if cfg
+        .notify_command
+        .as_deref()
+        .is_some_and(|c| c.trim().is_empty())
+    {
+        bail!("notify-command cannot be empty");
+    }
     if let Some(ms) = cfg.poll_interval_ms
-        && ms < min_poll_ms(cfg.realtime_mode, cfg.simulation_mode)
+        && ms < min_poll_ms(r.realtime_mode, cfg.simulation_mode)
     {
         bail!("poll-interval-ms too low for selected mode");
     }
@@ -2512,6 +4812,8 @@ fn copied_shares_from_notional(notional_usd: Decimal, price: Decimal) -> Decimal
     notional_usd / price
 }
 
+/// Dedup key and `movement_id` suffix for one leader fill. A transaction can
+/// batch fills on several assets, so the hash alone would collapse them.
 fn trade_event_key(trade: &polymarket_client_sdk::data::types::response::Trade) -> String {
     format!(
         "{}|{}|{}|{}|{}|{}|{}|{}",
@@ -2527,16 +4829,30 @@ fn trade_event_key(trade: &polymarket_client_sdk::data::types::response::Trade)
 }
 
 fn movement_copied_shares(m: &MovementRecord) -> Decimal {
-    let px = if m.simulated_copy_price > Decimal::ZERO {
-        m.simulated_copy_price
-    } else {
-        m.leader_price
-    };
-    copied_shares_from_notional(m.copied_value, px)
+    copied_shares_from_notional(m.copied_value, movement_entry_price(m))
+}
+
+/// A leader sell closes that leader's open copies on the market/outcome,
+/// unless `--copy-sides buy` asked to ignore the leader's exits.
+fn settle_from_leader_sell(
+    cfg: &CopyConfig,
+    state: &mut CopyState,
+    leader: &str,
+    market: &str,
+    outcome: &str,
+    sell_price: Decimal,
+) -> Vec<MovementRecord> {
+    if cfg.copy_sides == CopySides::Buy {
+        return Vec::new();
+    }
+    settle_open_buys_from_sell_trade(state, leader, market, outcome, sell_price)
 }
 
+/// Closes open buys of `leader` (plus untagged legacy ones) on the sold
+/// market/outcome; an empty `leader` matches every movement.
 fn settle_open_buys_from_sell_trade(
     state: &mut CopyState,
+    leader: &str,
     market: &str,
     outcome: &str,
     sell_price: Decimal,
@@ -2552,7 +4868,13 @@ fn settle_open_buys_from_sell_trade(
         if !movement.copy_side.eq_ignore_ascii_case("buy") {
             continue;
         }
-        if movement.outcome != outcome {
+        if !leader.is_empty()
+            && !movement.leader.is_empty()
+            && !movement.leader.eq_ignore_ascii_case(leader)
+        {
+            continue;
+        }
+        if !outcomes_match(&movement.outcome, outcome) {
             continue;
         }
         let movement_market_norm = normalize_market_slug(&movement.market);
@@ -2570,7 +4892,7 @@ fn settle_open_buys_from_sell_trade(
         }
 
         let roi = (sell_price - entry_price) / entry_price;
-        movement.pnl = movement.copied_value * roi;
+        apply_remainder_pnl(movement, movement.copied_value * roi);
         movement.copy_side = "sell".to_string();
         movement.resolved_outcome = outcome.to_string();
         movement.settled = true;
@@ -2580,6 +4902,261 @@ fn settle_open_buys_from_sell_trade(
     settled
 }
 
+fn is_open_buy_of(m: &MovementRecord, token_id: polymarket_client_sdk::types::U256) -> bool {
+    !m.settled
+        && m.copy_side.eq_ignore_ascii_case("buy")
+        && crate::commands::clob::parse_token_id(&m.asset).is_ok_and(|a| a == token_id)
+}
+
+/// Closes the open buys on `token_id` that a manual sell exits, at
+/// `sell_price`, the way a leader sell closes copies.
+fn settle_open_buys_of_token(
+    state: &mut CopyState,
+    token_id: polymarket_client_sdk::types::U256,
+    sell_price: Decimal,
+) -> Vec<MovementRecord> {
+    let mut settled = Vec::new();
+    for movement in state
+        .movements
+        .iter_mut()
+        .filter(|m| is_open_buy_of(m, token_id))
+    {
+        let entry_price = movement_entry_price(movement);
+        if entry_price > Decimal::ZERO {
+            let roi = (sell_price - entry_price) / entry_price;
+            apply_remainder_pnl(movement, movement.copied_value * roi);
+        }
+        movement.copy_side = "sell".to_string();
+        movement.resolved_outcome = movement.outcome.clone();
+        movement.settled = true;
+        settled.push(movement.clone());
+    }
+    settled
+}
+
+/// `copy record --execute --side sell`: the order exits earlier copies, so
+/// it settles them instead of adding an open movement.
+fn record_manual_sell(
+    state: &mut CopyState,
+    mode: StorageMode,
+    token_id: polymarket_client_sdk::types::U256,
+    price: Decimal,
+    sold_usd: Decimal,
+    output: OutputFormat,
+) -> Result<()> {
+    let closed = settle_open_buys_of_token(state, token_id, price);
+    save_state(state)?;
+    let cfg = load_config().ok();
+    for movement in &closed {
+        settle_db_movement_from_record(mode, movement)?;
+        if let Err(e) = append_settlement_log(cfg.as_ref(), mode, movement) {
+            tracing::warn!("could not append settlement log: {e}");
+        }
+    }
+    let ids: Vec<&str> = closed.iter().map(|m| m.movement_id.as_str()).collect();
+    if matches!(output, OutputFormat::Json | OutputFormat::Yaml) {
+        crate::output::print_json(&serde_json::json!({
+            "status": "closed",
+            "executed": true,
+            "copied_value": sold_usd,
+            "closed": ids,
+        }))?;
+    } else {
+        println!(
+            "Order placed (${sold_usd}) and closed {} open movement(s): {}",
+            ids.len(),
+            ids.join(", ")
+        );
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExitReason {
+    StopLoss,
+    TakeProfit,
+}
+
+impl ExitReason {
+    fn label(self) -> &'static str {
+        match self {
+            ExitReason::StopLoss => "stop-loss",
+            ExitReason::TakeProfit => "take-profit",
+        }
+    }
+}
+
+fn movement_entry_price(m: &MovementRecord) -> Decimal {
+    if m.simulated_copy_price > Decimal::ZERO {
+        m.simulated_copy_price
+    } else {
+        m.leader_price
+    }
+}
+
+/// Return of an open copy at `current_price`, in percent of its entry price.
+/// A copied sell gains when the price falls.
+fn unrealized_return_pct(m: &MovementRecord, current_price: Decimal) -> Option<Decimal> {
+    let entry = movement_entry_price(m);
+    if entry <= Decimal::ZERO || current_price <= Decimal::ZERO {
+        return None;
+    }
+    let change = (current_price - entry) / entry * Decimal::from(100);
+    Some(if m.copy_side.eq_ignore_ascii_case("sell") {
+        -change
+    } else {
+        change
+    })
+}
+
+/// Whether an open copy has crossed the configured stop-loss or take-profit.
+fn should_exit(m: &MovementRecord, current_price: Decimal, cfg: &CopyConfig) -> Option<ExitReason> {
+    if m.settled {
+        return None;
+    }
+    let ret = unrealized_return_pct(m, current_price)?;
+    if cfg.stop_loss_pct.is_some_and(|sl| ret <= -sl) {
+        return Some(ExitReason::StopLoss);
+    }
+    if cfg.take_profit_pct.is_some_and(|tp| ret >= tp) {
+        return Some(ExitReason::TakeProfit);
+    }
+    None
+}
+
+/// Prices every open movement of `mode` at its token's midpoint (one batched
+/// request per sweep) and exits the ones past stop-loss/take-profit: an
+/// opposite order when orders are posted, then a settlement at the midpoint
+/// return.
+async fn apply_exit_thresholds(
+    app: &UiAppState,
+    cfg: &CopyConfig,
+    clob_client: &polymarket_client_sdk::clob::Client,
+    mode: StorageMode,
+) -> Result<()> {
+    if cfg.stop_loss_pct.is_none() && cfg.take_profit_pct.is_none() {
+        return Ok(());
+    }
+    let log_mode = match mode {
+        StorageMode::Real => "real",
+        StorageMode::Simulation => "sim",
+    };
+    let open = exit_candidates(load_state()?, &read_db_rows(mode)?);
+    if open.is_empty() {
+        return Ok(());
+    }
+    let token_ids: HashSet<_> = open.iter().map(|(_, token_id)| *token_id).collect();
+    let requests: Vec<_> = token_ids
+        .into_iter()
+        .map(|token_id| {
+            polymarket_client_sdk::clob::types::request::MidpointRequest::builder()
+                .token_id(token_id)
+                .build()
+        })
+        .collect();
+    let midpoints = match crate::net::timed(clob_client.midpoints(&requests)).await {
+        Ok(r) => r.midpoints,
+        Err(e) => {
+            log_copy_event(
+                log_mode,
+                format!(
+                    "no se pudieron obtener midpoints de {} tokens: {e}",
+                    requests.len()
+                ),
+            );
+            return Ok(());
+        }
+    };
+    for (movement, token_id) in open {
+        let Some(&current) = midpoints.get(&token_id) else {
+            log_copy_event(log_mode, format!("sin midpoint para {}", movement.market));
+            continue;
+        };
+        let Some(reason) = should_exit(&movement, current, cfg) else {
+            continue;
+        };
+
+        if matches!(mode, StorageMode::Real) && posts_orders(cfg) && !movement.paper {
+            // Close the same number of shares the copy opened, priced at the
+            // midpoint.
+            let side = if movement.copy_side.eq_ignore_ascii_case("sell") {
+                ClobSide::Buy
+            } else {
+                ClobSide::Sell
+            };
+            let notional = movement_copied_shares(&movement) * current;
+            if let Err(e) =
+                execute_copy_order(&app.wallet, token_id, side, current, notional, true).await
+            {
+                let mut runtime = app.runtime.lock().await;
+                runtime.warning = Some(format!(
+                    "Error cerrando {} por {}: {e}",
+                    movement.movement_id,
+                    reason.label()
+                ));
+                log_copy_event(
+                    log_mode,
+                    format!(
+                        "error cerrando {} por {}: {e}",
+                        movement.movement_id,
+                        reason.label()
+                    ),
+                );
+                continue;
+            }
+        }
+
+        let mut state = load_state()?;
+        let Some(m) = state
+            .movements
+            .iter_mut()
+            .find(|m| m.movement_id == movement.movement_id && !m.settled)
+        else {
+            continue;
+        };
+        let ret = unrealized_return_pct(m, current).unwrap_or(Decimal::ZERO);
+        apply_remainder_pnl(m, m.copied_value * ret / Decimal::from(100));
+        m.resolved_outcome = m.outcome.clone();
+        m.settled = true;
+        let settled = m.clone();
+        save_state(&state)?;
+        record_settlement(cfg, mode, log_mode, &settled)?;
+        log_copy_event(
+            log_mode,
+            format!(
+                "{} alcanzado: cerrada {} (mercado={}, outcome={}) entrada={} midpoint={} pnl={}",
+                reason.label(),
+                settled.movement_id,
+                settled.market,
+                settled.outcome,
+                movement_entry_price(&settled),
+                current,
+                settled.pnl
+            ),
+        );
+    }
+    Ok(())
+}
+
+/// Unsettled movements of the shared state that belong to the mode whose db
+/// rows are given, paired with their token id. The state file holds both
+/// modes, so a real sweep must never price (or close) simulated copies.
+fn exit_candidates(
+    state: CopyState,
+    mode_rows: &[DbRow],
+) -> Vec<(MovementRecord, polymarket_client_sdk::types::U256)> {
+    let in_mode: HashSet<&str> = mode_rows.iter().map(|r| r.movement_id.as_str()).collect();
+    state
+        .movements
+        .into_iter()
+        .filter(|m| !m.settled && in_mode.contains(m.movement_id.as_str()))
+        .filter_map(|m| {
+            let token_id = crate::commands::clob::parse_token_id(&m.asset).ok()?;
+            Some((m, token_id))
+        })
+        .collect()
+}
+
 fn has_enough_inventory_for_sell(
     state: &CopyState,
     market: &str,
@@ -2592,7 +5169,7 @@ fn has_enough_inventory_for_sell(
 
     let mut net_long_shares = Decimal::ZERO;
     for movement in state.movements.iter().filter(|m| !m.settled) {
-        if movement.market != market || movement.outcome != outcome {
+        if movement.market != market || !outcomes_match(&movement.outcome, outcome) {
             continue;
         }
         let shares = movement_copied_shares(movement);
@@ -2612,8 +5189,11 @@ fn has_enough_inventory_for_sell(
 fn compute_plan(
     cfg: &CopyConfig,
     state: &CopyState,
+    leader: &str,
+    market: &str,
     leader_positions_value: Decimal,
     leader_movement_value: Decimal,
+    round_trip_fee_rate: Decimal,
 ) -> Result<PlanResult> {
     if leader_positions_value <= Decimal::ZERO {
         bail!("leader-positions-value must be > 0");
@@ -2624,10 +5204,26 @@ fn compute_plan(
         .filter(|m| m.settled)
         .map(|m| m.pnl - m.estimated_total_fee_usd)
         .sum();
-    let effective_funds = (cfg.allocated_funds + settled_pnl_after_fees).max(Decimal::ZERO);
+    // Each leader sizes and caps against its weighted slice of the funds, and
+    // only its own open movements count against that slice's exposure.
+    let effective_funds = (cfg.allocated_funds + settled_pnl_after_fees).max(Decimal::ZERO)
+        * cfg.leader_share(leader);
+    let counts_for_leader =
+        |m: &MovementRecord| cfg.leaders.len() <= 1 || movement_leader_is(m, cfg, leader);
+    let family = normalize_market_slug(market);
+    let counts_for_market = |m: &MovementRecord| match cfg.exposure_mode {
+        ExposureMode::Global => true,
+        ExposureMode::ByNormalizedMarket => normalize_market_slug(&m.market) == family,
+    };
 
     let ratio = effective_funds / leader_positions_value;
     let proportional = leader_movement_value * ratio;
+    let fixed = match cfg.sizing_mode {
+        SizingMode::Proportional => None,
+        SizingMode::Fixed => Some(cfg.fixed_copy_usd.unwrap_or_default()),
+    };
+    // The size we'd copy before any cap applies.
+    let target = fixed.unwrap_or(proportional);
 
     let safe_max_trade_pct = cfg.max_trade_pct.min(Decimal::from(100));
     let safe_max_total_exposure_pct = cfg.max_total_exposure_pct.min(Decimal::from(100));
@@ -2637,36 +5233,735 @@ fn compute_plan(
     let used_exposure: Decimal = state
         .movements
         .iter()
-        .filter(|m| !m.settled)
-        .map(|m| m.copied_value)
+        .filter(|m| !m.settled && counts_for_leader(m) && counts_for_market(m))
+        .map(|m| {
+            if cfg.include_fees {
+                m.copied_value + m.estimated_total_fee_usd
+            } else {
+                m.copied_value
+            }
+        })
         .sum();
-    let available_exposure = (max_total_exposure - used_exposure).max(Decimal::ZERO);
-    let capped = proportional.min(max_trade).min(available_exposure);
-
-    let reason = if capped < cfg.min_copy_usd {
-        "below minimum copy threshold".to_string()
-    } else if available_exposure <= Decimal::ZERO {
-        "no exposure available".to_string()
-    } else if proportional > max_trade {
-        "capped by max_trade_pct".to_string()
-    } else if proportional > available_exposure {
-        "capped by max_total_exposure_pct".to_string()
+    let global_available = (max_total_exposure - used_exposure).max(Decimal::ZERO);
+    let leader_available = cfg.per_leader_max_exposure_pct.map(|pct| {
+        let cap = (cfg.allocated_funds + settled_pnl_after_fees).max(Decimal::ZERO)
+            * (pct.min(Decimal::from(100)) / Decimal::from(100));
+        let used: Decimal = state
+            .movements
+            .iter()
+            .filter(|m| !m.settled && movement_leader_is(m, cfg, leader))
+            .map(|m| m.copied_value)
+            .sum();
+        (cap - used).max(Decimal::ZERO)
+    });
+    let leader_bound = leader_available.is_some_and(|a| a < global_available);
+    let available_exposure = leader_available.map_or(global_available, |a| a.min(global_available));
+    // A copy of size x costs x * (1 + rate) once fees are paid, so only
+    // available / (1 + rate) of the remaining exposure can go to the position.
+    let fee_reserve = cfg.include_fees.then(|| {
+        let rate = round_trip_fee_rate.max(Decimal::ZERO);
+        available_exposure - available_exposure / (Decimal::ONE + rate)
+    });
+    let available_exposure = available_exposure - fee_reserve.unwrap_or_default();
+    let capped = target.min(max_trade).min(available_exposure);
+    let open_positions = cfg
+        .max_open_positions
+        .map(|_| state.movements.iter().filter(|m| !m.settled).count());
+    let at_position_cap = cfg
+        .max_open_positions
+        .zip(open_positions)
+        .is_some_and(|(cap, open)| open >= cap);
+
+    let exposure_field = if leader_bound {
+        "per_leader_available"
+    } else {
+        "available_exposure"
+    };
+    let (reason, binding) = if at_position_cap {
+        ("max open positions reached", "open_positions")
+    } else if capped < cfg.min_copy_usd {
+        ("below minimum copy threshold", "min_copy_usd")
+    } else if available_exposure <= Decimal::ZERO {
+        ("no exposure available", exposure_field)
+    } else if target > max_trade {
+        ("capped by max_trade_pct", "max_trade")
+    } else if target > available_exposure && leader_bound {
+        ("capped by per-leader exposure", exposure_field)
+    } else if target > available_exposure {
+        ("capped by max_total_exposure_pct", exposure_field)
+    } else if fixed.is_some() {
+        ("ok", "fixed_copy_usd")
     } else {
-        "ok".to_string()
+        ("ok", "proportional_size")
     };
+    let reason = reason.to_string();
 
     Ok(PlanResult {
         proportional_size: proportional,
-        capped_size: if reason == "below minimum copy threshold" {
+        capped_size: if at_position_cap || reason == "below minimum copy threshold" {
             Decimal::ZERO
         } else {
             capped
         },
         available_funds: available_exposure,
         reason,
+        constraints: Some(PlanConstraints {
+            effective_funds,
+            proportional_size: proportional,
+            fixed_copy_usd: fixed,
+            max_trade,
+            available_exposure: global_available,
+            per_leader_available: leader_available,
+            fee_reserve,
+            min_copy_usd: cfg.min_copy_usd,
+            open_positions,
+            binding: binding.to_string(),
+        }),
+    })
+}
+
+fn utc_day(now: &chrono::DateTime<Utc>) -> String {
+    now.format("%Y-%m-%d").to_string()
+}
+
+/// Movements recorded on the UTC `day` (YYYY-MM-DD), bucketed like the PnL
+/// series so a timestamp with a non-UTC offset lands on the right day.
+fn movements_on_day(movements: &[MovementRecord], day: &str) -> usize {
+    movements
+        .iter()
+        .filter(|m| hour_bucket(&m.timestamp, chrono_tz::UTC).starts_with(day))
+        .count()
+}
+
+fn history_mode(mode: Option<HistoryMode>) -> StorageMode {
+    match mode {
+        Some(HistoryMode::Real) => StorageMode::Real,
+        Some(HistoryMode::Sim) => StorageMode::Simulation,
+        None => current_mode_from_disk(),
+    }
+}
+
+#[derive(Debug, Default, Serialize, JsonSchema)]
+pub struct ReplaySummary {
+    pub movements: usize,
+    pub copied: usize,
+    pub skipped: usize,
+    /// Sum of the replayed copy sizes.
+    pub total_exposure: Decimal,
+    pub gross_pnl: Decimal,
+    pub fees: Decimal,
+    pub net_pnl: Decimal,
+    /// Skipped movements counted by `compute_plan`'s reason.
+    pub skip_reasons: BTreeMap<String, usize>,
+}
+
+/// Sizes every recorded movement again under `cfg`, in timestamp order, with
+/// the same plan, daily-limit and fee checks as the monitor. Each replayed
+/// copy keeps the recorded return, so PnL scales with the new size. The
+/// history doesn't say when a movement settled, so settled ones count as
+/// closed (freeing exposure) from the moment they were copied.
+fn replay_history(
+    cfg: &CopyConfig,
+    fees: &FeeSchedule,
+    history: &[MovementRecord],
+    leader_positions_value: Decimal,
+) -> Result<ReplaySummary> {
+    let mut ordered: Vec<&MovementRecord> = history.iter().collect();
+    ordered.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+
+    let mut state = CopyState::default();
+    let mut summary = ReplaySummary {
+        movements: ordered.len(),
+        ..ReplaySummary::default()
+    };
+    fn skip(summary: &mut ReplaySummary, reason: &str) {
+        summary.skipped += 1;
+        *summary.skip_reasons.entry(reason.to_string()).or_default() += 1;
+    }
+
+    for m in ordered {
+        let leader = if m.leader.is_empty() {
+            cfg.primary_leader()
+        } else {
+            m.leader.as_str()
+        };
+        if !cfg
+            .leaders
+            .iter()
+            .any(|l| l.address.eq_ignore_ascii_case(leader))
+        {
+            skip(&mut summary, "leader not configured");
+            continue;
+        }
+        if !outcome_allowed(cfg.copy_outcome, &m.outcome) {
+            skip(&mut summary, "outcome filtered");
+            continue;
+        }
+
+        let mut plan = compute_plan(
+            cfg,
+            &state,
+            leader,
+            &m.market,
+            leader_positions_value,
+            m.leader_value,
+            round_trip_fee_rate(fees, &m.market),
+        )?;
+        let candidate = TradeCandidate {
+            leader,
+            leader_value: m.leader_value,
+            market: &m.market,
+            outcome: &m.outcome,
+            price: m.leader_price,
+            size: Decimal::ZERO,
+            is_sell: m.copy_side.eq_ignore_ascii_case("SELL"),
+        };
+        if let Err(filtered) = passes_filters(&candidate, cfg) {
+            skip(&mut summary, &filtered.label(&plan));
+            continue;
+        }
+        let hour = hour_bucket(&m.timestamp, chrono_tz::UTC);
+        apply_daily_trade_limit(cfg, &state, hour.get(..10).unwrap_or_default(), &mut plan);
+        if let Ok(ts) = chrono::DateTime::parse_from_rfc3339(&m.timestamp) {
+            apply_daily_loss_limit(cfg, &state, &ts.with_timezone(&Utc), &mut plan);
+        }
+        if plan.capped_size <= Decimal::ZERO {
+            skip(&mut summary, &plan.reason);
+            continue;
+        }
+        let fee_impact = trading_fee_impact(fees, &m.market, plan.capped_size);
+        if fee_impact.is_some_and(|i| i.max_net_profit_usd <= Decimal::ZERO) {
+            skip(&mut summary, "fees exceed max profit");
+            continue;
+        }
+
+        let fee = fee_impact.map_or(Decimal::ZERO, |i| i.round_trip_fee_usd);
+        let pnl = if m.settled && m.copied_value > Decimal::ZERO {
+            m.pnl * plan.capped_size / m.copied_value
+        } else {
+            Decimal::ZERO
+        };
+        summary.copied += 1;
+        summary.total_exposure += plan.capped_size;
+        if m.settled {
+            summary.gross_pnl += pnl;
+            summary.fees += fee;
+        }
+        state.movements.push(MovementRecord {
+            leader: leader.to_string(),
+            copied_value: plan.capped_size,
+            estimated_total_fee_usd: fee,
+            pnl,
+            ..m.clone()
+        });
+    }
+    summary.net_pnl = summary.gross_pnl - summary.fees;
+    Ok(summary)
+}
+
+/// Zeroes the plan once `max_daily_trades` movements were already recorded
+/// today, so the monitor skips the copy with a clear reason.
+fn apply_daily_trade_limit(cfg: &CopyConfig, state: &CopyState, day: &str, plan: &mut PlanResult) {
+    if let Some(limit) = cfg.max_daily_trades
+        && movements_on_day(&state.movements, day) >= limit
+    {
+        plan.capped_size = Decimal::ZERO;
+        plan.reason = "daily trade limit reached".to_string();
+    }
+}
+
+/// True once the settled net PnL of movements dated on `now`'s UTC day has
+/// lost `limit` USD or more. Movements from earlier days don't count, so the
+/// breaker resets at UTC midnight.
+fn daily_loss_breached(
+    movements: &[MovementRecord],
+    limit: Decimal,
+    now: &chrono::DateTime<Utc>,
+) -> bool {
+    let day = utc_day(now);
+    let net: Decimal = daily_pnl_series_in(movements, chrono_tz::UTC)
+        .into_iter()
+        .filter(|(hour, _)| hour.starts_with(&day))
+        .map(|(_, pnl)| pnl)
+        .sum();
+    -net >= limit
+}
+
+/// Zeroes the plan while the daily loss limit is breached. Returns whether it
+/// did, so the monitor can also surface a warning.
+fn apply_daily_loss_limit(
+    cfg: &CopyConfig,
+    state: &CopyState,
+    now: &chrono::DateTime<Utc>,
+    plan: &mut PlanResult,
+) -> bool {
+    let Some(limit) = cfg.daily_loss_limit_usd else {
+        return false;
+    };
+    if !daily_loss_breached(&state.movements, limit, now) {
+        return false;
+    }
+    plan.capped_size = Decimal::ZERO;
+    plan.reason = "daily loss limit reached".to_string();
+    true
+}
+
+/// A detected leader trade, reduced to what the copy decision needs.
+struct TradeCandidate<'a> {
+    leader: &'a str,
+    leader_value: Decimal,
+    market: &'a str,
+    outcome: &'a str,
+    price: Decimal,
+    size: Decimal,
+    is_sell: bool,
+}
+
+impl<'a> TradeCandidate<'a> {
+    fn from_trade(
+        leader: &'a str,
+        leader_value: Decimal,
+        t: &'a polymarket_client_sdk::data::types::response::Trade,
+    ) -> Self {
+        Self {
+            leader,
+            leader_value,
+            market: &t.slug,
+            outcome: &t.outcome,
+            price: t.price,
+            size: t.size,
+            is_sell: t.side.to_string().eq_ignore_ascii_case("sell"),
+        }
+    }
+}
+
+/// Why [`evaluate_trade`] passed on a trade.
+#[derive(Debug, Clone, PartialEq)]
+enum TradeSkip {
+    OutcomeFiltered,
+    /// The trade's side is excluded by `copy_sides`.
+    SideFiltered,
+    /// The market isn't on the slug allowlist, or matches the denylist.
+    SlugFiltered,
+    /// Sized to zero; the plan's reason says why.
+    Sizing,
+    DailyLossLimit,
+    /// A sell with no open buy of that outcome to close.
+    NoOpenBuy {
+        required_shares: Decimal,
+    },
+    /// A sell that matches open buys but didn't settle them; copying it would
+    /// leave an open SELL.
+    UnreconciledSell,
+    /// Round-trip fees eat the whole possible profit.
+    Fees(TradingFeeImpact),
+}
+
+impl TradeSkip {
+    fn label(&self, plan: &PlanResult) -> String {
+        match self {
+            Self::OutcomeFiltered => "outcome filtered".to_string(),
+            Self::SideFiltered => "side filtered".to_string(),
+            Self::SlugFiltered => "slug filtered".to_string(),
+            Self::Sizing | Self::DailyLossLimit => plan.reason.clone(),
+            Self::NoOpenBuy { .. } => "sell without an open buy".to_string(),
+            Self::UnreconciledSell => "sell not reconciled with open buys".to_string(),
+            Self::Fees(_) => "fees exceed max profit".to_string(),
+        }
+    }
+}
+
+struct TradeEvaluation {
+    plan: PlanResult,
+    fee_impact: Option<TradingFeeImpact>,
+    skip: Option<TradeSkip>,
+}
+
+/// Applies `copy_sides` and the slug allow/deny lists. Patterns are
+/// case-insensitive substrings of the normalized slug; an empty allowlist
+/// allows every market.
+fn passes_filters(trade: &TradeCandidate, cfg: &CopyConfig) -> Result<(), TradeSkip> {
+    let side_allowed = match cfg.copy_sides {
+        CopySides::Both => true,
+        CopySides::Buy => !trade.is_sell,
+        CopySides::Sell => trade.is_sell,
+    };
+    if !side_allowed {
+        return Err(TradeSkip::SideFiltered);
+    }
+    let slug = normalize_market_slug(trade.market).to_lowercase();
+    let matches = |patterns: &[String]| {
+        patterns
+            .iter()
+            .any(|p| slug.contains(p.to_lowercase().as_str()))
+    };
+    if (!cfg.slug_allowlist.is_empty() && !matches(&cfg.slug_allowlist))
+        || matches(&cfg.slug_denylist)
+    {
+        return Err(TradeSkip::SlugFiltered);
+    }
+    Ok(())
+}
+
+/// Decides whether, and how much, to copy one leader trade. Reads nothing
+/// from disk or the network: the monitor acts on the result and
+/// `copy preview` only prints it. Sells that close open buys are settled by
+/// the caller before this runs.
+fn evaluate_trade(
+    cfg: &CopyConfig,
+    fees: &FeeSchedule,
+    state: &CopyState,
+    trade: &TradeCandidate,
+    now: &chrono::DateTime<Utc>,
+) -> Result<TradeEvaluation> {
+    let mut plan = compute_plan(
+        cfg,
+        state,
+        trade.leader,
+        trade.market,
+        trade.leader_value,
+        trade.size * trade.price,
+        round_trip_fee_rate(fees, trade.market),
+    )?;
+    let skipped = |plan: PlanResult, skip: TradeSkip| -> Result<TradeEvaluation> {
+        Ok(TradeEvaluation {
+            plan,
+            fee_impact: None,
+            skip: Some(skip),
+        })
+    };
+
+    if !outcome_allowed(cfg.copy_outcome, trade.outcome) {
+        return skipped(plan, TradeSkip::OutcomeFiltered);
+    }
+    if let Err(skip) = passes_filters(trade, cfg) {
+        return skipped(plan, skip);
+    }
+    apply_daily_trade_limit(cfg, state, &utc_day(now), &mut plan);
+    if apply_daily_loss_limit(cfg, state, now, &mut plan) {
+        return skipped(plan, TradeSkip::DailyLossLimit);
+    }
+    if plan.capped_size <= Decimal::ZERO {
+        return skipped(plan, TradeSkip::Sizing);
+    }
+    if trade.is_sell {
+        let required_shares = copied_shares_from_notional(plan.capped_size, trade.price);
+        let skip =
+            if has_enough_inventory_for_sell(state, trade.market, trade.outcome, required_shares) {
+                TradeSkip::UnreconciledSell
+            } else {
+                TradeSkip::NoOpenBuy { required_shares }
+            };
+        return skipped(plan, skip);
+    }
+
+    let fee_impact = trading_fee_impact(fees, trade.market, plan.capped_size);
+    let skip = fee_impact
+        .filter(|i| i.max_net_profit_usd <= Decimal::ZERO)
+        .map(TradeSkip::Fees);
+    Ok(TradeEvaluation {
+        plan,
+        fee_impact,
+        skip,
     })
 }
 
+/// One leader trade as `copy preview` sees it.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct PreviewRow {
+    pub timestamp: String,
+    pub leader: String,
+    pub market: String,
+    pub side: String,
+    pub outcome: String,
+    pub leader_usd: Decimal,
+    /// `copy`, `close` (a sell that settles open copies) or `skip`.
+    pub action: String,
+    pub copy_usd: Decimal,
+    /// Average fill price walking the current book; `None` without liquidity.
+    pub estimated_price: Option<Decimal>,
+    pub full_fill: Option<bool>,
+    pub round_trip_fee_usd: Option<Decimal>,
+    pub max_net_profit_usd: Option<Decimal>,
+    pub reason: String,
+}
+
+/// Runs the detection and sizing of one monitor pass over each leader's 20
+/// most recent trades against a scratch copy of the state, so earlier
+/// candidates count against later ones as they would live. Places no orders
+/// and writes nothing.
+async fn preview_trades(cfg: &CopyConfig) -> Result<Vec<PreviewRow>> {
+    let data_client = polymarket_client_sdk::data::Client::default();
+    let clob_client = polymarket_client_sdk::clob::Client::default();
+
+    let mut trades = Vec::new();
+    for (leader_cfg, leader) in cfg.leaders.iter().zip(leader_addresses(cfg)?) {
+        let value_req = ValueRequest::builder().user(leader).build();
+        let leader_value = crate::net::retry(|| data_client.value(&value_req))
+            .await
+            .ok()
+            .and_then(|v| v.first().map(|x| x.value))
+            .unwrap_or(Decimal::ONE);
+        let trades_req = TradesRequest::builder().user(leader).limit(20)?.build();
+        let fetched = crate::net::retry(|| data_client.trades(&trades_req)).await?;
+        trades.extend(
+            fetched
+                .into_iter()
+                .map(|t| (leader_cfg.address.clone(), leader_value, t)),
+        );
+    }
+    trades.sort_by_key(|(_, _, t)| t.timestamp);
+
+    let prefix = if cfg.simulation_mode { "sim" } else { "real" };
+    let mut state = load_state()?;
+    let now = Utc::now();
+    let mut rows = Vec::with_capacity(trades.len());
+    for (leader, leader_value, t) in trades {
+        let candidate = TradeCandidate::from_trade(&leader, leader_value, &t);
+        let mut row = PreviewRow {
+            timestamp: chrono::DateTime::from_timestamp(t.timestamp, 0)
+                .map_or_else(|| t.timestamp.to_string(), |d| d.to_rfc3339()),
+            leader: leader.clone(),
+            market: t.slug.clone(),
+            side: t.side.to_string(),
+            outcome: t.outcome.clone(),
+            leader_usd: t.size * t.price,
+            action: "skip".to_string(),
+            copy_usd: Decimal::ZERO,
+            estimated_price: None,
+            full_fill: None,
+            round_trip_fee_usd: None,
+            max_net_profit_usd: None,
+            reason: String::new(),
+        };
+
+        let movement_id = format!("{prefix}-{}", trade_event_key(&t));
+        if state.movements.iter().any(|m| m.movement_id == movement_id) {
+            row.reason = "already copied".to_string();
+            rows.push(row);
+            continue;
+        }
+        if candidate.is_sell {
+            let closed =
+                settle_from_leader_sell(cfg, &mut state, &leader, &t.slug, &t.outcome, t.price);
+            if !closed.is_empty() {
+                row.action = "close".to_string();
+                row.reason = format!("closes {} open copy(ies)", closed.len());
+                rows.push(row);
+                continue;
+            }
+        }
+
+        let eval = evaluate_trade(cfg, fee_schedule(), &state, &candidate, &now)?;
+        row.copy_usd = eval.plan.capped_size;
+        row.round_trip_fee_usd = eval.fee_impact.map(|i| i.round_trip_fee_usd);
+        row.max_net_profit_usd = eval.fee_impact.map(|i| i.max_net_profit_usd);
+        if let Some(skip) = &eval.skip {
+            row.reason = skip.label(&eval.plan);
+            rows.push(row);
+            continue;
+        }
+
+        let fill =
+            estimate_simulated_copy_price_from_book(cfg, &clob_client, &t, eval.plan.capped_size)
+                .await
+                .ok();
+        row.estimated_price = fill.and_then(|(px, _)| px);
+        row.full_fill = fill.map(|(_, full)| full);
+        row.action = "copy".to_string();
+        row.reason.clone_from(&eval.plan.reason);
+        state.movements.push(MovementRecord {
+            movement_id,
+            leader,
+            paper: cfg.paper_mode,
+            market: t.slug.clone(),
+            asset: t.asset.to_string(),
+            timestamp: now.to_rfc3339(),
+            leader_value: t.size * t.price,
+            leader_price: t.price,
+            copied_value: eval.plan.capped_size,
+            simulated_copy_price: row.estimated_price.unwrap_or(Decimal::ZERO),
+            quantity: t.size,
+            copy_side: t.side.to_string(),
+            outcome: t.outcome.clone(),
+            resolved_outcome: String::new(),
+            diff_pct: Decimal::ZERO,
+            estimated_total_fee_usd: row.round_trip_fee_usd.unwrap_or(Decimal::ZERO),
+            settled: false,
+            pnl: Decimal::ZERO,
+            remaining_quantity: None,
+            last_partial_close_ts: None,
+        });
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+/// The monitor's log line for a skipped trade, in the real or sim wording.
+fn skip_log_message(
+    sim: bool,
+    skip: &TradeSkip,
+    plan: &PlanResult,
+    trade: &TradeCandidate,
+    tx_hash: &str,
+) -> String {
+    let slug = trade.market;
+    match skip {
+        TradeSkip::OutcomeFiltered => format!(
+            "trade detectado {slug} ({tx_hash}) sin {} (motivo: outcome filtered, outcome={})",
+            if sim { "simulacion" } else { "copia" },
+            trade.outcome
+        ),
+        TradeSkip::SideFiltered => format!(
+            "trade detectado {slug} ({tx_hash}) sin {} (motivo: side filtered, side={})",
+            if sim { "simulacion" } else { "copia" },
+            if trade.is_sell { "SELL" } else { "BUY" }
+        ),
+        TradeSkip::SlugFiltered => format!(
+            "trade detectado {slug} ({tx_hash}) sin {} (motivo: slug filtered, normalizado={})",
+            if sim { "simulacion" } else { "copia" },
+            normalize_market_slug(slug)
+        ),
+        TradeSkip::Sizing | TradeSkip::DailyLossLimit => format!(
+            "trade detectado {slug} ({tx_hash}) sin {} (motivo: {})",
+            if sim { "simulacion" } else { "copia" },
+            plan.reason
+        ),
+        TradeSkip::NoOpenBuy { required_shares } => format!(
+            "{} {slug} ({tx_hash}) {}: no hay buy abierto conciliable (outcome={}, required_shares={required_shares})",
+            if sim { "simulacion sell" } else { "sell" },
+            if sim { "descartada" } else { "descartado" },
+            trade.outcome
+        ),
+        TradeSkip::UnreconciledSell => format!(
+            "{} {slug} ({tx_hash}) {}: no se pudo conciliar cierre inmediato; evitando SELL abierto",
+            if sim { "simulacion sell" } else { "sell" },
+            if sim { "descartada" } else { "descartado" },
+        ),
+        TradeSkip::Fees(impact) => {
+            let prefix = if sim {
+                format!("simulacion descartada por fees {slug} ({tx_hash})")
+            } else {
+                format!("trade {slug} ({tx_hash}) descartado por fees")
+            };
+            format!(
+                "{prefix} ({} bps): profit_max_neto={} (gross_max={} fee_entry={} fees_rt={})",
+                impact.fee_bps,
+                impact.max_net_profit_usd,
+                impact.max_gross_profit_usd,
+                impact.entry_fee_usd,
+                impact.round_trip_fee_usd,
+            )
+        }
+    }
+}
+
+fn leader_addresses(cfg: &CopyConfig) -> Result<Vec<alloy::primitives::Address>> {
+    cfg.leaders
+        .iter()
+        .map(|l| crate::commands::parse_address(&l.address))
+        .collect()
+}
+
+/// Lowercased leaders whose copies' settled net PnL (after estimated fees)
+/// is at or below `-threshold`. Untagged movements are not counted.
+fn leaders_to_disable(movements: &[MovementRecord], threshold: Decimal) -> Vec<String> {
+    let mut pnl_by_leader: BTreeMap<String, Decimal> = BTreeMap::new();
+    for m in movements
+        .iter()
+        .filter(|m| m.settled && !m.leader.is_empty())
+    {
+        *pnl_by_leader.entry(m.leader.to_lowercase()).or_default() +=
+            m.pnl - m.estimated_total_fee_usd;
+    }
+    pnl_by_leader
+        .into_iter()
+        .filter(|(_, pnl)| *pnl <= -threshold)
+        .map(|(leader, _)| leader)
+        .collect()
+}
+
+/// Disables every leader that crossed `per_leader_disable_loss_usd`,
+/// counting only movements opened since the leader was last re-enabled.
+/// Returns whether a leader was newly disabled.
+fn refresh_disabled_leaders(
+    runtime: &mut RuntimeState,
+    cfg: &CopyConfig,
+    movements: &[MovementRecord],
+) -> bool {
+    let Some(threshold) = cfg.per_leader_disable_loss_usd else {
+        return false;
+    };
+    let counted: Vec<MovementRecord> = movements
+        .iter()
+        .filter_map(|m| {
+            let owner = if m.leader.is_empty() {
+                cfg.primary_leader()
+            } else {
+                m.leader.as_str()
+            }
+            .to_lowercase();
+            let since = runtime.leader_reenabled_at.get(&owner);
+            since
+                .is_none_or(|since| m.timestamp.as_str() >= since.as_str())
+                .then(|| MovementRecord {
+                    leader: owner,
+                    ..m.clone()
+                })
+        })
+        .collect();
+    let mut changed = false;
+    for leader in leaders_to_disable(&counted, threshold) {
+        if runtime.disabled_leaders.insert(leader.clone()) {
+            let msg = format!(
+                "lider {leader} desactivado: pnl neto liquidado <= -{threshold} USD; reactivalo desde la UI"
+            );
+            log_copy_event("core", &msg);
+            runtime.warning = Some(msg);
+            changed = true;
+        }
+    }
+    changed
+}
+
+async fn leader_disabled(app: &UiAppState, address: &str) -> bool {
+    app.runtime
+        .lock()
+        .await
+        .disabled_leaders
+        .contains(&address.to_lowercase())
+}
+
+/// Untagged (legacy) movements belong to the primary leader.
+fn movement_leader_is(m: &MovementRecord, cfg: &CopyConfig, leader: &str) -> bool {
+    let owner = if m.leader.is_empty() {
+        cfg.primary_leader()
+    } else {
+        m.leader.as_str()
+    };
+    owner.eq_ignore_ascii_case(leader)
+}
+
+/// The configured leader `user` is, if any. Settlement sources that aren't a
+/// leader (the executing wallet) return `None` and may settle any copy.
+fn settlement_leader(cfg: &CopyConfig, user: alloy::primitives::Address) -> Option<&str> {
+    cfg.leaders
+        .iter()
+        .find(|l| crate::commands::parse_address(&l.address).is_ok_and(|a| a == user))
+        .map(|l| l.address.as_str())
+}
+
+/// Outcome labels are compared case- and whitespace-insensitively so that
+/// categorical markets ("Trump", "Harris", "Other", ...) match across the Data
+/// API's trade, activity and closed-position payloads, not just "Yes"/"No".
+fn outcome_key(outcome: &str) -> String {
+    outcome.trim().to_lowercase()
+}
+
+fn outcomes_match(a: &str, b: &str) -> bool {
+    outcome_key(a) == outcome_key(b)
+}
+
 fn normalize_market_slug(slug: &str) -> String {
     let Some((prefix, suffix)) = slug.rsplit_once('-') else {
         return slug.to_string();
@@ -2701,6 +5996,37 @@ fn calculate_settlement_pnl_from_invested(
     invested_usd * (realized_pnl_usd / total_bought_usd)
 }
 
+/// Sets the PnL of a movement being settled from `whole_pnl`, what the full
+/// copy made. After partial closes only the still-open share counts, added
+/// to the PnL those closes already realized.
+fn apply_remainder_pnl(movement: &mut MovementRecord, whole_pnl: Decimal) {
+    match movement.remaining_quantity {
+        Some(remaining) if movement.quantity > Decimal::ZERO => {
+            movement.pnl += whole_pnl * remaining / movement.quantity;
+            movement.remaining_quantity = Some(Decimal::ZERO);
+        }
+        _ => movement.pnl = whole_pnl,
+    }
+}
+
+/// A closed position bought at least this share of the movement's open
+/// quantity closes all of it, so rounding doesn't leave dust open.
+const FULL_CLOSE_RATIO: Decimal = Decimal::from_parts(95, 0, 0, false, 2);
+
+/// How much of a movement a closed position of `total_bought` closes, as
+/// `(closing, remaining)` quantities. `None` when the movement predates
+/// partial-close tracking (no quantity) and is settled whole with the ROI.
+fn partial_close(movement: &MovementRecord, total_bought: Decimal) -> Option<(Decimal, Decimal)> {
+    if movement.quantity <= Decimal::ZERO {
+        return None;
+    }
+    let open = movement.remaining_quantity.unwrap_or(movement.quantity);
+    if total_bought >= open * FULL_CLOSE_RATIO {
+        return Some((open, Decimal::ZERO));
+    }
+    Some((total_bought, open - total_bought))
+}
+
 fn oldest_unsettled_db_row(rows: &[DbRow]) -> Option<&DbRow> {
     rows.iter().filter(|r| !r.settled).min_by_key(|r| r.id)
 }
@@ -2724,9 +6050,16 @@ fn movement_timestamp_epoch_seconds(ts: &str) -> Option<i64> {
 fn settle_open_movements_from_closed_positions(
     state: &mut CopyState,
     closed_positions: &[polymarket_client_sdk::data::types::response::ClosedPosition],
+    owns: impl Fn(&MovementRecord) -> bool,
 ) -> Vec<MovementRecord> {
     type ClosedEntry = (i64, Decimal, Decimal, String);
     let mut by_market_outcome: HashMap<(String, String), VecDeque<ClosedEntry>> = HashMap::new();
+    // Movements recorded without an outcome label (e.g. `copy record
+    // --token-id`) still carry the outcome token id, which names the outcome.
+    let outcome_by_asset: HashMap<String, String> = closed_positions
+        .iter()
+        .map(|c| (c.asset.to_string(), outcome_key(&c.outcome)))
+        .collect();
     let mut closed_sorted = closed_positions.to_vec();
     closed_sorted.sort_by_key(|c| c.timestamp);
 
@@ -2737,7 +6070,7 @@ fn settle_open_movements_from_closed_positions(
         let realized_pnl = closed.realized_pnl;
         let total_bought = closed.total_bought;
         let normalized = normalize_market_slug(&closed.slug);
-        let key_exact = (closed.slug.clone(), closed.outcome.clone());
+        let key_exact = (closed.slug.clone(), outcome_key(&closed.outcome));
         by_market_outcome.entry(key_exact).or_default().push_back((
             closed.timestamp,
             total_bought,
@@ -2745,7 +6078,7 @@ fn settle_open_movements_from_closed_positions(
             closed.outcome.clone(),
         ));
         if normalized != closed.slug {
-            let key_normalized = (normalized, closed.outcome.clone());
+            let key_normalized = (normalized, outcome_key(&closed.outcome));
             by_market_outcome
                 .entry(key_normalized)
                 .or_default()
@@ -2759,40 +6092,49 @@ fn settle_open_movements_from_closed_positions(
     }
 
     let mut settled = Vec::new();
-    for movement in state.movements.iter_mut().filter(|m| !m.settled) {
+    for movement in state.movements.iter_mut().filter(|m| !m.settled && owns(m)) {
         let normalized_market = normalize_market_slug(&movement.market);
 
         let Some(movement_ts) = movement_timestamp_epoch_seconds(&movement.timestamp) else {
             continue;
         };
 
+        let last_partial = movement.last_partial_close_ts;
         let mut pop_eligible_roi = |q: &mut VecDeque<ClosedEntry>| {
             if q.is_empty() {
                 return None;
             }
 
+            // After a partial close only later closures count; the ones already
+            // applied come back on every sync.
+            if let Some(last) = last_partial {
+                q.retain(|(ts, _, _, _)| *ts == 0 || *ts > last);
+                let idx = q.iter().position(|(ts, _, _, _)| *ts > last)?;
+                return q.remove(idx);
+            }
+
             // Prefer closures with usable timestamps that are >= movement timestamp,
             // or closures with unknown timestamp (0) which we consider usable.
             if let Some(idx) = q
                 .iter()
                 .position(|(ts, _, _, _)| *ts == 0 || *ts >= movement_ts)
             {
-                return q
-                    .remove(idx)
-                    .map(|(_, total_bought, realized_pnl, outcome)| {
-                        (total_bought, realized_pnl, outcome)
-                    });
+                return q.remove(idx);
             }
 
             // Fallback: some Data API responses can carry stale/legacy timestamps.
             // In that case, consume oldest closure to avoid movements stuck forever.
             q.pop_front()
-                .map(|(_, total_bought, realized_pnl, outcome)| {
-                    (total_bought, realized_pnl, outcome)
-                })
         };
 
-        let outcome = movement.outcome.clone();
+        let outcome = if movement.outcome.trim().is_empty() {
+            outcome_by_asset
+                .get(&movement.asset)
+                .cloned()
+                .unwrap_or_default()
+        } else {
+            outcome_key(&movement.outcome)
+        };
         let key_exact = (movement.market.clone(), outcome.clone());
         let key_normalized = (normalized_market, outcome);
 
@@ -2805,15 +6147,31 @@ fn settle_open_movements_from_closed_positions(
                     .and_then(&mut pop_eligible_roi)
             });
 
-        let Some((total_bought, realized_pnl, resolved_outcome)) = roi_and_outcome else {
+        let Some((closed_ts, total_bought, realized_pnl, resolved_outcome)) = roi_and_outcome
+        else {
             continue;
         };
 
-        movement.pnl = calculate_settlement_pnl_from_invested(
-            movement.copied_value,
-            total_bought,
-            realized_pnl,
-        );
+        match partial_close(movement, total_bought) {
+            Some((closing, remaining)) if !remaining.is_zero() => {
+                movement.pnl += calculate_settlement_pnl_from_invested(
+                    movement.copied_value * closing / movement.quantity,
+                    total_bought,
+                    realized_pnl,
+                );
+                movement.remaining_quantity = Some(remaining);
+                movement.last_partial_close_ts = Some(closed_ts);
+                continue;
+            }
+            _ => apply_remainder_pnl(
+                movement,
+                calculate_settlement_pnl_from_invested(
+                    movement.copied_value,
+                    total_bought,
+                    realized_pnl,
+                ),
+            ),
+        }
         if movement.copy_side.eq_ignore_ascii_case("buy") {
             movement.copy_side = "sell".to_string();
         }
@@ -2826,8 +6184,7 @@ fn settle_open_movements_from_closed_positions(
 }
 
 fn base_dir() -> Result<PathBuf> {
-    let home = dirs::home_dir().context("Could not determine home directory")?;
-    Ok(home.join(".config").join("polymarket"))
+    crate::config::config_dir()
 }
 
 fn config_path() -> Result<PathBuf> {
@@ -2838,15 +6195,30 @@ fn state_path() -> Result<PathBuf> {
     Ok(base_dir()?.join("copy_trader_state.json"))
 }
 
+fn monitor_state_path() -> Result<PathBuf> {
+    Ok(base_dir()?.join("copy_trader_monitor.json"))
+}
+
+const DEFAULT_SETTLEMENT_LOG_MAX_BYTES: u64 = 5 * 1024 * 1024;
+const SETTLEMENT_LOG_KEEP: usize = 5;
+
 fn settlement_log_path() -> Result<PathBuf> {
     Ok(base_dir()?.join("copy_trader_settlements.log"))
 }
 
-fn append_settlement_log(mode: StorageMode, movement: &MovementRecord) -> Result<()> {
-    let path = settlement_log_path()?;
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent)?;
+/// `cfg` is the caller's config; without one the log is written with the
+/// default rotation size.
+fn append_settlement_log(
+    cfg: Option<&CopyConfig>,
+    mode: StorageMode,
+    movement: &MovementRecord,
+) -> Result<()> {
+    if cfg.is_some_and(|c| c.no_settlement_log) {
+        return Ok(());
     }
+    let max_bytes = cfg
+        .and_then(|c| c.settlement_log_max_bytes)
+        .unwrap_or(DEFAULT_SETTLEMENT_LOG_MAX_BYTES);
     let line = format!(
         "{}\tmode={}\tmovement_id={}\tmarket={}\tside={}\toutcome={}\tresolved_outcome={}\tleader_price={}\tsimulated_copy_price={}\tquantity={}\tcopied_value={}\testimated_total_fee_usd={}\tpnl={}\n",
         Utc::now().to_rfc3339(),
@@ -2866,11 +6238,265 @@ fn append_settlement_log(mode: StorageMode, movement: &MovementRecord) -> Result
         movement.estimated_total_fee_usd,
         movement.pnl,
     );
-    let mut f = fs::OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(path)?;
-    f.write_all(line.as_bytes())?;
+    write_rotating_log(&settlement_log_path()?, &line, max_bytes)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct SettlementEntry {
+    #[schemars(with = "String")]
+    pub timestamp: chrono::DateTime<Utc>,
+    pub mode: String,
+    pub movement_id: String,
+    pub market: String,
+    pub side: String,
+    pub outcome: String,
+    pub resolved_outcome: String,
+    pub leader_price: Decimal,
+    pub simulated_copy_price: Decimal,
+    pub quantity: Decimal,
+    pub copied_value: Decimal,
+    pub estimated_total_fee_usd: Decimal,
+    pub pnl: Decimal,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct SettlementGroup {
+    pub market: String,
+    pub count: usize,
+    pub copied_value: Decimal,
+    pub estimated_total_fee_usd: Decimal,
+    pub pnl: Decimal,
+    pub entries: Vec<SettlementEntry>,
+}
+
+/// Parses one line written by `append_settlement_log`; `None` for anything
+/// malformed or missing a field.
+fn parse_settlement_line(line: &str) -> Option<SettlementEntry> {
+    let mut parts = line.split('\t');
+    let timestamp = chrono::DateTime::parse_from_rfc3339(parts.next()?)
+        .ok()?
+        .with_timezone(&Utc);
+    let fields: HashMap<&str, &str> = parts.filter_map(|p| p.split_once('=')).collect();
+    let text = |key: &str| fields.get(key).map(|v| (*v).to_string());
+    let num = |key: &str| fields.get(key).and_then(|v| v.parse::<Decimal>().ok());
+    Some(SettlementEntry {
+        timestamp,
+        mode: text("mode")?,
+        movement_id: text("movement_id")?,
+        market: text("market")?,
+        side: text("side")?,
+        outcome: text("outcome")?,
+        resolved_outcome: text("resolved_outcome")?,
+        leader_price: num("leader_price")?,
+        simulated_copy_price: num("simulated_copy_price")?,
+        quantity: num("quantity")?,
+        copied_value: num("copied_value")?,
+        estimated_total_fee_usd: num("estimated_total_fee_usd")?,
+        pnl: num("pnl")?,
+    })
+}
+
+/// Parses a settlements log body. A trailing line without its newline is a
+/// write cut short by a crash and is ignored, as are malformed lines.
+fn parse_settlement_log(content: &str) -> Vec<SettlementEntry> {
+    let complete = match content.rfind('\n') {
+        Some(end) => &content[..end],
+        None => "",
+    };
+    complete.lines().filter_map(parse_settlement_line).collect()
+}
+
+/// Reads the live log plus its rotations, oldest rotation first.
+fn read_settlement_log(path: &Path) -> Result<Vec<SettlementEntry>> {
+    let mut files: Vec<PathBuf> = (1..=SETTLEMENT_LOG_KEEP)
+        .rev()
+        .map(|n| rotated_log_path(path, n))
+        .collect();
+    files.push(path.to_path_buf());
+    let mut entries = Vec::new();
+    for file in files.iter().filter(|f| f.exists()) {
+        let content = fs::read_to_string(file)
+            .with_context(|| format!("failed to read {}", file.display()))?;
+        entries.extend(parse_settlement_log(&content));
+    }
+    Ok(entries)
+}
+
+/// One evaluated leader trade in `copy_trader_decisions.jsonl`, whether or
+/// not it was copied, so skipped trades can be explained after the fact.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct DecisionRecord {
+    pub timestamp: String,
+    pub mode: String,
+    #[serde(default)]
+    pub leader: String,
+    pub market: String,
+    pub side: String,
+    pub outcome: String,
+    pub leader_usd: Decimal,
+    pub reason: String,
+    pub proportional_size: Decimal,
+    pub capped_size: Decimal,
+    #[serde(default)]
+    pub round_trip_fee_usd: Option<Decimal>,
+    #[serde(default)]
+    pub max_net_profit_usd: Option<Decimal>,
+    pub acted: bool,
+}
+
+impl DecisionRecord {
+    fn new(
+        scope: &str,
+        candidate: &TradeCandidate,
+        plan: &PlanResult,
+        fee_impact: Option<TradingFeeImpact>,
+        reason: impl Into<String>,
+        acted: bool,
+    ) -> Self {
+        Self {
+            timestamp: Utc::now().to_rfc3339(),
+            mode: scope.to_string(),
+            leader: candidate.leader.to_string(),
+            market: candidate.market.to_string(),
+            side: if candidate.is_sell { "SELL" } else { "BUY" }.to_string(),
+            outcome: candidate.outcome.to_string(),
+            leader_usd: candidate.size * candidate.price,
+            reason: reason.into(),
+            proportional_size: plan.proportional_size,
+            capped_size: plan.capped_size,
+            round_trip_fee_usd: fee_impact.map(|i| i.round_trip_fee_usd),
+            max_net_profit_usd: fee_impact.map(|i| i.max_net_profit_usd),
+            acted,
+        }
+    }
+}
+
+fn decisions_path() -> Result<PathBuf> {
+    Ok(base_dir()?.join("copy_trader_decisions.jsonl"))
+}
+
+/// Appends to the decisions log (rotated like the settlements log). A write
+/// failure is logged and otherwise ignored so it never stops the monitor.
+fn record_decision(decision: &DecisionRecord) {
+    let result = serde_json::to_string(decision)
+        .map_err(anyhow::Error::from)
+        .and_then(|line| {
+            write_rotating_log(
+                &decisions_path()?,
+                &format!("{line}\n"),
+                DEFAULT_SETTLEMENT_LOG_MAX_BYTES,
+            )
+        });
+    if let Err(e) = result {
+        log_copy_event(
+            &decision.mode,
+            format!("error escribiendo log de decisiones: {e}"),
+        );
+    }
+}
+
+fn read_decisions(path: &Path) -> Result<Vec<DecisionRecord>> {
+    let mut files: Vec<PathBuf> = (1..=SETTLEMENT_LOG_KEEP)
+        .rev()
+        .map(|n| rotated_log_path(path, n))
+        .collect();
+    files.push(path.to_path_buf());
+    let mut decisions = Vec::new();
+    for file in files.iter().filter(|f| f.exists()) {
+        let content = fs::read_to_string(file)
+            .with_context(|| format!("failed to read {}", file.display()))?;
+        decisions.extend(
+            content
+                .lines()
+                .filter(|l| !l.trim().is_empty())
+                .filter_map(|l| serde_json::from_str::<DecisionRecord>(l).ok()),
+        );
+    }
+    Ok(decisions)
+}
+
+/// The newest `limit` decisions (optionally only acted-on ones), oldest first.
+fn latest_decisions(
+    mut decisions: Vec<DecisionRecord>,
+    limit: usize,
+    acted_only: bool,
+) -> Vec<DecisionRecord> {
+    if acted_only {
+        decisions.retain(|d| d.acted);
+    }
+    let skip = decisions.len().saturating_sub(limit);
+    decisions.split_off(skip)
+}
+
+fn sort_settlements(entries: &mut [SettlementEntry], sort: SettlementSort) {
+    match sort {
+        SettlementSort::Time => entries.sort_by_key(|e| e.timestamp),
+        SettlementSort::Pnl => entries.sort_by_key(|e| std::cmp::Reverse(e.pnl)),
+    }
+}
+
+/// Groups keep the order in which each market first appears in `entries`.
+fn group_settlements_by_market(entries: Vec<SettlementEntry>) -> Vec<SettlementGroup> {
+    let mut groups: Vec<SettlementGroup> = Vec::new();
+    for entry in entries {
+        let idx = match groups.iter().position(|g| g.market == entry.market) {
+            Some(idx) => idx,
+            None => {
+                groups.push(SettlementGroup {
+                    market: entry.market.clone(),
+                    count: 0,
+                    copied_value: Decimal::ZERO,
+                    estimated_total_fee_usd: Decimal::ZERO,
+                    pnl: Decimal::ZERO,
+                    entries: Vec::new(),
+                });
+                groups.len() - 1
+            }
+        };
+        let group = &mut groups[idx];
+        group.count += 1;
+        group.copied_value += entry.copied_value;
+        group.estimated_total_fee_usd += entry.estimated_total_fee_usd;
+        group.pnl += entry.pnl;
+        group.entries.push(entry);
+    }
+    groups
+}
+
+/// Appends `line` and, once the file reaches `max_bytes`, shifts it to `.1`
+/// (older rotations move up to `.N`, the oldest is dropped). Only renames are
+/// used, so a crash mid-rotation never loses lines already written.
+fn write_rotating_log(path: &Path, line: &str, max_bytes: u64) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut f = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    f.write_all(line.as_bytes())?;
+    let len = f.metadata()?.len();
+    drop(f);
+    if len >= max_bytes {
+        rotate_log(path)?;
+    }
+    Ok(())
+}
+
+fn rotated_log_path(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{n}"));
+    PathBuf::from(name)
+}
+
+fn rotate_log(path: &Path) -> Result<()> {
+    for n in (1..SETTLEMENT_LOG_KEEP).rev() {
+        let from = rotated_log_path(path, n);
+        if from.exists() {
+            fs::rename(&from, rotated_log_path(path, n + 1))?;
+        }
+    }
+    fs::rename(path, rotated_log_path(path, 1))?;
     Ok(())
 }
 
@@ -2882,6 +6508,37 @@ fn db_path(mode: StorageMode) -> Result<PathBuf> {
     Ok(base_dir()?.join(filename))
 }
 
+fn db_archive_path(mode: StorageMode) -> Result<PathBuf> {
+    let filename = match mode {
+        StorageMode::Real => "copy_trader_real_db.archive.jsonl",
+        StorageMode::Simulation => "copy_trader_sim_db.archive.jsonl",
+    };
+    Ok(base_dir()?.join(filename))
+}
+
+fn db_seq_path(mode: StorageMode) -> Result<PathBuf> {
+    let filename = match mode {
+        StorageMode::Real => "copy_trader_real_db.seq",
+        StorageMode::Simulation => "copy_trader_sim_db.seq",
+    };
+    Ok(base_dir()?.join(filename))
+}
+
+/// Highest row id ever issued for this db. Kept outside the db file so that
+/// compaction can drop the newest rows without the ids being handed out again.
+fn read_db_seq(mode: StorageMode) -> i64 {
+    db_seq_path(mode)
+        .ok()
+        .and_then(|p| fs::read_to_string(p).ok())
+        .and_then(|raw| raw.trim().parse().ok())
+        .unwrap_or(0)
+}
+
+fn write_db_seq(mode: StorageMode, id: i64) -> Result<()> {
+    fs::write(db_seq_path(mode)?, id.to_string())?;
+    Ok(())
+}
+
 fn init_db(mode: StorageMode) -> Result<()> {
     let path = db_path(mode)?;
     if let Some(parent) = path.parent() {
@@ -2893,7 +6550,7 @@ fn init_db(mode: StorageMode) -> Result<()> {
     Ok(())
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 enum StorageMode {
     Real,
     Simulation,
@@ -2929,7 +6586,13 @@ fn current_mode_from_disk() -> StorageMode {
 struct DbRow {
     id: i64,
     movement_id: String,
+    #[serde(default)]
+    leader: String,
+    #[serde(default)]
+    paper: bool,
     market: String,
+    #[serde(default)]
+    asset: String,
     timestamp: String,
     leader_value: String,
     #[serde(default)]
@@ -2952,8 +6615,8 @@ struct DbRow {
     pnl: String,
 }
 
-fn next_db_id(rows: &[DbRow]) -> i64 {
-    rows.last().map_or(1, |r| r.id + 1)
+fn next_db_id(rows: &[DbRow], last_issued: i64) -> i64 {
+    rows.last().map_or(0, |r| r.id).max(last_issued) + 1
 }
 
 fn read_db_rows(mode: StorageMode) -> Result<Vec<DbRow>> {
@@ -2969,6 +6632,65 @@ fn read_db_rows(mode: StorageMode) -> Result<Vec<DbRow>> {
     Ok(out)
 }
 
+const DB_CSV_HEADER: [&str; 20] = [
+    "id",
+    "movement_id",
+    "leader",
+    "paper",
+    "market",
+    "asset",
+    "timestamp",
+    "leader_value",
+    "leader_price",
+    "copied_value",
+    "simulated_copy_price",
+    "quantity",
+    "copy_side",
+    "outcome",
+    "resolved_outcome",
+    "diff_pct",
+    "estimated_total_fee_usd",
+    "settled",
+    "pnl",
+    "pnl_after_fees",
+];
+
+/// Renders `rows` as CSV with a header. `pnl_after_fees` is `pnl` minus the
+/// estimated fees, the same net figure the UI's equity uses.
+fn db_rows_csv(rows: &[DbRow]) -> String {
+    let mut out = crate::output::csv_line(&DB_CSV_HEADER);
+    out.push('\n');
+    for r in rows {
+        let pnl = Decimal::from_str_exact(&r.pnl).unwrap_or(Decimal::ZERO);
+        let fee = Decimal::from_str_exact(&r.estimated_total_fee_usd).unwrap_or(Decimal::ZERO);
+        let line = crate::output::csv_line(&[
+            r.id.to_string(),
+            r.movement_id.clone(),
+            r.leader.clone(),
+            r.paper.to_string(),
+            r.market.clone(),
+            r.asset.clone(),
+            r.timestamp.clone(),
+            r.leader_value.clone(),
+            r.leader_price.clone(),
+            r.copied_value.clone(),
+            r.simulated_copy_price.clone(),
+            r.quantity.clone(),
+            r.copy_side.clone(),
+            r.outcome.clone(),
+            r.resolved_outcome.clone(),
+            r.diff_pct.clone(),
+            r.estimated_total_fee_usd.clone(),
+            r.settled.to_string(),
+            r.pnl.clone(),
+            (pnl - fee).to_string(),
+        ]);
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
+
 fn write_db_rows(mode: StorageMode, rows: &[DbRow]) -> Result<()> {
     let mut body = String::new();
     for r in rows {
@@ -2979,15 +6701,22 @@ fn write_db_rows(mode: StorageMode, rows: &[DbRow]) -> Result<()> {
     Ok(())
 }
 
-fn append_db_movement(mode: StorageMode, m: &MovementRecord) -> Result<()> {
+/// Returns the stored movement, or `None` if it was already recorded; the
+/// monitor notifies with it, so the write path never reloads the config.
+fn append_db_movement(mode: StorageMode, m: &MovementRecord) -> Result<Option<DbMovement>> {
     let mut rows = read_db_rows(mode)?;
-    if rows.iter().any(|r| r.movement_id == m.movement_id) {
-        return Ok(());
+    if db_has_movement(&rows, &m.movement_id) {
+        return Ok(None);
     }
+    let id = next_db_id(&rows, read_db_seq(mode));
+    write_db_seq(mode, id)?;
     rows.push(DbRow {
-        id: next_db_id(&rows),
+        id,
         movement_id: m.movement_id.clone(),
+        leader: m.leader.clone(),
+        paper: m.paper,
         market: m.market.clone(),
+        asset: m.asset.clone(),
         timestamp: m.timestamp.clone(),
         leader_value: m.leader_value.to_string(),
         leader_price: m.leader_price.to_string(),
@@ -3002,7 +6731,216 @@ fn append_db_movement(mode: StorageMode, m: &MovementRecord) -> Result<()> {
         settled: m.settled,
         pnl: m.pnl.to_string(),
     });
-    write_db_rows(mode, &rows)
+    write_db_rows(mode, &rows)?;
+    let Some(row) = rows.pop() else {
+        return Ok(None);
+    };
+    let movement = db_movement_from_row(row);
+    // No subscribers is the normal case outside `copy ui`; nothing to do.
+    let _ = movement_events().send(MovementEvent {
+        mode,
+        movement: movement.clone(),
+    });
+    Ok(Some(movement))
+}
+
+fn db_has_movement(rows: &[DbRow], movement_id: &str) -> bool {
+    rows.iter().any(|r| r.movement_id == movement_id)
+}
+
+/// Parses an import file: a db JSONL, or a CSV from `copy export` (detected
+/// by its header). Returns the valid rows and how many lines were rejected.
+fn parse_import_rows(raw: &str) -> (Vec<DbRow>, usize) {
+    let mut lines = raw.lines().filter(|l| !l.trim().is_empty()).peekable();
+    let header = crate::output::csv_line(&DB_CSV_HEADER);
+    let is_csv = lines.peek().is_some_and(|l| l.trim() == header);
+    let mut rows = Vec::new();
+    let mut malformed = 0;
+    if is_csv {
+        let records = crate::output::csv_records(raw)
+            .into_iter()
+            .filter(|r| r.iter().any(|f| !f.trim().is_empty()));
+        for record in records.skip(1) {
+            match db_row_from_csv(&record).filter(valid_import_row) {
+                Some(row) => rows.push(row),
+                None => malformed += 1,
+            }
+        }
+    } else {
+        for line in lines {
+            match serde_json::from_str::<DbRow>(line)
+                .ok()
+                .filter(valid_import_row)
+            {
+                Some(row) => rows.push(row),
+                None => malformed += 1,
+            }
+        }
+    }
+    (rows, malformed)
+}
+
+fn db_row_from_csv(record: &[String]) -> Option<DbRow> {
+    if record.len() != DB_CSV_HEADER.len() {
+        return None;
+    }
+    let mut obj = serde_json::Map::new();
+    // `pnl_after_fees` is derived on export, so it isn't read back.
+    for (name, value) in DB_CSV_HEADER
+        .iter()
+        .zip(record)
+        .take(DB_CSV_HEADER.len() - 1)
+    {
+        let value = match *name {
+            "id" => serde_json::Value::from(value.parse::<i64>().ok()?),
+            "paper" | "settled" => serde_json::Value::from(value.parse::<bool>().ok()?),
+            _ => serde_json::Value::from(value.as_str()),
+        };
+        obj.insert((*name).to_string(), value);
+    }
+    serde_json::from_value(serde_json::Value::Object(obj)).ok()
+}
+
+fn valid_import_row(row: &DbRow) -> bool {
+    let decimal = |v: &str| Decimal::from_str_exact(v).is_ok();
+    let optional_decimal = |v: &str| v.is_empty() || decimal(v);
+    !row.movement_id.trim().is_empty()
+        && chrono::DateTime::parse_from_rfc3339(&row.timestamp).is_ok()
+        && decimal(&row.leader_value)
+        && decimal(&row.copied_value)
+        && decimal(&row.diff_pct)
+        && decimal(&row.pnl)
+        && optional_decimal(&row.leader_price)
+        && optional_decimal(&row.simulated_copy_price)
+        && optional_decimal(&row.quantity)
+        && optional_decimal(&row.estimated_total_fee_usd)
+}
+
+/// Appends the settled `incoming` rows whose movement isn't in `rows` or
+/// `archived` yet, in their original order, giving them fresh ids after both
+/// the db and `last_issued`. Open rows are left out: this machine's monitor
+/// never tracks them, so they would stay open forever and block the settled
+/// row a later import brings. Returns how many were added, already present
+/// and skipped as open.
+fn merge_db_rows(
+    rows: &mut Vec<DbRow>,
+    mut incoming: Vec<DbRow>,
+    archived: &HashSet<String>,
+    last_issued: i64,
+) -> (usize, usize, usize) {
+    incoming.sort_by_key(|r| r.id);
+    let (mut added, mut duplicates, mut open) = (0, 0, 0);
+    for mut row in incoming {
+        if archived.contains(&row.movement_id) || db_has_movement(rows, &row.movement_id) {
+            duplicates += 1;
+            continue;
+        }
+        if !row.settled {
+            open += 1;
+            continue;
+        }
+        row.id = next_db_id(rows, last_issued);
+        rows.push(row);
+        added += 1;
+    }
+    (added, duplicates, open)
+}
+
+/// Movement ids that compaction moved into the archive file.
+fn read_archived_movement_ids(mode: StorageMode) -> Result<HashSet<String>> {
+    let path = db_archive_path(mode)?;
+    if !path.exists() {
+        return Ok(HashSet::new());
+    }
+    Ok(fs::read_to_string(path)?
+        .lines()
+        .filter_map(|l| serde_json::from_str::<DbRow>(l).ok())
+        .map(|r| r.movement_id)
+        .collect())
+}
+
+/// Hands the movement's JSON (same shape as `/api/updates` rows) to the
+/// configured webhook and/or command on a background thread. Returns whether
+/// anything was dispatched; failures are only logged and never reach the monitor.
+fn notify_movement(
+    cfg: &CopyConfig,
+    mode: StorageMode,
+    event: NotifyEvent,
+    movement: &DbMovement,
+) -> bool {
+    if cfg.notify_webhook_url.is_none() && cfg.notify_command.is_none() {
+        return false;
+    }
+    if !should_notify(cfg, event) {
+        return false;
+    }
+    let scope = match mode {
+        StorageMode::Real => "real",
+        StorageMode::Simulation => "sim",
+    };
+    let payload = match serde_json::to_string(movement) {
+        Ok(p) => p,
+        Err(e) => {
+            log_copy_event(scope, format!("error serializando notificación: {e}"));
+            return false;
+        }
+    };
+    let webhook = cfg.notify_webhook_url.clone();
+    let command = cfg.notify_command.clone();
+    std::thread::spawn(move || {
+        if let Some(url) = webhook {
+            let mut curl = std::process::Command::new("curl");
+            curl.args(["-sSf", "--max-time"])
+                .arg(crate::net::request_timeout().as_secs().to_string())
+                .args(["-X", "POST", "-H", "Content-Type: application/json"])
+                .args(["--data-binary", "@-", &url]);
+            if let Err(e) = pipe_payload(curl, &payload) {
+                log_copy_event(scope, format!("error notificando webhook {url}: {e}"));
+            }
+        }
+        if let Some(command) = command {
+            let mut sh = std::process::Command::new("sh");
+            sh.args(["-c", &command]);
+            if let Err(e) = pipe_payload(sh, &payload) {
+                log_copy_event(scope, format!("error ejecutando notify-command: {e}"));
+            }
+        }
+    });
+    true
+}
+
+fn pipe_payload(mut cmd: std::process::Command, payload: &str) -> Result<()> {
+    use std::process::Stdio;
+    let mut child = cmd
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(payload.as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        bail!(
+            "{} {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+/// A row just appended to a movement db, fanned out to `/api/stream` clients.
+#[derive(Clone)]
+struct MovementEvent {
+    mode: StorageMode,
+    movement: DbMovement,
+}
+
+fn movement_events() -> &'static tokio::sync::broadcast::Sender<MovementEvent> {
+    static EVENTS: std::sync::OnceLock<tokio::sync::broadcast::Sender<MovementEvent>> =
+        std::sync::OnceLock::new();
+    EVENTS.get_or_init(|| tokio::sync::broadcast::channel(256).0)
 }
 
 fn apply_settlement_to_db_rows(
@@ -3026,13 +6964,97 @@ fn apply_settlement_to_db_rows(
     }
 }
 
+fn should_prune_row(row: &DbRow, cutoff: chrono::DateTime<Utc>) -> bool {
+    row.settled
+        && chrono::DateTime::parse_from_rfc3339(&row.timestamp)
+            .is_ok_and(|ts| ts.with_timezone(&Utc) < cutoff)
+}
+
+/// Moves settled rows older than `retention_days` into the archive file and
+/// rewrites the live db without them. Row ids are preserved and never reused,
+/// so `/api/updates?since=` watermarks stay valid. Returns the number of rows
+/// pruned.
+fn compact_db(mode: StorageMode, retention_days: u64) -> Result<usize> {
+    let days = i64::try_from(retention_days.min(100_000)).unwrap_or(100_000);
+    let cutoff = Utc::now() - chrono::Duration::days(days);
+    let last_issued = read_db_seq(mode);
+    let (pruned, kept, seq) = split_for_compaction(read_db_rows(mode)?, cutoff, last_issued);
+    if pruned.is_empty() {
+        return Ok(0);
+    }
+
+    let mut archive = String::new();
+    for r in &pruned {
+        archive.push_str(&serde_json::to_string(r)?);
+        archive.push('\n');
+    }
+    let mut f = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(db_archive_path(mode)?)?;
+    f.write_all(archive.as_bytes())?;
+
+    if seq > last_issued {
+        write_db_seq(mode, seq)?;
+    }
+    write_db_rows(mode, &kept)?;
+    Ok(pruned.len())
+}
+
+/// Splits rows into `(pruned, kept)` for compaction and returns the id
+/// sequence to persist, which never falls below an id already handed out.
+fn split_for_compaction(
+    rows: Vec<DbRow>,
+    cutoff: chrono::DateTime<Utc>,
+    last_issued: i64,
+) -> (Vec<DbRow>, Vec<DbRow>, i64) {
+    let highest = rows.iter().map(|r| r.id).max().unwrap_or(0);
+    let (pruned, kept) = rows.into_iter().partition(|r| should_prune_row(r, cutoff));
+    (pruned, kept, highest.max(last_issued))
+}
+
+/// Truncates the db of each mode, drops its compaction archive and removes
+/// the shared state file; `copy_trader.json` (the config) is left alone.
+/// Returns how many movements were cleared.
+fn reset_history(modes: &[StorageMode], rotate_logs: bool) -> Result<usize> {
+    let mut cleared = 0;
+    for &mode in modes {
+        cleared += read_db_rows(mode)?.len();
+        write_db_rows(mode, &[])?;
+        let archive = db_archive_path(mode)?;
+        if archive.exists() {
+            fs::remove_file(archive)?;
+        }
+    }
+    let state = state_path()?;
+    if state.exists() {
+        fs::remove_file(state)?;
+    }
+    if rotate_logs {
+        for log in [
+            base_dir()?.join("copy_trader.log"),
+            settlement_log_path()?,
+            decisions_path()?,
+        ] {
+            if log.exists() {
+                rotate_log(&log)?;
+            }
+        }
+    }
+    Ok(cleared)
+}
+
 fn settle_db_movement(mode: StorageMode, movement_id: &str, pnl: Decimal) -> Result<()> {
     let mut rows = read_db_rows(mode)?;
     apply_settlement_to_db_rows(&mut rows, movement_id, pnl, None, None);
     write_db_rows(mode, &rows)
 }
 
-fn settle_db_movement_from_record(mode: StorageMode, movement: &MovementRecord) -> Result<()> {
+/// Returns the settled row, or `None` if the movement is not in the db.
+fn settle_db_movement_from_record(
+    mode: StorageMode,
+    movement: &MovementRecord,
+) -> Result<Option<DbMovement>> {
     let mut rows = read_db_rows(mode)?;
     apply_settlement_to_db_rows(
         &mut rows,
@@ -3041,7 +7063,32 @@ fn settle_db_movement_from_record(mode: StorageMode, movement: &MovementRecord)
         Some(&movement.copy_side),
         Some(&movement.resolved_outcome),
     );
-    write_db_rows(mode, &rows)
+    write_db_rows(mode, &rows)?;
+    Ok(rows
+        .into_iter()
+        .find(|r| r.movement_id == movement.movement_id)
+        .map(db_movement_from_row))
+}
+
+/// Settles `movement` in the db and the settlement log, then sends the
+/// `Settled` notification. A failed log write is only logged.
+fn record_settlement(
+    cfg: &CopyConfig,
+    mode: StorageMode,
+    log_scope: &str,
+    movement: &MovementRecord,
+) -> Result<()> {
+    let settled = settle_db_movement_from_record(mode, movement)?;
+    if let Err(e) = append_settlement_log(Some(cfg), mode, movement) {
+        log_copy_event(
+            log_scope,
+            format!("error escribiendo log de settlement: {e}"),
+        );
+    }
+    if let Some(settled) = settled {
+        notify_movement(cfg, mode, NotifyEvent::Settled, &settled);
+    }
+    Ok(())
 }
 
 fn load_state_from_db(mode: StorageMode) -> Result<CopyState> {
@@ -3050,7 +7097,10 @@ fn load_state_from_db(mode: StorageMode) -> Result<CopyState> {
         .into_iter()
         .map(|r| MovementRecord {
             movement_id: r.movement_id,
+            leader: r.leader,
+            paper: r.paper,
             market: r.market,
+            asset: r.asset,
             timestamp: r.timestamp,
             leader_value: Decimal::from_str_exact(&r.leader_value).unwrap_or(Decimal::ZERO),
             leader_price: Decimal::from_str_exact(&r.leader_price).unwrap_or(Decimal::ZERO),
@@ -3066,6 +7116,8 @@ fn load_state_from_db(mode: StorageMode) -> Result<CopyState> {
                 .unwrap_or(Decimal::ZERO),
             settled: r.settled,
             pnl: Decimal::from_str_exact(&r.pnl).unwrap_or(Decimal::ZERO),
+            remaining_quantity: None,
+            last_partial_close_ts: None,
         })
         .collect();
     Ok(CopyState { movements })
@@ -3078,28 +7130,35 @@ fn db_updates_since(mode: StorageMode, since: i64) -> Result<(i64, Vec<DbMovemen
         .into_iter()
         .filter(|r| r.id > since)
         .take(200)
-        .map(|r| DbMovement {
-            id: r.id,
-            movement_id: r.movement_id,
-            market: r.market,
-            timestamp: r.timestamp,
-            leader_value: r.leader_value,
-            leader_price: r.leader_price,
-            copied_value: r.copied_value,
-            simulated_copy_price: r.simulated_copy_price,
-            quantity: r.quantity,
-            copy_side: r.copy_side,
-            outcome: r.outcome,
-            resolved_outcome: r.resolved_outcome,
-            diff_pct: r.diff_pct,
-            estimated_total_fee_usd: r.estimated_total_fee_usd,
-            settled: r.settled,
-            pnl: r.pnl,
-        })
+        .map(db_movement_from_row)
         .collect();
     Ok((latest_id, updates))
 }
 
+fn db_movement_from_row(r: DbRow) -> DbMovement {
+    DbMovement {
+        id: r.id,
+        movement_id: r.movement_id,
+        leader: r.leader,
+        paper: r.paper,
+        market: r.market,
+        asset: r.asset,
+        timestamp: r.timestamp,
+        leader_value: r.leader_value,
+        leader_price: r.leader_price,
+        copied_value: r.copied_value,
+        simulated_copy_price: r.simulated_copy_price,
+        quantity: r.quantity,
+        copy_side: r.copy_side,
+        outcome: r.outcome,
+        resolved_outcome: r.resolved_outcome,
+        diff_pct: r.diff_pct,
+        estimated_total_fee_usd: r.estimated_total_fee_usd,
+        settled: r.settled,
+        pnl: r.pnl,
+    }
+}
+
 fn save_config(cfg: &CopyConfig) -> Result<()> {
     let path = config_path()?;
     if let Some(parent) = path.parent() {
@@ -3112,7 +7171,35 @@ fn save_config(cfg: &CopyConfig) -> Result<()> {
 fn load_config() -> Result<CopyConfig> {
     let data = fs::read_to_string(config_path()?)
         .context("Copy-trader is not configured. Run `polymarket copy configure ...`")?;
-    serde_json::from_str(&data).context("Invalid copy-trader config")
+    let mut cfg: CopyConfig = serde_json::from_str(&data).context("Invalid copy-trader config")?;
+    for warning in sanitize_loaded_config(&mut cfg) {
+        tracing::warn!("{warning}");
+    }
+    Ok(cfg)
+}
+
+/// Applies the same mode and polling rules as `copy configure` to a config read
+/// from disk, so hand-edited or migrated files can't bypass them. Returns a
+/// description of each value that had to be changed.
+fn sanitize_loaded_config(cfg: &mut CopyConfig) -> Vec<String> {
+    let mut warnings = Vec::new();
+    if cfg.realtime_mode && cfg.simulation_mode {
+        cfg.realtime_mode = false;
+        warnings.push(
+            "realtime_mode and simulation_mode are mutually exclusive; disabling realtime_mode"
+                .to_string(),
+        );
+    }
+    let normalized =
+        normalize_poll_ms(cfg.poll_interval_ms, cfg.realtime_mode, cfg.simulation_mode);
+    if normalized != cfg.poll_interval_ms {
+        warnings.push(format!(
+            "poll_interval_ms {} is below the minimum for this mode; clamped to {normalized}",
+            cfg.poll_interval_ms
+        ));
+        cfg.poll_interval_ms = normalized;
+    }
+    warnings
 }
 
 fn save_state(state: &CopyState) -> Result<()> {
@@ -3133,25 +7220,92 @@ fn load_state() -> Result<CopyState> {
     serde_json::from_str(&data).context("Invalid copy-trader state")
 }
 
-pub fn daily_pnl_series(movements: &[MovementRecord]) -> Vec<(String, Decimal)> {
-    let mut by_day: BTreeMap<String, Decimal> = BTreeMap::new();
-    for m in movements.iter().filter(|m| m.settled) {
-        let day = m
-            .timestamp
-            .get(0..13)
-            .map(|v| format!("{}:00", v.replace('T', " ")))
-            .unwrap_or_else(|| "unknown".to_string());
-        let net_pnl = m.pnl - m.estimated_total_fee_usd;
-        by_day
-            .entry(day)
-            .and_modify(|x| *x += net_pnl)
-            .or_insert(net_pnl);
-    }
-    by_day.into_iter().collect()
+fn save_monitor_state(state: &MonitorState) -> Result<()> {
+    save_monitor_state_to(&monitor_state_path()?, state)
 }
 
-pub fn cumulative_pnl_series(movements: &[MovementRecord]) -> Vec<(String, Decimal)> {
-    let mut cumulative = Decimal::ZERO;
+fn save_monitor_state_to(path: &Path, state: &MonitorState) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(state)?)?;
+    Ok(())
+}
+
+fn load_monitor_state() -> Result<MonitorState> {
+    load_monitor_state_from(&monitor_state_path()?)
+}
+
+fn load_monitor_state_from(path: &Path) -> Result<MonitorState> {
+    if !path.exists() {
+        return Ok(MonitorState::default());
+    }
+    let data = fs::read_to_string(path)?;
+    serde_json::from_str(&data).context("Invalid copy-trader monitor state")
+}
+
+/// Writes the runtime's monitoring flag to disk; failures are logged rather
+/// than surfaced because the in-memory state is still authoritative.
+fn persist_monitor_state(runtime: &RuntimeState) {
+    let state = MonitorState::from_runtime(runtime);
+    if let Err(e) = save_monitor_state(&state) {
+        log_copy_event(
+            "core",
+            format!("no se pudo guardar el estado del monitor: {e}"),
+        );
+    }
+}
+
+/// Saves the disabled leaders while keeping the rest of the saved state, so
+/// the headless monitor does not overwrite the UI's monitoring flag.
+fn persist_leader_state(runtime: &RuntimeState) {
+    let saved = load_monitor_state().unwrap_or_default();
+    let state = MonitorState {
+        disabled_leaders: runtime.disabled_leaders.clone(),
+        leader_reenabled_at: runtime.leader_reenabled_at.clone(),
+        ..saved
+    };
+    if let Err(e) = save_monitor_state(&state) {
+        log_copy_event(
+            "core",
+            format!("no se pudo guardar el estado del monitor: {e}"),
+        );
+    }
+}
+
+pub fn daily_pnl_series(movements: &[MovementRecord]) -> Vec<(String, Decimal)> {
+    daily_pnl_series_in(movements, crate::output::display_timezone())
+}
+
+/// Hourly net-PnL buckets labelled `YYYY-MM-DD HH:00` in `tz`, so day
+/// boundaries follow the user's local midnight rather than UTC's.
+fn daily_pnl_series_in(movements: &[MovementRecord], tz: chrono_tz::Tz) -> Vec<(String, Decimal)> {
+    let mut by_day: BTreeMap<String, Decimal> = BTreeMap::new();
+    for m in movements.iter().filter(|m| m.settled) {
+        let day = hour_bucket(&m.timestamp, tz);
+        let net_pnl = m.pnl - m.estimated_total_fee_usd;
+        by_day
+            .entry(day)
+            .and_modify(|x| *x += net_pnl)
+            .or_insert(net_pnl);
+    }
+    by_day.into_iter().collect()
+}
+
+/// `YYYY-MM-DD HH:00` label of a movement timestamp in `tz`. Unparseable
+/// timestamps fall back to their own date/hour prefix.
+fn hour_bucket(timestamp: &str, tz: chrono_tz::Tz) -> String {
+    match chrono::DateTime::parse_from_rfc3339(timestamp) {
+        Ok(ts) => ts.with_timezone(&tz).format("%Y-%m-%d %H:00").to_string(),
+        Err(_) => timestamp
+            .get(0..13)
+            .map(|v| format!("{}:00", v.replace('T', " ")))
+            .unwrap_or_else(|| "unknown".to_string()),
+    }
+}
+
+pub fn cumulative_pnl_series(movements: &[MovementRecord]) -> Vec<(String, Decimal)> {
+    let mut cumulative = Decimal::ZERO;
     daily_pnl_series(movements)
         .into_iter()
         .map(|(day, pnl)| {
@@ -3170,62 +7324,562 @@ mod tests {
         Decimal::from_str(v).unwrap()
     }
 
-    #[test]
-    fn plan_is_capped_by_max_trade() {
-        let cfg = CopyConfig {
-            leader: "0x1".into(),
+    fn is_fast_market_with_fee(schedule: &FeeSchedule, slug: &str) -> bool {
+        schedule.fee_bps(slug).is_some()
+    }
+
+    /// Baseline config for tests: one leader, 1000 USD, no optional limits.
+    /// Override what a test cares about with struct update.
+    fn test_cfg() -> CopyConfig {
+        CopyConfig {
+            leaders: vec![LeaderConfig::new("0x1")],
             allocated_funds: d("1000"),
-            max_trade_pct: d("5"),
-            max_total_exposure_pct: d("100"),
+            max_trade_pct: d("10"),
+            max_total_exposure_pct: d("50"),
             min_copy_usd: d("1"),
             poll_interval_secs: 2,
             poll_interval_ms: 2000,
             risk_level: RiskLevel::Balanced,
             execute_orders: false,
+            paper_mode: false,
             realtime_mode: false,
             simulation_mode: false,
+            copy_outcome: CopyOutcome::Both,
+            history_retention_days: None,
+            per_leader_max_exposure_pct: None,
+            no_settlement_log: false,
+            settlement_log_max_bytes: None,
+            max_daily_trades: None,
+            include_fees: false,
+            notify_on: NotifyOn::Settled,
+            notify_webhook_url: None,
+            notify_command: None,
+            stop_loss_pct: None,
+            take_profit_pct: None,
+            backoff_recovery_successes: None,
+            daily_loss_limit_usd: None,
+            max_open_positions: None,
+            exposure_mode: ExposureMode::Global,
+            liquidity_multiple: None,
+            max_slippage_pct: None,
+            copy_sides: CopySides::Both,
+            slug_allowlist: Vec::new(),
+            slug_denylist: Vec::new(),
+            per_leader_disable_loss_usd: None,
+            sizing_mode: SizingMode::Proportional,
+            fixed_copy_usd: None,
+        }
+    }
+
+    /// Baseline movement for tests: an unsettled 20 USD BUY at 0.5.
+    fn test_movement() -> MovementRecord {
+        MovementRecord {
+            movement_id: "x1".into(),
+            leader: String::new(),
+            paper: false,
+            market: "mkt".into(),
+            asset: "123".into(),
+            timestamp: "2026-03-01T10:00:00Z".into(),
+            leader_value: d("100"),
+            leader_price: d("0.4"),
+            copied_value: d("20"),
+            simulated_copy_price: d("0.5"),
+            quantity: d("250"),
+            copy_side: "BUY".into(),
+            outcome: "Yes".into(),
+            resolved_outcome: String::new(),
+            diff_pct: Decimal::ZERO,
+            estimated_total_fee_usd: Decimal::ZERO,
+            settled: false,
+            pnl: Decimal::ZERO,
+            remaining_quantity: None,
+            last_partial_close_ts: None,
+        }
+    }
+
+    #[test]
+    fn only_the_first_sweep_primes_seen_trades() {
+        let mut seen = SeenTradeKeys::default();
+        assert!(seen.prime_leader("0xA", ["a".to_string(), "b".to_string()]));
+        assert!(seen.contains("a") && seen.contains("b"));
+        // The loop keeps running after priming; the next sweep must be copied.
+        assert!(!seen.prime_leader("0xa", ["c".to_string()]));
+        assert!(!seen.contains("c"));
+    }
+
+    #[test]
+    fn a_leader_first_fetched_later_is_primed_on_its_own() {
+        let mut seen = SeenTradeKeys::default();
+        assert!(seen.prime_leader("0xA", ["a".to_string()]));
+        // Added while monitoring, or its first fetch failed: still primed.
+        assert!(seen.prime_leader("0xB", ["b1".to_string(), "b2".to_string()]));
+        assert!(seen.contains("b1") && seen.contains("b2"));
+        assert!(!seen.prime_leader("0xB", ["b3".to_string()]));
+
+        seen.clear();
+        assert!(seen.prime_leader("0xA", []));
+    }
+
+    #[test]
+    fn plan_is_capped_by_max_trade() {
+        let cfg = CopyConfig {
+            max_trade_pct: d("5"),
+            max_total_exposure_pct: d("100"),
+            ..test_cfg()
         };
         let state = CopyState::default();
-        let p = compute_plan(&cfg, &state, d("1000"), d("200")).unwrap();
+        let p = compute_plan(
+            &cfg,
+            &state,
+            cfg.primary_leader(),
+            "m",
+            d("1000"),
+            d("200"),
+            Decimal::ZERO,
+        )
+        .unwrap();
         assert_eq!(p.capped_size, d("50"));
         assert_eq!(p.reason, "capped by max_trade_pct");
     }
 
+    #[test]
+    fn preset_fills_defaults_and_explicit_flags_override() {
+        let args: ConfigureArgs = serde_json::from_value(serde_json::json!({
+            "leader": "0x0000000000000000000000000000000000000001",
+            "allocated_funds": "500",
+            "preset": "scalper",
+            "max_trade_pct": "3",
+            "execute_orders": false,
+            "realtime_mode": false,
+            "simulation_mode": false,
+        }))
+        .unwrap();
+        validate_config(&args).unwrap();
+        let cfg = config_from_args(args);
+        assert_eq!(cfg.max_trade_pct, d("3"));
+        assert_eq!(cfg.max_total_exposure_pct, d("30"));
+        assert_eq!(cfg.poll_interval_ms, 200);
+        assert!(cfg.realtime_mode);
+        assert_eq!(cfg.risk_level, RiskLevel::Aggressive);
+
+        let plain: ConfigureArgs = serde_json::from_value(serde_json::json!({
+            "leader": "0x0000000000000000000000000000000000000001",
+            "allocated_funds": "500",
+            "execute_orders": false,
+            "realtime_mode": false,
+            "simulation_mode": true,
+        }))
+        .unwrap();
+        assert_eq!(plain.resolved(), preset_values(None));
+        let sim_scalper = ConfigureArgs {
+            preset: Some(CopyPreset::Scalper),
+            ..plain
+        };
+        assert!(!sim_scalper.resolved().realtime_mode);
+        validate_config(&sim_scalper).unwrap();
+    }
+
+    #[test]
+    fn paper_mode_never_reaches_the_order_path() {
+        let mut cfg = CopyConfig {
+            max_trade_pct: d("5"),
+            max_total_exposure_pct: d("100"),
+            execute_orders: true,
+            ..test_cfg()
+        };
+        assert!(posts_orders(&cfg));
+        assert_eq!(run_mode_label(&cfg), "execute");
+
+        // A hand-edited config with both flags still must not post.
+        cfg.paper_mode = true;
+        assert!(!posts_orders(&cfg));
+        assert_eq!(run_mode_label(&cfg), "paper");
+
+        cfg.paper_mode = false;
+        cfg.execute_orders = false;
+        assert_eq!(run_mode_label(&cfg), "dry-run");
+        cfg.simulation_mode = true;
+        assert!(!posts_orders(&cfg));
+        assert_eq!(run_mode_label(&cfg), "simulation");
+    }
+
+    #[test]
+    fn paper_flag_round_trips_through_the_db_row() {
+        let row: DbRow = serde_json::from_str(
+            r#"{"id":1,"movement_id":"m","market":"x","timestamp":"t","leader_value":"1","copied_value":"1","diff_pct":"0","settled":false,"pnl":"0"}"#,
+        )
+        .unwrap();
+        assert!(
+            !row.paper,
+            "rows written before paper mode default to not paper"
+        );
+        let paper: DbRow =
+            serde_json::from_str(&serde_json::to_string(&DbRow { paper: true, ..row }).unwrap())
+                .unwrap();
+        assert!(paper.paper);
+    }
+
+    #[test]
+    fn plan_explain_marks_the_binding_constraint() {
+        let mut cfg = CopyConfig {
+            max_trade_pct: d("5"),
+            max_total_exposure_pct: d("100"),
+            ..test_cfg()
+        };
+        let state = CopyState::default();
+        let c = compute_plan(&cfg, &state, "0x1", "m", d("1000"), d("200"), Decimal::ZERO)
+            .unwrap()
+            .constraints
+            .unwrap();
+        assert_eq!(c.binding, "max_trade");
+        assert_eq!(c.proportional_size, d("200"));
+        assert_eq!(c.max_trade, d("50"));
+        assert_eq!(c.available_exposure, d("1000"));
+        assert_eq!(c.per_leader_available, None);
+
+        let small =
+            compute_plan(&cfg, &state, "0x1", "m", d("1000"), d("10"), Decimal::ZERO).unwrap();
+        assert_eq!(small.constraints.unwrap().binding, "proportional_size");
+
+        cfg.min_copy_usd = d("20");
+        let below =
+            compute_plan(&cfg, &state, "0x1", "m", d("1000"), d("10"), Decimal::ZERO).unwrap();
+        assert_eq!(below.constraints.unwrap().binding, "min_copy_usd");
+    }
+
+    #[test]
+    fn order_sizes_are_floored_to_the_exchange_increment() {
+        assert_eq!(
+            floor_to_increment(d("12.3456"), ORDER_SIZE_INCREMENT),
+            d("12.34")
+        );
+        assert_eq!(floor_to_increment(d("0.009"), ORDER_SIZE_INCREMENT), d("0"));
+
+        let (usd, shares) = rounded_order_size(d("10.129"), d("0.5"), false);
+        assert_eq!(usd, d("10.12"));
+        assert_eq!(shares, d("20.24"));
+
+        let (usd, shares) = rounded_order_size(d("10"), d("0.3"), true);
+        assert_eq!(shares, d("33.33"));
+        assert_eq!(usd, d("9.999"));
+    }
+
+    #[test]
+    fn include_fees_reserves_round_trip_fees_from_exposure() {
+        let mut cfg = CopyConfig {
+            max_trade_pct: d("100"),
+            max_total_exposure_pct: d("10"),
+            ..test_cfg()
+        };
+        let state = CopyState::default();
+        let rate = round_trip_fee_rate(&FeeSchedule::default(), "btc-updown-5m-1700000000");
+        assert_eq!(rate, d("0.014"));
+
+        let without = compute_plan(&cfg, &state, "0x1", "m", d("1000"), d("500"), rate).unwrap();
+        assert_eq!(without.capped_size, d("100"));
+        assert_eq!(without.constraints.unwrap().fee_reserve, None);
+
+        cfg.include_fees = true;
+        let with = compute_plan(&cfg, &state, "0x1", "m", d("1000"), d("500"), rate).unwrap();
+        assert!(with.capped_size < without.capped_size);
+        assert_eq!(
+            (with.capped_size * (Decimal::ONE + rate)).round_dp(8),
+            d("100")
+        );
+        assert_eq!(with.reason, "capped by max_total_exposure_pct");
+
+        // Fee-free markets plan the same either way.
+        let free =
+            compute_plan(&cfg, &state, "0x1", "m", d("1000"), d("500"), Decimal::ZERO).unwrap();
+        assert_eq!(free.capped_size, d("100"));
+    }
+
+    #[test]
+    fn notify_payload_is_db_movement_and_noop_without_targets() {
+        let movement = stream_event(StorageMode::Real, 7).movement;
+        let payload = serde_json::to_value(&movement).unwrap();
+        let schema = serde_json::to_value(schemars::schema_for!(DbMovement)).unwrap();
+        let mut payload_keys: Vec<_> = payload.as_object().unwrap().keys().collect();
+        let mut schema_keys: Vec<_> = schema["properties"].as_object().unwrap().keys().collect();
+        payload_keys.sort();
+        schema_keys.sort();
+        assert_eq!(payload_keys, schema_keys);
+        assert_eq!(payload["movement_id"], "m7");
+
+        let mut cfg = exit_cfg(None, None);
+        cfg.notify_on = NotifyOn::Both;
+        assert!(!notify_movement(
+            &cfg,
+            StorageMode::Real,
+            NotifyEvent::Detected,
+            &movement
+        ));
+
+        cfg.notify_on = NotifyOn::Settled;
+        cfg.notify_command = Some("cat >/dev/null".into());
+        assert!(!notify_movement(
+            &cfg,
+            StorageMode::Real,
+            NotifyEvent::Detected,
+            &movement
+        ));
+    }
+
+    #[test]
+    fn notify_on_gates_detected_and_settled_events() {
+        let mut cfg: CopyConfig = serde_json::from_value(serde_json::json!({
+            "leader": "0x1",
+            "allocated_funds": "100",
+            "max_trade_pct": "5",
+            "max_total_exposure_pct": "70",
+            "min_copy_usd": "1",
+            "poll_interval_secs": 2,
+            "risk_level": "balanced",
+            "execute_orders": false,
+        }))
+        .unwrap();
+        assert_eq!(cfg.notify_on, NotifyOn::Settled);
+        assert!(should_notify(&cfg, NotifyEvent::Settled));
+        assert!(!should_notify(&cfg, NotifyEvent::Detected));
+
+        cfg.notify_on = NotifyOn::Detected;
+        assert!(should_notify(&cfg, NotifyEvent::Detected));
+        assert!(!should_notify(&cfg, NotifyEvent::Settled));
+
+        cfg.notify_on = NotifyOn::Both;
+        assert!(should_notify(&cfg, NotifyEvent::Detected));
+        assert!(should_notify(&cfg, NotifyEvent::Settled));
+    }
+
+    #[test]
+    fn daily_pnl_buckets_follow_the_display_timezone() {
+        let movement = MovementRecord {
+            movement_id: "late".into(),
+            market: "m".into(),
+            asset: String::new(),
+            timestamp: "2025-01-01T23:30:00+00:00".into(),
+            leader_value: d("10"),
+            leader_price: d("0.5"),
+            copied_value: d("10"),
+            quantity: d("20"),
+            settled: true,
+            pnl: d("3"),
+            ..test_movement()
+        };
+        let movements = [movement];
+
+        let utc = daily_pnl_series_in(&movements, chrono_tz::UTC);
+        assert_eq!(utc, vec![("2025-01-01 23:00".to_string(), d("3"))]);
+
+        let tokyo = daily_pnl_series_in(&movements, chrono_tz::Asia::Tokyo);
+        assert_eq!(tokyo, vec![("2025-01-02 08:00".to_string(), d("3"))]);
+
+        let new_york = daily_pnl_series_in(&movements, chrono_tz::America::New_York);
+        assert_eq!(new_york, vec![("2025-01-01 18:00".to_string(), d("3"))]);
+    }
+
+    #[test]
+    fn daily_trade_limit_skips_the_next_copy_and_resets_the_next_day() {
+        let cfg = CopyConfig {
+            max_trade_pct: d("100"),
+            max_total_exposure_pct: d("100"),
+            max_daily_trades: Some(2),
+            ..test_cfg()
+        };
+        let movement = |id: &str, timestamp: &str| MovementRecord {
+            movement_id: id.into(),
+            market: "m".into(),
+            asset: String::new(),
+            timestamp: timestamp.into(),
+            leader_value: d("10"),
+            leader_price: d("0.5"),
+            copied_value: d("10"),
+            quantity: d("20"),
+            settled: true,
+            ..test_movement()
+        };
+        let mut state = CopyState {
+            movements: vec![
+                movement("y", "2025-01-01T23:59:59+00:00"),
+                movement("a", "2025-01-02T00:00:01+00:00"),
+            ],
+        };
+        let today = "2025-01-02";
+
+        let mut plan =
+            compute_plan(&cfg, &state, "0x1", "m", d("1000"), d("50"), Decimal::ZERO).unwrap();
+        apply_daily_trade_limit(&cfg, &state, today, &mut plan);
+        assert_eq!(plan.reason, "ok");
+        assert_eq!(plan.capped_size, d("50"));
+
+        state
+            .movements
+            .push(movement("b", "2025-01-02T12:00:00+00:00"));
+        let mut plan =
+            compute_plan(&cfg, &state, "0x1", "m", d("1000"), d("50"), Decimal::ZERO).unwrap();
+        apply_daily_trade_limit(&cfg, &state, today, &mut plan);
+        assert_eq!(plan.reason, "daily trade limit reached");
+        assert_eq!(plan.capped_size, Decimal::ZERO);
+
+        let mut plan =
+            compute_plan(&cfg, &state, "0x1", "m", d("1000"), d("50"), Decimal::ZERO).unwrap();
+        apply_daily_trade_limit(&cfg, &state, "2025-01-03", &mut plan);
+        assert_eq!(plan.reason, "ok");
+    }
+
+    #[test]
+    fn movements_on_day_buckets_offset_timestamps_by_utc_day() {
+        let mut late = open_copy("BUY", "0.5");
+        late.timestamp = "2025-01-01T20:00:00-05:00".into();
+        let mut early = open_copy("BUY", "0.5");
+        early.timestamp = "2025-01-02T00:30:00+02:00".into();
+        let movements = [late, early];
+        assert_eq!(movements_on_day(&movements, "2025-01-02"), 1);
+        assert_eq!(movements_on_day(&movements, "2025-01-01"), 1);
+    }
+
+    #[test]
+    fn per_leader_cap_saturates_one_leader_but_not_the_other() {
+        let cfg = CopyConfig {
+            leaders: vec![LeaderConfig::new("0xaaa"), LeaderConfig::new("0xbbb")],
+            max_trade_pct: d("100"),
+            max_total_exposure_pct: d("100"),
+            per_leader_max_exposure_pct: Some(d("20")),
+            ..test_cfg()
+        };
+        let open = |id: &str, leader: &str, value: &str| MovementRecord {
+            movement_id: id.into(),
+            leader: leader.into(),
+            market: "m".into(),
+            asset: String::new(),
+            timestamp: "2025-01-01T00:00:00Z".into(),
+            leader_value: d(value),
+            leader_price: d("0.5"),
+            copied_value: d(value),
+            quantity: d("1"),
+            ..test_movement()
+        };
+        // An untagged movement counts against the primary leader. Each leader
+        // sizes against its half of the funds, so 0xbbb's leader is valued at 500.
+        let state = CopyState {
+            movements: vec![open("a1", "0xAAA", "150"), open("a2", "", "50")],
+        };
+
+        let saturated = compute_plan(
+            &cfg,
+            &state,
+            "0xaaa",
+            "m",
+            d("1000"),
+            d("300"),
+            Decimal::ZERO,
+        )
+        .unwrap();
+        assert_eq!(saturated.available_funds, Decimal::ZERO);
+        assert_eq!(saturated.capped_size, Decimal::ZERO);
+
+        let other = compute_plan(
+            &cfg,
+            &state,
+            "0xbbb",
+            "m",
+            d("500"),
+            d("300"),
+            Decimal::ZERO,
+        )
+        .unwrap();
+        assert_eq!(other.available_funds, d("200"));
+        assert_eq!(other.capped_size, d("200"));
+        assert_eq!(other.reason, "capped by per-leader exposure");
+
+        let uncapped = CopyConfig {
+            per_leader_max_exposure_pct: None,
+            ..cfg.clone()
+        };
+        let global = compute_plan(
+            &uncapped,
+            &state,
+            "0xbbb",
+            "m",
+            d("500"),
+            d("300"),
+            Decimal::ZERO,
+        )
+        .unwrap();
+        assert_eq!(global.capped_size, d("300"));
+        assert_eq!(global.reason, "ok");
+    }
+
+    #[test]
+    fn outcome_filter_skips_no_side_when_copying_yes_only() {
+        assert!(!outcome_allowed(CopyOutcome::Yes, "No"));
+        assert!(outcome_allowed(CopyOutcome::Yes, "Yes"));
+        assert!(outcome_allowed(CopyOutcome::No, "no"));
+        assert!(outcome_allowed(CopyOutcome::Both, "No"));
+    }
+
+    #[test]
+    fn loaded_config_with_too_fast_polling_is_clamped() {
+        let mut cfg = CopyConfig {
+            max_trade_pct: d("5"),
+            max_total_exposure_pct: d("70"),
+            poll_interval_ms: 10,
+            ..test_cfg()
+        };
+        let warnings = sanitize_loaded_config(&mut cfg);
+        assert_eq!(cfg.poll_interval_ms, 500);
+        assert_eq!(warnings.len(), 1);
+
+        cfg.realtime_mode = true;
+        cfg.simulation_mode = true;
+        cfg.poll_interval_ms = 50;
+        let warnings = sanitize_loaded_config(&mut cfg);
+        assert!(!cfg.realtime_mode);
+        assert_eq!(cfg.poll_interval_ms, 50);
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn ui_template_check_flags_empty_or_unwired_html() {
+        assert!(ui_template_problems(EMBEDDED_UI_HTML).is_empty());
+        assert_eq!(ui_template_problems("  \n"), vec!["template is empty"]);
+        let problems = ui_template_problems("<html>/api/state /api/updates</html>");
+        assert_eq!(problems.len(), 3);
+        assert!(problems[0].contains("/api/configure"));
+    }
+
     #[test]
     fn plan_respects_total_exposure_limit() {
         let cfg = CopyConfig {
-            leader: "0x1".into(),
-            allocated_funds: d("1000"),
             max_trade_pct: d("50"),
             max_total_exposure_pct: d("60"),
-            min_copy_usd: d("1"),
-            poll_interval_secs: 2,
-            poll_interval_ms: 2000,
-            risk_level: RiskLevel::Balanced,
-            execute_orders: false,
-            realtime_mode: false,
-            simulation_mode: false,
+            ..test_cfg()
         };
         let state = CopyState {
             movements: vec![MovementRecord {
                 movement_id: "a".into(),
                 market: "m".into(),
+                asset: String::new(),
                 timestamp: "2025-01-01T00:00:00Z".into(),
-                leader_value: d("100"),
                 leader_price: Decimal::ZERO,
                 copied_value: d("550"),
                 simulated_copy_price: Decimal::ZERO,
                 quantity: Decimal::ZERO,
                 copy_side: "unknown".into(),
                 outcome: String::new(),
-                resolved_outcome: String::new(),
-                diff_pct: Decimal::ZERO,
-                estimated_total_fee_usd: Decimal::ZERO,
-                settled: false,
-                pnl: Decimal::ZERO,
+                ..test_movement()
             }],
         };
-        let p = compute_plan(&cfg, &state, d("1000"), d("100")).unwrap();
+        let p = compute_plan(
+            &cfg,
+            &state,
+            cfg.primary_leader(),
+            "m",
+            d("1000"),
+            d("100"),
+            Decimal::ZERO,
+        )
+        .unwrap();
         assert_eq!(p.capped_size, d("50"));
         assert_eq!(p.available_funds, d("50"));
     }
@@ -3269,36 +7923,26 @@ mod tests {
                 MovementRecord {
                     movement_id: "b1".into(),
                     market: "eth-updown-5m-1772281500".into(),
+                    asset: String::new(),
                     timestamp: "2026-02-28T12:00:00Z".into(),
                     leader_value: d("10"),
-                    leader_price: d("0.4"),
                     copied_value: d("4"),
                     simulated_copy_price: d("0.4"),
                     quantity: d("10"),
                     copy_side: "buy".into(),
-                    outcome: "Yes".into(),
-                    resolved_outcome: String::new(),
-                    diff_pct: Decimal::ZERO,
-                    estimated_total_fee_usd: Decimal::ZERO,
-                    settled: false,
-                    pnl: Decimal::ZERO,
+                    ..test_movement()
                 },
                 MovementRecord {
                     movement_id: "s1".into(),
                     market: "eth-updown-5m-1772281500".into(),
+                    asset: String::new(),
                     timestamp: "2026-02-28T12:01:00Z".into(),
                     leader_value: d("4"),
                     leader_price: d("0.5"),
                     copied_value: d("2"),
-                    simulated_copy_price: d("0.5"),
                     quantity: d("4"),
                     copy_side: "sell".into(),
-                    outcome: "Yes".into(),
-                    resolved_outcome: String::new(),
-                    diff_pct: Decimal::ZERO,
-                    estimated_total_fee_usd: Decimal::ZERO,
-                    settled: false,
-                    pnl: Decimal::ZERO,
+                    ..test_movement()
                 },
             ],
         };
@@ -3326,11 +7970,21 @@ mod tests {
 
     #[test]
     fn fast_market_fee_detection_and_impact() {
-        assert!(is_fast_market_with_fee("eth-updown-5m-1772281500"));
-        assert!(is_fast_market_with_fee("btc-updown-15m-1772281500"));
-        assert!(!is_fast_market_with_fee("btc-updown-1h-1772281500"));
+        let schedule = FeeSchedule::default();
+        assert!(is_fast_market_with_fee(
+            &schedule,
+            "eth-updown-5m-1772281500"
+        ));
+        assert!(is_fast_market_with_fee(
+            &schedule,
+            "btc-updown-15m-1772281500"
+        ));
+        assert!(!is_fast_market_with_fee(
+            &schedule,
+            "btc-updown-1h-1772281500"
+        ));
 
-        let impact = trading_fee_impact_for_movement("eth-updown-5m-1772281500", d("10")).unwrap();
+        let impact = trading_fee_impact(&schedule, "eth-updown-5m-1772281500", d("10")).unwrap();
         assert_eq!(impact.fee_bps, FAST_MARKET_FEE_BPS);
         assert_eq!(impact.entry_fee_usd, d("0.07"));
         assert_eq!(impact.round_trip_fee_usd, d("0.14"));
@@ -3338,6 +7992,29 @@ mod tests {
         assert_eq!(impact.max_net_profit_usd, d("8.86"));
     }
 
+    #[test]
+    fn custom_fee_schedule_overrides_the_default() {
+        let schedule: FeeSchedule =
+            serde_json::from_str(r#"{"-updown-5m": 100, "-updown-1h": 25, "-updown-15m": 0}"#)
+                .unwrap();
+        let impact = trading_fee_impact(&schedule, "eth-updown-5m-1772281500", d("10")).unwrap();
+        assert_eq!(impact.fee_bps, 100);
+        assert_eq!(impact.entry_fee_usd, d("0.1"));
+        assert_eq!(schedule.fee_bps("btc-updown-1h-1772281500"), Some(25));
+        assert_eq!(schedule.fee_bps("btc-updown-15m-1772281500"), None);
+        assert_eq!(
+            FeeSchedule::default().fee_bps("btc-updown-1h-1772281500"),
+            None
+        );
+    }
+
+    #[test]
+    fn unmatched_slug_has_no_fee_impact() {
+        let schedule = FeeSchedule::default();
+        assert!(trading_fee_impact(&schedule, "will-it-rain-tomorrow", d("10")).is_none());
+        assert!(trading_fee_impact(&schedule, "eth-updown-5m-1772281500", d("0")).is_none());
+    }
+
     #[test]
     fn normalize_market_slug_strips_numeric_suffix() {
         assert_eq!(
@@ -3353,6 +8030,7 @@ mod tests {
             movements: vec![MovementRecord {
                 movement_id: "m-loss".into(),
                 market: "highest-temperature-in-lucknow-on-march-8-2026-39c".into(),
+                asset: String::new(),
                 timestamp: "2026-03-08T10:00:00Z".into(),
                 leader_value: d("39"),
                 leader_price: d("0.999"),
@@ -3361,11 +8039,7 @@ mod tests {
                 quantity: d("39"),
                 copy_side: "buy".into(),
                 outcome: "No".into(),
-                resolved_outcome: String::new(),
-                diff_pct: Decimal::ZERO,
-                estimated_total_fee_usd: Decimal::ZERO,
-                settled: false,
-                pnl: Decimal::ZERO,
+                ..test_movement()
             }],
         };
 
@@ -3390,19 +8064,15 @@ mod tests {
             movements: vec![MovementRecord {
                 movement_id: "m-1".into(),
                 market: "highest-temperature-in-lucknow-on-march-5-2026-40c".into(),
+                asset: String::new(),
                 timestamp: "2026-03-05T10:00:00Z".into(),
-                leader_value: d("100"),
                 leader_price: d("0.6"),
                 copied_value: d("50"),
                 simulated_copy_price: d("0.6"),
                 quantity: d("80"),
                 copy_side: "buy".into(),
                 outcome: "No".into(),
-                resolved_outcome: String::new(),
-                diff_pct: Decimal::ZERO,
-                estimated_total_fee_usd: Decimal::ZERO,
-                settled: false,
-                pnl: Decimal::ZERO,
+                ..test_movement()
             }],
         };
 
@@ -3432,7 +8102,7 @@ mod tests {
             }
         ])).unwrap();
 
-        let settled = settle_open_buys_from_activities(&mut state, &activities);
+        let settled = settle_open_buys_from_activities(&mut state, &activities, |_| true);
         assert_eq!(settled.len(), 1);
         assert!(state.movements[0].settled);
         assert_eq!(state.movements[0].copy_side, "sell");
@@ -3445,6 +8115,7 @@ mod tests {
             movements: vec![MovementRecord {
                 movement_id: "only-buy-other-outcome".into(),
                 market: "highest-temperature-in-lucknow-on-march-5-2026-40c".into(),
+                asset: String::new(),
                 timestamp: "2026-03-06T13:00:00Z".into(),
                 leader_value: d("10"),
                 leader_price: d("0.6"),
@@ -3452,17 +8123,13 @@ mod tests {
                 simulated_copy_price: d("0.6"),
                 quantity: d("8"),
                 copy_side: "buy".into(),
-                outcome: "Yes".into(),
-                resolved_outcome: String::new(),
-                diff_pct: Decimal::ZERO,
-                estimated_total_fee_usd: Decimal::ZERO,
-                settled: false,
-                pnl: Decimal::ZERO,
+                ..test_movement()
             }],
         };
 
         let settled = settle_open_buys_from_sell_trade(
             &mut state,
+            "",
             "highest-temperature-in-lucknow-on-march-5-2026-40c",
             "No",
             d("0.001"),
@@ -3473,12 +8140,34 @@ mod tests {
         assert_eq!(state.movements[0].copy_side, "buy");
     }
 
+    #[test]
+    fn manual_sell_settles_open_buys_of_its_token_only() {
+        let other = MovementRecord {
+            movement_id: "x2".into(),
+            asset: "456".into(),
+            ..test_movement()
+        };
+        let mut state = CopyState {
+            movements: vec![test_movement(), other],
+        };
+        let token = crate::commands::clob::parse_token_id("123").unwrap();
+        let closed = settle_open_buys_of_token(&mut state, token, d("0.6"));
+        assert_eq!(closed.len(), 1);
+        assert_eq!(closed[0].movement_id, "x1");
+        assert_eq!(closed[0].pnl, d("4"));
+        assert_eq!(closed[0].copy_side, "sell");
+        assert!(!state.movements[1].settled);
+        // Nothing left open on the token, so a repeated sell has nothing to close.
+        assert!(!state.movements.iter().any(|m| is_open_buy_of(m, token)));
+    }
+
     #[test]
     fn sell_trade_settles_open_buy_with_loss() {
         let mut state = CopyState {
             movements: vec![MovementRecord {
                 movement_id: "b1".into(),
                 market: "highest-temperature-in-ankara-on-march-7-2026-3c".into(),
+                asset: String::new(),
                 timestamp: "2026-03-06T09:00:00Z".into(),
                 leader_value: d("473.90945"),
                 leader_price: d("0.9873113541666668"),
@@ -3487,16 +8176,13 @@ mod tests {
                 quantity: d("480"),
                 copy_side: "buy".into(),
                 outcome: "No".into(),
-                resolved_outcome: String::new(),
-                diff_pct: Decimal::ZERO,
-                estimated_total_fee_usd: Decimal::ZERO,
-                settled: false,
-                pnl: Decimal::ZERO,
+                ..test_movement()
             }],
         };
 
         let settled = settle_open_buys_from_sell_trade(
             &mut state,
+            "",
             "highest-temperature-in-ankara-on-march-7-2026-3c",
             "No",
             d("0.984"),
@@ -3515,6 +8201,7 @@ mod tests {
                 MovementRecord {
                     movement_id: "1".into(),
                     market: "btc-updown-5m-1772278200".into(),
+                    asset: String::new(),
                     timestamp: "2025-01-01T00:00:00Z".into(),
                     leader_value: d("10"),
                     leader_price: Decimal::ZERO,
@@ -3523,15 +8210,12 @@ mod tests {
                     quantity: Decimal::ZERO,
                     copy_side: "buy".into(),
                     outcome: "No".into(),
-                    resolved_outcome: String::new(),
-                    diff_pct: Decimal::ZERO,
-                    estimated_total_fee_usd: Decimal::ZERO,
-                    settled: false,
-                    pnl: Decimal::ZERO,
+                    ..test_movement()
                 },
                 MovementRecord {
                     movement_id: "2".into(),
                     market: "btc-updown-5m-1772278300".into(),
+                    asset: String::new(),
                     timestamp: "2025-01-01T00:01:00Z".into(),
                     leader_value: d("10"),
                     leader_price: Decimal::ZERO,
@@ -3540,15 +8224,12 @@ mod tests {
                     quantity: Decimal::ZERO,
                     copy_side: "buy".into(),
                     outcome: String::new(),
-                    resolved_outcome: String::new(),
-                    diff_pct: Decimal::ZERO,
-                    estimated_total_fee_usd: Decimal::ZERO,
-                    settled: false,
-                    pnl: Decimal::ZERO,
+                    ..test_movement()
                 },
                 MovementRecord {
                     movement_id: "3".into(),
                     market: "eth-updown-5m-1772278300".into(),
+                    asset: String::new(),
                     timestamp: "2025-01-01T00:02:00Z".into(),
                     leader_value: d("10"),
                     leader_price: Decimal::ZERO,
@@ -3557,11 +8238,8 @@ mod tests {
                     quantity: Decimal::ZERO,
                     copy_side: "buy".into(),
                     outcome: String::new(),
-                    resolved_outcome: String::new(),
-                    diff_pct: Decimal::ZERO,
-                    estimated_total_fee_usd: Decimal::ZERO,
                     settled: true,
-                    pnl: Decimal::ZERO,
+                    ..test_movement()
                 },
             ],
         };
@@ -3570,13 +8248,305 @@ mod tests {
         assert_eq!(slugs, vec!["btc-updown-5m".to_string()]);
     }
 
+    #[test]
+    fn prune_predicate_only_drops_settled_rows_older_than_cutoff() {
+        let row = |timestamp: &str, settled: bool| DbRow {
+            id: 1,
+            movement_id: "a".into(),
+            leader: String::new(),
+            paper: false,
+            market: "m1".into(),
+            asset: String::new(),
+            timestamp: timestamp.into(),
+            leader_value: "10".into(),
+            leader_price: "0".into(),
+            copied_value: "5".into(),
+            simulated_copy_price: "0".into(),
+            quantity: "0".into(),
+            copy_side: "BUY".into(),
+            outcome: "Yes".into(),
+            resolved_outcome: String::new(),
+            diff_pct: "0".into(),
+            estimated_total_fee_usd: "0".into(),
+            settled,
+            pnl: "0".into(),
+        };
+        let cutoff = chrono::DateTime::parse_from_rfc3339("2025-02-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert!(should_prune_row(&row("2025-01-01T00:00:00Z", true), cutoff));
+        assert!(!should_prune_row(
+            &row("2025-01-01T00:00:00Z", false),
+            cutoff
+        ));
+        assert!(!should_prune_row(
+            &row("2025-03-01T00:00:00Z", true),
+            cutoff
+        ));
+        assert!(!should_prune_row(&row("not-a-timestamp", true), cutoff));
+    }
+
+    fn import_row(id: i64, movement_id: &str) -> DbRow {
+        DbRow {
+            id,
+            movement_id: movement_id.into(),
+            leader: "0xabc".into(),
+            paper: true,
+            market: "m".into(),
+            asset: "1".into(),
+            timestamp: "2025-03-01T00:00:00Z".into(),
+            leader_value: "10".into(),
+            leader_price: "0.5".into(),
+            copied_value: "5".into(),
+            simulated_copy_price: "0.5".into(),
+            quantity: "10".into(),
+            copy_side: "BUY".into(),
+            outcome: "Yes".into(),
+            resolved_outcome: String::new(),
+            diff_pct: "0".into(),
+            estimated_total_fee_usd: "0".into(),
+            settled: false,
+            pnl: "0".into(),
+        }
+    }
+
+    #[test]
+    fn exit_sweep_only_picks_open_movements_of_the_requested_mode() {
+        let movement = |id: &str, settled: bool| MovementRecord {
+            movement_id: id.into(),
+            settled,
+            ..test_movement()
+        };
+        let state = || CopyState {
+            movements: vec![
+                movement("real-a", false),
+                movement("real-b", true),
+                movement("sim-c", false),
+            ],
+        };
+        let real_rows = [import_row(1, "real-a"), import_row(2, "real-b")];
+        let sim_rows = [import_row(1, "sim-c")];
+
+        let ids = |rows: &[DbRow]| {
+            exit_candidates(state(), rows)
+                .into_iter()
+                .map(|(m, _)| m.movement_id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(&real_rows), ["real-a"]);
+        assert_eq!(ids(&sim_rows), ["sim-c"]);
+    }
+
+    fn settled_import_row(id: i64, movement_id: &str) -> DbRow {
+        DbRow {
+            settled: true,
+            ..import_row(id, movement_id)
+        }
+    }
+
+    #[test]
+    fn import_merges_new_movements_with_fresh_ids() {
+        let mut rows = vec![import_row(1, "a"), import_row(2, "b")];
+        let mut raw = String::new();
+        for row in [
+            settled_import_row(9, "d"),
+            settled_import_row(3, "b"),
+            settled_import_row(4, "c"),
+        ] {
+            raw.push_str(&serde_json::to_string(&row).unwrap());
+            raw.push('\n');
+        }
+        raw.push_str("{not json}\n");
+        let mut bad = import_row(5, "e");
+        bad.timestamp = "yesterday".into();
+        raw.push_str(&serde_json::to_string(&bad).unwrap());
+
+        let (incoming, malformed) = parse_import_rows(&raw);
+        assert_eq!(malformed, 2);
+        // Id 4 was handed out before and compacted away, so it isn't reused.
+        let (added, duplicates, open) = merge_db_rows(&mut rows, incoming, &HashSet::new(), 4);
+        assert_eq!((added, duplicates, open), (2, 1, 0));
+        let merged: Vec<(i64, &str)> = rows
+            .iter()
+            .map(|r| (r.id, r.movement_id.as_str()))
+            .collect();
+        assert_eq!(merged, vec![(1, "a"), (2, "b"), (5, "c"), (6, "d")]);
+    }
+
+    #[test]
+    fn import_skips_archived_and_open_movements() {
+        let mut rows = vec![settled_import_row(1, "a")];
+        let archived: HashSet<String> = ["old".to_string()].into();
+        let incoming = vec![
+            settled_import_row(1, "old"),
+            import_row(2, "open"),
+            settled_import_row(3, "new"),
+        ];
+        let (added, duplicates, open) = merge_db_rows(&mut rows, incoming, &archived, 1);
+        assert_eq!((added, duplicates, open), (1, 1, 1));
+        let ids: Vec<&str> = rows.iter().map(|r| r.movement_id.as_str()).collect();
+        assert_eq!(ids, ["a", "new"]);
+    }
+
+    #[test]
+    fn import_reads_back_a_csv_export() {
+        let mut row = import_row(3, "x");
+        row.market = "Rain, or shine?".into();
+        let csv = db_rows_csv(&[row, import_row(4, "")]);
+        let (incoming, malformed) = parse_import_rows(&csv);
+        assert_eq!(malformed, 1);
+        assert_eq!(incoming.len(), 1);
+        assert_eq!(incoming[0].movement_id, "x");
+        assert_eq!(incoming[0].market, "Rain, or shine?");
+        assert!(incoming[0].paper);
+    }
+
+    #[test]
+    fn db_rows_csv_has_header_and_net_pnl_column() {
+        let row = DbRow {
+            id: 7,
+            movement_id: "m7".into(),
+            leader: "0xabc".into(),
+            paper: false,
+            market: "Rain, or shine?".into(),
+            asset: "123".into(),
+            timestamp: "2025-03-01T00:00:00Z".into(),
+            leader_value: "10".into(),
+            leader_price: "0.4".into(),
+            copied_value: "5".into(),
+            simulated_copy_price: "0.41".into(),
+            quantity: "12".into(),
+            copy_side: "BUY".into(),
+            outcome: "Yes".into(),
+            resolved_outcome: "Yes".into(),
+            diff_pct: "0".into(),
+            estimated_total_fee_usd: "0.25".into(),
+            settled: true,
+            pnl: "3".into(),
+        };
+        let csv = db_rows_csv(&[row]);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("id,movement_id,leader,"));
+        assert!(lines[0].ends_with(",pnl,pnl_after_fees"));
+        assert!(lines[1].contains(",\"Rain, or shine?\","));
+        assert!(lines[1].ends_with(",true,3,2.75"));
+    }
+
+    #[test]
+    fn ids_are_not_reused_after_pruning_the_newest_rows() {
+        let row = |id: i64, timestamp: &str| DbRow {
+            id,
+            movement_id: format!("m{id}"),
+            leader: String::new(),
+            paper: false,
+            market: "m1".into(),
+            asset: String::new(),
+            timestamp: timestamp.into(),
+            leader_value: "10".into(),
+            leader_price: "0".into(),
+            copied_value: "5".into(),
+            simulated_copy_price: "0".into(),
+            quantity: "0".into(),
+            copy_side: "BUY".into(),
+            outcome: "Yes".into(),
+            resolved_outcome: String::new(),
+            diff_pct: "0".into(),
+            estimated_total_fee_usd: "0".into(),
+            settled: true,
+            pnl: "0".into(),
+        };
+        let cutoff = chrono::DateTime::parse_from_rfc3339("2025-02-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let rows = vec![
+            row(1, "2025-03-01T00:00:00Z"),
+            row(2, "2025-01-01T00:00:00Z"),
+            row(3, "2025-01-02T00:00:00Z"),
+        ];
+        // The seq file may lag behind the db (e.g. a db written before it existed).
+        let (pruned, kept, seq) = split_for_compaction(rows, cutoff, 0);
+        assert_eq!(pruned.iter().map(|r| r.id).collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(seq, 3);
+
+        let next = next_db_id(&kept, seq);
+        assert!(pruned.iter().all(|r| next > r.id));
+        assert_eq!(next, 4);
+        assert_eq!(next_db_id(&[], seq), 4);
+
+        let (_, _, seq) = split_for_compaction(kept, cutoff, 7);
+        assert_eq!(seq, 7);
+    }
+
+    #[test]
+    fn seen_trade_keys_evicts_oldest_past_cap() {
+        let mut seen = SeenTradeKeys::default();
+        for i in 0..=MAX_SEEN_TRADE_KEYS {
+            seen.insert(format!("k{i}"));
+        }
+        assert!(!seen.contains("k0"));
+        assert!(seen.contains("k1"));
+        assert!(seen.contains(&format!("k{MAX_SEEN_TRADE_KEYS}")));
+    }
+
+    fn leader_trade(
+        asset: &str,
+        outcome: &str,
+    ) -> polymarket_client_sdk::data::types::response::Trade {
+        serde_json::from_value(serde_json::json!({
+            "proxyWallet": "0x56687bf447db6ffa42ffe2204a05edaa20f55839",
+            "side": "BUY",
+            "asset": asset,
+            "conditionId": "0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917",
+            "size": 10,
+            "price": 0.5,
+            "timestamp": 1_700_000_000,
+            "title": "Batch",
+            "slug": "batch-market",
+            "icon": "",
+            "eventSlug": "batch",
+            "outcome": outcome,
+            "outcomeIndex": 0,
+            "name": "",
+            "pseudonym": "",
+            "bio": "",
+            "profileImage": "",
+            "profileImageOptimized": "",
+            "transactionHash": "0x1111111111111111111111111111111111111111111111111111111111111111"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn fills_batched_in_one_tx_get_distinct_keys() {
+        let yes = leader_trade("101", "Yes");
+        let no = leader_trade("202", "No");
+        assert_eq!(yes.transaction_hash, no.transaction_hash);
+
+        let mut seen = SeenTradeKeys::default();
+        let recorded: Vec<_> = [&yes, &no, &yes]
+            .into_iter()
+            .map(trade_event_key)
+            .filter(|key| seen.claim(key, false))
+            .collect();
+        assert_eq!(recorded.len(), 2, "one movement per asset: {recorded:?}");
+
+        let sell = trade_event_key(&leader_trade("303", "Yes"));
+        assert!(seen.claim(&sell, true));
+        assert!(seen.claim(&sell, true), "unrecorded sells are retried");
+    }
+
     #[test]
     fn oldest_unsettled_db_row_selects_lowest_id_not_settled() {
         let rows = vec![
             DbRow {
                 id: 2,
                 movement_id: "b".into(),
+                leader: String::new(),
+                paper: false,
                 market: "m2".into(),
+                asset: String::new(),
                 timestamp: "2025-01-01T00:00:01Z".into(),
                 leader_value: "10".into(),
                 leader_price: "0".into(),
@@ -3594,7 +8564,10 @@ mod tests {
             DbRow {
                 id: 1,
                 movement_id: "a".into(),
+                leader: String::new(),
+                paper: false,
                 market: "m1".into(),
+                asset: String::new(),
                 timestamp: "2025-01-01T00:00:00Z".into(),
                 leader_value: "10".into(),
                 leader_price: "0".into(),
@@ -3612,7 +8585,10 @@ mod tests {
             DbRow {
                 id: 3,
                 movement_id: "c".into(),
+                leader: String::new(),
+                paper: false,
                 market: "m3".into(),
+                asset: String::new(),
                 timestamp: "2025-01-01T00:00:02Z".into(),
                 leader_value: "10".into(),
                 leader_price: "0".into(),
@@ -3639,7 +8615,10 @@ mod tests {
         let mut rows = vec![DbRow {
             id: 1,
             movement_id: "m1".into(),
+            leader: String::new(),
+            paper: false,
             market: "mkt".into(),
+            asset: String::new(),
             timestamp: "2026-01-01T00:00:00Z".into(),
             leader_value: "10".into(),
             leader_price: "0.5".into(),
@@ -3678,36 +8657,27 @@ mod tests {
                 MovementRecord {
                     movement_id: "m1".into(),
                     market: "btc-updown-5m-1772278200".into(),
+                    asset: String::new(),
                     timestamp: "2025-01-01T00:00:00Z".into(),
-                    leader_value: d("100"),
                     leader_price: Decimal::ZERO,
                     copied_value: d("10"),
                     simulated_copy_price: Decimal::ZERO,
                     quantity: Decimal::ZERO,
                     copy_side: "unknown".into(),
-                    outcome: "Yes".into(),
-                    resolved_outcome: String::new().into(),
-                    diff_pct: Decimal::ZERO,
-                    estimated_total_fee_usd: Decimal::ZERO,
-                    settled: false,
-                    pnl: Decimal::ZERO,
+                    ..test_movement()
                 },
                 MovementRecord {
                     movement_id: "m2".into(),
                     market: "btc-updown-5m-1772278300".into(),
+                    asset: String::new(),
                     timestamp: "2025-01-01T00:05:00Z".into(),
-                    leader_value: d("100"),
                     leader_price: Decimal::ZERO,
                     copied_value: d("8"),
                     simulated_copy_price: Decimal::ZERO,
                     quantity: Decimal::ZERO,
                     copy_side: "unknown".into(),
                     outcome: "No".into(),
-                    resolved_outcome: String::new(),
-                    diff_pct: Decimal::ZERO,
-                    estimated_total_fee_usd: Decimal::ZERO,
-                    settled: false,
-                    pnl: Decimal::ZERO,
+                    ..test_movement()
                 },
             ],
         };
@@ -3754,7 +8724,7 @@ mod tests {
         ]))
         .unwrap();
 
-        let settled = settle_open_movements_from_closed_positions(&mut state, &closed);
+        let settled = settle_open_movements_from_closed_positions(&mut state, &closed, |_| true);
         assert_eq!(settled.len(), 2);
         assert_eq!(state.movements[0].pnl, d("-2"));
         assert_eq!(state.movements[1].pnl, d("1.6"));
@@ -3769,36 +8739,27 @@ mod tests {
                 MovementRecord {
                     movement_id: "yes-mov".into(),
                     market: "btc-updown-5m-1772278200".into(),
+                    asset: String::new(),
                     timestamp: "2025-01-01T00:00:00Z".into(),
-                    leader_value: d("100"),
                     leader_price: Decimal::ZERO,
                     copied_value: d("10"),
                     simulated_copy_price: Decimal::ZERO,
                     quantity: Decimal::ZERO,
                     copy_side: "buy".into(),
-                    outcome: "Yes".into(),
-                    resolved_outcome: String::new(),
-                    diff_pct: Decimal::ZERO,
-                    estimated_total_fee_usd: Decimal::ZERO,
-                    settled: false,
-                    pnl: Decimal::ZERO,
+                    ..test_movement()
                 },
                 MovementRecord {
                     movement_id: "no-mov".into(),
                     market: "btc-updown-5m-1772278300".into(),
+                    asset: String::new(),
                     timestamp: "2025-01-01T00:01:00Z".into(),
-                    leader_value: d("100"),
                     leader_price: Decimal::ZERO,
                     copied_value: d("10"),
                     simulated_copy_price: Decimal::ZERO,
                     quantity: Decimal::ZERO,
                     copy_side: "buy".into(),
                     outcome: "No".into(),
-                    resolved_outcome: String::new(),
-                    diff_pct: Decimal::ZERO,
-                    estimated_total_fee_usd: Decimal::ZERO,
-                    settled: false,
-                    pnl: Decimal::ZERO,
+                    ..test_movement()
                 },
             ],
         };
@@ -3846,7 +8807,7 @@ mod tests {
         ]))
         .unwrap();
 
-        let settled = settle_open_movements_from_closed_positions(&mut state, &closed);
+        let settled = settle_open_movements_from_closed_positions(&mut state, &closed, |_| true);
         assert_eq!(settled.len(), 2);
 
         // Must match by outcome, not just slug/order.
@@ -3858,6 +8819,266 @@ mod tests {
         assert_eq!(state.movements[1].pnl, d("5"));
     }
 
+    #[test]
+    fn one_leaders_closed_position_only_settles_its_own_copies() {
+        use polymarket_client_sdk::data::types::response::ClosedPosition;
+
+        let a = "0x00000000000000000000000000000000000000aa";
+        let b = "0x00000000000000000000000000000000000000bb";
+        let cfg = CopyConfig {
+            leaders: vec![LeaderConfig::new(a), LeaderConfig::new(b)],
+            ..test_cfg()
+        };
+        let open = |id: &str, leader: &str| MovementRecord {
+            movement_id: id.into(),
+            leader: leader.into(),
+            quantity: Decimal::ZERO,
+            ..test_movement()
+        };
+        // The untagged copy belongs to the primary leader, A.
+        let mut state = CopyState {
+            movements: vec![open("a-copy", a), open("b-copy", b), open("legacy", "")],
+        };
+        let closed: Vec<ClosedPosition> = serde_json::from_value(serde_json::json!([{
+            "proxyWallet": b,
+            "asset": "123",
+            "conditionId": "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "avgPrice": "0.5",
+            "totalBought": "10",
+            "realizedPnl": "5",
+            "curPrice": "1",
+            "timestamp": 0,
+            "title": "t",
+            "slug": "mkt",
+            "icon": "",
+            "eventSlug": "e",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "oppositeOutcome": "No",
+            "oppositeAsset": "2",
+            "endDate": "2025-01-01T00:00:00Z"
+        }]))
+        .unwrap();
+
+        let user = crate::commands::parse_address(b).unwrap();
+        let leader = settlement_leader(&cfg, user).unwrap();
+        assert_eq!(leader, b);
+        let settled = settle_open_movements_from_closed_positions(&mut state, &closed, |m| {
+            movement_leader_is(m, &cfg, leader)
+        });
+        assert_eq!(settled.len(), 1);
+        assert_eq!(settled[0].movement_id, "b-copy");
+        assert!(!state.movements[0].settled && !state.movements[2].settled);
+
+        let wallet =
+            crate::commands::parse_address("0x00000000000000000000000000000000000000cc").unwrap();
+        assert_eq!(settlement_leader(&cfg, wallet), None);
+    }
+
+    #[test]
+    fn settle_resolves_unlabelled_movement_outcome_from_token_id() {
+        use polymarket_client_sdk::data::types::response::ClosedPosition;
+
+        let closed_for = |asset: &str, outcome: &str, opposite: &str, pnl: &str| {
+            serde_json::json!({
+                "proxyWallet": "0x0000000000000000000000000000000000000001",
+                "asset": asset,
+                "conditionId": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                "avgPrice": "0.5",
+                "totalBought": "10",
+                "realizedPnl": pnl,
+                "curPrice": "0",
+                "timestamp": 1735689660,
+                "title": "t",
+                "slug": "mkt",
+                "icon": "",
+                "eventSlug": "e",
+                "outcome": outcome,
+                "outcomeIndex": 0,
+                "oppositeOutcome": opposite,
+                "oppositeAsset": "0",
+                "endDate": "2025-01-01T00:00:00Z"
+            })
+        };
+        let closed: Vec<ClosedPosition> = serde_json::from_value(serde_json::json!([
+            closed_for("111", "Yes", "No", "5"),
+            closed_for("222", "No", "Yes", "-3"),
+        ]))
+        .unwrap();
+        let unlabelled = |id: &str, asset: &str| MovementRecord {
+            movement_id: id.into(),
+            market: "mkt".into(),
+            asset: asset.into(),
+            timestamp: "2025-01-01T00:00:00Z".into(),
+            copied_value: d("10"),
+            quantity: d("10"),
+            outcome: String::new(),
+            ..open_copy("BUY", "0.5")
+        };
+        let mut state = CopyState {
+            movements: vec![unlabelled("no-mov", "222"), unlabelled("yes-mov", "111")],
+        };
+
+        let settled = settle_open_movements_from_closed_positions(&mut state, &closed, |_| true);
+        assert_eq!(settled.len(), 2);
+        assert_eq!(state.movements[0].resolved_outcome, "No");
+        assert_eq!(state.movements[0].pnl, d("-3"));
+        assert_eq!(state.movements[1].resolved_outcome, "Yes");
+        assert_eq!(state.movements[1].pnl, d("5"));
+    }
+
+    #[test]
+    fn partial_close_settles_proportional_share_then_the_rest() {
+        use polymarket_client_sdk::data::types::response::ClosedPosition;
+
+        let closed_at = |ts: i64, bought: &str, pnl: &str| {
+            serde_json::json!({
+                "proxyWallet": "0x0000000000000000000000000000000000000001",
+                "asset": "123",
+                "conditionId": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                "avgPrice": "0.4",
+                "totalBought": bought,
+                "realizedPnl": pnl,
+                "curPrice": "0",
+                "timestamp": ts,
+                "title": "t",
+                "slug": "mkt",
+                "icon": "",
+                "eventSlug": "e",
+                "outcome": "Yes",
+                "outcomeIndex": 0,
+                "oppositeOutcome": "No",
+                "oppositeAsset": "0",
+                "endDate": "2026-03-02T00:00:00Z"
+            })
+        };
+        // open_copy: leader bought 250 shares, 20 USD copied.
+        let mut state = CopyState {
+            movements: vec![open_copy("BUY", "0.4")],
+        };
+        let first = closed_at(1_772_400_000, "125", "25");
+        let half: Vec<ClosedPosition> =
+            serde_json::from_value(serde_json::json!([first.clone()])).unwrap();
+
+        let settled = settle_open_movements_from_closed_positions(&mut state, &half, |_| true);
+        assert!(settled.is_empty());
+        let m = &state.movements[0];
+        assert!(!m.settled);
+        assert_eq!(m.remaining_quantity, Some(d("125")));
+        // Half the copy (10 USD) at the closure's 20% ROI.
+        assert_eq!(m.pnl, d("2"));
+
+        // The same closed position comes back on the next sync: no change.
+        let settled = settle_open_movements_from_closed_positions(&mut state, &half, |_| true);
+        assert!(settled.is_empty());
+        assert_eq!(state.movements[0].pnl, d("2"));
+        assert_eq!(state.movements[0].remaining_quantity, Some(d("125")));
+
+        let both: Vec<ClosedPosition> = serde_json::from_value(serde_json::json!([
+            first,
+            closed_at(1_772_500_000, "125", "-12.5")
+        ]))
+        .unwrap();
+        let settled = settle_open_movements_from_closed_positions(&mut state, &both, |_| true);
+        assert_eq!(settled.len(), 1);
+        let m = &state.movements[0];
+        assert!(m.settled);
+        assert_eq!(m.remaining_quantity, Some(Decimal::ZERO));
+        // Plus the other 10 USD at -10%.
+        assert_eq!(m.pnl, d("1"));
+        assert_eq!(m.copy_side, "sell");
+    }
+
+    #[test]
+    fn settle_matches_three_outcome_closed_positions_by_outcome() {
+        use polymarket_client_sdk::data::types::response::ClosedPosition;
+
+        let movement = |id: &str, outcome: &str| MovementRecord {
+            movement_id: id.into(),
+            market: "election-winner".into(),
+            asset: String::new(),
+            timestamp: "2025-01-01T00:00:00Z".into(),
+            leader_price: Decimal::ZERO,
+            copied_value: d("10"),
+            simulated_copy_price: Decimal::ZERO,
+            quantity: Decimal::ZERO,
+            copy_side: "buy".into(),
+            outcome: outcome.into(),
+            ..test_movement()
+        };
+        let mut state = CopyState {
+            movements: vec![
+                movement("a", "Alice"),
+                movement("b", "Bob"),
+                movement("c", "carol"),
+            ],
+        };
+
+        let closed_entry = |asset: &str, outcome: &str, idx: u32, pnl: &str| {
+            serde_json::json!({
+                "proxyWallet": "0x0000000000000000000000000000000000000001",
+                "asset": asset,
+                "conditionId": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                "avgPrice": "0.3",
+                "totalBought": "10",
+                "realizedPnl": pnl,
+                "curPrice": "0",
+                "timestamp": 1735689660,
+                "title": "t",
+                "slug": "election-winner",
+                "icon": "",
+                "eventSlug": "e",
+                "outcome": outcome,
+                "outcomeIndex": idx,
+                "oppositeOutcome": "",
+                "oppositeAsset": "0",
+                "endDate": "2025-01-01T00:00:00Z"
+            })
+        };
+        // Deliberately out of order and with different label casing.
+        let closed: Vec<ClosedPosition> = serde_json::from_value(serde_json::json!([
+            closed_entry("3", "Carol", 2, "-10"),
+            closed_entry("1", "Alice", 0, "20"),
+            closed_entry("2", "Bob", 1, "-10"),
+        ]))
+        .unwrap();
+
+        let settled = settle_open_movements_from_closed_positions(&mut state, &closed, |_| true);
+        assert_eq!(settled.len(), 3);
+        assert_eq!(state.movements[0].resolved_outcome, "Alice");
+        assert_eq!(state.movements[0].pnl, d("20"));
+        assert_eq!(state.movements[1].resolved_outcome, "Bob");
+        assert_eq!(state.movements[1].pnl, d("-10"));
+        assert_eq!(state.movements[2].resolved_outcome, "Carol");
+        assert_eq!(state.movements[2].pnl, d("-10"));
+    }
+
+    #[test]
+    fn resolved_three_outcome_market_pays_only_winning_outcome() {
+        let movement = |id: &str, outcome: &str| MovementRecord {
+            movement_id: id.into(),
+            market: "election-winner".into(),
+            asset: String::new(),
+            timestamp: "2025-01-01T00:00:00Z".into(),
+            leader_price: d("0.25"),
+            copied_value: d("10"),
+            simulated_copy_price: d("0.25"),
+            quantity: d("40"),
+            copy_side: "buy".into(),
+            outcome: outcome.into(),
+            ..test_movement()
+        };
+        let mut state = CopyState {
+            movements: vec![movement("a", "Alice"), movement("b", "Bob")],
+        };
+        let resolved = HashMap::from([("election-winner".to_string(), "bob".to_string())]);
+
+        let settled = settle_open_buys_from_resolved_markets(&mut state, &resolved);
+        assert_eq!(settled.len(), 2);
+        assert_eq!(state.movements[0].pnl, d("-10"));
+        assert!(state.movements[1].pnl > Decimal::ZERO);
+    }
+
     #[test]
     fn settle_allows_unknown_closed_timestamp_zero() {
         use polymarket_client_sdk::data::types::response::ClosedPosition;
@@ -3866,6 +9087,7 @@ mod tests {
             movements: vec![MovementRecord {
                 movement_id: "m-zero-ts".into(),
                 market: "eth-updown-5m-1772281500".into(),
+                asset: String::new(),
                 timestamp: "2026-02-28T12:30:00Z".into(),
                 leader_value: d("20"),
                 leader_price: Decimal::ZERO,
@@ -3873,12 +9095,7 @@ mod tests {
                 simulated_copy_price: Decimal::ZERO,
                 quantity: Decimal::ZERO,
                 copy_side: "buy".into(),
-                outcome: "Yes".into(),
-                resolved_outcome: String::new(),
-                diff_pct: Decimal::ZERO,
-                estimated_total_fee_usd: Decimal::ZERO,
-                settled: false,
-                pnl: Decimal::ZERO,
+                ..test_movement()
             }],
         };
 
@@ -3905,7 +9122,7 @@ mod tests {
         ]))
         .unwrap();
 
-        let settled = settle_open_movements_from_closed_positions(&mut state, &closed);
+        let settled = settle_open_movements_from_closed_positions(&mut state, &closed, |_| true);
         assert_eq!(settled.len(), 1);
         assert!(state.movements[0].settled);
         assert_eq!(state.movements[0].pnl, d("1"));
@@ -3919,6 +9136,7 @@ mod tests {
             movements: vec![MovementRecord {
                 movement_id: "m-fallback".into(),
                 market: "eth-updown-5m-1772281500".into(),
+                asset: String::new(),
                 timestamp: "2026-02-28T12:30:00Z".into(),
                 leader_value: d("20"),
                 leader_price: Decimal::ZERO,
@@ -3926,12 +9144,7 @@ mod tests {
                 simulated_copy_price: Decimal::ZERO,
                 quantity: Decimal::ZERO,
                 copy_side: "buy".into(),
-                outcome: "Yes".into(),
-                resolved_outcome: String::new(),
-                diff_pct: Decimal::ZERO,
-                estimated_total_fee_usd: Decimal::ZERO,
-                settled: false,
-                pnl: Decimal::ZERO,
+                ..test_movement()
             }],
         };
 
@@ -3958,7 +9171,7 @@ mod tests {
         ]))
         .unwrap();
 
-        let settled = settle_open_movements_from_closed_positions(&mut state, &closed);
+        let settled = settle_open_movements_from_closed_positions(&mut state, &closed, |_| true);
         assert_eq!(settled.len(), 1);
         assert!(state.movements[0].settled);
         assert_eq!(state.movements[0].pnl, d("1"));
@@ -3972,6 +9185,7 @@ mod tests {
             movements: vec![MovementRecord {
                 movement_id: "m-new".into(),
                 market: "eth-updown-5m-1772281500".into(),
+                asset: String::new(),
                 timestamp: "2026-02-28T12:30:00Z".into(),
                 leader_value: d("20"),
                 leader_price: Decimal::ZERO,
@@ -3979,12 +9193,7 @@ mod tests {
                 simulated_copy_price: Decimal::ZERO,
                 quantity: Decimal::ZERO,
                 copy_side: "buy".into(),
-                outcome: "Yes".into(),
-                resolved_outcome: String::new(),
-                diff_pct: Decimal::ZERO,
-                estimated_total_fee_usd: Decimal::ZERO,
-                settled: false,
-                pnl: Decimal::ZERO,
+                ..test_movement()
             }],
         };
 
@@ -4011,7 +9220,7 @@ mod tests {
         ]))
         .unwrap();
 
-        let settled = settle_open_movements_from_closed_positions(&mut state, &closed);
+        let settled = settle_open_movements_from_closed_positions(&mut state, &closed, |_| true);
         assert!(settled.is_empty());
         assert!(!state.movements[0].settled);
     }
@@ -4020,7 +9229,7 @@ mod tests {
         let movements = vec![
             MovementRecord {
                 movement_id: "m1".into(),
-                market: "mkt".into(),
+                asset: String::new(),
                 timestamp: "2026-02-28T12:01:00Z".into(),
                 leader_value: d("10"),
                 leader_price: d("0.5"),
@@ -4028,16 +9237,13 @@ mod tests {
                 simulated_copy_price: d("0.52"),
                 quantity: d("10"),
                 copy_side: "buy".into(),
-                outcome: "Yes".into(),
-                resolved_outcome: String::new(),
-                diff_pct: Decimal::ZERO,
-                estimated_total_fee_usd: Decimal::ZERO,
                 settled: true,
                 pnl: d("1.5"),
+                ..test_movement()
             },
             MovementRecord {
                 movement_id: "m2".into(),
-                market: "mkt".into(),
+                asset: String::new(),
                 timestamp: "2026-02-28T12:40:00Z".into(),
                 leader_value: d("10"),
                 leader_price: d("0.5"),
@@ -4045,16 +9251,13 @@ mod tests {
                 simulated_copy_price: d("0.52"),
                 quantity: d("10"),
                 copy_side: "buy".into(),
-                outcome: "Yes".into(),
-                resolved_outcome: String::new(),
-                diff_pct: Decimal::ZERO,
-                estimated_total_fee_usd: Decimal::ZERO,
                 settled: true,
                 pnl: d("0.5"),
+                ..test_movement()
             },
             MovementRecord {
                 movement_id: "m3".into(),
-                market: "mkt".into(),
+                asset: String::new(),
                 timestamp: "2026-02-28T13:10:00Z".into(),
                 leader_value: d("10"),
                 leader_price: d("0.5"),
@@ -4062,12 +9265,9 @@ mod tests {
                 simulated_copy_price: d("0.52"),
                 quantity: d("10"),
                 copy_side: "buy".into(),
-                outcome: "Yes".into(),
-                resolved_outcome: String::new(),
-                diff_pct: Decimal::ZERO,
-                estimated_total_fee_usd: Decimal::ZERO,
                 settled: true,
                 pnl: d("2"),
+                ..test_movement()
             },
         ];
 
@@ -4082,7 +9282,7 @@ mod tests {
     fn daily_series_uses_net_pnl_after_fees() {
         let movements = vec![MovementRecord {
             movement_id: "m-net".into(),
-            market: "mkt".into(),
+            asset: String::new(),
             timestamp: "2026-02-28T12:01:00Z".into(),
             leader_value: d("10"),
             leader_price: d("0.5"),
@@ -4090,12 +9290,10 @@ mod tests {
             simulated_copy_price: d("0.52"),
             quantity: d("10"),
             copy_side: "buy".into(),
-            outcome: "Yes".into(),
-            resolved_outcome: String::new(),
-            diff_pct: Decimal::ZERO,
             estimated_total_fee_usd: d("0.2"),
             settled: true,
             pnl: d("1.0"),
+            ..test_movement()
         }];
 
         let series = daily_pnl_series(&movements);
@@ -4105,44 +9303,1040 @@ mod tests {
     }
     #[test]
     fn plan_uses_current_equity_after_settled_pnl_and_fees() {
-        let cfg = CopyConfig {
-            leader: "0x1".into(),
-            allocated_funds: d("1000"),
-            max_trade_pct: d("10"),
-            max_total_exposure_pct: d("50"),
-            min_copy_usd: d("1"),
-            poll_interval_secs: 2,
-            poll_interval_ms: 2000,
-            risk_level: RiskLevel::Balanced,
-            execute_orders: false,
-            realtime_mode: false,
-            simulation_mode: false,
-        };
+        let cfg = test_cfg();
         let state = CopyState {
             movements: vec![MovementRecord {
                 movement_id: "s1".into(),
-                market: "mkt".into(),
-                timestamp: "2026-03-01T10:00:00Z".into(),
-                leader_value: d("100"),
+                asset: String::new(),
                 leader_price: d("0.5"),
                 copied_value: d("50"),
-                simulated_copy_price: d("0.5"),
                 quantity: d("100"),
                 copy_side: "buy".into(),
-                outcome: "Yes".into(),
                 resolved_outcome: "Yes".into(),
-                diff_pct: Decimal::ZERO,
                 estimated_total_fee_usd: d("10"),
                 settled: true,
                 pnl: d("210"),
+                ..test_movement()
             }],
         };
 
-        let plan = compute_plan(&cfg, &state, d("1000"), d("200")).unwrap();
+        let plan = compute_plan(
+            &cfg,
+            &state,
+            cfg.primary_leader(),
+            "m",
+            d("1000"),
+            d("200"),
+            Decimal::ZERO,
+        )
+        .unwrap();
         // Equity = 1000 + (210 - 10) = 1200; proportional = 200 * 1.2 = 240
         // max_trade = 120 and max_total_exposure = 600, so capped = 120.
         assert_eq!(plan.proportional_size, d("240"));
         assert_eq!(plan.capped_size, d("120"));
         assert_eq!(plan.available_funds, d("600"));
     }
+
+    #[test]
+    fn decisions_log_round_trips_and_filters() {
+        let dir = std::env::temp_dir().join(format!("polymarket-decisions-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("decisions.jsonl");
+        let cfg = exit_cfg(None, None);
+        let candidate = TradeCandidate {
+            leader: "0x1",
+            leader_value: d("1000"),
+            market: "mkt",
+            outcome: "Yes",
+            price: d("0.5"),
+            size: d("20"),
+            is_sell: false,
+        };
+        let plan = compute_plan(
+            &cfg,
+            &CopyState::default(),
+            "0x1",
+            "m",
+            d("1000"),
+            d("10"),
+            Decimal::ZERO,
+        )
+        .unwrap();
+        let skipped = DecisionRecord::new("sim", &candidate, &plan, None, "no liquidity", false);
+        let copied = DecisionRecord::new("sim", &candidate, &plan, None, plan.reason.clone(), true);
+        for decision in [&skipped, &copied, &skipped] {
+            let line = serde_json::to_string(decision).unwrap();
+            write_rotating_log(&path, &format!("{line}\n"), u64::MAX).unwrap();
+        }
+        fs::write(&path, fs::read_to_string(&path).unwrap() + "not json\n").unwrap();
+
+        let all = read_decisions(&path).unwrap();
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[0], skipped);
+        assert_eq!(all[0].leader_usd, d("10"));
+        assert_eq!(all[0].capped_size, plan.capped_size);
+
+        assert_eq!(
+            latest_decisions(all.clone(), 2, false),
+            vec![copied.clone(), skipped]
+        );
+        assert_eq!(latest_decisions(all, 10, true), vec![copied]);
+        assert!(
+            read_decisions(&dir.join("missing.jsonl"))
+                .unwrap()
+                .is_empty()
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn settlement_log_rotates_past_the_size_threshold() {
+        let dir =
+            std::env::temp_dir().join(format!("polymarket-log-rotation-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("settlements.log");
+
+        write_rotating_log(&path, "first\n", 10).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "first\n");
+
+        write_rotating_log(&path, "second\n", 10).unwrap();
+        assert!(!path.exists() || fs::read_to_string(&path).unwrap().is_empty());
+        assert_eq!(
+            fs::read_to_string(rotated_log_path(&path, 1)).unwrap(),
+            "first\nsecond\n"
+        );
+
+        write_rotating_log(&path, "third line!\n", 10).unwrap();
+        assert_eq!(
+            fs::read_to_string(rotated_log_path(&path, 1)).unwrap(),
+            "third line!\n"
+        );
+        assert_eq!(
+            fs::read_to_string(rotated_log_path(&path, 2)).unwrap(),
+            "first\nsecond\n"
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    fn settlement_line(ts: &str, market: &str, pnl: &str) -> String {
+        format!(
+            "{ts}\tmode=real\tmovement_id=m-{market}\tmarket={market}\tside=BUY\toutcome=Yes\tresolved_outcome=Yes\tleader_price=0.5\tsimulated_copy_price=0.51\tquantity=10\tcopied_value=5\testimated_total_fee_usd=0.1\tpnl={pnl}\n"
+        )
+    }
+
+    #[test]
+    fn settlement_log_parser_ignores_a_truncated_last_line() {
+        let full = settlement_line("2026-03-01T10:00:00+00:00", "a", "1.5");
+        let cut = settlement_line("2026-03-02T10:00:00+00:00", "b", "2");
+        let content = format!("{full}not a settlement\n{}", &cut[..cut.len() - 8]);
+        let entries = parse_settlement_log(&content);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].market, "a");
+        assert_eq!(entries[0].pnl, d("1.5"));
+        assert_eq!(entries[0].side, "BUY");
+    }
+
+    #[test]
+    fn settlements_sort_by_pnl_and_group_by_market() {
+        let content = [
+            settlement_line("2026-03-01T10:00:00+00:00", "a", "1"),
+            settlement_line("2026-03-02T10:00:00+00:00", "b", "-2"),
+            settlement_line("2026-03-03T10:00:00+00:00", "a", "3"),
+        ]
+        .concat();
+        let mut entries = parse_settlement_log(&content);
+        sort_settlements(&mut entries, SettlementSort::Pnl);
+        let pnls: Vec<Decimal> = entries.iter().map(|e| e.pnl).collect();
+        assert_eq!(pnls, vec![d("3"), d("1"), d("-2")]);
+
+        let groups = group_settlements_by_market(entries);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].market, "a");
+        assert_eq!(groups[0].count, 2);
+        assert_eq!(groups[0].pnl, d("4"));
+        assert_eq!(groups[0].copied_value, d("10"));
+        assert_eq!(groups[1].pnl, d("-2"));
+    }
+
+    struct ChunkedReader {
+        chunks: Vec<Vec<u8>>,
+    }
+
+    impl Read for ChunkedReader {
+        fn read(&mut self, out: &mut [u8]) -> std::io::Result<usize> {
+            if self.chunks.is_empty() {
+                return Ok(0);
+            }
+            let chunk = self.chunks.remove(0);
+            out[..chunk.len()].copy_from_slice(&chunk);
+            Ok(chunk.len())
+        }
+    }
+
+    #[test]
+    fn read_http_request_reassembles_body_split_across_writes() {
+        let body = format!("{{\"leader\":\"0x1\",\"note\":\"{}\"}}", "x".repeat(200));
+        let request = format!(
+            "POST /api/config HTTP/1.1\r\nHost: x\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        );
+        let split = request.len() - 120;
+        let mut reader = ChunkedReader {
+            chunks: vec![
+                request.as_bytes()[..split].to_vec(),
+                request.as_bytes()[split..].to_vec(),
+            ],
+        };
+        let got = read_http_request(&mut reader).unwrap();
+        assert_eq!(got.split("\r\n\r\n").nth(1), Some(body.as_str()));
+
+        let truncated = &request.as_bytes()[..split];
+        let mut reader = ChunkedReader {
+            chunks: vec![truncated.to_vec()],
+        };
+        assert!(read_http_request(&mut reader).is_err());
+    }
+
+    #[test]
+    fn parse_since_finds_the_key_anywhere_in_the_query() {
+        assert_eq!(parse_since("since=42"), 42);
+        assert_eq!(parse_since("token=x&since=42"), 42);
+        assert_eq!(parse_since("token=x"), 0);
+        assert_eq!(parse_since("since=abc"), 0);
+        assert_eq!(parse_since(""), 0);
+    }
+
+    #[test]
+    fn monitor_state_roundtrips_and_respects_mode() {
+        let dir =
+            std::env::temp_dir().join(format!("polymarket-monitor-state-{}", std::process::id()));
+        let path = dir.join("copy_trader_monitor.json");
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(
+            load_monitor_state_from(&path).unwrap(),
+            MonitorState::default()
+        );
+
+        let state = MonitorState {
+            monitoring: true,
+            current_poll_interval_ms: 2_500,
+            simulation_mode: true,
+            disabled_leaders: BTreeSet::from(["0xaaa".to_string()]),
+            leader_reenabled_at: HashMap::from([(
+                "0xbbb".to_string(),
+                "2026-03-01T00:00:00Z".to_string(),
+            )]),
+        };
+        save_monitor_state_to(&path, &state).unwrap();
+        let loaded = load_monitor_state_from(&path).unwrap();
+        assert_eq!(loaded, state);
+
+        let mut cfg = exit_cfg(None, None);
+        cfg.simulation_mode = true;
+        assert!(loaded.should_resume(Some(&cfg)));
+        cfg.simulation_mode = false;
+        assert!(!loaded.should_resume(Some(&cfg)));
+        assert!(!loaded.should_resume(None));
+        let mut runtime = new_runtime_state(None);
+        loaded.restore_leaders(&mut runtime);
+        assert!(runtime.disabled_leaders.contains("0xaaa"));
+        assert_eq!(runtime.leader_reenabled_at["0xbbb"], "2026-03-01T00:00:00Z");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn replay_resizes_history_under_new_settings() {
+        let cfg = exit_cfg(None, None);
+        let mut winner = open_copy("BUY", "0.4");
+        winner.settled = true;
+        winner.pnl = d("10");
+        let mut tiny = open_copy("BUY", "0.4");
+        tiny.movement_id = "x2".into();
+        tiny.timestamp = "2026-03-01T11:00:00Z".into();
+        tiny.leader_value = d("0.5");
+        let mut other = open_copy("BUY", "0.4");
+        other.movement_id = "x3".into();
+        other.leader = "0x9".into();
+
+        let history = vec![tiny, winner, other];
+        let summary = replay_history(&cfg, &FeeSchedule::default(), &history, d("1000")).unwrap();
+        assert_eq!(summary.movements, 3);
+        assert_eq!(summary.copied, 1);
+        assert_eq!(summary.skipped, 2);
+        // max_trade = 10% of 1000 caps the 100 leader movement at 100 (5x the
+        // recorded 20), so the recorded +10 scales to +50.
+        assert_eq!(summary.total_exposure, d("100"));
+        assert_eq!(summary.gross_pnl, d("50"));
+        assert_eq!(summary.net_pnl, d("50"));
+        assert_eq!(summary.skip_reasons["leader not configured"], 1);
+        assert_eq!(summary.skip_reasons["below minimum copy threshold"], 1);
+    }
+
+    /// Accepts the response headers, then fails like a socket whose peer left.
+    struct HangsUpAfterHeaders {
+        wrote_headers: bool,
+    }
+
+    impl Write for HangsUpAfterHeaders {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            if self.wrote_headers {
+                return Err(std::io::ErrorKind::BrokenPipe.into());
+            }
+            self.wrote_headers = true;
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn stream_event(mode: StorageMode, id: i64) -> MovementEvent {
+        MovementEvent {
+            mode,
+            movement: DbMovement {
+                id,
+                movement_id: format!("m{id}"),
+                leader: String::new(),
+                paper: false,
+                market: "mkt".into(),
+                asset: String::new(),
+                timestamp: "2026-03-01T10:00:00Z".into(),
+                leader_value: "10".into(),
+                leader_price: "0.5".into(),
+                copied_value: "5".into(),
+                simulated_copy_price: "0".into(),
+                quantity: "10".into(),
+                copy_side: "BUY".into(),
+                outcome: "Yes".into(),
+                resolved_outcome: String::new(),
+                diff_pct: "0".into(),
+                estimated_total_fee_usd: "0".into(),
+                settled: false,
+                pnl: "0".into(),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn shutdown_waits_for_the_tick_in_flight() {
+        let app = UiAppState {
+            runtime: Arc::new(Mutex::new(new_runtime_state(None))),
+            tick: Arc::default(),
+            sync_tasks: Arc::default(),
+            wallet: Arc::default(),
+        };
+        app.runtime.lock().await.monitoring = true;
+        let tick = Arc::clone(&app.tick).lock_owned().await;
+        let finished = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let finished_in_tick = Arc::clone(&finished);
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            finished_in_tick.store(true, std::sync::atomic::Ordering::SeqCst);
+            drop(tick);
+        });
+
+        shutdown_monitor(&app, false).await;
+        assert!(finished.load(std::sync::atomic::Ordering::SeqCst));
+        assert!(!app.runtime.lock().await.monitoring);
+    }
+
+    #[tokio::test]
+    async fn shutdown_waits_for_spawned_syncs() {
+        let app = UiAppState {
+            runtime: Arc::new(Mutex::new(new_runtime_state(None))),
+            tick: Arc::default(),
+            sync_tasks: Arc::default(),
+            wallet: Arc::default(),
+        };
+        let finished = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let finished_in_sync = Arc::clone(&finished);
+        app.spawn_sync(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            finished_in_sync.store(true, std::sync::atomic::Ordering::SeqCst);
+        })
+        .await;
+
+        shutdown_monitor(&app, false).await;
+        assert!(finished.load(std::sync::atomic::Ordering::SeqCst));
+        assert!(app.sync_tasks.lock().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn stream_movements_sends_active_mode_frames_and_stops_on_disconnect() {
+        let app = UiAppState {
+            runtime: Arc::new(Mutex::new(new_runtime_state(None))),
+            tick: Arc::default(),
+            sync_tasks: Arc::default(),
+            wallet: Arc::default(),
+        };
+        let (tx, rx) = tokio::sync::broadcast::channel(8);
+        assert!(tx.send(stream_event(StorageMode::Simulation, 1)).is_ok());
+        assert!(tx.send(stream_event(StorageMode::Real, 2)).is_ok());
+        drop(tx);
+        let mut out = Vec::new();
+        stream_movements(&mut out, &app, rx).await.unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("HTTP/1.1 200 OK\r\nContent-Type: text/event-stream"));
+        assert_eq!(out.matches("data: ").count(), 1);
+        assert!(out.contains("data: {\"id\":2,"));
+        assert!(out.ends_with("}\n\n"));
+
+        // A client that has gone away ends the stream instead of waiting on it.
+        let (tx, rx) = tokio::sync::broadcast::channel(8);
+        assert!(tx.send(stream_event(StorageMode::Real, 3)).is_ok());
+        let result = tokio::time::timeout(
+            Duration::from_secs(1),
+            stream_movements(
+                &mut HangsUpAfterHeaders {
+                    wrote_headers: false,
+                },
+                &app,
+                rx,
+            ),
+        )
+        .await;
+        assert!(matches!(result, Ok(Ok(()))));
+    }
+
+    #[test]
+    fn poll_interval_backs_off_on_rate_limit() {
+        assert_eq!(
+            adjust_poll_interval(2_000, 2_000, PollEvent::RateLimited),
+            2_250
+        );
+        // Realtime intervals below 500ms jump straight to the 500ms floor.
+        assert_eq!(adjust_poll_interval(200, 200, PollEvent::RateLimited), 500);
+    }
+
+    #[test]
+    fn poll_interval_decays_gradually_to_baseline() {
+        assert_eq!(
+            adjust_poll_interval(2_750, 2_000, PollEvent::Recovered),
+            2_500
+        );
+        assert_eq!(
+            adjust_poll_interval(2_100, 2_000, PollEvent::Recovered),
+            2_000
+        );
+        assert_eq!(
+            adjust_poll_interval(2_000, 2_000, PollEvent::Recovered),
+            2_000
+        );
+
+        let mut cfg = exit_cfg(None, None);
+        cfg.backoff_recovery_successes = Some(2);
+        let mut runtime = new_runtime_state(Some(cfg));
+        runtime.current_poll_interval_ms = 2_500;
+        record_api_success(&mut runtime);
+        assert_eq!(runtime.current_poll_interval_ms, 2_500);
+        record_api_success(&mut runtime);
+        assert_eq!(runtime.current_poll_interval_ms, 2_250);
+        assert_eq!(runtime.consecutive_successes, 0);
+        record_api_success(&mut runtime);
+        record_api_success(&mut runtime);
+        assert_eq!(runtime.current_poll_interval_ms, 2_000);
+        record_api_success(&mut runtime);
+        record_api_success(&mut runtime);
+        assert_eq!(runtime.current_poll_interval_ms, 2_000);
+    }
+
+    #[test]
+    fn metrics_are_valid_prometheus_exposition() {
+        let mut runtime = new_runtime_state(Some(exit_cfg(None, None)));
+        runtime.current_poll_interval_ms = 2_500;
+        record_api_error(&mut runtime);
+        let mut settled = open_copy("BUY", "0.5");
+        settled.settled = true;
+        settled.pnl = d("4");
+        settled.estimated_total_fee_usd = d("0.5");
+        let state = CopyState {
+            movements: vec![open_copy("BUY", "0.5"), settled],
+        };
+
+        let body = prometheus_metrics(&state, &runtime);
+        let mut samples = HashMap::new();
+        let mut typed = HashSet::new();
+        for line in body.lines() {
+            if let Some(rest) = line.strip_prefix("# TYPE ") {
+                let (name, kind) = rest.split_once(' ').unwrap();
+                assert!(matches!(kind, "counter" | "gauge"), "{line}");
+                assert!(typed.insert(name.to_string()), "duplicate TYPE for {name}");
+            } else if !line.starts_with("# HELP ") {
+                let (name, value) = line.split_once(' ').unwrap();
+                assert!(
+                    name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'),
+                    "{line}"
+                );
+                assert!(typed.contains(name), "sample before TYPE: {line}");
+                samples.insert(name.to_string(), value.parse::<f64>().unwrap());
+            }
+        }
+        assert!(body.ends_with('\n'));
+        assert_eq!(samples["polymarket_copy_movements"], 2.0);
+        assert_eq!(samples["polymarket_copy_open_exposure_usd"], 20.0);
+        assert_eq!(samples["polymarket_copy_realized_pnl_usd"], 3.5);
+        assert_eq!(samples["polymarket_copy_poll_interval_seconds"], 2.5);
+        assert!(samples["polymarket_copy_last_error_timestamp_seconds"] > 0.0);
+        assert_eq!(samples["polymarket_copy_monitoring"], 0.0);
+    }
+
+    fn exit_cfg(stop_loss_pct: Option<Decimal>, take_profit_pct: Option<Decimal>) -> CopyConfig {
+        CopyConfig {
+            stop_loss_pct,
+            take_profit_pct,
+            ..test_cfg()
+        }
+    }
+
+    #[test]
+    fn fixed_sizing_ignores_leader_size_but_keeps_caps() {
+        let proportional = exit_cfg(None, None);
+        let mut fixed = exit_cfg(None, None);
+        fixed.sizing_mode = SizingMode::Fixed;
+        fixed.fixed_copy_usd = Some(d("25"));
+        let state = CopyState::default();
+        let plan = |cfg: &CopyConfig, state: &CopyState, movement: &str| {
+            compute_plan(
+                cfg,
+                state,
+                "0x1",
+                "m",
+                d("10000"),
+                d(movement),
+                Decimal::ZERO,
+            )
+            .unwrap()
+        };
+
+        // Same leader movement: 50 proportionally, the flat 25 when fixed.
+        let p = plan(&proportional, &state, "500");
+        assert_eq!(p.capped_size, d("50"));
+        assert_eq!(p.constraints.unwrap().binding, "proportional_size");
+        let f = plan(&fixed, &state, "500");
+        assert_eq!(f.capped_size, d("25"));
+        assert_eq!(f.proportional_size, d("50"));
+        let c = f.constraints.unwrap();
+        assert_eq!(c.binding, "fixed_copy_usd");
+        assert_eq!(c.fixed_copy_usd, Some(d("25")));
+
+        // A tiny leader trade is below the minimum proportionally, not when fixed.
+        assert_eq!(plan(&proportional, &state, "5").capped_size, Decimal::ZERO);
+        assert_eq!(plan(&fixed, &state, "5").capped_size, d("25"));
+
+        // The flat size is still clamped by max_trade_pct, exposure and the minimum.
+        fixed.fixed_copy_usd = Some(d("150"));
+        let big = plan(&fixed, &state, "5");
+        assert_eq!(big.capped_size, d("100"));
+        assert_eq!(big.reason, "capped by max_trade_pct");
+
+        fixed.fixed_copy_usd = Some(d("25"));
+        let busy = CopyState {
+            movements: vec![MovementRecord {
+                copied_value: d("480"),
+                ..open_copy("BUY", "0.5")
+            }],
+        };
+        let capped = plan(&fixed, &busy, "5");
+        assert_eq!(capped.capped_size, d("20"));
+        assert_eq!(capped.reason, "capped by max_total_exposure_pct");
+
+        fixed.fixed_copy_usd = Some(d("0.5"));
+        let below = plan(&fixed, &state, "500");
+        assert_eq!(below.capped_size, Decimal::ZERO);
+        assert_eq!(below.reason, "below minimum copy threshold");
+    }
+
+    fn open_copy(side: &str, entry: &str) -> MovementRecord {
+        MovementRecord {
+            copy_side: side.into(),
+            simulated_copy_price: d(entry),
+            ..test_movement()
+        }
+    }
+
+    #[test]
+    fn leaders_to_disable_sums_settled_net_pnl_per_leader() {
+        let copy = |leader: &str, pnl: &str, fee: &str, settled: bool| MovementRecord {
+            leader: leader.into(),
+            pnl: d(pnl),
+            estimated_total_fee_usd: d(fee),
+            settled,
+            ..open_copy("BUY", "0.5")
+        };
+        let movements = vec![
+            copy("0xAAA", "-30", "1", true),
+            copy("0xaaa", "-18", "1", true),
+            copy("0xbbb", "-49", "0", true),
+            copy("0xbbb", "-100", "0", false),
+            copy("0xccc", "-60", "0", true),
+            copy("0xccc", "20", "0", true),
+            copy("", "-500", "0", true),
+        ];
+        // 0xaaa nets -50 once fees count; 0xbbb's open loss is ignored.
+        assert_eq!(leaders_to_disable(&movements, d("50")), vec!["0xaaa"]);
+        assert_eq!(
+            leaders_to_disable(&movements, d("40")),
+            vec!["0xaaa", "0xbbb", "0xccc"]
+        );
+        assert!(leaders_to_disable(&movements, d("51")).is_empty());
+    }
+
+    #[test]
+    fn should_exit_long_copy_on_stop_loss_and_take_profit() {
+        let cfg = exit_cfg(Some(d("20")), Some(d("50")));
+        let long = open_copy("BUY", "0.5");
+        assert_eq!(should_exit(&long, d("0.41"), &cfg), None);
+        assert_eq!(
+            should_exit(&long, d("0.4"), &cfg),
+            Some(ExitReason::StopLoss)
+        );
+        assert_eq!(should_exit(&long, d("0.74"), &cfg), None);
+        assert_eq!(
+            should_exit(&long, d("0.75"), &cfg),
+            Some(ExitReason::TakeProfit)
+        );
+    }
+
+    #[test]
+    fn should_exit_short_copy_gains_when_price_falls() {
+        let cfg = exit_cfg(Some(d("20")), Some(d("50")));
+        let short = open_copy("SELL", "0.5");
+        assert_eq!(
+            should_exit(&short, d("0.6"), &cfg),
+            Some(ExitReason::StopLoss)
+        );
+        assert_eq!(
+            should_exit(&short, d("0.25"), &cfg),
+            Some(ExitReason::TakeProfit)
+        );
+        assert_eq!(should_exit(&short, d("0.45"), &cfg), None);
+    }
+
+    #[test]
+    fn should_exit_needs_thresholds_and_an_open_priced_copy() {
+        let long = open_copy("BUY", "0.5");
+        assert_eq!(should_exit(&long, d("0.01"), &exit_cfg(None, None)), None);
+
+        let cfg = exit_cfg(Some(d("10")), None);
+        assert_eq!(should_exit(&long, d("0.99"), &cfg), None);
+        assert_eq!(should_exit(&long, Decimal::ZERO, &cfg), None);
+
+        let mut settled = open_copy("BUY", "0.5");
+        settled.settled = true;
+        assert_eq!(should_exit(&settled, d("0.1"), &cfg), None);
+
+        let unpriced = MovementRecord {
+            simulated_copy_price: Decimal::ZERO,
+            leader_price: Decimal::ZERO,
+            ..open_copy("BUY", "0.5")
+        };
+        assert_eq!(should_exit(&unpriced, d("0.1"), &cfg), None);
+    }
+
+    #[test]
+    fn config_reads_the_old_single_leader_field() {
+        let cfg: CopyConfig = serde_json::from_value(serde_json::json!({
+            "leader": "0xabc",
+            "allocated_funds": "100",
+            "max_trade_pct": "5",
+            "max_total_exposure_pct": "70",
+            "min_copy_usd": "1",
+            "poll_interval_secs": 2,
+            "risk_level": "balanced",
+            "execute_orders": false,
+        }))
+        .unwrap();
+        assert_eq!(cfg.leaders, vec![LeaderConfig::new("0xabc")]);
+
+        let saved = serde_json::to_value(&cfg).unwrap();
+        assert!(saved.get("leader").is_none());
+        let reread: CopyConfig = serde_json::from_value(saved).unwrap();
+        assert_eq!(reread.leaders, cfg.leaders);
+    }
+
+    #[test]
+    fn leader_specs_parse_weights() {
+        assert_eq!(parse_leader_spec("0xA").unwrap(), LeaderConfig::new("0xA"));
+        assert_eq!(parse_leader_spec(" 0xB:2.5 ").unwrap().weight, d("2.5"));
+        assert!(parse_leader_spec("0xB:heavy").is_err());
+        assert!(parse_leader_spec(":2").is_err());
+    }
+
+    #[test]
+    fn plan_splits_funds_by_leader_weight() {
+        let mut cfg = exit_cfg(None, None);
+        cfg.leaders = vec![
+            LeaderConfig {
+                address: "0xA".into(),
+                weight: d("3"),
+            },
+            LeaderConfig::new("0xB"),
+        ];
+        let mut open_a = open_copy("BUY", "0.5");
+        open_a.leader = "0xA".into();
+        open_a.copied_value = d("370");
+        let state = CopyState {
+            movements: vec![open_a],
+        };
+
+        // 0xA's slice is 750: max_trade 75, exposure cap 375 of which 370 is in use.
+        let a = compute_plan(
+            &cfg,
+            &state,
+            "0xA",
+            "m",
+            d("1000"),
+            d("1000"),
+            Decimal::ZERO,
+        )
+        .unwrap();
+        assert_eq!(a.capped_size, d("5"));
+
+        // 0xB's slice is 250 and 0xA's open copy doesn't count against it.
+        let b = compute_plan(
+            &cfg,
+            &state,
+            "0xB",
+            "m",
+            d("1000"),
+            d("1000"),
+            Decimal::ZERO,
+        )
+        .unwrap();
+        assert_eq!(b.proportional_size, d("250"));
+        assert_eq!(b.capped_size, d("25"));
+        assert_eq!(b.reason, "capped by max_trade_pct");
+    }
+
+    #[test]
+    fn daily_loss_breaker_counts_only_todays_settled_losses() {
+        fn settled(ts: &str, pnl: &str) -> MovementRecord {
+            MovementRecord {
+                timestamp: ts.into(),
+                settled: true,
+                pnl: d(pnl),
+                ..open_copy("BUY", "0.5")
+            }
+        }
+        let now = chrono::DateTime::parse_from_rfc3339("2026-03-02T00:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let mut movements = vec![
+            // Yesterday's loss, one second before midnight, no longer counts.
+            settled("2026-03-01T23:59:59Z", "-500"),
+            settled("2026-03-02T00:00:00Z", "-30"),
+            // Still open, so not a realized loss.
+            open_copy("BUY", "0.5"),
+        ];
+        assert!(!daily_loss_breached(&movements, d("50"), &now));
+
+        movements.push(settled("2026-03-02T00:10:00Z", "-20"));
+        assert!(daily_loss_breached(&movements, d("50"), &now));
+
+        movements.push(settled("2026-03-02T00:20:00Z", "15"));
+        assert!(!daily_loss_breached(&movements, d("50"), &now));
+
+        let late = chrono::DateTime::parse_from_rfc3339("2026-03-01T23:59:59Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert!(daily_loss_breached(&movements, d("50"), &late));
+    }
+
+    #[test]
+    fn daily_loss_limit_zeroes_plan() {
+        let mut cfg = exit_cfg(None, None);
+        let now = chrono::DateTime::parse_from_rfc3339("2026-03-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let mut loss = open_copy("BUY", "0.5");
+        loss.settled = true;
+        loss.pnl = d("-100");
+        let state = CopyState {
+            movements: vec![loss],
+        };
+        let mut plan =
+            compute_plan(&cfg, &state, "0x1", "m", d("1000"), d("100"), Decimal::ZERO).unwrap();
+        assert!(!apply_daily_loss_limit(&cfg, &state, &now, &mut plan));
+
+        cfg.daily_loss_limit_usd = Some(d("100"));
+        assert!(apply_daily_loss_limit(&cfg, &state, &now, &mut plan));
+        assert_eq!(plan.capped_size, Decimal::ZERO);
+        assert_eq!(plan.reason, "daily loss limit reached");
+    }
+
+    #[test]
+    fn exposure_by_normalized_market_caps_each_slug_family() {
+        let copy_on = |market: &str, value: &str| MovementRecord {
+            market: market.into(),
+            copied_value: d(value),
+            ..open_copy("BUY", "0.5")
+        };
+        let state = CopyState {
+            movements: vec![
+                copy_on("btc-updown-5m-1772281200", "150"),
+                copy_on("btc-updown-5m-1772281500", "150"),
+                copy_on("btc-updown-5m-1772281800", "150"),
+                copy_on("eth-updown-5m-1772281800", "100"),
+            ],
+        };
+        let plan = |cfg: &CopyConfig, market: &str| {
+            compute_plan(
+                cfg,
+                &state,
+                "0x1",
+                market,
+                d("1000"),
+                d("100"),
+                Decimal::ZERO,
+            )
+            .unwrap()
+        };
+        // Cap is 50% of 1000: all 550 open counts globally, so nothing is left.
+        // With no minimum the exhausted exposure is reported as the reason.
+        let mut cfg = exit_cfg(None, None);
+        cfg.min_copy_usd = Decimal::ZERO;
+        for market in ["btc-updown-5m-1772282100", "eth-updown-5m-1772282100"] {
+            let global = plan(&cfg, market);
+            assert_eq!(global.capped_size, Decimal::ZERO);
+            assert_eq!(global.reason, "no exposure available");
+        }
+
+        cfg.exposure_mode = ExposureMode::ByNormalizedMarket;
+        let btc = plan(&cfg, "btc-updown-5m-1772282100");
+        assert_eq!(btc.capped_size, d("50"));
+        assert_eq!(btc.reason, "capped by max_total_exposure_pct");
+        let eth = plan(&cfg, "eth-updown-5m-1772282100");
+        assert_eq!(eth.capped_size, d("100"));
+        assert_eq!(eth.reason, "ok");
+    }
+
+    #[test]
+    fn book_depth_margin_and_slippage_gate_simulated_fills() {
+        // Asks: $25 at 0.50, $26 at 0.52, $120 at 0.60; copying $20 of a 0.50 buy.
+        let asks = [
+            (d("0.50"), d("50")),
+            (d("0.52"), d("50")),
+            (d("0.60"), d("200")),
+        ];
+        let mut cfg = exit_cfg(None, None);
+        let (px, full) = simulated_fill(&cfg, &asks, true, d("0.5"), d("20"));
+        assert_eq!((px, full), (Some(d("0.5")), true));
+
+        // 2x needs $40: reachable within 5% (0.525) of 0.50, not within 2% (0.51).
+        cfg.liquidity_multiple = Some(d("2"));
+        cfg.max_slippage_pct = Some(d("5"));
+        assert_eq!(
+            simulated_fill(&cfg, &asks, true, d("0.5"), d("20")).0,
+            Some(d("0.5"))
+        );
+        cfg.max_slippage_pct = Some(d("2"));
+        assert_eq!(
+            simulated_fill(&cfg, &asks, true, d("0.5"), d("20")),
+            (None, false)
+        );
+
+        // Without a slippage bound the whole book counts: $171 covers 8x but not 9x.
+        cfg.max_slippage_pct = None;
+        cfg.liquidity_multiple = Some(d("8"));
+        assert!(
+            simulated_fill(&cfg, &asks, true, d("0.5"), d("20"))
+                .0
+                .is_some()
+        );
+        cfg.liquidity_multiple = Some(d("9"));
+        assert_eq!(
+            simulated_fill(&cfg, &asks, true, d("0.5"), d("20")),
+            (None, false)
+        );
+
+        // Sells walk bids downward: 40 shares at 0.50 or better, 100 more at 0.40.
+        let bids = [(d("0.50"), d("40")), (d("0.40"), d("100"))];
+        cfg.liquidity_multiple = Some(d("2"));
+        cfg.max_slippage_pct = Some(d("10"));
+        assert_eq!(
+            simulated_fill(&cfg, &bids, false, d("0.5"), d("10")).0,
+            Some(d("0.5"))
+        );
+        assert_eq!(
+            simulated_fill(&cfg, &bids, false, d("0.5"), d("15")),
+            (None, false)
+        );
+    }
+
+    #[test]
+    fn max_open_positions_blocks_new_copies_at_cap() {
+        let mut cfg = exit_cfg(None, None);
+        cfg.max_open_positions = Some(2);
+        let mut closed = open_copy("BUY", "0.5");
+        closed.settled = true;
+        let mut state = CopyState {
+            movements: vec![open_copy("BUY", "0.5"), closed],
+        };
+        let below =
+            compute_plan(&cfg, &state, "0x1", "m", d("1000"), d("100"), Decimal::ZERO).unwrap();
+        assert!(below.capped_size > Decimal::ZERO);
+        assert_ne!(below.reason, "max open positions reached");
+
+        state.movements.push(open_copy("BUY", "0.4"));
+        let at_cap =
+            compute_plan(&cfg, &state, "0x1", "m", d("1000"), d("100"), Decimal::ZERO).unwrap();
+        assert_eq!(at_cap.capped_size, Decimal::ZERO);
+        assert_eq!(at_cap.reason, "max open positions reached");
+        assert_eq!(at_cap.constraints.unwrap().open_positions, Some(2));
+    }
+
+    #[test]
+    fn passes_filters_applies_sides_and_slug_lists() {
+        let mut cfg = exit_cfg(None, None);
+        let buy = TradeCandidate {
+            leader: "0x1",
+            leader_value: d("1000"),
+            market: "BTC-updown-5m-1772278200",
+            outcome: "Up",
+            price: d("0.5"),
+            size: d("10"),
+            is_sell: false,
+        };
+        let sell = TradeCandidate {
+            is_sell: true,
+            ..buy
+        };
+        assert_eq!(passes_filters(&buy, &cfg), Ok(()));
+        assert_eq!(passes_filters(&sell, &cfg), Ok(()));
+
+        cfg.copy_sides = CopySides::Buy;
+        assert_eq!(passes_filters(&buy, &cfg), Ok(()));
+        assert_eq!(passes_filters(&sell, &cfg), Err(TradeSkip::SideFiltered));
+        cfg.copy_sides = CopySides::Sell;
+        assert_eq!(passes_filters(&buy, &cfg), Err(TradeSkip::SideFiltered));
+        cfg.copy_sides = CopySides::Both;
+
+        cfg.slug_allowlist = vec!["eth-".into(), "Updown-5M".into()];
+        assert_eq!(passes_filters(&buy, &cfg), Ok(()));
+        cfg.slug_allowlist = vec!["eth-".into()];
+        assert_eq!(passes_filters(&buy, &cfg), Err(TradeSkip::SlugFiltered));
+
+        // The denylist wins over the allowlist, and the numeric rollover
+        // suffix is not part of the matched slug.
+        cfg.slug_allowlist = vec!["btc".into()];
+        cfg.slug_denylist = vec!["5m".into()];
+        assert_eq!(passes_filters(&buy, &cfg), Err(TradeSkip::SlugFiltered));
+        cfg.slug_denylist = vec!["1772278200".into()];
+        assert_eq!(passes_filters(&buy, &cfg), Ok(()));
+    }
+
+    fn leader_sell() -> TradeCandidate<'static> {
+        TradeCandidate {
+            leader: "0x1",
+            leader_value: d("1000"),
+            market: "mkt",
+            outcome: "Yes",
+            price: d("0.6"),
+            size: d("10"),
+            is_sell: true,
+        }
+    }
+
+    #[test]
+    fn copy_sides_both_closes_copies_on_leader_sell() {
+        let cfg = exit_cfg(None, None);
+        let mut state = CopyState {
+            movements: vec![test_movement()],
+        };
+        let t = leader_sell();
+        let closed =
+            settle_from_leader_sell(&cfg, &mut state, t.leader, t.market, t.outcome, t.price);
+        assert_eq!(closed.len(), 1);
+        assert!(state.movements[0].settled);
+        assert_eq!(state.movements[0].pnl, d("4"));
+    }
+
+    #[test]
+    fn copy_sides_buy_ignores_leader_sells() {
+        let mut cfg = exit_cfg(None, None);
+        cfg.copy_sides = CopySides::Buy;
+        let mut state = CopyState {
+            movements: vec![test_movement()],
+        };
+        let t = leader_sell();
+        let closed =
+            settle_from_leader_sell(&cfg, &mut state, t.leader, t.market, t.outcome, t.price);
+        assert!(closed.is_empty());
+        assert!(!state.movements[0].settled);
+
+        // The monitor then evaluates the sell, which is skipped by side.
+        let eval = evaluate_trade(&cfg, &FeeSchedule::default(), &state, &t, &Utc::now()).unwrap();
+        assert_eq!(eval.skip, Some(TradeSkip::SideFiltered));
+    }
+
+    #[test]
+    fn copy_sides_sell_is_rejected() {
+        let mut args: ConfigureArgs = serde_json::from_value(serde_json::json!({
+            "leader": "0x0000000000000000000000000000000000000001",
+            "allocated_funds": "500",
+            "execute_orders": false,
+            "realtime_mode": false,
+            "simulation_mode": false,
+            "copy_sides": "sell",
+        }))
+        .unwrap();
+        let err = validate_config(&args).unwrap_err().to_string();
+        assert!(err.contains("--copy-sides sell"), "{err}");
+        args.copy_sides = CopySides::Buy;
+        validate_config(&args).unwrap();
+    }
+
+    #[test]
+    fn evaluate_trade_sizes_buys_and_explains_skips() {
+        let mut cfg = exit_cfg(None, None);
+        let fees = FeeSchedule::default();
+        let now = Utc::now();
+        let buy = TradeCandidate {
+            leader: "0x1",
+            leader_value: d("1000"),
+            market: "mkt",
+            outcome: "Yes",
+            price: d("0.5"),
+            size: d("200"),
+            is_sell: false,
+        };
+        let state = CopyState::default();
+
+        let eval = evaluate_trade(&cfg, &fees, &state, &buy, &now).unwrap();
+        assert_eq!(eval.skip, None);
+        assert!(eval.plan.capped_size > Decimal::ZERO);
+
+        let sell = TradeCandidate {
+            is_sell: true,
+            ..buy
+        };
+        let eval = evaluate_trade(&cfg, &fees, &state, &sell, &now).unwrap();
+        assert!(matches!(eval.skip, Some(TradeSkip::NoOpenBuy { .. })));
+        assert_eq!(
+            eval.skip.unwrap().label(&eval.plan),
+            "sell without an open buy"
+        );
+
+        cfg.copy_outcome = CopyOutcome::No;
+        let eval = evaluate_trade(&cfg, &fees, &state, &buy, &now).unwrap();
+        assert_eq!(eval.skip, Some(TradeSkip::OutcomeFiltered));
+
+        cfg.copy_outcome = CopyOutcome::Both;
+        cfg.daily_loss_limit_usd = Some(d("10"));
+        let mut loss = open_copy("BUY", "0.5");
+        loss.timestamp = now.to_rfc3339();
+        loss.settled = true;
+        loss.pnl = d("-25");
+        let losing = CopyState {
+            movements: vec![loss],
+        };
+        let eval = evaluate_trade(&cfg, &fees, &losing, &buy, &now).unwrap();
+        assert_eq!(eval.skip, Some(TradeSkip::DailyLossLimit));
+        assert_eq!(eval.plan.capped_size, Decimal::ZERO);
+    }
 }
@@ -1,20 +1,36 @@
+#![allow(clippy::exhaustive_enums, reason = "Generated by sol! macro")]
+#![allow(clippy::exhaustive_structs, reason = "Generated by sol! macro")]
+
 use alloy::primitives::U256;
+use alloy::sol;
 use anyhow::{Context, Result};
 use clap::{Args, Subcommand};
 use polymarket_client_sdk::ctf::types::{
     CollectionIdRequest, ConditionIdRequest, MergePositionsRequest, PositionIdRequest,
     RedeemNegRiskRequest, RedeemPositionsRequest, SplitPositionRequest,
 };
+use polymarket_client_sdk::data::{
+    self,
+    types::{request::PositionsRequest, response::Position},
+};
 use polymarket_client_sdk::types::{Address, B256};
-use polymarket_client_sdk::{POLYGON, ctf};
+use polymarket_client_sdk::{POLYGON, contract_config, ctf};
 use rust_decimal::Decimal;
 
 use crate::auth;
 use crate::output::OutputFormat;
-use crate::output::ctf as ctf_output;
+use crate::output::ctf::{self as ctf_output, CtfPosition};
 
 const USDC_DECIMALS: Decimal = Decimal::from_parts(1_000_000, 0, 0, false, 0);
 
+sol! {
+    #[sol(rpc)]
+    interface IConditionalTokens {
+        function getOutcomeSlotCount(bytes32 conditionId) external view returns (uint256);
+        function balanceOf(address account, uint256 id) external view returns (uint256);
+    }
+}
+
 #[derive(Args)]
 pub struct CtfArgs {
     #[command(subcommand)]
@@ -24,13 +40,17 @@ pub struct CtfArgs {
 #[derive(Subcommand)]
 pub enum CtfCommand {
     /// Split collateral into outcome tokens
+    #[command(group(clap::ArgGroup::new("size").required(true).args(["amount", "amount_base_units"])))]
     Split {
         /// Condition ID (0x-prefixed 32-byte hex)
         #[arg(long)]
         condition: String,
-        /// Amount in USDC (e.g. 10 for $10)
+        /// Amount in USDC, up to 6 decimals (e.g. 10.5 for $10.50)
+        #[arg(long)]
+        amount: Option<String>,
+        /// Amount in raw collateral base units (1 USDC = 1000000)
         #[arg(long)]
-        amount: String,
+        amount_base_units: Option<u64>,
         /// Collateral token address (defaults to USDC)
         #[arg(long, default_value = "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174")]
         collateral: String,
@@ -42,13 +62,17 @@ pub enum CtfCommand {
         parent_collection: Option<String>,
     },
     /// Merge outcome tokens back into collateral
+    #[command(group(clap::ArgGroup::new("size").required(true).args(["amount", "amount_base_units"])))]
     Merge {
         /// Condition ID (0x-prefixed 32-byte hex)
         #[arg(long)]
         condition: String,
-        /// Amount in USDC (e.g. 10 for $10)
+        /// Amount in USDC, up to 6 decimals (e.g. 10.5 for $10.50)
+        #[arg(long)]
+        amount: Option<String>,
+        /// Amount in raw collateral base units (1 USDC = 1000000)
         #[arg(long)]
-        amount: String,
+        amount_base_units: Option<u64>,
         /// Collateral token address (defaults to USDC)
         #[arg(long, default_value = "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174")]
         collateral: String,
@@ -74,6 +98,18 @@ pub enum CtfCommand {
         #[arg(long)]
         parent_collection: Option<String>,
     },
+    /// Redeem winning tokens for several resolved conditions, one transaction each
+    RedeemBatch {
+        /// Comma-separated condition IDs, or a file with one per line (`#` comments allowed)
+        #[arg(long)]
+        conditions: String,
+        /// Collateral token address (defaults to USDC)
+        #[arg(long, default_value = "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174")]
+        collateral: String,
+        /// Custom index sets as comma-separated values, applied to every condition
+        #[arg(long)]
+        index_sets: Option<String>,
+    },
     /// Redeem neg-risk positions
     RedeemNegRisk {
         /// Condition ID (0x-prefixed 32-byte hex)
@@ -107,6 +143,15 @@ pub enum CtfCommand {
         #[arg(long)]
         parent_collection: Option<String>,
     },
+    /// Show the wallet's outcome token (ERC1155) balances
+    Positions {
+        /// Only this condition, listing every outcome even at zero balance (0x-prefixed 32-byte hex)
+        #[arg(long)]
+        condition: Option<String>,
+        /// Collateral token used to derive position IDs with --condition (defaults to USDC)
+        #[arg(long, default_value = "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174")]
+        collateral: String,
+    },
     /// Calculate a position ID (ERC1155 token ID) from collateral and collection
     PositionId {
         /// Collateral token address (defaults to USDC)
@@ -136,6 +181,24 @@ fn parse_usdc_amount(s: &str) -> Result<U256> {
     usdc_to_raw(val)
 }
 
+/// Resolves `--amount` (USDC) or `--amount-base-units` (raw) to base units.
+fn resolve_amount(amount: Option<&str>, base_units: Option<u64>) -> Result<U256> {
+    match (amount, base_units) {
+        (Some(a), None) => parse_usdc_amount(a),
+        (None, Some(raw)) => {
+            anyhow::ensure!(raw > 0, "Amount must be positive");
+            Ok(U256::from(raw))
+        }
+        _ => anyhow::bail!("Pass exactly one of --amount or --amount-base-units"),
+    }
+}
+
+fn describe_amount(raw: U256) -> String {
+    let raw_u64: u64 = raw.try_into().unwrap_or(u64::MAX);
+    let usdc = Decimal::from(raw_u64) / USDC_DECIMALS;
+    format!("{} USDC ({raw} base units)", usdc.normalize())
+}
+
 fn parse_usdc_amounts(s: &str) -> Result<Vec<U256>> {
     s.split(',')
         .map(|part| {
@@ -164,6 +227,36 @@ fn parse_u256_csv(s: &str) -> Result<Vec<U256>> {
         .collect()
 }
 
+/// Reads `--conditions`: a path to a newline-delimited file if one exists,
+/// otherwise a comma-separated list.
+fn read_condition_ids(input: &str) -> Result<Vec<B256>> {
+    let path = std::path::Path::new(input.trim());
+    if path.is_file() {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Could not read {}", path.display()))?;
+        parse_condition_ids(contents.lines())
+    } else {
+        parse_condition_ids(input.split(','))
+    }
+}
+
+/// Parses condition IDs, skipping blanks, `#` comments and repeats (a second
+/// redemption of the same condition would only revert).
+fn parse_condition_ids<'a>(items: impl Iterator<Item = &'a str>) -> Result<Vec<B256>> {
+    let mut ids: Vec<B256> = Vec::new();
+    for item in items.map(str::trim) {
+        if item.is_empty() || item.starts_with('#') {
+            continue;
+        }
+        let id = super::parse_condition_id(item).with_context(|| format!("Bad entry '{item}'"))?;
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    anyhow::ensure!(!ids.is_empty(), "No condition IDs given");
+    Ok(ids)
+}
+
 fn parse_optional_parent(parent: Option<&str>) -> Result<B256> {
     match parent {
         Some(p) => super::parse_condition_id(p),
@@ -183,17 +276,131 @@ fn default_index_sets() -> Vec<U256> {
     vec![U256::from(1), U256::from(2)]
 }
 
-pub async fn execute(args: CtfArgs, output: OutputFormat, private_key: Option<&str>) -> Result<()> {
+/// The single-outcome index sets of a condition: outcome `i` is `1 << i`.
+fn outcome_index_sets(slot_count: usize) -> Vec<U256> {
+    (0..slot_count.min(256))
+        .map(|i| U256::from(1u64) << i)
+        .collect()
+}
+
+/// Outcome tokens share the collateral's 6 decimals.
+fn shares_from_base_units(raw: U256) -> Decimal {
+    raw.to_string().parse::<Decimal>().unwrap_or(Decimal::MAX) / USDC_DECIMALS
+}
+
+/// Balances of every outcome of `condition_id`, with position IDs derived
+/// on-chain from the collateral and each outcome's index set.
+async fn condition_positions(
+    owner: Address,
+    condition_id: B256,
+    collateral: Address,
+) -> Result<Vec<CtfPosition>> {
+    let provider = auth::create_readonly_provider().await?;
+    let client = ctf::Client::new(provider.clone(), POLYGON)?;
+    let config = contract_config(POLYGON, false).context("No contract config for Polygon")?;
+    let tokens = IConditionalTokens::new(config.conditional_tokens, provider);
+
+    let slots = tokens
+        .getOutcomeSlotCount(condition_id)
+        .call()
+        .await
+        .context("Failed to read outcome count")?;
+    anyhow::ensure!(
+        !slots.is_zero(),
+        "Condition {condition_id} is not prepared on-chain"
+    );
+    let slots = usize::try_from(slots).context("Outcome count out of range")?;
+
+    let mut positions = Vec::new();
+    for (outcome_index, index_set) in (0..).zip(outcome_index_sets(slots)) {
+        let collection = CollectionIdRequest::builder()
+            .parent_collection_id(B256::default())
+            .condition_id(condition_id)
+            .index_set(index_set)
+            .build();
+        let collection_id = client.collection_id(&collection).await?.collection_id;
+        let position = PositionIdRequest::builder()
+            .collateral_token(collateral)
+            .collection_id(collection_id)
+            .build();
+        let token_id = client.position_id(&position).await?.position_id;
+        let balance = tokens
+            .balanceOf(owner, token_id)
+            .call()
+            .await
+            .context("Failed to read position balance")?;
+        positions.push(CtfPosition {
+            token_id,
+            condition_id,
+            outcome_index,
+            outcome: None,
+            balance: shares_from_base_units(balance),
+        });
+    }
+    Ok(positions)
+}
+
+/// ERC1155 balances can't be enumerated on-chain, so the Data API supplies
+/// the wallet's position tokens and each balance is then read on-chain.
+/// Tokens whose on-chain balance is zero are dropped.
+async fn wallet_positions(owner: Address) -> Result<Vec<CtfPosition>> {
+    const PAGE_SIZE: i32 = 500;
+    const MAX_PAGES: u32 = 20;
+
+    let client = data::Client::default();
+    let client = &client;
+    let page = |offset| async move {
+        let request = PositionsRequest::builder()
+            .user(owner)
+            .limit(PAGE_SIZE)?
+            .maybe_offset(Some(offset))?
+            .build();
+        crate::net::retry(|| client.positions(&request)).await
+    };
+    let listed =
+        crate::net::fetch_all(0, PAGE_SIZE, MAX_PAGES, |p: &Position| p.asset, page).await?;
+
+    let provider = auth::create_readonly_provider().await?;
+    let config = contract_config(POLYGON, false).context("No contract config for Polygon")?;
+    let tokens = IConditionalTokens::new(config.conditional_tokens, provider);
+    let mut positions = Vec::new();
+    for p in listed {
+        let balance = tokens
+            .balanceOf(owner, p.asset)
+            .call()
+            .await
+            .context("Failed to read position balance")?;
+        if balance.is_zero() {
+            continue;
+        }
+        positions.push(CtfPosition {
+            token_id: p.asset,
+            condition_id: p.condition_id,
+            outcome_index: u32::try_from(p.outcome_index).unwrap_or_default(),
+            outcome: Some(p.outcome),
+            balance: shares_from_base_units(balance),
+        });
+    }
+    Ok(positions)
+}
+
+pub async fn execute(
+    args: CtfArgs,
+    output: OutputFormat,
+    private_key: Option<&str>,
+    signature_type: Option<&str>,
+) -> Result<()> {
     match args.command {
         CtfCommand::Split {
             condition,
             amount,
+            amount_base_units,
             collateral,
             partition,
             parent_collection,
         } => {
             let condition_id = super::parse_condition_id(&condition)?;
-            let usdc_amount = parse_usdc_amount(&amount)?;
+            let usdc_amount = resolve_amount(amount.as_deref(), amount_base_units)?;
             let collateral_addr = resolve_collateral(&collateral)?;
             let parent = parse_optional_parent(parent_collection.as_deref())?;
             let partition = match partition {
@@ -201,6 +408,7 @@ pub async fn execute(args: CtfArgs, output: OutputFormat, private_key: Option<&s
                 None => default_partition(),
             };
 
+            tracing::info!("Splitting {}", describe_amount(usdc_amount));
             let provider = auth::create_provider(private_key).await?;
             let client = ctf::Client::new(provider, POLYGON)?;
 
@@ -222,12 +430,13 @@ pub async fn execute(args: CtfArgs, output: OutputFormat, private_key: Option<&s
         CtfCommand::Merge {
             condition,
             amount,
+            amount_base_units,
             collateral,
             partition,
             parent_collection,
         } => {
             let condition_id = super::parse_condition_id(&condition)?;
-            let usdc_amount = parse_usdc_amount(&amount)?;
+            let usdc_amount = resolve_amount(amount.as_deref(), amount_base_units)?;
             let collateral_addr = resolve_collateral(&collateral)?;
             let parent = parse_optional_parent(parent_collection.as_deref())?;
             let partition = match partition {
@@ -235,6 +444,7 @@ pub async fn execute(args: CtfArgs, output: OutputFormat, private_key: Option<&s
                 None => default_partition(),
             };
 
+            tracing::info!("Merging {}", describe_amount(usdc_amount));
             let provider = auth::create_provider(private_key).await?;
             let client = ctf::Client::new(provider, POLYGON)?;
 
@@ -284,6 +494,56 @@ pub async fn execute(args: CtfArgs, output: OutputFormat, private_key: Option<&s
 
             ctf_output::print_tx_result("redeem", resp.transaction_hash, resp.block_number, &output)
         }
+        CtfCommand::RedeemBatch {
+            conditions,
+            collateral,
+            index_sets,
+        } => {
+            let condition_ids = read_condition_ids(&conditions)?;
+            let collateral_addr = resolve_collateral(&collateral)?;
+            let index_sets = match index_sets {
+                Some(s) => parse_u256_csv(&s)?,
+                None => default_index_sets(),
+            };
+
+            let provider = auth::create_provider(private_key).await?;
+            let client = ctf::Client::new(provider, POLYGON)?;
+
+            // Sequential on purpose: each redemption is its own transaction
+            // from the same wallet, and one revert shouldn't stop the rest.
+            let mut results = Vec::with_capacity(condition_ids.len());
+            for (i, condition_id) in condition_ids.iter().enumerate() {
+                tracing::info!(
+                    "Redeeming {}/{}: {condition_id}",
+                    i + 1,
+                    condition_ids.len()
+                );
+                let req = RedeemPositionsRequest::builder()
+                    .collateral_token(collateral_addr)
+                    .parent_collection_id(B256::default())
+                    .condition_id(*condition_id)
+                    .index_sets(index_sets.clone())
+                    .build();
+                let result = client
+                    .redeem_positions(&req)
+                    .await
+                    .map(|resp| (resp.transaction_hash, resp.block_number))
+                    .map_err(|e| format!("{e:#}"));
+                results.push((*condition_id, result));
+            }
+
+            ctf_output::print_redeem_batch(&results, &output)?;
+            let failed = results.iter().filter(|(_, r)| r.is_err()).count();
+            if failed > 0 {
+                // The batch result above already lists each failure.
+                return Err(crate::output::ReportedFailure(format!(
+                    "{failed} of {} redemption(s) failed",
+                    results.len()
+                ))
+                .into());
+            }
+            Ok(())
+        }
         CtfCommand::RedeemNegRisk { condition, amounts } => {
             let condition_id = super::parse_condition_id(&condition)?;
             let amounts = parse_usdc_amounts(&amounts)?;
@@ -348,6 +608,21 @@ pub async fn execute(args: CtfArgs, output: OutputFormat, private_key: Option<&s
             let resp = client.collection_id(&req).await?;
             ctf_output::print_collection_id(resp.collection_id, &output)
         }
+        CtfCommand::Positions {
+            condition,
+            collateral,
+        } => {
+            let owner = crate::commands::wallet::trading_address(private_key, signature_type)?;
+            let positions = match condition {
+                Some(condition) => {
+                    let condition_id = super::parse_condition_id(&condition)?;
+                    let collateral_addr = resolve_collateral(&collateral)?;
+                    condition_positions(owner, condition_id, collateral_addr).await?
+                }
+                None => wallet_positions(owner).await?,
+            };
+            ctf_output::print_positions(owner, &positions, &output)
+        }
         CtfCommand::PositionId {
             collateral,
             collection,
@@ -373,6 +648,49 @@ pub async fn execute(args: CtfArgs, output: OutputFormat, private_key: Option<&s
 mod tests {
     use super::*;
 
+    const COND_A: &str = "0x1111111111111111111111111111111111111111111111111111111111111111";
+    const COND_B: &str = "0x2222222222222222222222222222222222222222222222222222222222222222";
+
+    #[test]
+    fn positions_owner_follows_the_signature_type_flag() {
+        let key = "0x0000000000000000000000000000000000000000000000000000000000000001";
+        let signer = crate::commands::wallet::trading_address(Some(key), Some("eoa")).unwrap();
+        assert_eq!(
+            signer.to_string(),
+            "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf"
+        );
+        let safe =
+            crate::commands::wallet::trading_address(Some(key), Some("gnosis-safe")).unwrap();
+        assert_eq!(
+            Some(safe),
+            polymarket_client_sdk::derive_safe_wallet(signer, POLYGON)
+        );
+    }
+
+    #[test]
+    fn condition_list_inline_dedupes_and_trims() {
+        let ids = read_condition_ids(&format!(" {COND_A}, {COND_B},{COND_A} ")).unwrap();
+        assert_eq!(ids.len(), 2);
+        assert_eq!(format!("{}", ids[1]), COND_B);
+    }
+
+    #[test]
+    fn condition_list_from_file_skips_comments() {
+        let path = std::env::temp_dir().join(format!("pm-conditions-{}.txt", std::process::id()));
+        std::fs::write(&path, format!("# resolved today\n{COND_A}\n\n{COND_B}\n")).unwrap();
+        let ids = read_condition_ids(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(ids.len(), 2);
+        assert_eq!(format!("{}", ids[0]), COND_A);
+    }
+
+    #[test]
+    fn condition_list_rejects_bad_or_empty_input() {
+        let err = read_condition_ids(&format!("{COND_A},0xnope")).unwrap_err();
+        assert!(format!("{err:#}").contains("0xnope"));
+        assert!(read_condition_ids(" , ").is_err());
+    }
+
     #[test]
     fn parse_usdc_amount_whole_dollars() {
         let result = parse_usdc_amount("10").unwrap();
@@ -418,6 +736,27 @@ mod tests {
         assert!(parse_usdc_amount("abc").is_err());
     }
 
+    #[test]
+    fn resolve_amount_accepts_base_units() {
+        assert_eq!(
+            resolve_amount(None, Some(10_500_000)).unwrap(),
+            U256::from(10_500_000u64)
+        );
+        assert_eq!(
+            resolve_amount(Some("10.5"), None).unwrap(),
+            U256::from(10_500_000u64)
+        );
+        assert!(resolve_amount(None, Some(0)).is_err());
+    }
+
+    #[test]
+    fn describe_amount_shows_both_units() {
+        assert_eq!(
+            describe_amount(U256::from(10_500_000u64)),
+            "10.5 USDC (10500000 base units)"
+        );
+    }
+
     #[test]
     fn parse_usdc_amounts_single() {
         let result = parse_usdc_amounts("10").unwrap();
@@ -526,6 +865,23 @@ mod tests {
         assert_eq!(p, vec![U256::from(1u64), U256::from(2u64)]);
     }
 
+    #[test]
+    fn outcome_index_sets_are_single_bits() {
+        assert_eq!(
+            outcome_index_sets(3),
+            vec![U256::from(1u64), U256::from(2u64), U256::from(4u64)]
+        );
+        assert!(outcome_index_sets(0).is_empty());
+    }
+
+    #[test]
+    fn shares_from_base_units_uses_six_decimals() {
+        assert_eq!(
+            shares_from_base_units(U256::from(12_345_000u64)),
+            Decimal::new(12_345, 3)
+        );
+    }
+
     #[test]
     fn default_index_sets_is_binary() {
         let s = default_index_sets();
@@ -1,20 +1,32 @@
-use super::{parse_address, parse_condition_id};
+use std::collections::{HashSet, VecDeque};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::copy::MAX_SEEN_TRADE_KEYS;
+use super::{normalize_slug_input, parse_address, parse_condition_id};
+use crate::net::{fetch_all, for_each_page, retry};
 use crate::output::OutputFormat;
 use crate::output::data::{
-    print_activity, print_builder_leaderboard, print_builder_volume, print_closed_positions,
-    print_holders, print_leaderboard, print_live_volume, print_open_interest, print_positions,
-    print_traded, print_trades, print_value,
+    LeaderboardColumn, print_activity, print_builder_leaderboard, print_builder_volume,
+    print_closed_positions, print_holders, print_leaderboard, print_live_volume,
+    print_open_interest, print_positions, print_trade_line, print_traded, print_trades,
+    print_value,
 };
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::{Args, Subcommand};
+use polymarket_client_sdk::data::types::response::{Position, Trade};
 use polymarket_client_sdk::data::{
     self,
+    types::MarketFilter,
     types::request::{
         ActivityRequest, BuilderLeaderboardRequest, BuilderVolumeRequest, ClosedPositionsRequest,
         HoldersRequest, LiveVolumeRequest, OpenInterestRequest, PositionsRequest, TradedRequest,
         TraderLeaderboardRequest, TradesRequest, ValueRequest,
     },
 };
+use polymarket_client_sdk::gamma::{self, types::request::MarketBySlugRequest};
+use polymarket_client_sdk::types::{Address, B256};
 
 #[derive(Args)]
 pub struct DataArgs {
@@ -30,12 +42,22 @@ pub enum DataCommand {
         address: String,
 
         /// Max results
-        #[arg(long, default_value = "25")]
+        #[arg(long, default_value = "25", conflicts_with_all = ["condition", "market"])]
         limit: i32,
 
         /// Pagination offset
-        #[arg(long)]
+        #[arg(long, conflicts_with_all = ["condition", "market"])]
         offset: Option<i32>,
+
+        /// Only the position(s) in this market (condition ID 0x...); the API
+        /// filters by market and every matching position is returned
+        #[arg(long, conflicts_with = "market")]
+        condition: Option<String>,
+
+        /// Only the position(s) in this market, by slug (resolved via Gamma);
+        /// every matching position is returned
+        #[arg(long)]
+        market: Option<String>,
     },
 
     /// Get closed positions for a wallet address
@@ -76,6 +98,31 @@ pub enum DataCommand {
         /// Pagination offset
         #[arg(long)]
         offset: Option<i32>,
+
+        /// Keep polling and print only new trades as they appear (Ctrl-C to stop)
+        #[arg(long)]
+        follow: bool,
+
+        /// Seconds between polls in --follow mode
+        #[arg(long, default_value = "5", requires = "follow", value_parser = clap::value_parser!(u64).range(1..))]
+        interval: u64,
+
+        /// Follow pagination and fetch every page (--limit is the page size)
+        #[arg(long, conflicts_with = "follow")]
+        all: bool,
+
+        /// Stop --all after this many pages
+        #[arg(long, default_value_t = crate::net::DEFAULT_MAX_PAGES, requires = "all")]
+        max_pages: u32,
+
+        /// Also write the fetched trades to this CSV file (replaced unless --export-append)
+        #[arg(long, alias = "csv-to", conflicts_with = "follow")]
+        export: Option<PathBuf>,
+
+        /// Add to the --export file instead of replacing it, skipping trades
+        /// already in it (same transaction hash and asset)
+        #[arg(long, requires = "export")]
+        export_append: bool,
     },
 
     /// Get on-chain activity for a wallet address
@@ -131,6 +178,14 @@ pub enum DataCommand {
         /// Pagination offset
         #[arg(long)]
         offset: Option<i32>,
+
+        /// Columns to show, comma-separated (rank, user_name, proxy_wallet, pnl, volume)
+        #[arg(long, value_enum, value_delimiter = ',')]
+        columns: Option<Vec<LeaderboardColumn>>,
+
+        /// Only print the first N entries of the fetched page
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+        top: Option<u32>,
     },
 
     /// Builder leaderboard
@@ -214,16 +269,241 @@ pub async fn execute(client: &data::Client, args: DataArgs, output: OutputFormat
     }
 }
 
+/// Polls the wallet's trades forever, printing each transaction the first
+/// time it shows up, oldest first. Poll failures are reported and retried on
+/// the next tick rather than ending the stream.
+async fn follow_trades(
+    client: &data::Client,
+    request: &TradesRequest,
+    interval: u64,
+    output: &OutputFormat,
+) -> Result<()> {
+    let mut seen = SeenKeys::default();
+    loop {
+        match client.trades(request).await {
+            Ok(trades) => {
+                let fresh = take_unseen(trades, |t| t.transaction_hash.to_string(), &mut seen);
+                for t in &fresh {
+                    print_trade_line(t, output)?;
+                }
+                std::io::stdout().flush()?;
+            }
+            Err(e) => tracing::warn!("could not fetch trades: {e}"),
+        }
+        tokio::time::sleep(Duration::from_secs(interval)).await;
+    }
+}
+
+/// Keys `--follow` has printed, forgetting the oldest past the monitor's
+/// `MAX_SEEN_TRADE_KEYS` (far more than one page can bring back), so a long
+/// follow stays bounded.
+#[derive(Default)]
+struct SeenKeys {
+    keys: HashSet<String>,
+    order: VecDeque<String>,
+}
+
+impl SeenKeys {
+    /// Returns false if `key` was already seen.
+    fn insert(&mut self, key: String) -> bool {
+        if !self.keys.insert(key.clone()) {
+            return false;
+        }
+        self.order.push_back(key);
+        while self.order.len() > MAX_SEEN_TRADE_KEYS {
+            if let Some(oldest) = self.order.pop_front() {
+                self.keys.remove(&oldest);
+            }
+        }
+        true
+    }
+}
+
+/// Keeps the items whose key hasn't been seen yet, recording their keys.
+/// The API lists newest first, so the result is reversed to read like a log.
+fn take_unseen<T>(items: Vec<T>, key: impl Fn(&T) -> String, seen: &mut SeenKeys) -> Vec<T> {
+    let mut fresh: Vec<T> = items.into_iter().filter(|t| seen.insert(key(t))).collect();
+    fresh.reverse();
+    fresh
+}
+
+const TRADE_CSV_HEADER: [&str; 12] = [
+    "timestamp",
+    "transaction_hash",
+    "asset",
+    "condition_id",
+    "side",
+    "outcome",
+    "outcome_index",
+    "size",
+    "price",
+    "title",
+    "slug",
+    "proxy_wallet",
+];
+
+fn trade_csv_row(t: &Trade) -> Vec<String> {
+    vec![
+        t.timestamp.to_string(),
+        t.transaction_hash.to_string(),
+        t.asset.to_string(),
+        t.condition_id.to_string(),
+        t.side.to_string(),
+        t.outcome.clone(),
+        t.outcome_index.to_string(),
+        t.size.to_string(),
+        t.price.to_string(),
+        t.title.clone(),
+        t.slug.clone(),
+        t.proxy_wallet.to_string(),
+    ]
+}
+
+/// A trade is identified by its transaction hash and asset: one transaction
+/// can fill several outcome tokens.
+fn trade_csv_key(row: &[String]) -> (String, String) {
+    let field = |i: usize| row.get(i).cloned().unwrap_or_default();
+    (field(1), field(2))
+}
+
+fn export_trades(path: &Path, trades: &[Trade], append: bool) -> Result<()> {
+    let rows: Vec<Vec<String>> = trades.iter().map(trade_csv_row).collect();
+    let (written, skipped) = write_trade_rows(path, &rows, append)?;
+    tracing::info!(
+        "Exported {written} trade(s) to {} ({skipped} already present)",
+        path.display()
+    );
+    Ok(())
+}
+
+/// Writes `rows` (in `TRADE_CSV_HEADER` order) to `path`, skipping repeats.
+/// With `append`, keys already in the file count as repeats and new rows are
+/// added after them. Returns how many rows were written and skipped.
+fn write_trade_rows(path: &Path, rows: &[Vec<String>], append: bool) -> Result<(usize, usize)> {
+    let existing = if append && path.exists() {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("Could not read {}", path.display()))?
+    } else {
+        String::new()
+    };
+    let mut seen = HashSet::new();
+    let mut body = String::new();
+    if existing.is_empty() {
+        body.push_str(&crate::output::csv_line(&TRADE_CSV_HEADER));
+        body.push('\n');
+    } else {
+        let records = crate::output::csv_records(&existing);
+        anyhow::ensure!(
+            records
+                .first()
+                .is_some_and(|h| h.iter().map(String::as_str).eq(TRADE_CSV_HEADER)),
+            "{} was not written by `data trades --export`; refusing to append",
+            path.display()
+        );
+        seen.extend(records.iter().skip(1).map(|r| trade_csv_key(r)));
+        if !existing.ends_with('\n') {
+            body.push('\n');
+        }
+    }
+
+    let mut skipped = 0;
+    for row in rows {
+        if seen.insert(trade_csv_key(row)) {
+            body.push_str(&crate::output::csv_line(row));
+            body.push('\n');
+        } else {
+            skipped += 1;
+        }
+    }
+
+    let result = if existing.is_empty() {
+        std::fs::write(path, body)
+    } else {
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(path)
+            .and_then(|mut f| f.write_all(body.as_bytes()))
+    };
+    result.with_context(|| format!("Could not write {}", path.display()))?;
+    Ok((rows.len() - skipped, skipped))
+}
+
+async fn resolve_market_condition(slug: &str) -> Result<B256> {
+    let slug = normalize_slug_input(slug);
+    let request = MarketBySlugRequest::builder().slug(&slug).build();
+    let client = gamma::Client::default();
+    let market = retry(|| client.market_by_slug(&request))
+        .await
+        .with_context(|| format!("Market not found: {slug}"))?;
+    market
+        .condition_id
+        .ok_or_else(|| anyhow::anyhow!("Market {slug} has no condition ID"))
+}
+
+const MARKET_POSITIONS_PAGE: i32 = 500;
+
+/// One page of `user`'s positions, filtered to `condition_id` by the API.
+fn market_positions_request(
+    user: Address,
+    condition_id: B256,
+    offset: i32,
+) -> Result<PositionsRequest> {
+    Ok(PositionsRequest::builder()
+        .user(user)
+        .filter(MarketFilter::markets([condition_id]))
+        .limit(MARKET_POSITIONS_PAGE)?
+        .maybe_offset(Some(offset))?
+        .build())
+}
+
+/// Every open position of `user` in `condition_id`, paged until a short page.
+async fn positions_in_market(
+    client: &data::Client,
+    user: Address,
+    condition_id: B256,
+) -> Result<Vec<Position>> {
+    const MAX_PAGES: i32 = 20;
+
+    let mut out = Vec::new();
+    for page in 0..MAX_PAGES {
+        let request = market_positions_request(user, condition_id, page * MARKET_POSITIONS_PAGE)?;
+        let batch = retry(|| client.positions(&request)).await?;
+        let done = batch.len() < usize::try_from(MARKET_POSITIONS_PAGE).unwrap_or(0);
+        out.extend(batch);
+        if done {
+            break;
+        }
+    }
+    Ok(out)
+}
+
 async fn execute_user(
     client: &data::Client,
     command: DataCommand,
     output: &OutputFormat,
 ) -> Result<()> {
     match command {
+        DataCommand::Positions {
+            address,
+            condition,
+            market,
+            ..
+        } if condition.is_some() || market.is_some() => {
+            let user = parse_address(&address)?;
+            let condition_id = match (condition, market) {
+                (Some(c), _) => parse_condition_id(&c)?,
+                (None, Some(slug)) => resolve_market_condition(&slug).await?,
+                (None, None) => unreachable!("guarded above"),
+            };
+            let positions = positions_in_market(client, user, condition_id).await?;
+            print_positions(&positions, output)?;
+        }
+
         DataCommand::Positions {
             address,
             limit,
             offset,
+            ..
         } => {
             let request = PositionsRequest::builder()
                 .user(parse_address(&address)?)
@@ -231,7 +511,7 @@ async fn execute_user(
                 .maybe_offset(offset)?
                 .build();
 
-            let positions = client.positions(&request).await?;
+            let positions = retry(|| client.positions(&request)).await?;
             print_positions(&positions, output)?;
         }
 
@@ -246,7 +526,7 @@ async fn execute_user(
                 .maybe_offset(offset)?
                 .build();
 
-            let positions = client.closed_positions(&request).await?;
+            let positions = retry(|| client.closed_positions(&request)).await?;
             print_closed_positions(&positions, output)?;
         }
 
@@ -255,7 +535,7 @@ async fn execute_user(
                 .user(parse_address(&address)?)
                 .build();
 
-            let values = client.value(&request).await?;
+            let values = retry(|| client.value(&request)).await?;
             print_value(&values, output)?;
         }
 
@@ -264,7 +544,7 @@ async fn execute_user(
                 .user(parse_address(&address)?)
                 .build();
 
-            let traded = client.traded(&request).await?;
+            let traded = retry(|| client.traded(&request)).await?;
             print_traded(&traded, output)?;
         }
 
@@ -272,14 +552,75 @@ async fn execute_user(
             address,
             limit,
             offset,
+            follow: true,
+            interval,
+            ..
         } => {
             let request = TradesRequest::builder()
                 .user(parse_address(&address)?)
                 .limit(limit)?
                 .maybe_offset(offset)?
                 .build();
+            follow_trades(client, &request, interval, output).await?;
+        }
 
-            let trades = client.trades(&request).await?;
+        DataCommand::Trades {
+            address,
+            limit,
+            offset,
+            all: true,
+            max_pages,
+            export,
+            export_append,
+            ..
+        } => {
+            let user = parse_address(&address)?;
+            let page = |offset| async move {
+                let request = TradesRequest::builder()
+                    .user(user)
+                    .limit(limit)?
+                    .maybe_offset(Some(offset))?
+                    .build();
+                retry(|| client.trades(&request)).await
+            };
+            let key = |t: &Trade| {
+                format!(
+                    "{}|{}|{}|{}|{}|{}",
+                    t.transaction_hash, t.asset, t.side, t.timestamp, t.size, t.price
+                )
+            };
+            if export.is_none() && crate::output::ndjson() {
+                let start = offset.unwrap_or(0);
+                return for_each_page(start, limit, max_pages, key, page, |trades| {
+                    print_trades(&trades, output)
+                })
+                .await;
+            }
+            let trades = fetch_all(offset.unwrap_or(0), limit, max_pages, key, page).await?;
+            if let Some(path) = export {
+                export_trades(&path, &trades, export_append)?;
+            }
+            print_trades(&trades, output)?;
+        }
+
+        DataCommand::Trades {
+            address,
+            limit,
+            offset,
+            export,
+            export_append,
+            ..
+        } => {
+            let request = TradesRequest::builder()
+                .user(parse_address(&address)?)
+                .limit(limit)?
+                .maybe_offset(offset)?
+                .build();
+
+            let trades = retry(|| client.trades(&request)).await?;
+            if let Some(path) = export {
+                export_trades(&path, &trades, export_append)?;
+            }
             print_trades(&trades, output)?;
         }
 
@@ -294,7 +635,7 @@ async fn execute_user(
                 .maybe_offset(offset)?
                 .build();
 
-            let activity = client.activity(&request).await?;
+            let activity = retry(|| client.activity(&request)).await?;
             print_activity(&activity, output)?;
         }
 
@@ -317,7 +658,7 @@ async fn execute_market(
                 .limit(limit)?
                 .build();
 
-            let holders = client.holders(&request).await?;
+            let holders = retry(|| client.holders(&request)).await?;
             print_holders(&holders, output)?;
         }
 
@@ -325,13 +666,13 @@ async fn execute_market(
             let cid = parse_condition_id(&market)?;
             let request = OpenInterestRequest::builder().markets(vec![cid]).build();
 
-            let oi = client.open_interest(&request).await?;
+            let oi = retry(|| client.open_interest(&request)).await?;
             print_open_interest(&oi, output)?;
         }
 
         DataCommand::Volume { id } => {
             let request = LiveVolumeRequest::builder().id(id).build();
-            let volume = client.live_volume(&request).await?;
+            let volume = retry(|| client.live_volume(&request)).await?;
             print_live_volume(&volume, output)?;
         }
 
@@ -352,6 +693,8 @@ async fn execute_leaderboard(
             order_by,
             limit,
             offset,
+            columns,
+            top,
         } => {
             let request = TraderLeaderboardRequest::builder()
                 .maybe_time_period(period.map(Into::into))
@@ -360,8 +703,9 @@ async fn execute_leaderboard(
                 .maybe_offset(offset)?
                 .build();
 
-            let entries = client.leaderboard(&request).await?;
-            print_leaderboard(&entries, output)?;
+            let entries = retry(|| client.leaderboard(&request)).await?;
+            let shown = top.map_or(entries.len(), |n| entries.len().min(n as usize));
+            print_leaderboard(&entries[..shown], columns.as_deref(), output)?;
         }
 
         DataCommand::BuilderLeaderboard {
@@ -375,7 +719,7 @@ async fn execute_leaderboard(
                 .maybe_offset(offset)?
                 .build();
 
-            let entries = client.builder_leaderboard(&request).await?;
+            let entries = retry(|| client.builder_leaderboard(&request)).await?;
             print_builder_leaderboard(&entries, output)?;
         }
 
@@ -384,7 +728,7 @@ async fn execute_leaderboard(
                 .maybe_time_period(period.map(Into::into))
                 .build();
 
-            let entries = client.builder_volume(&request).await?;
+            let entries = retry(|| client.builder_volume(&request)).await?;
             print_builder_volume(&entries, output)?;
         }
 
@@ -393,3 +737,85 @@ async fn execute_leaderboard(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn market_positions_request_filters_by_condition() {
+        let condition: B256 = "0x1111111111111111111111111111111111111111111111111111111111111111"
+            .parse()
+            .unwrap();
+        let request = market_positions_request(Address::ZERO, condition, 500).unwrap();
+        let query = serde_json::to_value(&request).unwrap();
+        assert_eq!(query["market"], condition.to_string());
+        assert_eq!(query["offset"], 500);
+    }
+
+    #[test]
+    fn take_unseen_skips_known_keys_and_reads_oldest_first() {
+        let mut seen = SeenKeys::default();
+        let first = take_unseen(vec!["c", "b", "a"], |s| (*s).to_string(), &mut seen);
+        assert_eq!(first, ["a", "b", "c"]);
+
+        let second = take_unseen(vec!["e", "d", "c", "b"], |s| (*s).to_string(), &mut seen);
+        assert_eq!(second, ["d", "e"]);
+
+        let third = take_unseen(vec!["e", "d"], |s| (*s).to_string(), &mut seen);
+        assert!(third.is_empty());
+    }
+
+    #[test]
+    fn follow_seen_keys_evict_oldest_past_cap() {
+        let mut seen = SeenKeys::default();
+        for i in 0..=MAX_SEEN_TRADE_KEYS {
+            assert!(seen.insert(format!("k{i}")));
+        }
+        assert_eq!(seen.keys.len(), MAX_SEEN_TRADE_KEYS);
+        assert!(seen.insert("k0".to_string()));
+        assert!(!seen.insert(format!("k{MAX_SEEN_TRADE_KEYS}")));
+    }
+
+    fn trade_row(hash: &str, asset: &str, title: &str) -> Vec<String> {
+        let mut row = vec![String::new(); TRADE_CSV_HEADER.len()];
+        row[1] = hash.to_string();
+        row[2] = asset.to_string();
+        row[9] = title.to_string();
+        row
+    }
+
+    #[test]
+    fn export_append_twice_adds_no_duplicate_rows() {
+        let path =
+            std::env::temp_dir().join(format!("pm-trades-export-{}.csv", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let rows = vec![
+            trade_row("0xaa", "1", "Rain, today?"),
+            trade_row("0xaa", "2", "Rain, today?"),
+            trade_row("0xbb", "1", "Say \"hi\""),
+        ];
+
+        assert_eq!(write_trade_rows(&path, &rows, true).unwrap(), (3, 0));
+        assert_eq!(write_trade_rows(&path, &rows, true).unwrap(), (0, 3));
+        let mut more = rows.clone();
+        more.push(trade_row("0xcc", "1", "New"));
+        assert_eq!(write_trade_rows(&path, &more, true).unwrap(), (1, 3));
+
+        let records = crate::output::csv_records(&std::fs::read_to_string(&path).unwrap());
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(records.len(), 5, "header plus four distinct trades");
+        assert_eq!(records[0], TRADE_CSV_HEADER);
+        assert_eq!(records[3], rows[2]);
+    }
+
+    #[test]
+    fn export_refuses_to_append_to_a_foreign_csv() {
+        let path =
+            std::env::temp_dir().join(format!("pm-trades-foreign-{}.csv", std::process::id()));
+        std::fs::write(&path, "a,b\n1,2\n").unwrap();
+        let err = write_trade_rows(&path, &[trade_row("0xaa", "1", "x")], true).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert!(err.to_string().contains("refusing to append"));
+    }
+}
@@ -5,10 +5,14 @@ use polymarket_client_sdk::gamma::{
     types::request::{EventByIdRequest, EventBySlugRequest, EventTagsRequest, EventsRequest},
 };
 
-use super::is_numeric_id;
-use crate::output::events::{print_event_detail, print_events_table};
+use super::{is_numeric_id, normalize_event_slug_input};
+use crate::cache::cached;
+use crate::net::timed;
+use crate::output::events::{
+    print_event_detail, print_event_tree, print_events_table, print_volume_breakdown,
+};
 use crate::output::tags::print_tags_table;
-use crate::output::{OutputFormat, print_json};
+use crate::output::{OutputFormat, print_json, print_json_list};
 
 #[derive(Args)]
 pub struct EventsArgs {
@@ -53,6 +57,14 @@ pub enum EventsCommand {
     Get {
         /// Event ID (numeric) or slug
         id: String,
+
+        /// Show each child market's share of the event's total volume
+        #[arg(long)]
+        volume_breakdown: bool,
+
+        /// Show the event with its child markets (question and price) nested beneath it
+        #[arg(long, conflicts_with = "volume_breakdown")]
+        tree: bool,
     },
 
     /// Get tags for an event
@@ -84,37 +96,49 @@ pub async fn execute(client: &gamma::Client, args: EventsArgs, output: OutputFor
                 .order(order.into_iter().collect::<Vec<_>>())
                 .build();
 
-            let events = client.events(&request).await?;
+            let events = cached("events", &request, || timed(client.events(&request))).await?;
 
             match output {
                 OutputFormat::Table => print_events_table(&events),
-                OutputFormat::Json => print_json(&events)?,
+                OutputFormat::Json | OutputFormat::Yaml => print_json_list(&events)?,
             }
         }
 
-        EventsCommand::Get { id } => {
+        EventsCommand::Get {
+            id,
+            volume_breakdown,
+            tree,
+        } => {
+            let id = normalize_event_slug_input(&id);
             let is_numeric = is_numeric_id(&id);
             let event = if is_numeric {
                 let req = EventByIdRequest::builder().id(id).build();
-                client.event_by_id(&req).await?
+                cached("event_by_id", &req, || timed(client.event_by_id(&req))).await?
             } else {
                 let req = EventBySlugRequest::builder().slug(id).build();
-                client.event_by_slug(&req).await?
+                cached("event_by_slug", &req, || timed(client.event_by_slug(&req))).await?
             };
 
+            if volume_breakdown {
+                return print_volume_breakdown(&event, output);
+            }
+            if tree {
+                return print_event_tree(&event, output);
+            }
+
             match output {
                 OutputFormat::Table => print_event_detail(&event),
-                OutputFormat::Json => print_json(&event)?,
+                OutputFormat::Json | OutputFormat::Yaml => print_json(&event)?,
             }
         }
 
         EventsCommand::Tags { id } => {
             let req = EventTagsRequest::builder().id(id).build();
-            let tags = client.event_tags(&req).await?;
+            let tags = cached("event_tags", &req, || timed(client.event_tags(&req))).await?;
 
             match output {
                 OutputFormat::Table => print_tags_table(&tags),
-                OutputFormat::Json => print_json(&tags)?,
+                OutputFormat::Json | OutputFormat::Yaml => print_json_list(&tags)?,
             }
         }
     }
@@ -1,5 +1,12 @@
+use std::cmp::Ordering;
+use std::time::Duration;
+
 use anyhow::Result;
-use clap::{Args, Subcommand};
+use clap::{Args, Subcommand, ValueEnum};
+use polymarket_client_sdk::clob::{
+    self,
+    types::request::{MidpointRequest, OrderBookSummaryRequest},
+};
 use polymarket_client_sdk::gamma::{
     self,
     types::{
@@ -10,11 +17,16 @@ use polymarket_client_sdk::gamma::{
         response::Market,
     },
 };
+use polymarket_client_sdk::types::Decimal;
 
-use super::is_numeric_id;
-use crate::output::markets::{print_market_detail, print_markets_table};
+use super::{is_numeric_id, normalize_slug_input};
+use crate::cache::cached;
+use crate::net::{fetch_all, for_each_page, retry};
+use crate::output::markets::{
+    TokenBook, print_market_detail, print_market_with_books, print_markets_table, print_watch_tick,
+};
 use crate::output::tags::print_tags_table;
-use crate::output::{OutputFormat, print_json};
+use crate::output::{OutputFormat, print_json, print_json_list};
 
 #[derive(Args)]
 pub struct MarketsArgs {
@@ -49,12 +61,44 @@ pub enum MarketsCommand {
         /// Sort ascending instead of descending
         #[arg(long)]
         ascending: bool,
+
+        /// Only markets carrying this tag (see `tags list` / `markets tags` for IDs)
+        #[arg(long)]
+        tag_id: Option<String>,
+
+        /// Re-sort the fetched page locally (markets missing the field go last)
+        #[arg(long, value_enum)]
+        sort: Option<MarketSort>,
+
+        /// With --sort, largest / latest first
+        #[arg(long, requires = "sort")]
+        desc: bool,
+
+        /// Drop markets with less volume than this (USD)
+        #[arg(long)]
+        min_volume: Option<Decimal>,
+
+        /// Drop markets with less liquidity than this (USD)
+        #[arg(long)]
+        min_liquidity: Option<Decimal>,
+
+        /// Follow pagination and fetch every page (--limit is the page size)
+        #[arg(long)]
+        all: bool,
+
+        /// Stop --all after this many pages
+        #[arg(long, default_value_t = crate::net::DEFAULT_MAX_PAGES, requires = "all")]
+        max_pages: u32,
     },
 
     /// Get a single market by ID or slug
     Get {
         /// Market ID (numeric) or slug
         id: String,
+
+        /// Also fetch the live CLOB order book of each outcome token
+        #[arg(long)]
+        with_book: bool,
     },
 
     /// Search markets
@@ -72,6 +116,35 @@ pub enum MarketsCommand {
         /// Market ID
         id: String,
     },
+
+    /// Poll a token's midpoint and best bid/ask until Ctrl-C
+    Watch {
+        /// Token ID (numeric string)
+        token_id: String,
+
+        /// Seconds between polls
+        #[arg(long, default_value = "2", value_parser = clap::value_parser!(u64).range(1..))]
+        interval_secs: u64,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum MarketSort {
+    Volume,
+    Liquidity,
+    EndDate,
+}
+
+/// One `markets watch` sample. Prices are `None` when that side of the book
+/// is empty (or the midpoint request failed).
+#[derive(Debug, serde::Serialize)]
+pub struct WatchTick {
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    pub token_id: String,
+    pub midpoint: Option<polymarket_client_sdk::types::Decimal>,
+    pub best_bid: Option<polymarket_client_sdk::types::Decimal>,
+    pub best_ask: Option<polymarket_client_sdk::types::Decimal>,
+    pub spread: Option<polymarket_client_sdk::types::Decimal>,
 }
 
 pub async fn execute(
@@ -87,38 +160,88 @@ pub async fn execute(
             offset,
             order,
             ascending,
+            tag_id,
+            sort,
+            desc,
+            min_volume,
+            min_liquidity,
+            all,
+            max_pages,
         } => {
             let resolved_closed = closed.or_else(|| active.map(|a| !a));
 
-            let request = MarketsRequest::builder()
-                .limit(limit)
-                .maybe_closed(resolved_closed)
-                .maybe_offset(offset)
-                .maybe_order(order)
-                .maybe_ascending(if ascending { Some(true) } else { None })
-                .build();
+            let request_at = |offset: Option<i32>| {
+                MarketsRequest::builder()
+                    .limit(limit)
+                    .maybe_closed(resolved_closed)
+                    .maybe_offset(offset)
+                    .maybe_order(order.clone())
+                    .maybe_ascending(if ascending { Some(true) } else { None })
+                    .maybe_tag_id(tag_id.clone())
+                    .build()
+            };
 
-            let markets = client.markets(&request).await?;
+            let mut markets = if all {
+                let page = |offset| {
+                    let request = request_at(Some(offset));
+                    async move {
+                        cached("markets", &request, || retry(|| client.markets(&request))).await
+                    }
+                };
+                // Sorting needs every page; otherwise stream them as they arrive.
+                if sort.is_none() && crate::output::ndjson() {
+                    let start = offset.unwrap_or(0);
+                    let key = |m: &Market| m.id.clone();
+                    return for_each_page(start, limit, max_pages, key, page, |markets| {
+                        print_json_list(&filter_markets(markets, min_volume, min_liquidity))
+                    })
+                    .await;
+                }
+                fetch_all(
+                    offset.unwrap_or(0),
+                    limit,
+                    max_pages,
+                    |m: &Market| m.id.clone(),
+                    page,
+                )
+                .await?
+            } else {
+                let request = request_at(offset);
+                cached("markets", &request, || retry(|| client.markets(&request))).await?
+            };
+            markets = filter_markets(markets, min_volume, min_liquidity);
+            if let Some(key) = sort {
+                markets = sort_markets(markets, key, desc);
+            }
 
             match output {
                 OutputFormat::Table => print_markets_table(&markets),
-                OutputFormat::Json => print_json(&markets)?,
+                OutputFormat::Json | OutputFormat::Yaml => print_json_list(&markets)?,
             }
         }
 
-        MarketsCommand::Get { id } => {
+        MarketsCommand::Get { id, with_book } => {
+            let id = normalize_slug_input(&id);
             let is_numeric = is_numeric_id(&id);
             let market = if is_numeric {
                 let req = MarketByIdRequest::builder().id(id).build();
-                client.market_by_id(&req).await?
+                cached("market_by_id", &req, || retry(|| client.market_by_id(&req))).await?
             } else {
                 let req = MarketBySlugRequest::builder().slug(id).build();
-                client.market_by_slug(&req).await?
+                cached("market_by_slug", &req, || {
+                    retry(|| client.market_by_slug(&req))
+                })
+                .await?
             };
 
-            match output {
-                OutputFormat::Table => print_market_detail(&market),
-                OutputFormat::Json => print_json(&market)?,
+            if with_book {
+                let books = fetch_token_books(&market).await;
+                print_market_with_books(&market, &books, &output)?;
+            } else {
+                match output {
+                    OutputFormat::Table => print_market_detail(&market),
+                    OutputFormat::Json | OutputFormat::Yaml => print_json(&market)?,
+                }
             }
         }
 
@@ -128,7 +251,7 @@ pub async fn execute(
                 .limit_per_type(limit)
                 .build();
 
-            let results = client.search(&request).await?;
+            let results = cached("search", &request, || retry(|| client.search(&request))).await?;
 
             let markets: Vec<Market> = results
                 .events
@@ -139,20 +262,195 @@ pub async fn execute(
 
             match output {
                 OutputFormat::Table => print_markets_table(&markets),
-                OutputFormat::Json => print_json(&markets)?,
+                OutputFormat::Json | OutputFormat::Yaml => print_json_list(&markets)?,
             }
         }
 
+        MarketsCommand::Watch {
+            token_id,
+            interval_secs,
+        } => watch_token(&token_id, interval_secs, &output).await?,
+
         MarketsCommand::Tags { id } => {
             let req = MarketTagsRequest::builder().id(id).build();
-            let tags = client.market_tags(&req).await?;
+            let tags = cached("market_tags", &req, || retry(|| client.market_tags(&req))).await?;
 
             match output {
                 OutputFormat::Table => print_tags_table(&tags),
-                OutputFormat::Json => print_json(&tags)?,
+                OutputFormat::Json | OutputFormat::Yaml => print_json_list(&tags)?,
             }
         }
     }
 
     Ok(())
 }
+
+/// Keeps markets meeting both minimums; a market without the field fails a
+/// minimum that is set.
+fn filter_markets(
+    markets: Vec<Market>,
+    min_volume: Option<Decimal>,
+    min_liquidity: Option<Decimal>,
+) -> Vec<Market> {
+    let meets = |value: Option<Decimal>, min: Option<Decimal>| {
+        min.is_none_or(|min| value.is_some_and(|v| v >= min))
+    };
+    markets
+        .into_iter()
+        .filter(|m| meets(m.volume_num, min_volume) && meets(m.liquidity_num, min_liquidity))
+        .collect()
+}
+
+/// Stable sort on `key`, ascending unless `desc`. Markets missing the field
+/// keep their API order after all the others.
+fn sort_markets(mut markets: Vec<Market>, key: MarketSort, desc: bool) -> Vec<Market> {
+    markets.sort_by(|a, b| match key {
+        MarketSort::Volume => cmp_missing_last(a.volume_num, b.volume_num, desc),
+        MarketSort::Liquidity => cmp_missing_last(a.liquidity_num, b.liquidity_num, desc),
+        MarketSort::EndDate => cmp_missing_last(a.end_date, b.end_date, desc),
+    });
+    markets
+}
+
+fn cmp_missing_last<T: Ord>(a: Option<T>, b: Option<T>, desc: bool) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) if desc => b.cmp(&a),
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Prints a tick every `interval_secs` until Ctrl-C. A failed poll is
+/// reported on stderr and retried on the next tick.
+async fn watch_token(token_id: &str, interval_secs: u64, output: &OutputFormat) -> Result<()> {
+    let client = clob::Client::default();
+    let token = super::clob::parse_token_id(token_id)?;
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+            tick = fetch_watch_tick(&client, token, token_id) => match tick {
+                Ok(tick) => print_watch_tick(&tick, output)?,
+                Err(e) => tracing::warn!("could not fetch quote: {e}"),
+            },
+        }
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+            () = tokio::time::sleep(Duration::from_secs(interval_secs)) => {}
+        }
+    }
+}
+
+/// One book per CLOB token, paired with the outcome at the same position.
+async fn fetch_token_books(market: &Market) -> Vec<TokenBook> {
+    let client = clob::Client::default();
+    let outcomes = market.outcomes.clone().unwrap_or_default();
+    let mut books = Vec::new();
+    for (i, token_id) in market.clob_token_ids.iter().flatten().copied().enumerate() {
+        let request = OrderBookSummaryRequest::builder()
+            .token_id(token_id)
+            .build();
+        books.push(TokenBook {
+            token_id,
+            outcome: outcomes.get(i).cloned(),
+            book: retry(|| client.order_book(&request))
+                .await
+                .map_err(|e| e.to_string()),
+        });
+    }
+    books
+}
+
+async fn fetch_watch_tick(
+    client: &clob::Client,
+    token: polymarket_client_sdk::types::U256,
+    token_id: &str,
+) -> Result<WatchTick> {
+    let book_req = OrderBookSummaryRequest::builder().token_id(token).build();
+    let mid_req = MidpointRequest::builder().token_id(token).build();
+    let (book, mid) = tokio::join!(client.order_book(&book_req), client.midpoint(&mid_req));
+    let book = book?;
+    let best_bid = book.bids.iter().map(|o| o.price).max();
+    let best_ask = book.asks.iter().map(|o| o.price).min();
+    Ok(WatchTick {
+        timestamp: chrono::Utc::now(),
+        token_id: token_id.to_string(),
+        midpoint: mid.ok().map(|m| m.mid),
+        best_bid,
+        best_ask,
+        spread: best_bid.zip(best_ask).map(|(bid, ask)| ask - bid),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn market(id: &str, fields: serde_json::Value) -> Market {
+        let mut val = fields;
+        val["id"] = json!(id);
+        serde_json::from_value(val).unwrap()
+    }
+
+    fn ids(markets: &[Market]) -> Vec<&str> {
+        markets.iter().map(|m| m.id.as_str()).collect()
+    }
+
+    fn sample() -> Vec<Market> {
+        vec![
+            market("a", json!({"volumeNum": "500", "liquidityNum": "10"})),
+            market(
+                "b",
+                json!({"liquidityNum": "300", "endDate": "2025-06-01T00:00:00Z"}),
+            ),
+            market(
+                "c",
+                json!({"volumeNum": "2000", "endDate": "2025-01-01T00:00:00Z"}),
+            ),
+            market("d", json!({"volumeNum": "50", "liquidityNum": "300"})),
+        ]
+    }
+
+    #[test]
+    fn sort_by_volume_puts_missing_last_both_ways() {
+        assert_eq!(
+            ids(&sort_markets(sample(), MarketSort::Volume, false)),
+            ["d", "a", "c", "b"]
+        );
+        assert_eq!(
+            ids(&sort_markets(sample(), MarketSort::Volume, true)),
+            ["c", "a", "d", "b"]
+        );
+    }
+
+    #[test]
+    fn sort_ties_keep_api_order() {
+        assert_eq!(
+            ids(&sort_markets(sample(), MarketSort::Liquidity, true)),
+            ["b", "d", "a", "c"]
+        );
+    }
+
+    #[test]
+    fn sort_by_end_date() {
+        assert_eq!(
+            ids(&sort_markets(sample(), MarketSort::EndDate, false)),
+            ["c", "b", "a", "d"]
+        );
+        assert_eq!(
+            ids(&sort_markets(sample(), MarketSort::EndDate, true)),
+            ["b", "c", "a", "d"]
+        );
+    }
+
+    #[test]
+    fn minimums_drop_markets_missing_the_field() {
+        let kept = filter_markets(sample(), Some(Decimal::from(100)), None);
+        assert_eq!(ids(&kept), ["a", "c"]);
+        let kept = filter_markets(sample(), Some(Decimal::from(10)), Some(Decimal::from(100)));
+        assert_eq!(ids(&kept), ["d"]);
+        assert_eq!(filter_markets(sample(), None, None).len(), 4);
+    }
+}
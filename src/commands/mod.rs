@@ -2,6 +2,7 @@ use polymarket_client_sdk::types::{Address, B256};
 
 pub mod approve;
 pub mod bridge;
+pub mod cache;
 pub mod clob;
 pub mod comments;
 pub mod copy;
@@ -10,6 +11,7 @@ pub mod data;
 pub mod events;
 pub mod markets;
 pub mod profiles;
+pub mod schema;
 pub mod series;
 pub mod setup;
 pub mod sports;
@@ -21,6 +23,41 @@ pub fn is_numeric_id(id: &str) -> bool {
     !id.is_empty() && id.chars().all(|c| c.is_ascii_digit())
 }
 
+/// Extracts a bare slug from what users paste: full Polymarket URLs (with or
+/// without scheme), trailing slashes, query strings and mixed case. For URLs
+/// the last path segment wins, e.g. `https://polymarket.com/event/foo/bar?tid=1`
+/// gives `bar`.
+pub fn normalize_slug_input(input: &str) -> String {
+    slug_path_segments(input)
+        .last()
+        .copied()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+/// Like [`normalize_slug_input`], but for event lookups: a market URL such as
+/// `https://polymarket.com/event/foo/bar` names the event `foo`, not `bar`.
+pub fn normalize_event_slug_input(input: &str) -> String {
+    let segments = slug_path_segments(input);
+    match segments.iter().position(|seg| *seg == "event") {
+        Some(i) if i + 1 < segments.len() => segments[i + 1].to_lowercase(),
+        _ => normalize_slug_input(input),
+    }
+}
+
+/// Path segments of a pasted slug or URL, without scheme, host, query or
+/// fragment.
+fn slug_path_segments(input: &str) -> Vec<&str> {
+    let s = input.trim();
+    let s = s.split(['?', '#']).next().unwrap_or_default();
+    let s = s.split_once("://").map_or(s, |(_, rest)| rest);
+    let mut segments: Vec<&str> = s.split('/').filter(|seg| !seg.is_empty()).collect();
+    if segments.len() > 1 && segments[0].contains('.') {
+        segments.remove(0);
+    }
+    segments
+}
+
 pub fn parse_address(s: &str) -> anyhow::Result<Address> {
     s.parse()
         .map_err(|_| anyhow::anyhow!("Invalid address: must be a 0x-prefixed hex address"))
@@ -53,6 +90,43 @@ mod tests {
         assert!(!is_numeric_id(""));
     }
 
+    #[test]
+    fn normalize_slug_input_extracts_slug_from_url() {
+        assert_eq!(
+            normalize_slug_input("https://polymarket.com/event/will-it-rain?tid=123"),
+            "will-it-rain"
+        );
+        assert_eq!(
+            normalize_slug_input("polymarket.com/event/election/who-wins#comments"),
+            "who-wins"
+        );
+    }
+
+    #[test]
+    fn normalize_slug_input_strips_trailing_slash_and_case() {
+        assert_eq!(normalize_slug_input("Will-It-Rain/"), "will-it-rain");
+        assert_eq!(normalize_slug_input("  will-it-rain//  "), "will-it-rain");
+    }
+
+    #[test]
+    fn normalize_slug_input_passes_bare_slug_through() {
+        assert_eq!(normalize_slug_input("will-it-rain"), "will-it-rain");
+        assert_eq!(normalize_slug_input("500"), "500");
+    }
+
+    #[test]
+    fn normalize_event_slug_input_takes_the_event_segment() {
+        assert_eq!(
+            normalize_event_slug_input("https://polymarket.com/event/election/who-wins?tid=1"),
+            "election"
+        );
+        assert_eq!(
+            normalize_event_slug_input("polymarket.com/event/Will-It-Rain/"),
+            "will-it-rain"
+        );
+        assert_eq!(normalize_event_slug_input("will-it-rain"), "will-it-rain");
+    }
+
     #[test]
     fn parse_address_valid_hex() {
         let addr = "0x0000000000000000000000000000000000000001";
@@ -1,9 +1,23 @@
+use std::process::Command;
+use std::str::FromStr;
+
 use super::parse_address;
-use crate::output::profiles::print_profile_detail;
+use crate::net::{fetch_all, retry, timed};
+use crate::output::profiles::{PnlPoint, print_pnl_history, print_profile_detail};
 use crate::output::{OutputFormat, print_json};
-use anyhow::Result;
-use clap::{Args, Subcommand};
-use polymarket_client_sdk::gamma::{self, types::request::PublicProfileRequest};
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, NaiveDate, Utc};
+use clap::{Args, Subcommand, ValueEnum};
+use polymarket_client_sdk::data::{
+    self,
+    types::{ActivityType, request::ActivityRequest, response::Activity},
+};
+use polymarket_client_sdk::gamma::{
+    self,
+    types::request::{PublicProfileRequest, SearchRequest},
+};
+use polymarket_client_sdk::types::Address;
+use rust_decimal::Decimal;
 
 #[derive(Args)]
 pub struct ProfilesArgs {
@@ -13,13 +27,58 @@ pub struct ProfilesArgs {
 
 #[derive(Subcommand)]
 pub enum ProfilesCommand {
-    /// Get a public profile by wallet address
+    /// Get a public profile by wallet address or username
     Get {
-        /// Wallet address (0x...)
+        /// Wallet address (0x...) or username (@name or name)
+        address: String,
+    },
+    /// PnL and traded volume over time for a wallet, with drawdown from the running peak
+    PnlHistory {
+        /// Wallet address (0x...) or username (@name or name)
         address: String,
+        /// First day to include (YYYY-MM-DD, UTC)
+        #[arg(long)]
+        from: Option<String>,
+        /// Last day to include (YYYY-MM-DD, UTC)
+        #[arg(long)]
+        to: Option<String>,
+        /// Spacing between points
+        #[arg(long, value_enum, default_value_t = PnlInterval::Day)]
+        interval: PnlInterval,
     },
 }
 
+/// Polymarket's PnL chart API; the data SDK has no endpoint for the series.
+const PNL_API: &str = "https://user-pnl-api.polymarket.com/user-pnl";
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum PnlInterval {
+    #[value(name = "1h")]
+    Hour,
+    #[value(name = "12h")]
+    HalfDay,
+    #[value(name = "1d")]
+    Day,
+}
+
+impl PnlInterval {
+    fn fidelity(self) -> &'static str {
+        match self {
+            Self::Hour => "1h",
+            Self::HalfDay => "12h",
+            Self::Day => "1d",
+        }
+    }
+
+    fn seconds(self) -> i64 {
+        match self {
+            Self::Hour => 3_600,
+            Self::HalfDay => 43_200,
+            Self::Day => 86_400,
+        }
+    }
+}
+
 pub async fn execute(
     client: &gamma::Client,
     args: ProfilesArgs,
@@ -27,16 +86,338 @@ pub async fn execute(
 ) -> Result<()> {
     match args.command {
         ProfilesCommand::Get { address } => {
-            let addr = parse_address(&address)?;
+            let addr = resolve_address(client, &address).await?;
             let req = PublicProfileRequest::builder().address(addr).build();
-            let profile = client.public_profile(&req).await?;
+            let profile = timed(client.public_profile(&req)).await?;
 
             match output {
                 OutputFormat::Table => print_profile_detail(&profile),
-                OutputFormat::Json => print_json(&profile)?,
+                OutputFormat::Json | OutputFormat::Yaml => print_json(&profile)?,
             }
         }
+        ProfilesCommand::PnlHistory {
+            address,
+            from,
+            to,
+            interval,
+        } => {
+            let from = from.as_deref().map(parse_day).transpose()?;
+            let to = to.as_deref().map(parse_day).transpose()?;
+            check_range(from, to)?;
+            let addr = resolve_address(client, &address).await?;
+            let mut points = fetch_pnl_history(addr.to_string(), interval).await?;
+            // One interval before `--from` so the first kept point's volume is whole.
+            let since = from.map(|d| day_start(d) - interval.seconds());
+            let until = to.map(|d| day_start(d) + 86_400);
+            attach_volume(&mut points, &fetch_trade_volume(addr, since, until).await?);
+            print_pnl_history(&filter_range(points, from, to), &output)?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn resolve_address(
+    client: &gamma::Client,
+    input: &str,
+) -> Result<polymarket_client_sdk::types::Address> {
+    if input.trim().starts_with("0x") {
+        parse_address(input)
+    } else {
+        parse_address(&resolve_username(client, input).await?)
     }
+}
 
+fn parse_day(s: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .map_err(|_| anyhow::anyhow!("Invalid date '{s}': expected YYYY-MM-DD format"))
+}
+
+fn check_range(from: Option<NaiveDate>, to: Option<NaiveDate>) -> Result<()> {
+    if let (Some(from), Some(to)) = (from, to)
+        && from > to
+    {
+        bail!("--from ({from}) must not be after --to ({to})");
+    }
     Ok(())
 }
+
+/// Keeps points whose UTC day is within `from..=to`.
+fn filter_range(
+    points: Vec<PnlPoint>,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+) -> Vec<PnlPoint> {
+    points
+        .into_iter()
+        .filter(|p| {
+            let day = p.timestamp.date_naive();
+            from.is_none_or(|f| day >= f) && to.is_none_or(|t| day <= t)
+        })
+        .collect()
+}
+
+fn day_start(day: NaiveDate) -> i64 {
+    day.and_time(chrono::NaiveTime::MIN).and_utc().timestamp()
+}
+
+/// Runs curl on a blocking thread so it doesn't stall the async runtime.
+async fn fetch_pnl_history(address: String, interval: PnlInterval) -> Result<Vec<PnlPoint>> {
+    let timeout = crate::net::request_timeout().as_secs().to_string();
+    tokio::task::spawn_blocking(move || {
+        let url = format!(
+            "{PNL_API}?user_address={address}&interval=all&fidelity={}",
+            interval.fidelity()
+        );
+        let output = Command::new("curl")
+            .args(["-sSf", "--max-time", &timeout, &url])
+            .output()
+            .context("Failed to run curl")?;
+        if !output.status.success() {
+            bail!(
+                "Failed to fetch PnL history: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        parse_pnl_history(&String::from_utf8_lossy(&output.stdout))
+    })
+    .await
+    .context("PnL history fetch did not complete")?
+}
+
+/// `user`'s trades between `since` and `until` (unix seconds) as
+/// `(timestamp, usdc)`. Reading stops after `MAX_PAGES` with a warning.
+async fn fetch_trade_volume(
+    user: Address,
+    since: Option<i64>,
+    until: Option<i64>,
+) -> Result<Vec<(i64, Decimal)>> {
+    const PAGE_SIZE: i32 = 500;
+    const MAX_PAGES: u32 = 20;
+
+    let client = data::Client::default();
+    let client = &client;
+    let since = since.and_then(|t| u64::try_from(t).ok());
+    let until = until.and_then(|t| u64::try_from(t).ok());
+    let page = |offset| async move {
+        let request = ActivityRequest::builder()
+            .user(user)
+            .activity_types(vec![ActivityType::Trade])
+            .limit(PAGE_SIZE)?
+            .maybe_offset(Some(offset))?
+            .maybe_start(since)
+            .maybe_end(until)
+            .build();
+        retry(|| client.activity(&request)).await
+    };
+    let trades = fetch_all(
+        0,
+        PAGE_SIZE,
+        MAX_PAGES,
+        |a: &Activity| {
+            format!(
+                "{}|{:?}|{}|{}",
+                a.transaction_hash, a.asset, a.timestamp, a.size
+            )
+        },
+        page,
+    )
+    .await?;
+    Ok(trades
+        .into_iter()
+        .map(|a| (a.timestamp, a.usdc_size))
+        .collect())
+}
+
+/// Sets each point's volume to the USDC traded after the previous point, up
+/// to and including its own timestamp.
+fn attach_volume(points: &mut [PnlPoint], trades: &[(i64, Decimal)]) {
+    let mut previous = i64::MIN;
+    for point in points {
+        let at = point.timestamp.timestamp();
+        point.volume = trades
+            .iter()
+            .filter(|(t, _)| *t > previous && *t <= at)
+            .map(|(_, usdc)| *usdc)
+            .sum();
+        previous = at;
+    }
+}
+
+/// The API answers with `[{"t": <unix seconds>, "p": <pnl>}, ...]`.
+fn parse_pnl_history(body: &str) -> Result<Vec<PnlPoint>> {
+    let json: serde_json::Value =
+        serde_json::from_str(body).context("Failed to parse PnL history response")?;
+    let entries = json
+        .as_array()
+        .context("Unexpected PnL history response: expected an array")?;
+    let mut points = entries
+        .iter()
+        .map(|e| {
+            let t = e["t"].as_i64().context("PnL point without a timestamp")?;
+            let pnl = match &e["p"] {
+                serde_json::Value::Number(n) => Decimal::from_str(&n.to_string())
+                    .or_else(|_| Decimal::from_scientific(&n.to_string()))
+                    .ok(),
+                serde_json::Value::String(s) => Decimal::from_str(s).ok(),
+                _ => None,
+            }
+            .context("PnL point without a value")?;
+            let timestamp = DateTime::<Utc>::from_timestamp(t, 0)
+                .with_context(|| format!("Invalid PnL timestamp {t}"))?;
+            Ok(PnlPoint {
+                timestamp,
+                pnl,
+                volume: Decimal::ZERO,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    points.sort_by_key(|p| p.timestamp);
+    Ok(points)
+}
+
+/// Resolves a Polymarket username to its proxy wallet address through the
+/// Gamma profile search.
+async fn resolve_username(client: &gamma::Client, input: &str) -> Result<String> {
+    let username = input.trim().trim_start_matches('@');
+    if username.is_empty() {
+        bail!("Username cannot be empty");
+    }
+    let request = SearchRequest::builder()
+        .q(username.to_string())
+        .search_profiles(true)
+        .build();
+    let results = timed(client.search(&request)).await?;
+    let candidates: Vec<(String, String)> = results
+        .profiles
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|p| {
+            let address = p.proxy_wallet.map(|a| format!("{a}"))?;
+            let name = p.name.or(p.pseudonym).unwrap_or_default();
+            Some((name, address))
+        })
+        .collect();
+    pick_profile_match(username, &candidates)
+}
+
+/// Picks the address for `username` from `(name, address)` search hits.
+/// Exact (case-insensitive) name matches win over fuzzy hits; more than one
+/// candidate is an error listing them so the user can pass an address instead.
+fn pick_profile_match(username: &str, candidates: &[(String, String)]) -> Result<String> {
+    let exact: Vec<&(String, String)> = candidates
+        .iter()
+        .filter(|(name, _)| name.eq_ignore_ascii_case(username))
+        .collect();
+    let matches: Vec<&(String, String)> = if exact.is_empty() {
+        candidates.iter().collect()
+    } else {
+        exact
+    };
+
+    match matches.as_slice() {
+        [] => bail!("No profile found for username '{username}'"),
+        [(_, address)] => Ok(address.clone()),
+        many => {
+            let listing = many
+                .iter()
+                .map(|(name, address)| format!("  {name} ({address})"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            bail!(
+                "Multiple profiles match '{username}'; re-run with one of these addresses:\n{listing}"
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hit(name: &str, address: &str) -> (String, String) {
+        (name.to_string(), address.to_string())
+    }
+
+    #[test]
+    fn pick_profile_prefers_exact_name_match() {
+        let hits = vec![hit("alice", "0xaaa"), hit("alice2", "0xbbb")];
+        assert_eq!(pick_profile_match("Alice", &hits).unwrap(), "0xaaa");
+    }
+
+    #[test]
+    fn pick_profile_single_fuzzy_hit_is_used() {
+        let hits = vec![hit("alice_trades", "0xaaa")];
+        assert_eq!(pick_profile_match("alice", &hits).unwrap(), "0xaaa");
+    }
+
+    #[test]
+    fn pick_profile_ambiguous_lists_candidates() {
+        let hits = vec![hit("bob1", "0xaaa"), hit("bob2", "0xbbb")];
+        let err = pick_profile_match("bob", &hits).unwrap_err().to_string();
+        assert!(err.contains("0xaaa") && err.contains("0xbbb"));
+    }
+
+    #[test]
+    fn pick_profile_no_hits_errors() {
+        assert!(pick_profile_match("nobody", &[]).is_err());
+    }
+
+    #[test]
+    fn pnl_history_parses_and_sorts_points() {
+        let body = r#"[{"t":1735776000,"p":12.5},{"t":1735689600,"p":-3}]"#;
+        let points = parse_pnl_history(body).unwrap();
+        assert_eq!(points.len(), 2);
+        assert_eq!(
+            points[0].timestamp.to_rfc3339(),
+            "2025-01-01T00:00:00+00:00"
+        );
+        assert_eq!(points[0].pnl, Decimal::from(-3));
+        assert_eq!(points[1].pnl, Decimal::from_str("12.5").unwrap());
+        assert!(parse_pnl_history(r#"{"error":"bad address"}"#).is_err());
+    }
+
+    #[test]
+    fn volume_is_bucketed_between_consecutive_points() {
+        let body = r#"[{"t":100,"p":0},{"t":200,"p":1},{"t":300,"p":2}]"#;
+        let mut points = parse_pnl_history(body).unwrap();
+        let trades = [
+            (50, Decimal::from(1)),
+            (100, Decimal::from(2)),
+            (150, Decimal::from(4)),
+            (200, Decimal::from(8)),
+            (301, Decimal::from(16)),
+        ];
+        attach_volume(&mut points, &trades);
+        let volumes: Vec<_> = points.iter().map(|p| p.volume).collect();
+        assert_eq!(
+            volumes,
+            vec![Decimal::from(3), Decimal::from(12), Decimal::ZERO]
+        );
+    }
+
+    #[test]
+    fn pnl_history_range_must_be_ordered() {
+        let a = parse_day("2025-01-01").unwrap();
+        let b = parse_day("2025-02-01").unwrap();
+        assert!(check_range(Some(a), Some(b)).is_ok());
+        assert!(check_range(Some(a), Some(a)).is_ok());
+        assert!(check_range(None, Some(a)).is_ok());
+        let err = check_range(Some(b), Some(a)).unwrap_err().to_string();
+        assert!(err.contains("--from (2025-02-01)"), "{err}");
+        assert!(parse_day("01/02/2025").is_err());
+    }
+
+    #[test]
+    fn pnl_history_range_is_inclusive() {
+        let body = r#"[{"t":1735689600,"p":1},{"t":1735776000,"p":2},{"t":1735862399,"p":3},{"t":1735862400,"p":4}]"#;
+        let points = parse_pnl_history(body).unwrap();
+        let kept = filter_range(
+            points,
+            Some(parse_day("2025-01-02").unwrap()),
+            Some(parse_day("2025-01-02").unwrap()),
+        );
+        let values: Vec<_> = kept.iter().map(|p| p.pnl).collect();
+        assert_eq!(values, vec![Decimal::from(2), Decimal::from(3)]);
+    }
+}
@@ -0,0 +1,109 @@
+use anyhow::Result;
+use clap::{Args, ValueEnum};
+use schemars::{Schema, schema_for};
+
+use crate::commands::copy::{
+    CopyConfig, DbMovement, DecisionRecord, PlanResult, UiStateResponse, UpdatesResponse,
+};
+use crate::commands::wallet::WalletShowView;
+use crate::output::copy::{DashboardSummary, DashboardView, StatusView};
+use crate::output::data::{ClosedPositionView, PositionView};
+
+#[derive(Args)]
+pub struct SchemaArgs {
+    /// Command whose `--output json` shape to describe
+    #[arg(value_enum)]
+    pub target: SchemaTarget,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum SchemaTarget {
+    /// `copy status`
+    CopyStatus,
+    /// `copy plan`
+    CopyPlan,
+    /// `copy dashboard`
+    CopyDashboard,
+    /// `copy dashboard --summary-only`
+    CopyDashboardSummary,
+    /// Saved copy configuration (also `config` in `copy ui` state)
+    CopyConfig,
+    /// `GET /api/state` of the copy web UI
+    CopyUiState,
+    /// `GET /api/updates` of the copy web UI
+    CopyUpdates,
+    /// One movement row as served by the copy web UI
+    CopyMovement,
+    /// `copy decisions`
+    CopyDecisions,
+    /// `data positions`
+    DataPositions,
+    /// `data closed-positions`
+    DataClosedPositions,
+    /// `wallet show`
+    WalletShow,
+}
+
+pub fn schema(target: SchemaTarget) -> Schema {
+    match target {
+        SchemaTarget::CopyStatus => schema_for!(StatusView<'static>),
+        SchemaTarget::CopyPlan => schema_for!(PlanResult),
+        SchemaTarget::CopyDashboard => schema_for!(DashboardView<'static>),
+        SchemaTarget::CopyDashboardSummary => schema_for!(DashboardSummary),
+        SchemaTarget::CopyConfig => schema_for!(CopyConfig),
+        SchemaTarget::CopyUiState => schema_for!(UiStateResponse),
+        SchemaTarget::CopyUpdates => schema_for!(UpdatesResponse),
+        SchemaTarget::CopyMovement => schema_for!(DbMovement),
+        SchemaTarget::CopyDecisions => schema_for!(Vec<DecisionRecord>),
+        SchemaTarget::DataPositions => schema_for!(Vec<PositionView>),
+        SchemaTarget::DataClosedPositions => schema_for!(Vec<ClosedPositionView>),
+        SchemaTarget::WalletShow => schema_for!(WalletShowView),
+    }
+}
+
+pub fn execute(args: &SchemaArgs) -> Result<()> {
+    crate::output::print_json(&schema(args.target))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_target_describes_its_fields() {
+        for target in SchemaTarget::value_variants() {
+            let value = serde_json::to_value(schema(*target)).unwrap();
+            let fields = value
+                .get("properties")
+                .or_else(|| value.pointer("/items/$ref"))
+                .or_else(|| value.get("items"));
+            assert!(fields.is_some(), "{target:?} schema has no fields: {value}");
+        }
+    }
+
+    #[test]
+    fn plan_schema_lists_capped_size() {
+        let value = serde_json::to_value(schema(SchemaTarget::CopyPlan)).unwrap();
+        assert!(value["properties"]["capped_size"].is_object());
+        assert!(
+            value["required"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|f| f == "reason")
+        );
+    }
+
+    #[test]
+    fn wallet_show_schema_requires_configured() {
+        let value = serde_json::to_value(schema(SchemaTarget::WalletShow)).unwrap();
+        assert_eq!(value["properties"]["configured"]["type"], "boolean");
+        assert!(
+            value["required"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|f| f == "configured")
+        );
+    }
+}
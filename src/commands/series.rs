@@ -5,6 +5,8 @@ use polymarket_client_sdk::gamma::{
     types::request::{SeriesByIdRequest, SeriesListRequest},
 };
 
+use crate::cache::cached;
+use crate::net::timed;
 use crate::output::series::{print_series_detail, print_series_table};
 use crate::output::{OutputFormat, print_json};
 
@@ -63,21 +65,21 @@ pub async fn execute(client: &gamma::Client, args: SeriesArgs, output: OutputFor
                 .maybe_closed(closed)
                 .build();
 
-            let series = client.series(&request).await?;
+            let series = cached("series", &request, || timed(client.series(&request))).await?;
 
             match output {
                 OutputFormat::Table => print_series_table(&series),
-                OutputFormat::Json => print_json(&series)?,
+                OutputFormat::Json | OutputFormat::Yaml => print_json(&series)?,
             }
         }
 
         SeriesCommand::Get { id } => {
             let req = SeriesByIdRequest::builder().id(id).build();
-            let series = client.series_by_id(&req).await?;
+            let series = cached("series_by_id", &req, || timed(client.series_by_id(&req))).await?;
 
             match output {
                 OutputFormat::Table => print_series_detail(&series),
-                OutputFormat::Json => print_json(&series)?,
+                OutputFormat::Json | OutputFormat::Yaml => print_json(&series)?,
             }
         }
     }
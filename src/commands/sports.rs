@@ -1,10 +1,22 @@
-use anyhow::Result;
+use std::process::Command;
+
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
 use clap::{Args, Subcommand};
 use polymarket_client_sdk::gamma::{self, types::request::TeamsRequest};
+use serde::Serialize;
+use serde_json::Value;
 
-use crate::output::sports::{print_sport_types, print_sports_table, print_teams_table};
+use crate::net::timed;
+use crate::output::sports::{
+    print_games, print_sport_types, print_sports_table, print_teams_table,
+};
 use crate::output::{OutputFormat, print_json};
 
+/// Gamma's events endpoint, read raw because the SDK's `Event` doesn't carry
+/// the live game fields (`score`, `period`, `live`, ...).
+const GAMMA_EVENTS_API: &str = "https://gamma-api.polymarket.com/events";
+
 #[derive(Args)]
 pub struct SportsArgs {
     #[command(subcommand)]
@@ -41,25 +53,179 @@ pub enum SportsCommand {
         #[arg(long)]
         league: Option<String>,
     },
+
+    /// Live and upcoming games with scores and their market slugs
+    Scores {
+        /// League as listed by `sports list` (e.g. nba, nfl) [default: all sports]
+        #[arg(long)]
+        league: Option<String>,
+
+        /// Only games starting on this UTC day (YYYY-MM-DD); includes finished games
+        #[arg(long, value_parser = parse_day)]
+        date: Option<NaiveDate>,
+    },
+}
+
+fn parse_day(s: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .map_err(|_| anyhow::anyhow!("Invalid date '{s}': expected YYYY-MM-DD format"))
+}
+
+/// One game event. Teams come from the "Away vs. Home" title, so either is
+/// `None` for events titled differently (futures, props).
+#[derive(Debug, Serialize)]
+pub struct Game {
+    pub title: String,
+    pub away: Option<String>,
+    pub home: Option<String>,
+    pub score: Option<String>,
+    /// `live`, `final` or `scheduled`
+    pub status: &'static str,
+    pub period: Option<String>,
+    pub elapsed: Option<String>,
+    pub start: Option<String>,
+    pub event_slug: String,
+    pub market_slugs: Vec<String>,
+}
+
+impl Game {
+    /// UTC day the game starts, from `start` ("2026-03-01T19:30:00Z" or a
+    /// bare date).
+    fn start_day(&self) -> Option<NaiveDate> {
+        let start = self.start.as_deref()?;
+        NaiveDate::parse_from_str(start.get(..10)?, "%Y-%m-%d").ok()
+    }
+}
+
+fn text(v: &Value, key: &str) -> Option<String> {
+    match &v[key] {
+        Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+fn game_from_event(e: &Value) -> Game {
+    let title = text(e, "title").unwrap_or_default();
+    let (away, home) = title
+        .split_once(" vs. ")
+        .or_else(|| title.split_once(" vs "))
+        .map_or((None, None), |(a, h)| {
+            (Some(a.trim().to_string()), Some(h.trim().to_string()))
+        });
+    let status = if e["ended"].as_bool() == Some(true) || e["closed"].as_bool() == Some(true) {
+        "final"
+    } else if e["live"].as_bool() == Some(true) {
+        "live"
+    } else {
+        "scheduled"
+    };
+    let market_slugs = e["markets"]
+        .as_array()
+        .map(|ms| ms.iter().filter_map(|m| text(m, "slug")).collect())
+        .unwrap_or_default();
+    Game {
+        away,
+        home,
+        score: text(e, "score"),
+        status,
+        period: text(e, "period"),
+        elapsed: text(e, "elapsed"),
+        start: text(e, "startTime").or_else(|| text(e, "eventDate")),
+        event_slug: text(e, "slug").unwrap_or_default(),
+        market_slugs,
+        title,
+    }
+}
+
+fn parse_events(body: &str) -> Result<Vec<Value>> {
+    let json: Value = serde_json::from_str(body).context("Failed to parse events response")?;
+    match json {
+        Value::Array(events) => Ok(events),
+        _ => bail!("Unexpected events response: expected an array"),
+    }
+}
+
+/// Turns events into games, optionally keeping one start day, live games
+/// first and then by start time.
+fn games_from_events(events: &[Value], date: Option<NaiveDate>) -> Vec<Game> {
+    let mut games: Vec<Game> = events
+        .iter()
+        .map(game_from_event)
+        .filter(|g| date.is_none_or(|d| g.start_day() == Some(d)))
+        .collect();
+    games.sort_by(|a, b| (a.status != "live", &a.start).cmp(&(b.status != "live", &b.start)));
+    games
+}
+
+const GAMES_PAGE_SIZE: usize = 200;
+const GAMES_MAX_PAGES: usize = 10;
+
+/// Without a date only open events matter. A past day needs closed ones too,
+/// but not those that ended before it.
+fn games_url(league: &str, date: Option<NaiveDate>, offset: usize) -> String {
+    let filter = match date {
+        Some(day) => format!("&end_date_min={day}T00:00:00Z"),
+        None => "&closed=false".to_string(),
+    };
+    format!("{GAMMA_EVENTS_API}?tag_slug={league}&limit={GAMES_PAGE_SIZE}&offset={offset}{filter}")
+}
+
+fn fetch_events_page(url: &str) -> Result<Vec<Value>> {
+    let timeout = crate::net::request_timeout().as_secs().to_string();
+    let output = Command::new("curl")
+        .args(["-sSf", "--max-time", &timeout, url])
+        .output()
+        .context("Failed to run curl")?;
+    if !output.status.success() {
+        bail!(
+            "Failed to fetch games: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    parse_events(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Pages through the league's events with curl on a blocking thread, so the
+/// runtime isn't stalled. Stops after `GAMES_MAX_PAGES` with a warning.
+async fn fetch_games(league: String, date: Option<NaiveDate>) -> Result<Vec<Game>> {
+    tokio::task::spawn_blocking(move || {
+        let mut events = Vec::new();
+        for page in 0..GAMES_MAX_PAGES {
+            let batch = fetch_events_page(&games_url(&league, date, page * GAMES_PAGE_SIZE))?;
+            let done = batch.len() < GAMES_PAGE_SIZE;
+            events.extend(batch);
+            if done {
+                return Ok(games_from_events(&events, date));
+            }
+        }
+        tracing::warn!(
+            "stopped after {} events ({GAMES_MAX_PAGES} pages); some games may be missing",
+            events.len()
+        );
+        Ok(games_from_events(&events, date))
+    })
+    .await
+    .context("Games fetch did not complete")?
 }
 
 pub async fn execute(client: &gamma::Client, args: SportsArgs, output: OutputFormat) -> Result<()> {
     match args.command {
         SportsCommand::List => {
-            let sports = client.sports().await?;
+            let sports = timed(client.sports()).await?;
 
             match output {
                 OutputFormat::Table => print_sports_table(&sports),
-                OutputFormat::Json => print_json(&sports)?,
+                OutputFormat::Json | OutputFormat::Yaml => print_json(&sports)?,
             }
         }
 
         SportsCommand::MarketTypes => {
-            let types = client.sports_market_types().await?;
+            let types = timed(client.sports_market_types()).await?;
 
             match output {
                 OutputFormat::Table => print_sport_types(&types),
-                OutputFormat::Json => print_json(&types)?,
+                OutputFormat::Json | OutputFormat::Yaml => print_json(&types)?,
             }
         }
 
@@ -78,14 +244,96 @@ pub async fn execute(client: &gamma::Client, args: SportsArgs, output: OutputFor
                 .league(league.into_iter().collect::<Vec<_>>())
                 .build();
 
-            let teams = client.teams(&request).await?;
+            let teams = timed(client.teams(&request)).await?;
 
             match output {
                 OutputFormat::Table => print_teams_table(&teams),
-                OutputFormat::Json => print_json(&teams)?,
+                OutputFormat::Json | OutputFormat::Yaml => print_json(&teams)?,
             }
         }
+
+        SportsCommand::Scores { league, date } => {
+            let league = match league {
+                Some(league) => {
+                    let league = league.trim().to_lowercase();
+                    let sports = timed(client.sports()).await?;
+                    if !sports.iter().any(|s| s.sport.eq_ignore_ascii_case(&league)) {
+                        bail!("Unknown league '{league}'. See `polymarket sports list`.");
+                    }
+                    league
+                }
+                None => "sports".to_string(),
+            };
+            let games = fetch_games(league, date).await?;
+            print_games(&games, date, output)?;
+        }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EVENTS: &str = r#"[
+        {"title": "Lakers vs. Celtics", "slug": "nba-lal-bos-2026-03-01",
+         "startTime": "2026-03-01T00:30:00Z", "live": true, "ended": false,
+         "score": "88-91", "period": "Q4", "elapsed": "05:12",
+         "markets": [{"slug": "nba-lal-bos-2026-03-01"}, {"slug": "nba-lal-bos-2026-03-01-spread"}]},
+        {"title": "Knicks vs. Heat", "slug": "nba-nyk-mia-2026-03-02",
+         "startTime": "2026-03-02T00:00:00Z", "live": false, "ended": false, "markets": []},
+        {"title": "Nets vs. Bulls", "slug": "nba-bkn-chi-2026-03-01",
+         "eventDate": "2026-03-01", "ended": true, "score": "101-99"},
+        {"title": "NBA Champion 2026", "slug": "nba-champion-2026"}
+    ]"#;
+
+    fn parse_games(body: &str, date: Option<NaiveDate>) -> Result<Vec<Game>> {
+        Ok(games_from_events(&parse_events(body)?, date))
+    }
+
+    #[test]
+    fn games_url_pages_and_skips_events_ended_before_the_date() {
+        let open = games_url("nba", None, 0);
+        assert!(open.ends_with("?tag_slug=nba&limit=200&offset=0&closed=false"));
+        let day = games_url("nba", NaiveDate::from_ymd_opt(2026, 3, 1), 400);
+        assert!(day.ends_with("&offset=400&end_date_min=2026-03-01T00:00:00Z"));
+        assert!(!day.contains("closed"));
+    }
+
+    #[test]
+    fn parses_teams_status_and_market_slugs_live_first() {
+        let games = parse_games(EVENTS, None).unwrap();
+        let first = &games[0];
+        assert_eq!(first.status, "live");
+        assert_eq!(first.away.as_deref(), Some("Lakers"));
+        assert_eq!(first.home.as_deref(), Some("Celtics"));
+        assert_eq!(first.score.as_deref(), Some("88-91"));
+        assert_eq!(first.market_slugs.len(), 2);
+
+        let futures = games
+            .iter()
+            .find(|g| g.event_slug == "nba-champion-2026")
+            .unwrap();
+        assert_eq!((futures.away.as_ref(), futures.status), (None, "scheduled"));
+        let nets = games
+            .iter()
+            .find(|g| g.event_slug.starts_with("nba-bkn"))
+            .unwrap();
+        assert_eq!(nets.status, "final");
+    }
+
+    #[test]
+    fn date_filter_keeps_games_starting_that_day() {
+        let day = NaiveDate::from_ymd_opt(2026, 3, 1);
+        let games = parse_games(EVENTS, day).unwrap();
+        let slugs: Vec<&str> = games.iter().map(|g| g.event_slug.as_str()).collect();
+        assert_eq!(slugs, ["nba-lal-bos-2026-03-01", "nba-bkn-chi-2026-03-01"]);
+
+        assert!(
+            parse_games(EVENTS, NaiveDate::from_ymd_opt(2026, 4, 1))
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -9,6 +9,8 @@ use polymarket_client_sdk::gamma::{
 };
 
 use super::is_numeric_id;
+use crate::cache::cached;
+use crate::net::timed;
 use crate::output::tags::{print_related_tags_table, print_tag_detail, print_tags_table};
 use crate::output::{OutputFormat, print_json};
 
@@ -75,11 +77,11 @@ pub async fn execute(client: &gamma::Client, args: TagsArgs, output: OutputForma
                 .maybe_ascending(if ascending { Some(true) } else { None })
                 .build();
 
-            let tags = client.tags(&request).await?;
+            let tags = cached("tags", &request, || timed(client.tags(&request))).await?;
 
             match output {
                 OutputFormat::Table => print_tags_table(&tags),
-                OutputFormat::Json => print_json(&tags)?,
+                OutputFormat::Json | OutputFormat::Yaml => print_json(&tags)?,
             }
         }
 
@@ -87,15 +89,15 @@ pub async fn execute(client: &gamma::Client, args: TagsArgs, output: OutputForma
             let is_numeric = is_numeric_id(&id);
             let tag = if is_numeric {
                 let req = TagByIdRequest::builder().id(id).build();
-                client.tag_by_id(&req).await?
+                cached("tag_by_id", &req, || timed(client.tag_by_id(&req))).await?
             } else {
                 let req = TagBySlugRequest::builder().slug(id).build();
-                client.tag_by_slug(&req).await?
+                cached("tag_by_slug", &req, || timed(client.tag_by_slug(&req))).await?
             };
 
             match output {
                 OutputFormat::Table => print_tag_detail(&tag),
-                OutputFormat::Json => print_json(&tag)?,
+                OutputFormat::Json | OutputFormat::Yaml => print_json(&tag)?,
             }
         }
 
@@ -106,18 +108,24 @@ pub async fn execute(client: &gamma::Client, args: TagsArgs, output: OutputForma
                     .id(id)
                     .maybe_omit_empty(omit_empty)
                     .build();
-                client.related_tags_by_id(&req).await?
+                cached("related_tags_by_id", &req, || {
+                    timed(client.related_tags_by_id(&req))
+                })
+                .await?
             } else {
                 let req = RelatedTagsBySlugRequest::builder()
                     .slug(id)
                     .maybe_omit_empty(omit_empty)
                     .build();
-                client.related_tags_by_slug(&req).await?
+                cached("related_tags_by_slug", &req, || {
+                    timed(client.related_tags_by_slug(&req))
+                })
+                .await?
             };
 
             match output {
                 OutputFormat::Table => print_related_tags_table(&related),
-                OutputFormat::Json => print_json(&related)?,
+                OutputFormat::Json | OutputFormat::Yaml => print_json(&related)?,
             }
         }
 
@@ -128,18 +136,24 @@ pub async fn execute(client: &gamma::Client, args: TagsArgs, output: OutputForma
                     .id(id)
                     .maybe_omit_empty(omit_empty)
                     .build();
-                client.tags_related_to_tag_by_id(&req).await?
+                cached("tags_related_to_tag_by_id", &req, || {
+                    timed(client.tags_related_to_tag_by_id(&req))
+                })
+                .await?
             } else {
                 let req = RelatedTagsBySlugRequest::builder()
                     .slug(id)
                     .maybe_omit_empty(omit_empty)
                     .build();
-                client.tags_related_to_tag_by_slug(&req).await?
+                cached("tags_related_to_tag_by_slug", &req, || {
+                    timed(client.tags_related_to_tag_by_slug(&req))
+                })
+                .await?
             };
 
             match output {
                 OutputFormat::Table => print_tags_table(&tags),
-                OutputFormat::Json => print_json(&tags)?,
+                OutputFormat::Json | OutputFormat::Yaml => print_json(&tags)?,
             }
         }
     }
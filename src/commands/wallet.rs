@@ -1,14 +1,31 @@
 use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use anyhow::{Context, Result, bail};
 use clap::{Args, Subcommand};
 use polymarket_client_sdk::auth::LocalSigner;
 use polymarket_client_sdk::auth::Signer as _;
-use polymarket_client_sdk::{POLYGON, derive_proxy_wallet};
+use polymarket_client_sdk::data::types::request::ValueRequest;
+use polymarket_client_sdk::types::{Address, Decimal};
+use polymarket_client_sdk::{POLYGON, derive_proxy_wallet, derive_safe_wallet};
+use schemars::JsonSchema;
+use serde::Serialize;
 
 use crate::config;
-use crate::output::OutputFormat;
+use crate::output::{OutputFormat, format_decimal};
+
+/// `wallet show --output json`.
+#[derive(Serialize, JsonSchema)]
+pub struct WalletShowView {
+    pub address: Option<String>,
+    pub proxy_address: Option<String>,
+    pub signature_type: String,
+    pub config_path: String,
+    pub source: &'static str,
+    /// Whether a private key could be resolved from any source
+    pub configured: bool,
+}
 
 #[derive(Args)]
 pub struct WalletArgs {
@@ -41,7 +58,55 @@ pub enum WalletCommand {
     /// Show the address of the configured wallet
     Address,
     /// Show wallet info (address, config path, key source)
-    Show,
+    Show {
+        /// Print only the complete signer address, nothing else (for copy/paste)
+        #[arg(long)]
+        full: bool,
+        /// Also render the address as a QR code for funding from a mobile wallet
+        #[arg(long)]
+        qr: bool,
+    },
+    /// Show USDC cash, open positions value and their total for the configured wallet
+    Balance,
+    /// Replace the stored key, keeping a backup of the old one
+    ///
+    /// The new key is prompted for, or read from --new-key-file or
+    /// --new-keystore. There is deliberately no --new-key: a key passed as
+    /// an argument ends up in shell history and `ps`.
+    Rotate {
+        /// Read the new private key from this file instead of prompting for it
+        #[arg(long)]
+        new_key_file: Option<PathBuf>,
+        /// Take the new key from an encrypted V3 keystore (prompts for its passphrase)
+        #[arg(long, conflicts_with = "new_key_file")]
+        new_keystore: Option<PathBuf>,
+        /// Signature type for the new key (default: keep the current one)
+        #[arg(long)]
+        signature_type: Option<String>,
+        /// Skip confirmation prompt
+        #[arg(long)]
+        yes: bool,
+        /// Delete the backup of the old key after installing the new one
+        #[arg(long)]
+        purge_old: bool,
+    },
+    /// Write the key as an encrypted Ethereum V3 keystore (geth/MetaMask compatible)
+    ExportKeystore {
+        /// Keystore file to create
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Import the key from an encrypted Ethereum V3 keystore file
+    ImportKeystore {
+        /// Keystore JSON file (scrypt or pbkdf2)
+        path: PathBuf,
+        /// Overwrite existing wallet
+        #[arg(long)]
+        force: bool,
+        /// Signature type: eoa, proxy (default), or gnosis-safe
+        #[arg(long, default_value = "proxy")]
+        signature_type: String,
+    },
     /// Delete all config and keys (fresh install)
     Reset {
         /// Skip confirmation prompt
@@ -50,10 +115,11 @@ pub enum WalletCommand {
     },
 }
 
-pub fn execute(
+pub async fn execute(
     args: WalletArgs,
     output: &OutputFormat,
     private_key_flag: Option<&str>,
+    signature_type_flag: Option<&str>,
 ) -> Result<()> {
     match args.command {
         WalletCommand::Create {
@@ -66,7 +132,32 @@ pub fn execute(
             signature_type,
         } => cmd_import(&key, output, force, &signature_type),
         WalletCommand::Address => cmd_address(output, private_key_flag),
-        WalletCommand::Show => cmd_show(output, private_key_flag),
+        WalletCommand::Show { full, qr } => {
+            cmd_show(output, private_key_flag, signature_type_flag, full, qr)
+        }
+        WalletCommand::Balance => cmd_balance(output, private_key_flag, signature_type_flag).await,
+        WalletCommand::Rotate {
+            new_key_file,
+            new_keystore,
+            signature_type,
+            yes,
+            purge_old,
+        } => cmd_rotate(
+            new_key_file.as_deref(),
+            new_keystore.as_deref(),
+            signature_type.as_deref(),
+            output,
+            yes,
+            purge_old,
+        ),
+        WalletCommand::ExportKeystore { out } => {
+            cmd_export_keystore(&out, output, private_key_flag)
+        }
+        WalletCommand::ImportKeystore {
+            path,
+            force,
+            signature_type,
+        } => cmd_import_keystore(&path, output, force, &signature_type),
         WalletCommand::Reset { force } => cmd_reset(output, force),
     }
 }
@@ -89,33 +180,34 @@ pub(crate) fn normalize_key(key: &str) -> String {
     }
 }
 
+fn key_hex(bytes: &[u8]) -> String {
+    let mut key_hex = String::with_capacity(2 + bytes.len() * 2);
+    key_hex.push_str("0x");
+    for b in bytes {
+        write!(key_hex, "{b:02x}").unwrap();
+    }
+    key_hex
+}
+
 fn cmd_create(output: &OutputFormat, force: bool, signature_type: &str) -> Result<()> {
     guard_overwrite(force)?;
 
     let signer = LocalSigner::random().with_chain_id(Some(POLYGON));
     let address = signer.address();
-    let bytes = signer.credential().to_bytes();
-    let mut key_hex = String::with_capacity(2 + bytes.len() * 2);
-    key_hex.push_str("0x");
-    for b in &bytes {
-        write!(key_hex, "{b:02x}").unwrap();
-    }
+    let key_hex = key_hex(&signer.credential().to_bytes());
 
     config::save_wallet(&key_hex, POLYGON, signature_type)?;
     let config_path = config::config_path()?;
     let proxy_addr = derive_proxy_wallet(address, POLYGON);
 
     match output {
-        OutputFormat::Json => {
-            println!(
-                "{}",
-                serde_json::json!({
-                    "address": address.to_string(),
-                    "proxy_address": proxy_addr.map(|a| a.to_string()),
-                    "signature_type": signature_type,
-                    "config_path": config_path.display().to_string(),
-                })
-            );
+        OutputFormat::Json | OutputFormat::Yaml => {
+            crate::output::print_json(&serde_json::json!({
+                "address": address.to_string(),
+                "proxy_address": proxy_addr.map(|a| a.to_string()),
+                "signature_type": signature_type,
+                "config_path": config_path.display().to_string(),
+            }))?;
         }
         OutputFormat::Table => {
             println!("Wallet created successfully!");
@@ -147,16 +239,13 @@ fn cmd_import(key: &str, output: &OutputFormat, force: bool, signature_type: &st
     let proxy_addr = derive_proxy_wallet(address, POLYGON);
 
     match output {
-        OutputFormat::Json => {
-            println!(
-                "{}",
-                serde_json::json!({
-                    "address": address.to_string(),
-                    "proxy_address": proxy_addr.map(|a| a.to_string()),
-                    "signature_type": signature_type,
-                    "config_path": config_path.display().to_string(),
-                })
-            );
+        OutputFormat::Json | OutputFormat::Yaml => {
+            crate::output::print_json(&serde_json::json!({
+                "address": address.to_string(),
+                "proxy_address": proxy_addr.map(|a| a.to_string()),
+                "signature_type": signature_type,
+                "config_path": config_path.display().to_string(),
+            }))?;
         }
         OutputFormat::Table => {
             println!("Wallet imported successfully!");
@@ -179,8 +268,8 @@ fn cmd_address(output: &OutputFormat, private_key_flag: Option<&str>) -> Result<
     let address = signer.address();
 
     match output {
-        OutputFormat::Json => {
-            println!("{}", serde_json::json!({"address": address.to_string()}));
+        OutputFormat::Json | OutputFormat::Yaml => {
+            crate::output::print_json(&serde_json::json!({"address": address.to_string()}))?;
         }
         OutputFormat::Table => {
             println!("{address}");
@@ -189,7 +278,96 @@ fn cmd_address(output: &OutputFormat, private_key_flag: Option<&str>) -> Result<
     Ok(())
 }
 
-fn cmd_show(output: &OutputFormat, private_key_flag: Option<&str>) -> Result<()> {
+/// Cash and positions value of the wallet that holds the funds (the proxy
+/// wallet unless the key trades as a plain EOA).
+pub struct WalletBalance {
+    pub address: String,
+    pub cash: Decimal,
+    pub positions_value: Decimal,
+}
+
+impl WalletBalance {
+    pub fn total(&self) -> Decimal {
+        self.cash + self.positions_value
+    }
+}
+
+/// Amounts as strings so JSON consumers keep full decimal precision.
+fn balance_json(b: &WalletBalance) -> serde_json::Value {
+    serde_json::json!({
+        "address": b.address,
+        "cash": b.cash.to_string(),
+        "positions_value": b.positions_value.to_string(),
+        "total": b.total().to_string(),
+    })
+}
+
+/// The address that holds `signer`'s funds and positions: the signer itself
+/// for EOA wallets, otherwise its derived Safe or proxy wallet.
+pub(crate) fn funding_address(signer: Address, signature_type: &str) -> Address {
+    let derived = match signature_type {
+        "eoa" => None,
+        "gnosis-safe" => derive_safe_wallet(signer, POLYGON),
+        _ => derive_proxy_wallet(signer, POLYGON),
+    };
+    derived.unwrap_or(signer)
+}
+
+/// [`funding_address`] of the configured wallet, honouring `--signature-type`.
+pub(crate) fn trading_address(
+    private_key_flag: Option<&str>,
+    signature_type_flag: Option<&str>,
+) -> Result<Address> {
+    let signer = crate::auth::resolve_signer(private_key_flag)?;
+    let signature_type = config::resolve_signature_type(signature_type_flag);
+    Ok(funding_address(signer.address(), &signature_type))
+}
+
+async fn cmd_balance(
+    output: &OutputFormat,
+    private_key_flag: Option<&str>,
+    signature_type_flag: Option<&str>,
+) -> Result<()> {
+    let owner = trading_address(private_key_flag, signature_type_flag)?;
+
+    let data = polymarket_client_sdk::data::Client::default();
+    let value_req = ValueRequest::builder().user(owner).build();
+    let (raw_cash, values) = tokio::try_join!(
+        crate::commands::approve::usdc_balance(owner),
+        crate::net::retry(|| data.value(&value_req)),
+    )?;
+    let cash = Decimal::from_str(&raw_cash.to_string()).context("USDC balance out of range")?
+        / Decimal::from(1_000_000);
+    let balance = WalletBalance {
+        address: owner.to_string(),
+        cash,
+        positions_value: values.first().map_or(Decimal::ZERO, |v| v.value),
+    };
+
+    match output {
+        OutputFormat::Json | OutputFormat::Yaml => {
+            crate::output::print_json(&balance_json(&balance))?;
+        }
+        OutputFormat::Table => {
+            println!("Wallet:          {}", balance.address);
+            println!("Cash (USDC):     {}", format_decimal(balance.cash));
+            println!(
+                "Positions value: {}",
+                format_decimal(balance.positions_value)
+            );
+            println!("Total:           {}", format_decimal(balance.total()));
+        }
+    }
+    Ok(())
+}
+
+fn cmd_show(
+    output: &OutputFormat,
+    private_key_flag: Option<&str>,
+    signature_type_flag: Option<&str>,
+    full: bool,
+    qr: bool,
+) -> Result<()> {
     let (key, source) = config::resolve_key(private_key_flag);
     let signer = key.as_deref().and_then(|k| LocalSigner::from_str(k).ok());
     let address = signer.as_ref().map(|s| s.address().to_string());
@@ -198,22 +376,38 @@ fn cmd_show(output: &OutputFormat, private_key_flag: Option<&str>) -> Result<()>
         .and_then(|s| derive_proxy_wallet(s.address(), POLYGON))
         .map(|a| a.to_string());
 
-    let sig_type = config::resolve_signature_type(None);
+    let sig_type = config::resolve_signature_type(signature_type_flag);
     let config_path = config::config_path()?;
 
     match output {
-        OutputFormat::Json => {
-            println!(
-                "{}",
-                serde_json::json!({
-                    "address": address,
-                    "proxy_address": proxy_addr,
-                    "signature_type": sig_type,
-                    "config_path": config_path.display().to_string(),
-                    "source": source.label(),
-                    "configured": address.is_some(),
-                })
-            );
+        OutputFormat::Json | OutputFormat::Yaml => {
+            let configured = address.is_some();
+            crate::output::print_json(&WalletShowView {
+                address,
+                proxy_address: proxy_addr,
+                signature_type: sig_type,
+                config_path: config_path.display().to_string(),
+                source: source.label(),
+                configured,
+            })?;
+        }
+        OutputFormat::Table if full || qr => {
+            let Some(addr) = &address else {
+                bail!("No wallet configured. Run `polymarket wallet create` or `wallet import`");
+            };
+            if qr {
+                // Funds go to the proxy or Safe wallet unless the key trades as a plain EOA.
+                let funding = signer
+                    .as_ref()
+                    .map(|s| funding_address(s.address(), &sig_type).to_string())
+                    .unwrap_or_else(|| addr.clone());
+                print!("{}", crate::output::render_qr(&funding)?);
+                println!();
+                println!("{funding}");
+            }
+            if full {
+                println!("{addr}");
+            }
         }
         OutputFormat::Table => {
             match &address {
@@ -231,15 +425,218 @@ fn cmd_show(output: &OutputFormat, private_key_flag: Option<&str>) -> Result<()>
     Ok(())
 }
 
+fn check_rotation(old: &str, new: &str) -> Result<()> {
+    if old.eq_ignore_ascii_case(new) {
+        bail!("The new key controls the same address ({new}); nothing to rotate");
+    }
+    Ok(())
+}
+
+fn cmd_rotate(
+    new_key_file: Option<&Path>,
+    new_keystore: Option<&Path>,
+    signature_type: Option<&str>,
+    output: &OutputFormat,
+    yes: bool,
+    purge_old: bool,
+) -> Result<()> {
+    let old_config = config::load_config().ok_or_else(|| {
+        anyhow::anyhow!("No stored wallet to rotate. Use `polymarket wallet import <key>` instead")
+    })?;
+    let old_address = LocalSigner::from_str(&old_config.private_key)
+        .context("Stored private key is invalid")?
+        .address();
+
+    // Never taken on argv, where it would end up in shell history and `ps`.
+    let new_key = match (new_key_file, new_keystore) {
+        (Some(path), _) => std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?,
+        (None, Some(path)) => {
+            let passphrase = rpassword::prompt_password("Keystore passphrase: ")?;
+            import_keystore(path, &passphrase)?.0
+        }
+        (None, None) => rpassword::prompt_password("New private key: ")?,
+    };
+    let normalized = normalize_key(new_key.trim());
+    let new_address = LocalSigner::from_str(&normalized)
+        .context("Invalid new private key")?
+        .with_chain_id(Some(POLYGON))
+        .address();
+    check_rotation(&old_address.to_string(), &new_address.to_string())?;
+    let signature_type = signature_type.unwrap_or(&old_config.signature_type);
+
+    if !yes {
+        use std::io::{self, BufRead, Write};
+        print!("Replace wallet {old_address} with {new_address}? [y/N] ");
+        io::stdout().flush()?;
+        let mut input = String::new();
+        io::stdin().lock().read_line(&mut input)?;
+        if !matches!(input.trim().to_lowercase().as_str(), "y" | "yes") {
+            println!("Aborted.");
+            return Ok(());
+        }
+    }
+
+    let stamp = chrono::Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    let backup = config::backup_config(&stamp)?;
+    config::save_wallet(&normalized, old_config.chain_id, signature_type)?;
+    let backup = if purge_old {
+        std::fs::remove_file(&backup).context("Failed to remove old key backup")?;
+        None
+    } else {
+        Some(backup)
+    };
+
+    match output {
+        OutputFormat::Json | OutputFormat::Yaml => {
+            crate::output::print_json(&serde_json::json!({
+                "old_address": old_address.to_string(),
+                "new_address": new_address.to_string(),
+                "new_proxy_address": derive_proxy_wallet(new_address, POLYGON).map(|a| a.to_string()),
+                "signature_type": signature_type,
+                "backup_path": backup.as_ref().map(|p| p.display().to_string()),
+                "purged": purge_old,
+            }))?;
+        }
+        OutputFormat::Table => {
+            println!("Wallet rotated.");
+            println!("Old address:    {old_address}");
+            println!("New address:    {new_address}");
+            if let Some(proxy) = derive_proxy_wallet(new_address, POLYGON) {
+                println!("New proxy:      {proxy}");
+            }
+            println!("Signature type: {signature_type}");
+            match &backup {
+                Some(path) => {
+                    println!("Old key backup: {}", path.display());
+                    println!();
+                    println!("Move any funds from the old address, then delete the backup.");
+                }
+                None => println!("Old key backup: (purged)"),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Encrypts `key` into a V3 keystore at `out` and returns the wallet address.
+fn export_keystore(key: &str, out: &Path, passphrase: &str) -> Result<String> {
+    if out.exists() {
+        bail!("{} already exists; refusing to overwrite", out.display());
+    }
+    let signer = LocalSigner::from_str(key).context("Invalid private key")?;
+    let dir = match out.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p,
+        _ => Path::new("."),
+    };
+    let name = out
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| anyhow::anyhow!("Invalid keystore path: {}", out.display()))?;
+    std::fs::create_dir_all(dir).context("Failed to create keystore directory")?;
+    LocalSigner::encrypt_keystore(
+        dir,
+        &mut rand::thread_rng(),
+        signer.credential().to_bytes(),
+        passphrase,
+        Some(name),
+    )
+    .context("Failed to write keystore")?;
+    Ok(signer.address().to_string())
+}
+
+/// Decrypts a V3 keystore, returning the private key hex and its address.
+fn import_keystore(path: &Path, passphrase: &str) -> Result<(String, String)> {
+    if !path.exists() {
+        bail!("Keystore not found: {}", path.display());
+    }
+    let signer = LocalSigner::decrypt_keystore(path, passphrase)
+        .map_err(|_| anyhow::anyhow!("Wrong passphrase or corrupt keystore"))?;
+    Ok((
+        key_hex(&signer.credential().to_bytes()),
+        signer.address().to_string(),
+    ))
+}
+
+fn cmd_export_keystore(
+    out: &Path,
+    output: &OutputFormat,
+    private_key_flag: Option<&str>,
+) -> Result<()> {
+    let (key, _) = config::resolve_key(private_key_flag);
+    let key = key.ok_or_else(|| anyhow::anyhow!("{}", config::NO_WALLET_MSG))?;
+
+    let passphrase = rpassword::prompt_password("Keystore passphrase: ")?;
+    if passphrase.is_empty() {
+        bail!("Passphrase must not be empty");
+    }
+    if rpassword::prompt_password("Confirm passphrase: ")? != passphrase {
+        bail!("Passphrases do not match");
+    }
+    let address = export_keystore(&key, out, &passphrase)?;
+
+    match output {
+        OutputFormat::Json | OutputFormat::Yaml => {
+            crate::output::print_json(&serde_json::json!({
+                "address": address,
+                "keystore": out.display().to_string(),
+            }))?;
+        }
+        OutputFormat::Table => {
+            println!("Keystore written to {}", out.display());
+            println!("Address: {address}");
+        }
+    }
+    Ok(())
+}
+
+fn cmd_import_keystore(
+    path: &Path,
+    output: &OutputFormat,
+    force: bool,
+    signature_type: &str,
+) -> Result<()> {
+    guard_overwrite(force)?;
+
+    let passphrase = rpassword::prompt_password("Keystore passphrase: ")?;
+    let (key, address) = import_keystore(path, &passphrase)?;
+
+    config::save_wallet(&key, POLYGON, signature_type)?;
+    let config_path = config::config_path()?;
+    let proxy_addr = LocalSigner::from_str(&key)
+        .ok()
+        .and_then(|s| derive_proxy_wallet(s.address(), POLYGON));
+
+    match output {
+        OutputFormat::Json | OutputFormat::Yaml => {
+            crate::output::print_json(&serde_json::json!({
+                "address": address,
+                "proxy_address": proxy_addr.map(|a| a.to_string()),
+                "signature_type": signature_type,
+                "config_path": config_path.display().to_string(),
+            }))?;
+        }
+        OutputFormat::Table => {
+            println!("Wallet imported from keystore!");
+            println!("Address:        {address}");
+            if let Some(proxy) = proxy_addr {
+                println!("Proxy wallet:   {proxy}");
+            }
+            println!("Signature type: {signature_type}");
+            println!("Config:         {}", config_path.display());
+        }
+    }
+    Ok(())
+}
+
 fn cmd_reset(output: &OutputFormat, force: bool) -> Result<()> {
     if !config::config_exists() {
         match output {
             OutputFormat::Table => println!("Nothing to reset. No config found."),
-            OutputFormat::Json => {
-                println!(
-                    "{}",
-                    serde_json::json!({"reset": false, "reason": "no config found"})
-                );
+            OutputFormat::Json | OutputFormat::Yaml => {
+                crate::output::print_json(
+                    &serde_json::json!({"reset": false, "reason": "no config found"}),
+                )?;
             }
         }
         return Ok(());
@@ -265,14 +662,11 @@ fn cmd_reset(output: &OutputFormat, force: bool) -> Result<()> {
             println!("Config deleted: {}", path.display());
             println!("All keys and settings have been removed.");
         }
-        OutputFormat::Json => {
-            println!(
-                "{}",
-                serde_json::json!({
-                    "reset": true,
-                    "deleted": path.display().to_string(),
-                })
-            );
+        OutputFormat::Json | OutputFormat::Yaml => {
+            crate::output::print_json(&serde_json::json!({
+                "reset": true,
+                "deleted": path.display().to_string(),
+            }))?;
         }
     }
     Ok(())
@@ -282,6 +676,30 @@ fn cmd_reset(output: &OutputFormat, force: bool) -> Result<()> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn balance_json_keeps_amounts_as_exact_strings() {
+        let b = WalletBalance {
+            address: "0xabc".into(),
+            cash: Decimal::from_str("12.345678").unwrap(),
+            positions_value: Decimal::from_str("100.10").unwrap(),
+        };
+        let json = balance_json(&b);
+        assert_eq!(json["cash"], "12.345678");
+        assert_eq!(json["positions_value"], "100.10");
+        assert_eq!(json["total"], "112.445678");
+    }
+
+    #[test]
+    fn funding_address_follows_the_signature_type() {
+        let signer = Address::repeat_byte(0x11);
+        let proxy = derive_proxy_wallet(signer, POLYGON).unwrap();
+        let safe = derive_safe_wallet(signer, POLYGON).unwrap();
+        assert_ne!(proxy, safe);
+        assert_eq!(funding_address(signer, "eoa"), signer);
+        assert_eq!(funding_address(signer, "proxy"), proxy);
+        assert_eq!(funding_address(signer, "gnosis-safe"), safe);
+    }
+
     #[test]
     fn normalize_key_adds_prefix() {
         assert_eq!(
@@ -296,6 +714,32 @@ mod tests {
         assert_eq!(normalize_key(key), key);
     }
 
+    #[test]
+    fn rotation_to_same_address_is_rejected() {
+        let a = "0x1111111111111111111111111111111111111111";
+        assert!(check_rotation(a, &a.to_uppercase().replace("0X", "0x")).is_err());
+        assert!(check_rotation(a, "0x2222222222222222222222222222222222222222").is_ok());
+    }
+
+    #[test]
+    fn keystore_round_trips_to_same_address() {
+        let dir = std::env::temp_dir().join(format!("pm-keystore-{}", std::process::id()));
+        let out = dir.join("wallet.json");
+        let _ = std::fs::remove_dir_all(&dir);
+        let key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+        let exported = export_keystore(key, &out, "correct horse").unwrap();
+        let (imported_key, imported) = import_keystore(&out, "correct horse").unwrap();
+        assert_eq!(exported, imported);
+        assert_eq!(imported_key, key);
+
+        let err = import_keystore(&out, "wrong").unwrap_err();
+        assert!(err.to_string().contains("Wrong passphrase"));
+        assert!(export_keystore(key, &out, "again").is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn normalize_key_uppercase_prefix() {
         let key = "0Xabcdef";
@@ -1,11 +1,13 @@
 use std::fs;
 use std::path::PathBuf;
+use std::sync::OnceLock;
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 
 const ENV_VAR: &str = "POLYMARKET_PRIVATE_KEY";
 const SIG_TYPE_ENV_VAR: &str = "POLYMARKET_SIGNATURE_TYPE";
+const CONFIG_DIR_ENV_VAR: &str = "POLYMARKET_CONFIG_DIR";
 pub const DEFAULT_SIGNATURE_TYPE: &str = "proxy";
 
 pub const NO_WALLET_MSG: &str =
@@ -33,7 +35,7 @@ pub enum KeySource {
 impl KeySource {
     pub fn label(&self) -> &'static str {
         match self {
-            Self::Flag => "--private-key flag",
+            Self::Flag => "--private-key / --private-key-file flag",
             Self::EnvVar => "POLYMARKET_PRIVATE_KEY env var",
             Self::ConfigFile => "config file",
             Self::None => "not configured",
@@ -41,7 +43,23 @@ impl KeySource {
     }
 }
 
-fn config_dir() -> Result<PathBuf> {
+/// `--config-dir`, set once at startup.
+static CONFIG_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+pub fn set_config_dir(dir: PathBuf) {
+    let _ = CONFIG_DIR.set(dir);
+}
+
+/// Priority: `--config-dir` > `POLYMARKET_CONFIG_DIR` > `~/.config/polymarket`.
+pub(crate) fn config_dir() -> Result<PathBuf> {
+    if let Some(dir) = CONFIG_DIR.get() {
+        return Ok(dir.clone());
+    }
+    if let Some(dir) = std::env::var_os(CONFIG_DIR_ENV_VAR)
+        && !dir.is_empty()
+    {
+        return Ok(PathBuf::from(dir));
+    }
     let home = dirs::home_dir().context("Could not determine home directory")?;
     Ok(home.join(".config").join("polymarket"))
 }
@@ -125,6 +143,27 @@ pub fn save_wallet(key: &str, chain_id: u64, signature_type: &str) -> Result<()>
     Ok(())
 }
 
+/// Copies the current config file next to it as `config.backup-<stamp>.json`
+/// (owner-only on unix) and returns the backup path.
+pub fn backup_config(stamp: &str) -> Result<PathBuf> {
+    let src = config_path()?;
+    let dest = config_dir()?.join(format!("config.backup-{stamp}.json"));
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options
+        .open(&dest)
+        .context("Failed to create config backup")?;
+    let mut source = fs::File::open(&src).context("Failed to read config file")?;
+    std::io::copy(&mut source, &mut file).context("Failed to back up config file")?;
+
+    Ok(dest)
+}
+
 /// Priority: CLI flag > env var > config file.
 pub fn resolve_key(cli_flag: Option<&str>) -> (Option<String>, KeySource) {
     if let Some(key) = cli_flag {
@@ -157,6 +196,41 @@ mod tests {
         unsafe { std::env::remove_var(var) };
     }
 
+    #[test]
+    fn config_dir_env_var_redirects_config_path() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        unsafe { set(CONFIG_DIR_ENV_VAR, "/tmp/pm-profile-a") };
+        assert_eq!(
+            config_path().unwrap(),
+            PathBuf::from("/tmp/pm-profile-a/config.json")
+        );
+        unsafe { unset(CONFIG_DIR_ENV_VAR) };
+        assert!(
+            config_path()
+                .unwrap()
+                .ends_with(".config/polymarket/config.json")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn backup_config_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+        let _lock = ENV_LOCK.lock().unwrap();
+        let dir = std::env::temp_dir().join(format!("pm-backup-{}", std::process::id()));
+        unsafe { set(CONFIG_DIR_ENV_VAR, dir.to_str().unwrap()) };
+        save_wallet("0xabc", 137, "proxy").unwrap();
+        let backup = backup_config("test").unwrap();
+        let mode = fs::metadata(&backup).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(
+            fs::read_to_string(&backup).unwrap(),
+            fs::read_to_string(config_path().unwrap()).unwrap()
+        );
+        unsafe { unset(CONFIG_DIR_ENV_VAR) };
+        let _ = fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn resolve_key_flag_overrides_env() {
         let _lock = ENV_LOCK.lock().unwrap();
@@ -0,0 +1,142 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::str::FromStr;
+
+use anyhow::{Context, Result, bail};
+use chrono::Utc;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+const FX_API: &str = "https://api.frankfurter.app/latest";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum DisplayCurrency {
+    #[default]
+    Usd,
+    Eur,
+    Gbp,
+}
+
+impl DisplayCurrency {
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Usd => "USD",
+            Self::Eur => "EUR",
+            Self::Gbp => "GBP",
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Usd => "$",
+            Self::Eur => "€",
+            Self::Gbp => "£",
+        }
+    }
+}
+
+/// One cached USD→currency rate, refreshed once per UTC day.
+#[derive(Serialize, Deserialize)]
+struct CachedRate {
+    currency: String,
+    date: String,
+    rate: Decimal,
+}
+
+fn cache_path(currency: DisplayCurrency) -> Result<PathBuf> {
+    Ok(crate::config::config_dir()?.join(format!(
+        "fx_usd_{}.json",
+        currency.code().to_ascii_lowercase()
+    )))
+}
+
+fn read_cache(currency: DisplayCurrency, today: &str) -> Option<Decimal> {
+    let data = fs::read_to_string(cache_path(currency).ok()?).ok()?;
+    let cached: CachedRate = serde_json::from_str(&data).ok()?;
+    (cached.currency == currency.code() && cached.date == today).then_some(cached.rate)
+}
+
+fn write_cache(currency: DisplayCurrency, today: &str, rate: Decimal) -> Result<()> {
+    let path = cache_path(currency)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let cached = CachedRate {
+        currency: currency.code().to_string(),
+        date: today.to_string(),
+        rate,
+    };
+    fs::write(path, serde_json::to_string_pretty(&cached)?)?;
+    Ok(())
+}
+
+fn parse_rate(body: &str, currency: DisplayCurrency) -> Result<Decimal> {
+    let json: serde_json::Value =
+        serde_json::from_str(body).context("Failed to parse FX rate response")?;
+    let raw = &json["rates"][currency.code()];
+    let rate = match raw {
+        serde_json::Value::Number(n) => Decimal::from_str(&n.to_string()).ok(),
+        serde_json::Value::String(s) => Decimal::from_str(s).ok(),
+        _ => None,
+    }
+    .with_context(|| format!("No {} rate in FX response", currency.code()))?;
+    if rate <= Decimal::ZERO {
+        bail!("FX rate for {} must be positive", currency.code());
+    }
+    Ok(rate)
+}
+
+fn fetch_rate(currency: DisplayCurrency) -> Result<Decimal> {
+    let url = format!("{FX_API}?from=USD&to={}", currency.code());
+    let output = Command::new("curl")
+        .args(["-sSf", "--max-time", "10", &url])
+        .output()
+        .context("Failed to run curl")?;
+    if !output.status.success() {
+        bail!("Failed to fetch FX rate");
+    }
+    parse_rate(&String::from_utf8_lossy(&output.stdout), currency)
+}
+
+/// Returns how many units of `currency` one USD buys, using today's cached
+/// rate when available. USD always resolves to 1 without touching the network.
+pub fn usd_rate(currency: DisplayCurrency) -> Result<Decimal> {
+    if currency == DisplayCurrency::Usd {
+        return Ok(Decimal::ONE);
+    }
+    let today = Utc::now().format("%Y-%m-%d").to_string();
+    if let Some(rate) = read_cache(currency, &today) {
+        return Ok(rate);
+    }
+    let rate = fetch_rate(currency)?;
+    if let Err(e) = write_cache(currency, &today, rate) {
+        tracing::warn!("could not cache FX rate: {e}");
+    }
+    Ok(rate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rate_reads_numeric_rate() {
+        let body = r#"{"amount":1.0,"base":"USD","date":"2025-01-02","rates":{"EUR":0.9213}}"#;
+        assert_eq!(
+            parse_rate(body, DisplayCurrency::Eur).unwrap(),
+            Decimal::from_str("0.9213").unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_rate_missing_currency_errors() {
+        let body = r#"{"rates":{"EUR":0.92}}"#;
+        assert!(parse_rate(body, DisplayCurrency::Gbp).is_err());
+    }
+
+    #[test]
+    fn usd_rate_is_one_without_fetch() {
+        assert_eq!(usd_rate(DisplayCurrency::Usd).unwrap(), Decimal::ONE);
+    }
+}
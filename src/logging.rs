@@ -0,0 +1,45 @@
+//! Diagnostics (warnings, progress, copy-monitor logs) go through `tracing`
+//! to stderr so `-q`/`-v` can filter them; command results stay on stdout.
+
+use tracing::level_filters::LevelFilter;
+
+/// `-q` keeps only errors; each `-v` adds a level above the default `info`.
+pub(crate) fn level(quiet: bool, verbose: u8) -> LevelFilter {
+    if quiet {
+        return LevelFilter::ERROR;
+    }
+    match verbose {
+        0 => LevelFilter::INFO,
+        1 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    }
+}
+
+pub(crate) fn init(level: LevelFilter) {
+    let ansi = std::io::IsTerminal::is_terminal(&std::io::stderr())
+        && std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty());
+    let builder = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(std::io::stderr)
+        .with_ansi(ansi)
+        .with_target(false);
+    // Timestamps only help when reading verbose logs after the fact.
+    let _ = if level >= LevelFilter::DEBUG {
+        builder.try_init()
+    } else {
+        builder.without_time().try_init()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quiet_wins_and_verbose_is_repeatable() {
+        assert_eq!(level(false, 0), LevelFilter::INFO);
+        assert_eq!(level(false, 1), LevelFilter::DEBUG);
+        assert_eq!(level(false, 3), LevelFilter::TRACE);
+        assert_eq!(level(true, 0), LevelFilter::ERROR);
+    }
+}
@@ -1,6 +1,10 @@
 mod auth;
+mod cache;
 mod commands;
 mod config;
+mod fx;
+mod logging;
+mod net;
 mod output;
 mod shell;
 
@@ -15,17 +19,78 @@ pub(crate) struct Cli {
     #[command(subcommand)]
     command: Commands,
 
-    /// Output format: table or json
+    /// Output format: table, json or yaml
     #[arg(short, long, global = true, default_value = "table")]
     pub(crate) output: OutputFormat,
 
+    /// Stream json as one compact object per line (implies -o json)
+    #[arg(long, global = true)]
+    ndjson: bool,
+
+    /// Only print errors on stderr, not warnings or progress
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+
+    /// More diagnostic detail on stderr (-v debug, -vv trace)
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
     /// Private key (overrides env var and config file)
     #[arg(long, global = true)]
     private_key: Option<String>,
 
+    /// Read the private key from this file instead of passing it inline (overrides env var and config file)
+    #[arg(long, global = true, conflicts_with = "private_key")]
+    private_key_file: Option<std::path::PathBuf>,
+
+    /// Refuse a --private-key-file other users can read instead of only warning
+    #[arg(long, global = true, requires = "private_key_file")]
+    strict_key_permissions: bool,
+
     /// Signature type: eoa, proxy, or gnosis-safe
     #[arg(long, global = true)]
     signature_type: Option<String>,
+
+    /// Show dollar amounts in tables converted to this currency (display only)
+    #[arg(long, global = true, alias = "base-currency", value_enum, default_value_t = fx::DisplayCurrency::Usd)]
+    display_currency: fx::DisplayCurrency,
+
+    /// Color and box-drawing table borders: auto (terminal only, honors NO_COLOR), always, never
+    #[arg(long, global = true, value_enum, default_value_t = output::ColorChoice::Auto)]
+    color: output::ColorChoice,
+
+    /// Same as --color never
+    #[arg(long, global = true)]
+    no_color: bool,
+
+    /// Retries for transient API failures (5xx, timeouts); 4xx errors are never retried
+    #[arg(long, global = true, default_value_t = net::DEFAULT_MAX_RETRIES)]
+    max_retries: u32,
+
+    /// Give up on a single API request after this many seconds instead of waiting on a stalled connection
+    #[arg(long, global = true, default_value_t = net::DEFAULT_TIMEOUT_SECS, value_parser = clap::value_parser!(u64).range(1..))]
+    timeout_secs: u64,
+
+    /// Serve repeated read-only Gamma queries (markets, events, tags, series) from an on-disk cache this many seconds old at most [default: 0, off]
+    #[arg(long, global = true, default_value_t = 0)]
+    cache_ttl_secs: u64,
+
+    /// Bypass the response cache for this command
+    #[arg(long, global = true)]
+    no_cache: bool,
+
+    /// Directory for config, copy-trading state and logs [env: POLYMARKET_CONFIG_DIR] [default: ~/.config/polymarket]
+    #[arg(long, global = true)]
+    config_dir: Option<std::path::PathBuf>,
+
+    /// IANA timezone (e.g. America/New_York) for PnL day buckets and shown timestamps [default: UTC]
+    #[arg(long, global = true, value_parser = parse_timezone)]
+    timezone: Option<chrono_tz::Tz>,
+}
+
+fn parse_timezone(s: &str) -> Result<chrono_tz::Tz, String> {
+    s.parse()
+        .map_err(|_| format!("unknown timezone '{s}' (expected an IANA name like Europe/Madrid)"))
 }
 
 #[derive(Subcommand)]
@@ -33,7 +98,7 @@ enum Commands {
     /// Guided first-time setup (wallet, proxy, approvals)
     Setup,
     /// Launch interactive shell
-    Shell,
+    Shell(shell::ShellArgs),
     /// Interact with markets
     Markets(commands::markets::MarketsArgs),
     /// Interact with events
@@ -55,13 +120,17 @@ enum Commands {
     /// CTF operations: split, merge, redeem positions
     Ctf(commands::ctf::CtfArgs),
     /// Copy-trading helper workflow and dashboard
-    Copy(commands::copy::CopyArgs),
+    Copy(Box<commands::copy::CopyArgs>),
     /// Query on-chain data (positions, trades, leaderboards)
     Data(commands::data::DataArgs),
     /// Bridge assets from other chains to Polymarket
     Bridge(commands::bridge::BridgeArgs),
     /// Manage wallet and authentication
     Wallet(commands::wallet::WalletArgs),
+    /// Print the JSON Schema of a command's json output
+    Schema(commands::schema::SchemaArgs),
+    /// Manage the local response cache
+    Cache(commands::cache::CacheArgs),
     /// Check API health status
     Status,
     /// Update to the latest version
@@ -70,30 +139,104 @@ enum Commands {
 
 #[tokio::main]
 async fn main() -> ExitCode {
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
+    logging::init(logging::level(cli.quiet, cli.verbose));
+    apply_output_mode(&mut cli);
     let output = cli.output;
+    apply_display_currency(cli.display_currency, output);
+    if let Some(tz) = cli.timezone {
+        output::set_display_timezone(tz);
+    }
+    if let Some(dir) = cli.config_dir.clone() {
+        config::set_config_dir(dir);
+    }
+    let color = if cli.no_color {
+        output::ColorChoice::Never
+    } else {
+        cli.color
+    };
+    output::set_color_enabled(
+        matches!(output, OutputFormat::Table)
+            && color.resolve(
+                std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()),
+                std::io::IsTerminal::is_terminal(&std::io::stdout()),
+            ),
+    );
 
     if let Err(e) = run(cli).await {
-        match output {
-            OutputFormat::Json => {
-                println!("{}", serde_json::json!({"error": e.to_string()}));
-            }
-            OutputFormat::Table => {
-                eprintln!("Error: {e}");
-            }
-        }
+        output::print_error(&e, output);
         return ExitCode::FAILURE;
     }
 
     ExitCode::SUCCESS
 }
 
+/// `--ndjson` is a json variant, so it overrides `-o`; yaml reuses the json
+/// printers and only swaps the serializer.
+pub(crate) fn apply_output_mode(cli: &mut Cli) {
+    if cli.ndjson {
+        cli.output = OutputFormat::Json;
+    }
+    output::set_ndjson(cli.ndjson);
+    output::set_yaml(matches!(cli.output, OutputFormat::Yaml));
+}
+
+/// Times one health check; a failure is reported as a down service, not an error.
+async fn probe(
+    service: &'static str,
+    check: impl Future<Output = anyhow::Result<String>>,
+) -> output::status::ServiceStatus {
+    let started = std::time::Instant::now();
+    let result = check.await;
+    let latency_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+    output::status::ServiceStatus {
+        service,
+        up: result.is_ok(),
+        latency_ms,
+        status: match result {
+            Ok(status) => status,
+            Err(e) => e.to_string(),
+        },
+    }
+}
+
+fn apply_display_currency(currency: fx::DisplayCurrency, output: OutputFormat) {
+    if currency == fx::DisplayCurrency::Usd || !matches!(output, OutputFormat::Table) {
+        return;
+    }
+    match fx::usd_rate(currency) {
+        Ok(rate) => {
+            output::set_display_currency(currency.symbol(), rate);
+            tracing::info!(
+                "Amounts shown in {} at 1 USD = {rate} {} (display only)",
+                currency.code(),
+                currency.code()
+            );
+        }
+        Err(e) => tracing::warn!(
+            "could not get {} FX rate ({e}); showing USD",
+            currency.code()
+        ),
+    }
+}
+
 #[allow(clippy::too_many_lines)]
 pub(crate) async fn run(cli: Cli) -> anyhow::Result<()> {
+    net::set_max_retries(cli.max_retries);
+    net::set_timeout_secs(cli.timeout_secs);
+    cache::set_ttl_secs(if cli.no_cache { 0 } else { cli.cache_ttl_secs });
+    // Read lazily so a bad --private-key-file only fails commands that sign.
+    let private_key = || {
+        auth::cli_private_key(
+            cli.private_key.clone(),
+            cli.private_key_file.as_deref(),
+            cli.strict_key_permissions,
+        )
+    };
     match cli.command {
         Commands::Setup => commands::setup::execute(),
-        Commands::Shell => {
-            Box::pin(shell::run_shell()).await;
+        Commands::Shell(args) => {
+            Box::pin(shell::run_shell(args)).await;
             Ok(())
         }
         Commands::Markets(args) => {
@@ -153,21 +296,47 @@ pub(crate) async fn run(cli: Cli) -> anyhow::Result<()> {
             .await
         }
         Commands::Approve(args) => {
-            commands::approve::execute(args, cli.output, cli.private_key.as_deref()).await
+            let key = private_key()?;
+            commands::approve::execute(args, cli.output, key.as_deref()).await
         }
         Commands::Clob(args) => {
+            let key = if args.command.needs_wallet() {
+                private_key()?
+            } else {
+                None
+            };
             commands::clob::execute(
                 args,
                 cli.output,
-                cli.private_key.as_deref(),
+                key.as_deref(),
                 cli.signature_type.as_deref(),
             )
             .await
         }
         Commands::Ctf(args) => {
-            commands::ctf::execute(args, cli.output, cli.private_key.as_deref()).await
+            let key = private_key()?;
+            commands::ctf::execute(
+                args,
+                cli.output,
+                key.as_deref(),
+                cli.signature_type.as_deref(),
+            )
+            .await
+        }
+        Commands::Copy(args) => {
+            let key = if args.command.needs_wallet() {
+                private_key()?
+            } else {
+                None
+            };
+            commands::copy::execute(
+                *args,
+                cli.output,
+                key.as_deref(),
+                cli.signature_type.as_deref(),
+            )
+            .await
         }
-        Commands::Copy(args) => commands::copy::execute(args, cli.output).await,
         Commands::Data(args) => {
             commands::data::execute(
                 &polymarket_client_sdk::data::Client::default(),
@@ -185,20 +354,47 @@ pub(crate) async fn run(cli: Cli) -> anyhow::Result<()> {
             .await
         }
         Commands::Wallet(args) => {
-            commands::wallet::execute(args, &cli.output, cli.private_key.as_deref())
+            let key = private_key()?;
+            commands::wallet::execute(
+                args,
+                &cli.output,
+                key.as_deref(),
+                cli.signature_type.as_deref(),
+            )
+            .await
         }
         Commands::Upgrade => commands::upgrade::execute(),
+        Commands::Schema(args) => commands::schema::execute(&args),
+        Commands::Cache(args) => commands::cache::execute(&args, cli.output),
         Commands::Status => {
-            let status = polymarket_client_sdk::gamma::Client::default()
-                .status()
-                .await?;
-            match cli.output {
-                OutputFormat::Json => {
-                    println!("{}", serde_json::json!({"status": status}));
-                }
-                OutputFormat::Table => {
-                    println!("API Status: {status}");
-                }
+            let gamma = polymarket_client_sdk::gamma::Client::default();
+            let data = polymarket_client_sdk::data::Client::default();
+            let clob = polymarket_client_sdk::clob::Client::default();
+            let bridge = polymarket_client_sdk::bridge::Client::default();
+            let leaderboard =
+                polymarket_client_sdk::data::types::request::TraderLeaderboardRequest::builder()
+                    .limit(1)?
+                    .build();
+            let (gamma, data, clob, bridge) = tokio::join!(
+                probe("gamma", async {
+                    Ok(net::retry(|| gamma.status()).await?.to_string())
+                }),
+                probe("data", async {
+                    net::retry(|| data.leaderboard(&leaderboard)).await?;
+                    Ok("OK".to_string())
+                }),
+                probe("clob", async {
+                    Ok(net::retry(|| clob.ok()).await?.to_string())
+                }),
+                probe("bridge", async {
+                    net::retry(|| bridge.supported_assets()).await?;
+                    Ok("OK".to_string())
+                }),
+            );
+            let statuses = [gamma, data, clob, bridge];
+            output::status::print_service_statuses(&statuses, cli.output)?;
+            if statuses.iter().all(|s| !s.up) {
+                anyhow::bail!("all Polymarket APIs are down");
             }
             Ok(())
         }
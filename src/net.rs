@@ -0,0 +1,503 @@
+//! Retries for transient API failures (5xx, timeouts, dropped connections),
+//! the `--timeout-secs` bound on each request, and `--all` pagination.
+
+use std::collections::HashSet;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::time::Duration;
+
+use anyhow::anyhow;
+
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
+pub const DEFAULT_MAX_PAGES: u32 = 50;
+const BASE_DELAY: Duration = Duration::from_millis(250);
+const MAX_DELAY: Duration = Duration::from_secs(5);
+/// Pause between pages in `--all` mode, to stay clear of rate limits.
+const PAGE_DELAY: Duration = Duration::from_millis(200);
+
+/// `--max-retries`. Atomic rather than `OnceLock` because the shell sets it
+/// per command.
+static MAX_RETRIES: AtomicU32 = AtomicU32::new(DEFAULT_MAX_RETRIES);
+
+/// `--timeout-secs`, atomic for the same reason.
+static TIMEOUT_SECS: AtomicU64 = AtomicU64::new(DEFAULT_TIMEOUT_SECS);
+
+pub fn set_max_retries(n: u32) {
+    MAX_RETRIES.store(n, Ordering::Relaxed);
+}
+
+pub fn set_timeout_secs(secs: u64) {
+    TIMEOUT_SECS.store(secs, Ordering::Relaxed);
+}
+
+/// How long a single API request may take before it's abandoned. The SDK
+/// clients have no timeout of their own, so callers bound the request future.
+pub fn request_timeout() -> Duration {
+    Duration::from_secs(TIMEOUT_SECS.load(Ordering::Relaxed))
+}
+
+/// Awaits `fut`, failing once `--timeout-secs` passes instead of hanging on
+/// a stalled connection. For calls that must not be repeated (posting
+/// orders); idempotent reads should go through [`retry`].
+pub async fn timed<T, E, Fut>(fut: Fut) -> anyhow::Result<T>
+where
+    E: Into<anyhow::Error>,
+    Fut: Future<Output = Result<T, E>>,
+{
+    timed_with(request_timeout(), fut).await
+}
+
+async fn timed_with<T, E, Fut>(limit: Duration, fut: Fut) -> anyhow::Result<T>
+where
+    E: Into<anyhow::Error>,
+    Fut: Future<Output = Result<T, E>>,
+{
+    match tokio::time::timeout(limit, fut).await {
+        Ok(result) => result.map_err(Into::into),
+        Err(_) => Err(anyhow!("request timed out after {}s", limit.as_secs_f32())),
+    }
+}
+
+/// Runs `op`, retrying up to `--max-retries` more times with exponential
+/// backoff and jitter. Each attempt is bounded by `--timeout-secs`; client
+/// errors (4xx) are returned at once.
+pub async fn retry<T, E, F, Fut>(op: F) -> anyhow::Result<T>
+where
+    E: Into<anyhow::Error>,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    retry_with(
+        MAX_RETRIES.load(Ordering::Relaxed),
+        BASE_DELAY,
+        request_timeout(),
+        op,
+    )
+    .await
+}
+
+async fn retry_with<T, E, F, Fut>(
+    max_retries: u32,
+    base_delay: Duration,
+    limit: Duration,
+    mut op: F,
+) -> anyhow::Result<T>
+where
+    E: Into<anyhow::Error>,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut attempt = 0;
+    loop {
+        match timed_with(limit, op()).await {
+            Ok(v) => return Ok(v),
+            Err(e) if attempt < max_retries && is_retryable(&format!("{e:#}")) => {
+                tokio::time::sleep(backoff_delay(base_delay, attempt)).await;
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Follows offset pagination for `--all`: calls `fetch_page(offset)`,
+/// advancing by each page's length, until a page is empty or shorter than
+/// `page_size`, or `max_pages` pages were read (then warns that the result
+/// may be incomplete). Items whose `key` was already seen are dropped, so
+/// pages that overlap while the list shifts don't produce duplicates.
+pub async fn fetch_all<T, K, F, Fut>(
+    start_offset: i32,
+    page_size: i32,
+    max_pages: u32,
+    key: impl Fn(&T) -> K,
+    fetch_page: F,
+) -> anyhow::Result<Vec<T>>
+where
+    K: Eq + Hash,
+    F: FnMut(i32) -> Fut,
+    Fut: Future<Output = anyhow::Result<Vec<T>>>,
+{
+    fetch_all_with(
+        PAGE_DELAY,
+        start_offset,
+        page_size,
+        max_pages,
+        key,
+        fetch_page,
+    )
+    .await
+}
+
+async fn fetch_all_with<T, K, F, Fut>(
+    delay: Duration,
+    start_offset: i32,
+    page_size: i32,
+    max_pages: u32,
+    key: impl Fn(&T) -> K,
+    fetch_page: F,
+) -> anyhow::Result<Vec<T>>
+where
+    K: Eq + Hash,
+    F: FnMut(i32) -> Fut,
+    Fut: Future<Output = anyhow::Result<Vec<T>>>,
+{
+    let mut out = Vec::new();
+    for_each_page_with(
+        delay,
+        start_offset,
+        page_size,
+        max_pages,
+        key,
+        fetch_page,
+        |items| {
+            out.extend(items);
+            Ok(())
+        },
+    )
+    .await?;
+    Ok(out)
+}
+
+/// Same pagination as [`fetch_all`], but hands each page's new items to
+/// `on_page` as soon as it arrives instead of collecting them, so `--ndjson`
+/// can stream long lists.
+pub async fn for_each_page<T, K, F, Fut>(
+    start_offset: i32,
+    page_size: i32,
+    max_pages: u32,
+    key: impl Fn(&T) -> K,
+    fetch_page: F,
+    on_page: impl FnMut(Vec<T>) -> anyhow::Result<()>,
+) -> anyhow::Result<()>
+where
+    K: Eq + Hash,
+    F: FnMut(i32) -> Fut,
+    Fut: Future<Output = anyhow::Result<Vec<T>>>,
+{
+    for_each_page_with(
+        PAGE_DELAY,
+        start_offset,
+        page_size,
+        max_pages,
+        key,
+        fetch_page,
+        on_page,
+    )
+    .await
+}
+
+async fn for_each_page_with<T, K, F, Fut>(
+    delay: Duration,
+    start_offset: i32,
+    page_size: i32,
+    max_pages: u32,
+    key: impl Fn(&T) -> K,
+    mut fetch_page: F,
+    mut on_page: impl FnMut(Vec<T>) -> anyhow::Result<()>,
+) -> anyhow::Result<()>
+where
+    K: Eq + Hash,
+    F: FnMut(i32) -> Fut,
+    Fut: Future<Output = anyhow::Result<Vec<T>>>,
+{
+    let page_size = page_size.max(1);
+    let mut seen = HashSet::new();
+    let mut offset = start_offset;
+    for page in 0..max_pages {
+        if page > 0 {
+            tokio::time::sleep(delay).await;
+        }
+        let batch = fetch_page(offset).await?;
+        let len = batch.len();
+        let fresh: Vec<T> = batch
+            .into_iter()
+            .filter(|item| seen.insert(key(item)))
+            .collect();
+        let exhausted = fresh.is_empty();
+        if !exhausted {
+            on_page(fresh)?;
+        }
+        if len < usize::try_from(page_size).unwrap_or(usize::MAX) || exhausted {
+            return Ok(());
+        }
+        offset = offset.saturating_add(i32::try_from(len).unwrap_or(i32::MAX));
+    }
+    tracing::warn!("stopped after {max_pages} page(s) (--max-pages); results may be incomplete");
+    Ok(())
+}
+
+/// "Equal jitter": half of the capped exponential delay, plus a random share
+/// of the other half, so concurrent retries don't line up.
+fn backoff_delay(base: Duration, attempt: u32) -> Duration {
+    let exp = base.saturating_mul(1 << attempt.min(16)).min(MAX_DELAY);
+    let half = exp / 2;
+    half + jitter(half)
+}
+
+fn jitter(max: Duration) -> Duration {
+    let range = u64::try_from(max.as_millis()).unwrap_or(u64::MAX);
+    if range == 0 {
+        return Duration::ZERO;
+    }
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| u64::from(d.subsec_nanos()));
+    Duration::from_millis(nanos % (range + 1))
+}
+
+/// The SDK's errors only carry the HTTP status in their message, so this
+/// looks for a 4xx status there. 408 (timeout) and 429 (rate limited) are
+/// worth retrying; any other 4xx will fail the same way again.
+fn is_retryable(msg: &str) -> bool {
+    !http_statuses(msg).any(|code| (400..500).contains(&code) && code != 408 && code != 429)
+}
+
+/// Three-digit numbers that read as an HTTP status: `404 Not Found` (a code
+/// followed by its reason phrase) or `status: 404`. Numbers inside URLs,
+/// hex ids or amounts (`offset=400`, `0x56687bf447`) don't count.
+fn http_statuses(msg: &str) -> impl Iterator<Item = u16> + '_ {
+    let bytes = msg.as_bytes();
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        while i < bytes.len() {
+            let start = i;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            if i == start {
+                i += 1;
+                continue;
+            }
+            let standalone = i - start == 3
+                && (start == 0 || !bytes[start - 1].is_ascii_alphanumeric())
+                && bytes.get(i).is_none_or(|b| !b.is_ascii_alphanumeric());
+            if !standalone {
+                continue;
+            }
+            let reason_follows = bytes[i..].starts_with(b" ")
+                && bytes.get(i + 1).is_some_and(u8::is_ascii_uppercase);
+            let status_label = msg[..start]
+                .trim_end_matches([' ', ':', '='])
+                .to_ascii_lowercase()
+                .ends_with("status");
+            if reason_follows || status_label {
+                return msg[start..i].parse().ok();
+            }
+        }
+        None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    const LIMIT: Duration = Duration::from_secs(5);
+
+    #[tokio::test]
+    async fn flaky_call_succeeds_on_third_attempt() {
+        let calls = Cell::new(0);
+        let result = retry_with(3, Duration::from_millis(1), LIMIT, || {
+            calls.set(calls.get() + 1);
+            let n = calls.get();
+            async move {
+                if n < 3 {
+                    Err(anyhow!("503 Service Unavailable"))
+                } else {
+                    Ok("ok")
+                }
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), "ok");
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn client_error_is_not_retried() {
+        let calls = Cell::new(0);
+        let result: anyhow::Result<()> = retry_with(3, Duration::from_millis(1), LIMIT, || {
+            calls.set(calls.get() + 1);
+            async { Err(anyhow!("status 404 Not Found")) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_retries() {
+        let calls = Cell::new(0);
+        let result: anyhow::Result<()> = retry_with(2, Duration::from_millis(1), LIMIT, || {
+            calls.set(calls.get() + 1);
+            async { Err(anyhow!("connection reset")) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn stalled_endpoint_times_out_instead_of_hanging() {
+        use tokio::io::AsyncReadExt;
+
+        // Accepts the connection but never answers, like a stalled API.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (_socket, _) = listener.accept().await.unwrap();
+            std::future::pending::<()>().await;
+        });
+
+        let started = std::time::Instant::now();
+        let result = timed_with(Duration::from_millis(200), async {
+            let mut stream = tokio::net::TcpStream::connect(addr).await?;
+            let mut buf = [0u8; 1];
+            stream.read_exact(&mut buf).await?;
+            Ok::<_, std::io::Error>(())
+        })
+        .await;
+        let elapsed = started.elapsed();
+
+        assert!(result.unwrap_err().to_string().contains("timed out"));
+        assert!(elapsed >= Duration::from_millis(200));
+        assert!(elapsed < Duration::from_secs(2), "took {elapsed:?}");
+    }
+
+    #[tokio::test]
+    async fn timed_out_attempts_are_retried() {
+        let calls = Cell::new(0);
+        let result = retry_with(
+            2,
+            Duration::from_millis(1),
+            Duration::from_millis(20),
+            || {
+                calls.set(calls.get() + 1);
+                let n = calls.get();
+                async move {
+                    if n == 1 {
+                        std::future::pending::<()>().await;
+                    }
+                    Ok::<_, anyhow::Error>(n)
+                }
+            },
+        )
+        .await;
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[test]
+    fn retryable_classification() {
+        assert!(is_retryable("error sending request: connection refused"));
+        assert!(is_retryable("502 Bad Gateway"));
+        assert!(is_retryable("429 Too Many Requests"));
+        assert!(is_retryable("token 12345678901234567890 not found at 500"));
+        assert!(!is_retryable("400 Bad Request: invalid limit"));
+        assert!(!is_retryable("HTTP status client error (404 Not Found)"));
+        assert!(!is_retryable("status: 403"));
+    }
+
+    #[test]
+    fn numbers_in_urls_and_ids_are_not_statuses() {
+        assert!(is_retryable(
+            "HTTP status server error (502 Bad Gateway) for url \
+             (https://data-api.polymarket.com/trades?user=0x56687bf447&offset=400&limit=404)"
+        ));
+        assert!(is_retryable(
+            "error sending request for url (https://clob.polymarket.com/book?id=401)"
+        ));
+        assert!(!is_retryable(
+            "HTTP status client error (404 Not Found) for url (https://gamma-api.polymarket.com/markets?offset=500)"
+        ));
+    }
+
+    #[test]
+    fn backoff_grows_and_is_capped() {
+        let base = Duration::from_millis(100);
+        let first = backoff_delay(base, 0);
+        assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(100));
+        let third = backoff_delay(base, 2);
+        assert!(third >= Duration::from_millis(200) && third <= Duration::from_millis(400));
+        assert!(backoff_delay(base, 30) <= MAX_DELAY);
+    }
+
+    #[tokio::test]
+    async fn fetch_all_follows_pages_until_empty_and_drops_overlap() {
+        let pages: Vec<Vec<u32>> = vec![vec![1, 2], vec![2, 3], vec![4, 5], vec![]];
+        let offsets = std::cell::RefCell::new(Vec::new());
+        let fetched = fetch_all_with(
+            Duration::ZERO,
+            0,
+            2,
+            10,
+            |n: &u32| *n,
+            |offset| {
+                offsets.borrow_mut().push(offset);
+                let page = pages[offsets.borrow().len() - 1].clone();
+                async move { Ok(page) }
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(fetched, [1, 2, 3, 4, 5]);
+        assert_eq!(*offsets.borrow(), [0, 2, 4, 6]);
+    }
+
+    #[tokio::test]
+    async fn for_each_page_hands_over_each_page_before_fetching_the_next() {
+        let pages: Vec<Vec<u32>> = vec![vec![1, 2], vec![2, 3], vec![4]];
+        let events = std::cell::RefCell::new(Vec::new());
+        for_each_page_with(
+            Duration::ZERO,
+            0,
+            2,
+            10,
+            |n: &u32| *n,
+            |offset| {
+                events.borrow_mut().push(format!("fetch {offset}"));
+                let page = pages[usize::try_from(offset).unwrap() / 2].clone();
+                async move { Ok(page) }
+            },
+            |items| {
+                events.borrow_mut().push(format!("print {items:?}"));
+                Ok(())
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            *events.borrow(),
+            [
+                "fetch 0",
+                "print [1, 2]",
+                "fetch 2",
+                "print [3]",
+                "fetch 4",
+                "print [4]"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_all_stops_at_max_pages() {
+        let calls = Cell::new(0);
+        let fetched = fetch_all_with(
+            Duration::ZERO,
+            10,
+            1,
+            3,
+            |n: &i32| *n,
+            |offset| {
+                calls.set(calls.get() + 1);
+                async move { Ok(vec![offset]) }
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(fetched, [10, 11, 12]);
+        assert_eq!(calls.get(), 3);
+    }
+}
@@ -4,7 +4,6 @@
 use alloy::primitives::U256;
 use anyhow::Result;
 use tabled::Tabled;
-use tabled::settings::Style;
 
 use super::OutputFormat;
 
@@ -17,6 +16,24 @@ pub struct ApprovalStatus {
     pub ctf_error: Option<String>,
 }
 
+/// A spender's USDC allowance measured against a required minimum, both in
+/// base units.
+pub struct AllowanceRequirement {
+    pub spender: String,
+    pub current: U256,
+    pub required: U256,
+}
+
+impl AllowanceRequirement {
+    pub fn sufficient(&self) -> bool {
+        self.current >= self.required
+    }
+
+    pub fn gap(&self) -> U256 {
+        self.required.saturating_sub(self.current)
+    }
+}
+
 #[derive(Tabled)]
 struct ApprovalRow {
     #[tabled(rename = "Contract")]
@@ -39,6 +56,22 @@ fn format_allowance(allowance: U256) -> String {
     }
 }
 
+/// Formats a base-unit amount as USDC with up to 6 decimals.
+pub fn format_usdc(amount: U256) -> String {
+    if amount == U256::MAX {
+        return "unlimited".to_string();
+    }
+    let usdc_decimals = U256::from(1_000_000);
+    let whole = amount / usdc_decimals;
+    let frac = (amount % usdc_decimals).to::<u64>();
+    if frac == 0 {
+        format!("{whole} USDC")
+    } else {
+        let frac = format!("{frac:06}");
+        format!("{whole}.{} USDC", frac.trim_end_matches('0'))
+    }
+}
+
 fn format_ctf(approved: bool) -> String {
     if approved {
         "\u{2713} Approved".to_string()
@@ -49,7 +82,7 @@ fn format_ctf(approved: bool) -> String {
 
 pub fn print_approval_status(statuses: &[ApprovalStatus], output: &OutputFormat) -> Result<()> {
     match output {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let json: Vec<serde_json::Value> = statuses
                 .iter()
                 .map(|s| {
@@ -69,7 +102,7 @@ pub fn print_approval_status(statuses: &[ApprovalStatus], output: &OutputFormat)
                     obj
                 })
                 .collect();
-            println!("{}", serde_json::to_string_pretty(&json)?);
+            super::print_json(&json)?;
             Ok(())
         }
         OutputFormat::Table => {
@@ -89,15 +122,80 @@ pub fn print_approval_status(statuses: &[ApprovalStatus], output: &OutputFormat)
                     },
                 })
                 .collect();
-            let table = tabled::Table::new(rows).with(Style::rounded()).to_string();
+            let table = tabled::Table::new(rows)
+                .with(super::table_style())
+                .to_string();
             println!("{table}");
             Ok(())
         }
     }
 }
 
+pub fn print_allowance_requirement(
+    requirement: &AllowanceRequirement,
+    output: &OutputFormat,
+) -> Result<()> {
+    match output {
+        OutputFormat::Json | OutputFormat::Yaml => {
+            let json = serde_json::json!({
+                "spender": requirement.spender,
+                "current": requirement.current.to_string(),
+                "required": requirement.required.to_string(),
+                "sufficient": requirement.sufficient(),
+            });
+            super::print_json(&json)?;
+        }
+        OutputFormat::Table => {
+            let status = if requirement.sufficient() {
+                "\u{2713} Sufficient".to_string()
+            } else {
+                format!("\u{2717} Short by {}", format_usdc(requirement.gap()))
+            };
+            let rows = vec![
+                ["Spender".into(), requirement.spender.clone()],
+                ["Current".into(), format_usdc(requirement.current)],
+                ["Required".into(), format_usdc(requirement.required)],
+                ["Status".into(), status],
+            ];
+            crate::output::print_detail_table(rows);
+        }
+    }
+    Ok(())
+}
+
 pub fn print_tx_result(step: usize, total: usize, label: &str, tx_hash: alloy::primitives::B256) {
     let hash_str = format!("{tx_hash}");
     let short = &hash_str[..10];
     println!("  [{step}/{total}] {label:<30} \u{2713} {short}\u{2026}");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_usdc_keeps_significant_decimals() {
+        assert_eq!(format_usdc(U256::from(12_500_000u64)), "12.5 USDC");
+        assert_eq!(format_usdc(U256::from(3_000_000u64)), "3 USDC");
+        assert_eq!(format_usdc(U256::from(1u64)), "0.000001 USDC");
+        assert_eq!(format_usdc(U256::MAX), "unlimited");
+    }
+
+    #[test]
+    fn allowance_requirement_reports_gap() {
+        let short = AllowanceRequirement {
+            spender: "0x0".into(),
+            current: U256::from(40_000_000u64),
+            required: U256::from(100_000_000u64),
+        };
+        assert!(!short.sufficient());
+        assert_eq!(short.gap(), U256::from(60_000_000u64));
+
+        let enough = AllowanceRequirement {
+            current: U256::MAX,
+            ..short
+        };
+        assert!(enough.sufficient());
+        assert_eq!(enough.gap(), U256::ZERO);
+    }
+}
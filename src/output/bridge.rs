@@ -4,7 +4,6 @@ use polymarket_client_sdk::bridge::types::{
     DepositResponse, DepositTransactionStatus, StatusResponse, SupportedAssetsResponse,
 };
 use serde_json::json;
-use tabled::settings::Style;
 use tabled::{Table, Tabled};
 
 use super::{OutputFormat, detail_field, format_decimal, print_detail_table};
@@ -21,7 +20,7 @@ pub fn print_deposit(response: &DepositResponse, output: &OutputFormat) -> anyho
             }
             print_detail_table(rows);
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data = json!({
                 "evm": format!("{}", response.address.evm),
                 "svm": response.address.svm,
@@ -34,14 +33,58 @@ pub fn print_deposit(response: &DepositResponse, output: &OutputFormat) -> anyho
     Ok(())
 }
 
+fn asset_matches(
+    chain_id: &str,
+    chain_name: &str,
+    symbol: &str,
+    chain: Option<&str>,
+    token: Option<&str>,
+) -> bool {
+    let chain_ok = chain.is_none_or(|c| {
+        let c = c.trim();
+        c == chain_id || c.eq_ignore_ascii_case(chain_name)
+    });
+    let token_ok = token.is_none_or(|t| t.trim().eq_ignore_ascii_case(symbol));
+    chain_ok && token_ok
+}
+
 pub fn print_supported_assets(
     response: &SupportedAssetsResponse,
+    chain: Option<&str>,
+    token: Option<&str>,
     output: &OutputFormat,
 ) -> anyhow::Result<()> {
+    let assets: Vec<_> = response
+        .supported_assets
+        .iter()
+        .filter(|a| {
+            asset_matches(
+                &a.chain_id.to_string(),
+                &a.chain_name,
+                &a.token.symbol,
+                chain,
+                token,
+            )
+        })
+        .collect();
     match output {
         OutputFormat::Table => {
-            if response.supported_assets.is_empty() {
-                println!("No supported assets found.");
+            if assets.is_empty() {
+                if chain.is_some() || token.is_some() {
+                    let mut filters = Vec::new();
+                    if let Some(c) = chain {
+                        filters.push(format!("chain {c}"));
+                    }
+                    if let Some(t) = token {
+                        filters.push(format!("token {t}"));
+                    }
+                    println!(
+                        "Not supported: no deposit asset matches {}.",
+                        filters.join(", ")
+                    );
+                } else {
+                    println!("No supported assets found.");
+                }
                 return Ok(());
             }
             #[derive(Tabled)]
@@ -59,8 +102,7 @@ pub fn print_supported_assets(
                 #[tabled(rename = "Min Deposit")]
                 min_deposit: String,
             }
-            let rows: Vec<Row> = response
-                .supported_assets
+            let rows: Vec<Row> = assets
                 .iter()
                 .map(|a| Row {
                     chain: a.chain_name.clone(),
@@ -71,12 +113,11 @@ pub fn print_supported_assets(
                     min_deposit: format_decimal(a.min_checkout_usd),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
-            let data: Vec<_> = response
-                .supported_assets
+        OutputFormat::Json | OutputFormat::Yaml => {
+            let data: Vec<_> = assets
                 .iter()
                 .map(|a| {
                     json!({
@@ -108,6 +149,70 @@ fn format_status(s: &DepositTransactionStatus) -> &'static str {
     }
 }
 
+/// Completed and Failed are final. So is any status this client doesn't
+/// recognise: waiting on it could only end in a timeout.
+fn is_terminal(s: &DepositTransactionStatus) -> bool {
+    matches!(format_status(s), "Completed" | "Failed" | "Unknown")
+}
+
+/// True once there is at least one transaction and none is still in flight.
+pub fn all_terminal(response: &StatusResponse) -> bool {
+    !response.transactions.is_empty()
+        && response
+            .transactions
+            .iter()
+            .all(|tx| is_terminal(&tx.status))
+}
+
+/// The per-transaction statuses, used to redraw only when something changed.
+pub fn status_signature(response: &StatusResponse) -> Vec<&'static str> {
+    response
+        .transactions
+        .iter()
+        .map(|tx| format_status(&tx.status))
+        .collect()
+}
+
+pub fn print_track_summary(
+    address: &str,
+    response: Option<&StatusResponse>,
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    let complete = response.is_some_and(all_terminal);
+    match output {
+        OutputFormat::Table => {
+            if complete {
+                println!("All transactions reached a final state.");
+            } else {
+                println!("Timed out before every transaction reached a final state.");
+            }
+        }
+        OutputFormat::Json | OutputFormat::Yaml => {
+            let transactions: Vec<_> = response
+                .map(|r| r.transactions.as_slice())
+                .unwrap_or_default()
+                .iter()
+                .map(|tx| {
+                    json!({
+                        "tx_hash": tx.tx_hash,
+                        "from_chain_id": tx.from_chain_id,
+                        "from_amount_base_unit": tx.from_amount_base_unit.to_string(),
+                        "status": format_status(&tx.status),
+                        "terminal": is_terminal(&tx.status),
+                    })
+                })
+                .collect();
+            super::print_json(&json!({
+                "address": address,
+                "complete": complete,
+                "timed_out": !complete,
+                "transactions": transactions,
+            }))?;
+        }
+    }
+    Ok(())
+}
+
 pub fn print_status(response: &StatusResponse, output: &OutputFormat) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => {
@@ -145,10 +250,10 @@ pub fn print_status(response: &StatusResponse, output: &OutputFormat) -> anyhow:
                         .map_or_else(|| "—".into(), |h| super::truncate(h, 14)),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = response
                 .transactions
                 .iter()
@@ -199,4 +304,33 @@ mod tests {
         );
         assert_eq!(format_status(&DepositTransactionStatus::Failed), "Failed");
     }
+
+    #[test]
+    fn only_completed_and_failed_are_terminal() {
+        assert!(is_terminal(&DepositTransactionStatus::Completed));
+        assert!(is_terminal(&DepositTransactionStatus::Failed));
+        for in_flight in [
+            DepositTransactionStatus::DepositDetected,
+            DepositTransactionStatus::Processing,
+            DepositTransactionStatus::OriginTxConfirmed,
+            DepositTransactionStatus::Submitted,
+        ] {
+            assert!(!is_terminal(&in_flight), "{in_flight:?}");
+        }
+    }
+
+    #[test]
+    fn asset_matches_chain_by_id_or_name_and_token_symbol() {
+        assert!(asset_matches("137", "Polygon", "USDC", None, None));
+        assert!(asset_matches("137", "Polygon", "USDC", Some("137"), None));
+        assert!(asset_matches(
+            "137",
+            "Polygon",
+            "USDC",
+            Some("polygon"),
+            Some("usdc")
+        ));
+        assert!(!asset_matches("137", "Polygon", "USDC", Some("1"), None));
+        assert!(!asset_matches("137", "Polygon", "USDC", None, Some("ETH")));
+    }
 }
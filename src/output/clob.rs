@@ -13,10 +13,9 @@ use polymarket_client_sdk::clob::types::response::{
 };
 use polymarket_client_sdk::types::Decimal;
 use serde_json::json;
-use tabled::settings::Style;
 use tabled::{Table, Tabled};
 
-use super::{OutputFormat, format_decimal, truncate};
+use super::{OutputFormat, detail_field, format_decimal, print_detail_table, truncate};
 
 /// Base64-encoded empty cursor returned by the CLOB API when there are no more pages.
 const END_CURSOR: &str = "LTE=";
@@ -24,7 +23,7 @@ const END_CURSOR: &str = "LTE=";
 pub fn print_ok(result: &str, output: &OutputFormat) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => println!("CLOB API: {result}"),
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(&json!({"status": result}))?;
         }
     }
@@ -34,13 +33,57 @@ pub fn print_ok(result: &str, output: &OutputFormat) -> anyhow::Result<()> {
 pub fn print_price(result: &PriceResponse, output: &OutputFormat) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => println!("Price: {}", result.price),
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(&json!({"price": result.price.to_string()}))?;
         }
     }
     Ok(())
 }
 
+/// Prices for `clob price --tokens-file`, in input order with per-line errors.
+pub fn print_price_list(
+    inputs: &[String],
+    results: &[anyhow::Result<Decimal>],
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Token")]
+                token: String,
+                #[tabled(rename = "Price")]
+                price: String,
+            }
+            let rows: Vec<Row> = inputs
+                .iter()
+                .zip(results)
+                .map(|(token, result)| Row {
+                    token: truncate(token, 40),
+                    price: match result {
+                        Ok(price) => price.to_string(),
+                        Err(e) => format!("error: {e}"),
+                    },
+                })
+                .collect();
+            let table = Table::new(rows).with(super::table_style()).to_string();
+            println!("{table}");
+        }
+        OutputFormat::Json | OutputFormat::Yaml => {
+            let data: Vec<_> = inputs
+                .iter()
+                .zip(results)
+                .map(|(token, result)| match result {
+                    Ok(price) => json!({"token": token, "price": price.to_string()}),
+                    Err(e) => json!({"token": token, "error": e.to_string()}),
+                })
+                .collect();
+            super::print_json(&data)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn print_batch_prices(result: &PricesResponse, output: &OutputFormat) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => {
@@ -71,10 +114,10 @@ pub fn print_batch_prices(result: &PricesResponse, output: &OutputFormat) -> any
                     });
                 }
             }
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data = result.prices.as_ref().map(|prices| {
                 prices
                     .iter()
@@ -96,7 +139,7 @@ pub fn print_batch_prices(result: &PricesResponse, output: &OutputFormat) -> any
 pub fn print_midpoint(result: &MidpointResponse, output: &OutputFormat) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => println!("Midpoint: {}", result.mid),
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(&json!({"midpoint": result.mid.to_string()}))?;
         }
     }
@@ -125,10 +168,10 @@ pub fn print_midpoints(result: &MidpointsResponse, output: &OutputFormat) -> any
                     midpoint: mid.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: serde_json::Map<String, serde_json::Value> = result
                 .midpoints
                 .iter()
@@ -143,7 +186,7 @@ pub fn print_midpoints(result: &MidpointsResponse, output: &OutputFormat) -> any
 pub fn print_spread(result: &SpreadResponse, output: &OutputFormat) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => println!("Spread: {}", result.spread),
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(&json!({"spread": result.spread.to_string()}))?;
         }
     }
@@ -175,10 +218,10 @@ pub fn print_spreads(result: &SpreadsResponse, output: &OutputFormat) -> anyhow:
                     spread: spread.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data = result.spreads.as_ref().map(|spreads| {
                 spreads
                     .iter()
@@ -191,7 +234,7 @@ pub fn print_spreads(result: &SpreadsResponse, output: &OutputFormat) -> anyhow:
     Ok(())
 }
 
-fn order_book_to_json(book: &OrderBookSummaryResponse) -> serde_json::Value {
+pub(crate) fn order_book_to_json(book: &OrderBookSummaryResponse) -> serde_json::Value {
     let bids: Vec<_> = book
         .bids
         .iter()
@@ -251,7 +294,7 @@ pub fn print_order_book(
                         size: o.size.to_string(),
                     })
                     .collect();
-                let table = Table::new(rows).with(Style::rounded()).to_string();
+                let table = Table::new(rows).with(super::table_style()).to_string();
                 println!("{table}");
             }
 
@@ -269,17 +312,222 @@ pub fn print_order_book(
                         size: o.size.to_string(),
                     })
                     .collect();
-                let table = Table::new(rows).with(Style::rounded()).to_string();
+                let table = Table::new(rows).with(super::table_style()).to_string();
                 println!("{table}");
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(&order_book_to_json(result))?;
         }
     }
     Ok(())
 }
 
+/// One order-book level plus the running totals from the best price to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LadderLevel {
+    pub price: Decimal,
+    pub size: Decimal,
+    pub cum_size: Decimal,
+    pub cum_notional: Decimal,
+}
+
+/// Accumulates size and notional down a side already ordered best-first.
+pub fn with_cumulative(levels: &[(Decimal, Decimal)]) -> Vec<LadderLevel> {
+    let mut cum_size = Decimal::ZERO;
+    let mut cum_notional = Decimal::ZERO;
+    levels
+        .iter()
+        .map(|&(price, size)| {
+            cum_size += size;
+            cum_notional += price * size;
+            LadderLevel {
+                price,
+                size,
+                cum_size,
+                cum_notional,
+            }
+        })
+        .collect()
+}
+
+/// Midpoint and spread from the best levels of best-first ladders.
+fn ladder_mid_spread(
+    bids: &[LadderLevel],
+    asks: &[LadderLevel],
+) -> (Option<Decimal>, Option<Decimal>) {
+    match (bids.first(), asks.first()) {
+        (Some(bid), Some(ask)) => (
+            Some((bid.price + ask.price) / Decimal::TWO),
+            Some(ask.price - bid.price),
+        ),
+        _ => (None, None),
+    }
+}
+
+fn ladder_to_json(levels: &[LadderLevel]) -> Vec<serde_json::Value> {
+    levels
+        .iter()
+        .map(|l| {
+            json!({
+                "price": l.price.to_string(),
+                "size": l.size.to_string(),
+                "cum_size": l.cum_size.to_string(),
+                "cum_notional": l.cum_notional.to_string(),
+            })
+        })
+        .collect()
+}
+
+pub fn print_book_ladder(
+    result: &OrderBookSummaryResponse,
+    bids: &[LadderLevel],
+    asks: &[LadderLevel],
+    cumulative: bool,
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    let (mid, spread) = ladder_mid_spread(bids, asks);
+    match output {
+        OutputFormat::Table => {
+            println!("Market: {}", result.market);
+            println!("Asset: {}", result.asset_id);
+            println!(
+                "Last Trade: {}",
+                result
+                    .last_trade_price
+                    .map_or("—".into(), |p| p.to_string())
+            );
+            println!(
+                "Midpoint: {}  Spread: {}",
+                mid.map_or("—".into(), |m| m.to_string()),
+                spread.map_or("—".into(), |s| s.to_string())
+            );
+
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Price")]
+                price: String,
+                #[tabled(rename = "Size")]
+                size: String,
+            }
+
+            #[derive(Tabled)]
+            struct CumulativeRow {
+                #[tabled(rename = "Price")]
+                price: String,
+                #[tabled(rename = "Size")]
+                size: String,
+                #[tabled(rename = "Cum Size")]
+                cum_size: String,
+                #[tabled(rename = "Cum Notional")]
+                cum_notional: String,
+            }
+
+            for (label, levels) in [("Bids", bids), ("Asks", asks)] {
+                println!();
+                if levels.is_empty() {
+                    println!("No {}.", label.to_lowercase());
+                    continue;
+                }
+                println!("{label}:");
+                let mut table = if cumulative {
+                    Table::new(levels.iter().map(|l| CumulativeRow {
+                        price: l.price.to_string(),
+                        size: l.size.to_string(),
+                        cum_size: l.cum_size.to_string(),
+                        cum_notional: l.cum_notional.to_string(),
+                    }))
+                } else {
+                    Table::new(levels.iter().map(|l| Row {
+                        price: l.price.to_string(),
+                        size: l.size.to_string(),
+                    }))
+                };
+                println!("{}", table.with(super::table_style()));
+            }
+        }
+        OutputFormat::Json | OutputFormat::Yaml => {
+            super::print_json(&json!({
+                "market": result.market.to_string(),
+                "asset_id": result.asset_id.to_string(),
+                "timestamp": result.timestamp.to_rfc3339(),
+                "min_order_size": result.min_order_size.to_string(),
+                "neg_risk": result.neg_risk,
+                "tick_size": result.tick_size.as_decimal().to_string(),
+                "last_trade_price": result.last_trade_price.map(|p| p.to_string()),
+                "midpoint": mid.map(|m| m.to_string()),
+                "spread": spread.map(|s| s.to_string()),
+                "bids": ladder_to_json(bids),
+                "asks": ladder_to_json(asks),
+            }))?;
+        }
+    }
+    Ok(())
+}
+
+pub fn print_depth(
+    metrics: &crate::commands::clob::DepthMetrics,
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    let fill_price = metrics.fill.and_then(|f| f.avg_price());
+    match output {
+        OutputFormat::Table => {
+            let mut rows = Vec::new();
+            detail_field!(
+                rows,
+                "Midpoint",
+                metrics.mid.map_or_else(|| "—".into(), |m| m.to_string())
+            );
+            detail_field!(
+                rows,
+                format!("Bid Depth (±{} ticks)", metrics.ticks),
+                metrics.bid_depth.to_string()
+            );
+            detail_field!(
+                rows,
+                format!("Ask Depth (±{} ticks)", metrics.ticks),
+                metrics.ask_depth.to_string()
+            );
+            detail_field!(
+                rows,
+                "Imbalance",
+                metrics
+                    .imbalance
+                    .map_or_else(|| "—".into(), |i| format!("{i:.4}"))
+            );
+            if let (Some(size), Some(fill)) = (metrics.size, metrics.fill) {
+                detail_field!(
+                    rows,
+                    format!("Avg Fill ({} {size})", metrics.side),
+                    fill_price.map_or_else(|| "no liquidity".into(), |p| format!("{p:.4}"))
+                );
+                if !fill.complete {
+                    detail_field!(
+                        rows,
+                        "Fillable Size",
+                        format!("{} (book too thin)", fill.shares)
+                    );
+                }
+            }
+            print_detail_table(rows);
+        }
+        OutputFormat::Json | OutputFormat::Yaml => {
+            super::print_json(&json!({
+                "mid": metrics.mid.map(|m| m.to_string()),
+                "ticks": metrics.ticks,
+                "bid_depth": metrics.bid_depth.to_string(),
+                "ask_depth": metrics.ask_depth.to_string(),
+                "imbalance": metrics.imbalance.map(|i| i.to_string()),
+                "size": metrics.size.map(|s| s.to_string()),
+                "side": metrics.side,
+                "fill_price_for_size": fill_price.map(|p| p.to_string()),
+                "fully_filled": metrics.fill.map(|f| f.complete),
+            }))?;
+        }
+    }
+    Ok(())
+}
+
 pub fn print_order_books(
     result: &[OrderBookSummaryResponse],
     output: &OutputFormat,
@@ -297,7 +545,7 @@ pub fn print_order_books(
                 print_order_book(book, output)?;
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = result.iter().map(order_book_to_json).collect();
             super::print_json(&data)?;
         }
@@ -311,7 +559,7 @@ pub fn print_last_trade(
 ) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => println!("Last Trade: {} ({})", result.price, result.side),
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(&json!({
                 "price": result.price.to_string(),
                 "side": result.side.to_string(),
@@ -348,10 +596,10 @@ pub fn print_last_trades_prices(
                     side: t.side.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .iter()
                 .map(|t| {
@@ -407,7 +655,7 @@ pub fn print_clob_market(result: &MarketResponse, output: &OutputFormat) -> anyh
             }
             super::print_detail_table(rows);
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(result)?;
         }
     }
@@ -445,13 +693,13 @@ pub fn print_clob_markets(
                     min_tick: m.minimum_tick_size.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
             if result.next_cursor != END_CURSOR {
                 println!("Next cursor: {}", result.next_cursor);
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(result)?;
         }
     }
@@ -494,13 +742,13 @@ pub fn print_simplified_markets(
                     accepting_orders: if m.accepting_orders { "Yes" } else { "No" }.into(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
             if result.next_cursor != END_CURSOR {
                 println!("Next cursor: {}", result.next_cursor);
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(result)?;
         }
     }
@@ -512,7 +760,7 @@ pub fn print_tick_size(result: &TickSizeResponse, output: &OutputFormat) -> anyh
         OutputFormat::Table => {
             println!("Tick size: {}", result.minimum_tick_size.as_decimal());
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(&json!({
                 "minimum_tick_size": result.minimum_tick_size.as_decimal().to_string(),
             }))?;
@@ -526,7 +774,7 @@ pub fn print_fee_rate(result: &FeeRateResponse, output: &OutputFormat) -> anyhow
         OutputFormat::Table => {
             println!("Fee rate: {} bps", result.base_fee);
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(&json!({
                 "base_fee_bps": result.base_fee,
             }))?;
@@ -535,10 +783,39 @@ pub fn print_fee_rate(result: &FeeRateResponse, output: &OutputFormat) -> anyhow
     Ok(())
 }
 
+fn account_fees_json(maker_bps: Option<i32>, taker: &FeeRateResponse) -> serde_json::Value {
+    json!({
+        "maker_bps": maker_bps,
+        "taker_bps": taker.base_fee,
+    })
+}
+
+/// `maker_bps` is the market's maker base fee (`None` when the market is not
+/// listed); the taker rate is the one the CLOB quotes for this account.
+pub fn print_account_fees(
+    maker_bps: Option<i32>,
+    taker: &FeeRateResponse,
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            match maker_bps {
+                Some(bps) => println!("Maker fee: {bps} bps"),
+                None => println!("Maker fee: unknown"),
+            }
+            println!("Taker fee: {} bps", taker.base_fee);
+        }
+        OutputFormat::Json | OutputFormat::Yaml => {
+            super::print_json(&account_fees_json(maker_bps, taker))?;
+        }
+    }
+    Ok(())
+}
+
 pub fn print_neg_risk(result: &NegRiskResponse, output: &OutputFormat) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => println!("Neg risk: {}", result.neg_risk),
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(&json!({"neg_risk": result.neg_risk}))?;
         }
     }
@@ -573,10 +850,10 @@ pub fn print_price_history(
                     price: p.p.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .history
                 .iter()
@@ -602,7 +879,7 @@ pub fn print_server_time(timestamp: i64, output: &OutputFormat) -> anyhow::Resul
                 None => println!("Server time: {timestamp}"),
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(&json!({"timestamp": timestamp}))?;
         }
     }
@@ -617,7 +894,7 @@ pub fn print_geoblock(result: &GeoblockResponse, output: &OutputFormat) -> anyho
             println!("Country: {}", result.country);
             println!("Region: {}", result.region);
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(&json!({
                 "blocked": result.blocked,
                 "ip": result.ip,
@@ -666,13 +943,13 @@ pub fn print_orders(result: &Page<OpenOrderResponse>, output: &OutputFormat) ->
                     order_type: o.order_type.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
             if result.next_cursor != END_CURSOR {
                 println!("Next cursor: {}", result.next_cursor);
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .data
                 .iter()
@@ -720,7 +997,7 @@ pub fn print_order_detail(result: &OpenOrderResponse, output: &OutputFormat) ->
             ];
             super::print_detail_table(rows);
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data = json!({
                 "id": result.id,
                 "status": result.status.to_string(),
@@ -779,7 +1056,7 @@ pub fn print_post_order_result(
             println!("Making: {}", result.making_amount);
             println!("Taking: {}", result.taking_amount);
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(&post_order_to_json(result))?;
         }
     }
@@ -799,7 +1076,7 @@ pub fn print_post_orders_result(
                 print_post_order_result(r, output)?;
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = results.iter().map(post_order_to_json).collect();
             super::print_json(&data)?;
         }
@@ -826,7 +1103,7 @@ pub fn print_cancel_result(
                 println!("No orders to cancel.");
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data = json!({
                 "canceled": result.canceled,
                 "not_canceled": result.not_canceled,
@@ -871,13 +1148,13 @@ pub fn print_trades(result: &Page<TradeResponse>, output: &OutputFormat) -> anyh
                     match_time: t.match_time.format("%Y-%m-%d %H:%M").to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
             if result.next_cursor != END_CURSOR {
                 println!("Next cursor: {}", result.next_cursor);
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .data
                 .iter()
@@ -930,7 +1207,7 @@ pub fn print_balance(
                 }
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let allowances: serde_json::Map<String, serde_json::Value> = result
                 .allowances
                 .iter()
@@ -979,10 +1256,10 @@ pub fn print_notifications(
                     size: n.payload.matched_size.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .iter()
                 .map(|n| {
@@ -1037,13 +1314,13 @@ pub fn print_rewards(
                     rate: e.asset_rate.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
             if result.next_cursor != END_CURSOR {
                 println!("Next cursor: {}", result.next_cursor);
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .data
                 .iter()
@@ -1085,7 +1362,7 @@ pub fn print_earnings(
                 println!("Maker: {}", e.maker_address);
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .iter()
                 .map(|e| {
@@ -1137,10 +1414,10 @@ pub fn print_user_earnings_markets(
                     min_size: e.rewards_min_size.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .iter()
                 .map(|e| {
@@ -1205,10 +1482,10 @@ pub fn print_reward_percentages(
                     percentage: format!("{pct}%"),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: serde_json::Map<String, serde_json::Value> = result
                 .iter()
                 .map(|(k, v)| (k.clone(), json!(v.to_string())))
@@ -1250,13 +1527,13 @@ pub fn print_current_rewards(
                     configs: r.rewards_config.len().to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
             if result.next_cursor != END_CURSOR {
                 println!("Next cursor: {}", result.next_cursor);
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .data
                 .iter()
@@ -1313,7 +1590,7 @@ pub fn print_market_reward(
                 println!("Next cursor: {}", result.next_cursor);
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = result
                 .data
                 .iter()
@@ -1357,7 +1634,7 @@ pub fn print_order_scoring(
 ) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => println!("Scoring: {}", result.scoring),
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(&json!({"scoring": result.scoring}))?;
         }
     }
@@ -1388,10 +1665,10 @@ pub fn print_orders_scoring(
                     scoring: scoring.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(result)?;
         }
     }
@@ -1406,7 +1683,7 @@ pub fn print_api_keys(result: &ApiKeysResponse, output: &OutputFormat) -> anyhow
         OutputFormat::Table => {
             println!("API Keys: {debug}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(&json!({"api_keys": debug}))?;
         }
     }
@@ -1419,7 +1696,7 @@ pub fn print_delete_api_key(
 ) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => println!("API key deleted: {result}"),
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(result)?;
         }
     }
@@ -1433,7 +1710,7 @@ pub fn print_create_api_key(result: &Credentials, output: &OutputFormat) -> anyh
             println!("Secret: [redacted]");
             println!("Passphrase: [redacted]");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(&json!({
                 "api_key": result.key().to_string(),
                 "secret": "[redacted]",
@@ -1459,9 +1736,45 @@ pub fn print_account_status(
                 }
             );
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(&json!({"closed_only": result.closed_only}))?;
         }
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn account_fees_report_the_market_maker_fee() {
+        let taker = FeeRateResponse::builder().base_fee(100).build();
+        let json = account_fees_json(Some(25), &taker);
+        assert_eq!(json["maker_bps"], 25);
+        assert_eq!(json["taker_bps"], 100);
+        assert!(account_fees_json(None, &taker)["maker_bps"].is_null());
+    }
+
+    #[test]
+    fn with_cumulative_runs_totals_down_each_side() {
+        let bids = with_cumulative(&[(dec!(0.45), dec!(10)), (dec!(0.40), dec!(5))]);
+        assert_eq!(bids[0].cum_size, dec!(10));
+        assert_eq!(bids[1].cum_size, dec!(15));
+        assert_eq!(bids[1].cum_notional, dec!(6.5));
+
+        let asks = with_cumulative(&[(dec!(0.555), dec!(3)), (dec!(0.61), dec!(2))]);
+        assert_eq!(asks[0].cum_notional, dec!(1.665));
+        assert_eq!(asks[1].size, dec!(2));
+        assert_eq!(asks[1].cum_size, dec!(5));
+        assert_eq!(asks[1].cum_notional, dec!(2.885));
+
+        assert_eq!(
+            ladder_mid_spread(&bids, &asks),
+            (Some(dec!(0.5025)), Some(dec!(0.105)))
+        );
+        assert_eq!(ladder_mid_spread(&bids, &[]), (None, None));
+        assert!(with_cumulative(&[]).is_empty());
+    }
+}
@@ -1,8 +1,10 @@
+use std::collections::{HashMap, HashSet};
+
 use polymarket_client_sdk::gamma::types::response::Comment;
-use tabled::settings::Style;
+use serde::Serialize;
 use tabled::{Table, Tabled};
 
-use super::{detail_field, print_detail_table, truncate};
+use super::{OutputFormat, detail_field, print_detail_table, print_json, truncate};
 
 #[derive(Tabled)]
 struct CommentRow {
@@ -47,7 +49,7 @@ pub fn print_comments_table(comments: &[Comment]) {
         return;
     }
     let rows: Vec<CommentRow> = comments.iter().map(comment_to_row).collect();
-    let table = Table::new(rows).with(Style::rounded()).to_string();
+    let table = Table::new(rows).with(super::table_style()).to_string();
     println!("{table}");
 }
 
@@ -111,3 +113,191 @@ pub fn print_comment_detail(c: &Comment) {
 
     print_detail_table(rows);
 }
+
+/// One comment and its replies; serializes as the item's own fields plus a
+/// nested `replies` array.
+#[derive(Serialize)]
+pub struct ThreadNode<'a, T> {
+    #[serde(flatten)]
+    item: &'a T,
+    replies: Vec<ThreadNode<'a, T>>,
+}
+
+/// Rebuilds the reply tree from a flat list, children ordered by `sort_key`.
+/// Items whose parent is absent (or themselves) become roots, repeated ids
+/// keep their first copy, and items caught in a parent cycle are promoted to
+/// roots so every item appears exactly once.
+fn build_thread<'a, T, K: Ord>(
+    items: &'a [T],
+    id: impl Fn(&T) -> &str,
+    parent: impl Fn(&T) -> Option<&str>,
+    sort_key: impl Fn(&T) -> K,
+) -> Vec<ThreadNode<'a, T>> {
+    let mut order: Vec<usize> = Vec::new();
+    let mut seen_ids = HashSet::new();
+    for (i, item) in items.iter().enumerate() {
+        if seen_ids.insert(id(item)) {
+            order.push(i);
+        }
+    }
+    order.sort_by_key(|&i| sort_key(&items[i]));
+
+    let mut children: HashMap<&str, Vec<usize>> = HashMap::new();
+    let mut roots = Vec::new();
+    for &i in &order {
+        match parent(&items[i]) {
+            Some(p) if p != id(&items[i]) && seen_ids.contains(p) => {
+                children.entry(p).or_default().push(i);
+            }
+            _ => roots.push(i),
+        }
+    }
+
+    fn attach<'a, T>(
+        i: usize,
+        items: &'a [T],
+        id: &dyn Fn(&T) -> &str,
+        children: &HashMap<&str, Vec<usize>>,
+        placed: &mut HashSet<usize>,
+    ) -> ThreadNode<'a, T> {
+        placed.insert(i);
+        let mut replies = Vec::new();
+        for &c in children.get(id(&items[i])).into_iter().flatten() {
+            // Only reachable again through a parent cycle.
+            if !placed.contains(&c) {
+                replies.push(attach(c, items, id, children, placed));
+            }
+        }
+        ThreadNode {
+            item: &items[i],
+            replies,
+        }
+    }
+
+    let mut placed = HashSet::new();
+    let mut tree: Vec<ThreadNode<'a, T>> = roots
+        .into_iter()
+        .map(|i| attach(i, items, &id, &children, &mut placed))
+        .collect();
+    for &i in &order {
+        if !placed.contains(&i) {
+            tree.push(attach(i, items, &id, &children, &mut placed));
+        }
+    }
+    tree
+}
+
+pub fn comment_thread(comments: &[Comment]) -> Vec<ThreadNode<'_, Comment>> {
+    build_thread(
+        comments,
+        |c| c.id.as_str(),
+        |c| c.parent_comment_id.as_deref(),
+        |c| (c.created_at, c.id.clone()),
+    )
+}
+
+/// One line per comment, replies indented two spaces per level.
+fn thread_lines<T>(nodes: &[ThreadNode<'_, T>], label: &dyn Fn(&T) -> String) -> Vec<String> {
+    fn walk<T>(
+        nodes: &[ThreadNode<'_, T>],
+        depth: usize,
+        label: &dyn Fn(&T) -> String,
+        lines: &mut Vec<String>,
+    ) {
+        for node in nodes {
+            let marker = if depth == 0 { "" } else { "↳ " };
+            lines.push(format!(
+                "{}{marker}{}",
+                "  ".repeat(depth),
+                label(node.item)
+            ));
+            walk(&node.replies, depth + 1, label, lines);
+        }
+    }
+    let mut lines = Vec::new();
+    walk(nodes, 0, label, &mut lines);
+    lines
+}
+
+pub fn print_comment_thread(comments: &[Comment], output: OutputFormat) -> anyhow::Result<()> {
+    let thread = comment_thread(comments);
+    match output {
+        OutputFormat::Json | OutputFormat::Yaml => print_json(&thread)?,
+        OutputFormat::Table if thread.is_empty() => println!("No comments found."),
+        OutputFormat::Table => {
+            let label = |c: &Comment| {
+                let created = c
+                    .created_at
+                    .map_or_else(|| "—".into(), |d| d.format("%Y-%m-%d %H:%M").to_string());
+                format!(
+                    "{} · {created} · {}",
+                    comment_author(c),
+                    truncate(c.body.as_deref().unwrap_or("—"), 80)
+                )
+            };
+            for line in thread_lines(&thread, &label) {
+                println!("{line}");
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Item {
+        id: &'static str,
+        parent: Option<&'static str>,
+        at: u32,
+    }
+
+    fn item(id: &'static str, parent: Option<&'static str>, at: u32) -> Item {
+        Item { id, parent, at }
+    }
+
+    fn lines(items: &[Item]) -> Vec<String> {
+        let tree = build_thread(items, |i| i.id, |i| i.parent, |i| i.at);
+        thread_lines(&tree, &|i: &Item| i.id.to_string())
+    }
+
+    #[test]
+    fn nests_replies_ordered_by_time() {
+        let items = [
+            item("b", None, 5),
+            item("a2", Some("a"), 4),
+            item("a", None, 1),
+            item("a1", Some("a"), 2),
+            item("a1x", Some("a1"), 3),
+        ];
+        assert_eq!(lines(&items), ["a", "  ↳ a1", "    ↳ a1x", "  ↳ a2", "b"]);
+
+        let tree = build_thread(&items, |i| i.id, |i| i.parent, |i| i.at);
+        let json = serde_json::to_value(&tree).unwrap();
+        assert_eq!(json[0]["id"], "a");
+        assert_eq!(json[0]["replies"][0]["replies"][0]["id"], "a1x");
+        assert_eq!(json[1]["replies"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn missing_parents_self_parents_and_duplicates_become_roots_once() {
+        let items = [
+            item("orphan", Some("gone"), 1),
+            item("me", Some("me"), 2),
+            item("orphan", None, 3),
+        ];
+        assert_eq!(lines(&items), ["orphan", "me"]);
+    }
+
+    #[test]
+    fn parent_cycles_terminate_with_every_item_once() {
+        let items = [
+            item("x", Some("y"), 1),
+            item("y", Some("x"), 2),
+            item("z", Some("y"), 3),
+        ];
+        assert_eq!(lines(&items), ["x", "  ↳ y", "    ↳ z"]);
+    }
+}
@@ -1,27 +1,240 @@
 use anyhow::Result;
 use rust_decimal::Decimal;
+use schemars::JsonSchema;
 use serde::Serialize;
+use tabled::{Table, Tabled};
 
 use crate::{
-    commands::copy::{CopyState, PlanResult, cumulative_pnl_series, daily_pnl_series},
-    output::OutputFormat,
+    commands::copy::{
+        CopyConfig, CopyState, DashboardChart, DecisionRecord, MovementRecord, PlanConstraints,
+        PlanResult, PreviewRow, ReplaySummary, SettlementEntry, SettlementGroup,
+        cumulative_pnl_series, daily_pnl_series,
+    },
+    output::{OutputFormat, color_pnl_column, format_decimal, paint_pnl},
 };
 
-#[derive(Serialize)]
-struct StatusView<'a> {
-    leader: &'a str,
+#[derive(Serialize, JsonSchema)]
+pub(crate) struct StatusView<'a> {
+    leaders: Vec<LeaderStatus>,
     allocated_funds: Decimal,
     open_movements: usize,
     settled_movements: usize,
     open_exposure: Decimal,
     realized_pnl: Decimal,
+    /// Share of settled movements with positive PnL (0–1); null when none are settled.
+    win_rate: Option<Decimal>,
+    /// Mean of `pnl / copied_value` over settled movements, in percent.
+    avg_return_pct: Option<Decimal>,
+    best: Option<MovementPnl<'a>>,
+    worst: Option<MovementPnl<'a>>,
 }
 
-pub fn print_status(
-    config: &crate::commands::copy::CopyConfig,
+/// Per-leader slice of the status. Untagged (legacy) movements count toward
+/// the first configured leader; leaders no longer configured keep a row with
+/// a weight of 0 while they still have history.
+#[derive(Serialize, JsonSchema)]
+pub(crate) struct LeaderStatus {
+    address: String,
+    weight: Decimal,
+    open_movements: usize,
+    open_exposure: Decimal,
+    realized_pnl: Decimal,
+}
+
+fn leader_statuses(config: &CopyConfig, movements: &[MovementRecord]) -> Vec<LeaderStatus> {
+    let mut rows: Vec<LeaderStatus> = config
+        .leaders
+        .iter()
+        .map(|l| LeaderStatus {
+            address: l.address.clone(),
+            weight: l.weight,
+            open_movements: 0,
+            open_exposure: Decimal::ZERO,
+            realized_pnl: Decimal::ZERO,
+        })
+        .collect();
+    for m in movements {
+        let owner = if m.leader.is_empty() {
+            config.primary_leader()
+        } else {
+            m.leader.as_str()
+        };
+        let idx = match rows
+            .iter()
+            .position(|r| r.address.eq_ignore_ascii_case(owner))
+        {
+            Some(idx) => idx,
+            None => {
+                rows.push(LeaderStatus {
+                    address: owner.to_string(),
+                    weight: Decimal::ZERO,
+                    open_movements: 0,
+                    open_exposure: Decimal::ZERO,
+                    realized_pnl: Decimal::ZERO,
+                });
+                rows.len() - 1
+            }
+        };
+        let row = &mut rows[idx];
+        if m.settled {
+            row.realized_pnl += m.pnl;
+        } else {
+            row.open_movements += 1;
+            row.open_exposure += m.copied_value;
+        }
+    }
+    rows
+}
+
+#[derive(Serialize, JsonSchema)]
+pub(crate) struct MovementPnl<'a> {
+    movement_id: &'a str,
+    market: &'a str,
+    pnl: Decimal,
+}
+
+impl<'a> From<&'a MovementRecord> for MovementPnl<'a> {
+    fn from(m: &'a MovementRecord) -> Self {
+        Self {
+            movement_id: &m.movement_id,
+            market: &m.market,
+            pnl: m.pnl,
+        }
+    }
+}
+
+#[derive(Serialize, JsonSchema)]
+pub(crate) struct DashboardView<'a> {
+    movements: &'a [MovementRecord],
+    daily_pnl: Vec<(String, Decimal)>,
+    historical_pnl: Vec<(String, Decimal)>,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub(crate) struct DashboardSummary {
+    total_copied: Decimal,
+    open_movements: usize,
+    settled_movements: usize,
+    realized_pnl: Decimal,
+    /// Realized PnL minus estimated fees on settled movements.
+    net_pnl: Decimal,
+    /// Allocated funds plus net PnL; null when copy trading isn't configured.
+    current_equity: Option<Decimal>,
+    win_rate: Option<Decimal>,
+    last_7_days_pnl: Vec<(String, Decimal)>,
+}
+
+fn win_rate(movements: &[MovementRecord]) -> Option<Decimal> {
+    let settled = movements.iter().filter(|m| m.settled).count();
+    if settled == 0 {
+        return None;
+    }
+    let wins = movements
+        .iter()
+        .filter(|m| m.settled && m.pnl > Decimal::ZERO)
+        .count();
+    Some(Decimal::from(wins) / Decimal::from(settled))
+}
+
+fn avg_return_pct(movements: &[MovementRecord]) -> Option<Decimal> {
+    let returns: Vec<Decimal> = movements
+        .iter()
+        .filter(|m| m.settled && m.copied_value > Decimal::ZERO)
+        .map(|m| m.pnl / m.copied_value * Decimal::from(100))
+        .collect();
+    if returns.is_empty() {
+        return None;
+    }
+    let total: Decimal = returns.iter().sum();
+    Some(total / Decimal::from(returns.len()))
+}
+
+fn format_movement_pnl(m: Option<&MovementPnl<'_>>) -> String {
+    m.map_or_else(
+        || "N/A".to_string(),
+        |m| {
+            format!(
+                "{} ({}, {})",
+                format_decimal(m.pnl),
+                m.market,
+                m.movement_id
+            )
+        },
+    )
+}
+
+fn format_rate(rate: Option<Decimal>) -> String {
+    rate.map_or_else(
+        || "N/A".to_string(),
+        |r| format!("{:.2}%", r * Decimal::from(100)),
+    )
+}
+
+fn dashboard_summary(
     state: &CopyState,
-    output: OutputFormat,
-) -> Result<()> {
+    allocated_funds: Option<Decimal>,
+    today: chrono::NaiveDate,
+) -> DashboardSummary {
+    let settled = || state.movements.iter().filter(|m| m.settled);
+    let realized_pnl: Decimal = settled().map(|m| m.pnl).sum();
+    let net_pnl: Decimal = settled().map(|m| m.pnl - m.estimated_total_fee_usd).sum();
+    DashboardSummary {
+        total_copied: state.movements.iter().map(|m| m.copied_value).sum(),
+        open_movements: state.movements.iter().filter(|m| !m.settled).count(),
+        settled_movements: settled().count(),
+        realized_pnl,
+        net_pnl,
+        current_equity: allocated_funds.map(|funds| funds + net_pnl),
+        win_rate: win_rate(&state.movements),
+        last_7_days_pnl: last_days_pnl(&daily_pnl_series(&state.movements), today, 7),
+    }
+}
+
+/// Folds the hourly PnL series into one bucket per calendar day for the
+/// `days` days ending on `today`, zero-filling days without settlements.
+fn last_days_pnl(
+    series: &[(String, Decimal)],
+    today: chrono::NaiveDate,
+    days: u64,
+) -> Vec<(String, Decimal)> {
+    (0..days)
+        .rev()
+        .filter_map(|back| today.checked_sub_days(chrono::Days::new(back)))
+        .map(|day| {
+            let key = day.format("%Y-%m-%d").to_string();
+            let pnl = series
+                .iter()
+                .filter(|(bucket, _)| bucket.starts_with(&key))
+                .map(|(_, v)| *v)
+                .sum();
+            (key, pnl)
+        })
+        .collect()
+}
+
+fn sparkline(values: &[Decimal]) -> String {
+    const TICKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let (Some(min), Some(max)) = (values.iter().min(), values.iter().max()) else {
+        return String::new();
+    };
+    let span = *max - *min;
+    values
+        .iter()
+        .map(|v| {
+            if span.is_zero() {
+                return TICKS[0];
+            }
+            let idx = ((*v - *min) / span * Decimal::from(7))
+                .round()
+                .to_i32()
+                .unwrap_or(0)
+                .clamp(0, 7);
+            TICKS[idx as usize]
+        })
+        .collect()
+}
+
+pub fn print_status(config: &CopyConfig, state: &CopyState, output: OutputFormat) -> Result<()> {
     let open_movements = state.movements.iter().filter(|m| !m.settled).count();
     let settled_movements = state.movements.iter().filter(|m| m.settled).count();
     let open_exposure: Decimal = state
@@ -30,36 +243,48 @@ pub fn print_status(
         .filter(|m| !m.settled)
         .map(|m| m.copied_value)
         .sum();
-    let realized_pnl: Decimal = state
-        .movements
-        .iter()
-        .filter(|m| m.settled)
-        .map(|m| m.pnl)
-        .sum();
+    let settled = || state.movements.iter().filter(|m| m.settled);
+    let realized_pnl: Decimal = settled().map(|m| m.pnl).sum();
 
     let view = StatusView {
-        leader: &config.leader,
+        leaders: leader_statuses(config, &state.movements),
         allocated_funds: config.allocated_funds,
         open_movements,
         settled_movements,
         open_exposure,
         realized_pnl,
+        win_rate: win_rate(&state.movements),
+        avg_return_pct: avg_return_pct(&state.movements),
+        best: settled().max_by_key(|m| m.pnl).map(MovementPnl::from),
+        worst: settled().min_by_key(|m| m.pnl).map(MovementPnl::from),
     };
 
     match output {
-        OutputFormat::Json => crate::output::print_json(&view),
+        OutputFormat::Json | OutputFormat::Yaml => crate::output::print_json(&view),
         OutputFormat::Table => {
             crate::output::print_detail_table(vec![
-                ["Leader".into(), view.leader.to_string()],
-                ["Allocated funds".into(), view.allocated_funds.to_string()],
+                [
+                    "Allocated funds".into(),
+                    format_decimal(view.allocated_funds),
+                ],
                 ["Open movements".into(), view.open_movements.to_string()],
                 [
                     "Settled movements".into(),
                     view.settled_movements.to_string(),
                 ],
-                ["Open exposure".into(), view.open_exposure.to_string()],
-                ["Realized PnL".into(), view.realized_pnl.to_string()],
+                ["Open exposure".into(), format_decimal(view.open_exposure)],
+                ["Realized PnL".into(), format_decimal(view.realized_pnl)],
+                ["Win rate".into(), format_rate(view.win_rate)],
+                [
+                    "Avg return".into(),
+                    view.avg_return_pct
+                        .map_or_else(|| "N/A".to_string(), |r| format!("{r:.2}%")),
+                ],
+                ["Best".into(), format_movement_pnl(view.best.as_ref())],
+                ["Worst".into(), format_movement_pnl(view.worst.as_ref())],
             ]);
+            println!("\nLeaders:");
+            println!("{}", leaders_table(&view.leaders));
             Ok(())
         }
     }
@@ -67,7 +292,7 @@ pub fn print_status(
 
 pub fn print_plan(result: &PlanResult, output: OutputFormat) -> Result<()> {
     match output {
-        OutputFormat::Json => crate::output::print_json(result),
+        OutputFormat::Json | OutputFormat::Yaml => crate::output::print_json(result),
         OutputFormat::Table => {
             crate::output::print_detail_table(vec![
                 [
@@ -78,18 +303,203 @@ pub fn print_plan(result: &PlanResult, output: OutputFormat) -> Result<()> {
                 ["Available funds".into(), result.available_funds.to_string()],
                 ["Reason".into(), result.reason.clone()],
             ]);
+            if let Some(c) = &result.constraints {
+                println!("\nConstraints:");
+                crate::output::print_detail_table(explain_rows(c));
+            }
+            Ok(())
+        }
+    }
+}
+
+pub fn print_replay(summary: &ReplaySummary, output: OutputFormat) -> Result<()> {
+    match output {
+        OutputFormat::Json | OutputFormat::Yaml => crate::output::print_json(summary),
+        OutputFormat::Table => {
+            crate::output::print_detail_table(vec![
+                ["Movements".into(), summary.movements.to_string()],
+                ["Would copy".into(), summary.copied.to_string()],
+                ["Skipped".into(), summary.skipped.to_string()],
+                [
+                    "Total exposure".into(),
+                    format_decimal(summary.total_exposure),
+                ],
+                [
+                    "Gross PnL".into(),
+                    paint_pnl(&format_decimal(summary.gross_pnl), summary.gross_pnl),
+                ],
+                ["Fees".into(), format_decimal(summary.fees)],
+                [
+                    "Net PnL".into(),
+                    paint_pnl(&format_decimal(summary.net_pnl), summary.net_pnl),
+                ],
+            ]);
+            if !summary.skip_reasons.is_empty() {
+                println!("\nSkipped by reason:");
+                crate::output::print_detail_table(
+                    summary
+                        .skip_reasons
+                        .iter()
+                        .map(|(reason, count)| [reason.clone(), count.to_string()])
+                        .collect(),
+                );
+            }
+            Ok(())
+        }
+    }
+}
+
+pub fn print_preview(rows: &[PreviewRow], output: OutputFormat) -> Result<()> {
+    match output {
+        OutputFormat::Json | OutputFormat::Yaml => crate::output::print_json_list(rows),
+        OutputFormat::Table => {
+            if rows.is_empty() {
+                println!("No recent leader trades.");
+                return Ok(());
+            }
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Market")]
+                market: String,
+                #[tabled(rename = "Side")]
+                side: String,
+                #[tabled(rename = "Outcome")]
+                outcome: String,
+                #[tabled(rename = "Leader $")]
+                leader_usd: String,
+                #[tabled(rename = "Action")]
+                action: String,
+                #[tabled(rename = "Copy $")]
+                copy_usd: String,
+                #[tabled(rename = "Est. price")]
+                estimated_price: String,
+                #[tabled(rename = "Fees RT")]
+                fees: String,
+                #[tabled(rename = "Reason")]
+                reason: String,
+            }
+            let table_rows: Vec<Row> = rows
+                .iter()
+                .map(|r| Row {
+                    market: crate::output::truncate(&r.market, 36),
+                    side: r.side.clone(),
+                    outcome: r.outcome.clone(),
+                    leader_usd: format!("{:.2}", r.leader_usd),
+                    action: r.action.clone(),
+                    copy_usd: format!("{:.2}", r.copy_usd),
+                    estimated_price: match (r.estimated_price, r.full_fill) {
+                        (Some(px), Some(false)) => format!("{px:.4} (partial)"),
+                        (Some(px), _) => format!("{px:.4}"),
+                        (None, Some(_)) => "no liquidity".to_string(),
+                        (None, None) => "—".to_string(),
+                    },
+                    fees: r
+                        .round_trip_fee_usd
+                        .map_or_else(|| "—".to_string(), |f| format!("{f:.2}")),
+                    reason: r.reason.clone(),
+                })
+                .collect();
+            let mut table = Table::new(table_rows);
+            table.with(crate::output::table_style());
+            println!("{table}");
+            let copies: Vec<_> = rows.iter().filter(|r| r.action == "copy").collect();
+            let total: Decimal = copies.iter().map(|r| r.copy_usd).sum();
+            println!(
+                "{} of {} trade(s) would be copied, {total:.2} USD in total. Nothing was executed or saved.",
+                copies.len(),
+                rows.len()
+            );
             Ok(())
         }
     }
 }
 
-pub fn print_dashboard(state: &CopyState, output: OutputFormat) -> Result<()> {
-    if matches!(output, OutputFormat::Json) {
-        return crate::output::print_json(&serde_json::json!({
-            "movements": state.movements,
-            "daily_pnl": daily_pnl_series(&state.movements),
-            "historical_pnl": cumulative_pnl_series(&state.movements),
-        }));
+fn explain_rows(c: &PlanConstraints) -> Vec<[String; 2]> {
+    let per_leader = c
+        .per_leader_available
+        .map_or_else(|| "(no cap)".to_string(), |v| v.to_string());
+    let fee_reserve = c
+        .fee_reserve
+        .map_or_else(|| "(fees not reserved)".to_string(), |v| v.to_string());
+    let open_positions = c
+        .open_positions
+        .map_or_else(|| "(no cap)".to_string(), |v| v.to_string());
+    let fixed = c
+        .fixed_copy_usd
+        .map_or_else(|| "(proportional sizing)".to_string(), |v| v.to_string());
+    [
+        (
+            "effective_funds",
+            "Effective funds",
+            c.effective_funds.to_string(),
+        ),
+        (
+            "proportional_size",
+            "Proportional size",
+            c.proportional_size.to_string(),
+        ),
+        ("fixed_copy_usd", "Fixed copy size (sizing_mode)", fixed),
+        (
+            "max_trade",
+            "Max trade (max_trade_pct)",
+            c.max_trade.to_string(),
+        ),
+        (
+            "available_exposure",
+            "Available exposure (max_total_exposure_pct)",
+            c.available_exposure.to_string(),
+        ),
+        ("per_leader_available", "Per-leader available", per_leader),
+        ("fee_reserve", "Fee reserve (include_fees)", fee_reserve),
+        (
+            "open_positions",
+            "Open positions (max_open_positions)",
+            open_positions,
+        ),
+        (
+            "min_copy_usd",
+            "Min copy threshold",
+            c.min_copy_usd.to_string(),
+        ),
+    ]
+    .into_iter()
+    .map(|(key, label, value)| {
+        let value = if key == c.binding {
+            format!("{value}  <- binding")
+        } else {
+            value
+        };
+        [label.to_string(), value]
+    })
+    .collect()
+}
+
+pub fn print_dashboard(
+    state: &CopyState,
+    allocated_funds: Option<Decimal>,
+    summary_only: bool,
+    chart: DashboardChart,
+    output: OutputFormat,
+) -> Result<()> {
+    if summary_only {
+        let today = chrono::Utc::now()
+            .with_timezone(&crate::output::display_timezone())
+            .date_naive();
+        let summary = dashboard_summary(state, allocated_funds, today);
+        return match output {
+            OutputFormat::Json | OutputFormat::Yaml => crate::output::print_json(&summary),
+            OutputFormat::Table => {
+                print_summary(&summary);
+                Ok(())
+            }
+        };
+    }
+    if matches!(output, OutputFormat::Json | OutputFormat::Yaml) {
+        return crate::output::print_json(&DashboardView {
+            movements: &state.movements,
+            daily_pnl: daily_pnl_series(&state.movements),
+            historical_pnl: cumulative_pnl_series(&state.movements),
+        });
     }
 
     println!("Copied movements:");
@@ -99,33 +509,248 @@ pub fn print_dashboard(state: &CopyState, output: OutputFormat) -> Result<()> {
         for m in &state.movements {
             println!(
                 "- {} | {} | side={} | outcome={} | leader_px={} | sim_px={} | qty={} | copied={} | diff={}pp | settled={} | pnl={}",
-                m.timestamp,
+                crate::output::format_timestamp(&m.timestamp),
                 m.market,
                 m.copy_side,
                 m.outcome,
                 m.leader_price,
                 m.simulated_copy_price,
                 m.quantity,
-                m.copied_value,
+                format_decimal(m.copied_value),
                 m.diff_pct,
                 m.settled,
-                m.pnl
+                paint_pnl(&format_decimal(m.pnl), m.pnl)
             );
         }
     }
 
     println!("\nDaily PnL:");
-    for (day, pnl) in daily_pnl_series(&state.movements) {
-        println!("{} {} {pnl}", day, bar(pnl));
-    }
+    print_series(&daily_pnl_series(&state.movements), chart);
 
     println!("\nHistorical PnL:");
-    for (day, pnl) in cumulative_pnl_series(&state.movements) {
-        println!("{} {} {pnl}", day, bar(pnl));
-    }
+    print_series(&cumulative_pnl_series(&state.movements), chart);
     Ok(())
 }
 
+pub fn print_decisions(decisions: &[DecisionRecord], output: OutputFormat) -> Result<()> {
+    match output {
+        OutputFormat::Json | OutputFormat::Yaml => crate::output::print_json_list(decisions),
+        OutputFormat::Table => {
+            if decisions.is_empty() {
+                println!("No decisions logged.");
+                return Ok(());
+            }
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Time")]
+                timestamp: String,
+                #[tabled(rename = "Mode")]
+                mode: String,
+                #[tabled(rename = "Market")]
+                market: String,
+                #[tabled(rename = "Side")]
+                side: String,
+                #[tabled(rename = "Leader $")]
+                leader_usd: String,
+                #[tabled(rename = "Proportional $")]
+                proportional: String,
+                #[tabled(rename = "Capped $")]
+                capped: String,
+                #[tabled(rename = "Fees RT")]
+                fees: String,
+                #[tabled(rename = "Copied")]
+                acted: &'static str,
+                #[tabled(rename = "Reason")]
+                reason: String,
+            }
+            let rows: Vec<Row> = decisions
+                .iter()
+                .map(|d| Row {
+                    timestamp: crate::output::format_timestamp(&d.timestamp),
+                    mode: d.mode.clone(),
+                    market: crate::output::truncate(&d.market, 36),
+                    side: format!("{} {}", d.side, d.outcome),
+                    leader_usd: format!("{:.2}", d.leader_usd),
+                    proportional: format!("{:.2}", d.proportional_size),
+                    capped: format!("{:.2}", d.capped_size),
+                    fees: d
+                        .round_trip_fee_usd
+                        .map_or_else(|| "—".to_string(), |f| format!("{f:.2}")),
+                    acted: if d.acted { "yes" } else { "no" },
+                    reason: crate::output::truncate(&d.reason, 48),
+                })
+                .collect();
+            let mut table = Table::new(rows);
+            table.with(crate::output::table_style());
+            println!("{table}");
+            let acted = decisions.iter().filter(|d| d.acted).count();
+            println!("{acted} of {} decision(s) copied.", decisions.len());
+            Ok(())
+        }
+    }
+}
+
+pub fn print_settlements(entries: &[SettlementEntry], output: OutputFormat) -> Result<()> {
+    match output {
+        OutputFormat::Json | OutputFormat::Yaml => crate::output::print_json(&entries),
+        OutputFormat::Table => {
+            if entries.is_empty() {
+                println!("No settlements logged.");
+            } else {
+                println!("{}", settlements_table(entries));
+            }
+            Ok(())
+        }
+    }
+}
+
+pub fn print_settlement_groups(groups: &[SettlementGroup], output: OutputFormat) -> Result<()> {
+    match output {
+        OutputFormat::Json | OutputFormat::Yaml => crate::output::print_json(&groups),
+        OutputFormat::Table => {
+            if groups.is_empty() {
+                println!("No settlements logged.");
+                return Ok(());
+            }
+            for group in groups {
+                println!(
+                    "{} — {} settlement(s) | copied={} | fees={} | pnl={}",
+                    group.market,
+                    group.count,
+                    format_decimal(group.copied_value),
+                    format_decimal(group.estimated_total_fee_usd),
+                    paint_pnl(&format_decimal(group.pnl), group.pnl)
+                );
+                println!("{}\n", settlements_table(&group.entries));
+            }
+            let total: Decimal = groups.iter().map(|g| g.pnl).sum();
+            println!("Total PnL: {}", paint_pnl(&format_decimal(total), total));
+            Ok(())
+        }
+    }
+}
+
+fn settlements_table(entries: &[SettlementEntry]) -> String {
+    #[derive(Tabled)]
+    struct Row {
+        #[tabled(rename = "Settled")]
+        timestamp: String,
+        #[tabled(rename = "Mode")]
+        mode: String,
+        #[tabled(rename = "Market")]
+        market: String,
+        #[tabled(rename = "Side")]
+        side: String,
+        #[tabled(rename = "Outcome")]
+        outcome: String,
+        #[tabled(rename = "Copied")]
+        copied_value: String,
+        #[tabled(rename = "Fees")]
+        fees: String,
+        #[tabled(rename = "PnL")]
+        pnl: String,
+    }
+    let rows: Vec<Row> = entries
+        .iter()
+        .map(|e| Row {
+            timestamp: crate::output::format_timestamp(&e.timestamp.to_rfc3339()),
+            mode: e.mode.clone(),
+            market: e.market.clone(),
+            side: e.side.clone(),
+            outcome: format!("{} → {}", e.outcome, e.resolved_outcome),
+            copied_value: format_decimal(e.copied_value),
+            fees: format_decimal(e.estimated_total_fee_usd),
+            pnl: format_decimal(e.pnl),
+        })
+        .collect();
+    let pnls: Vec<Decimal> = entries.iter().map(|e| e.pnl).collect();
+    let mut table = Table::new(rows);
+    table.with(super::table_style());
+    color_pnl_column(&mut table, 7, &pnls);
+    table.to_string()
+}
+
+fn leaders_table(leaders: &[LeaderStatus]) -> String {
+    #[derive(Tabled)]
+    struct Row {
+        #[tabled(rename = "Leader")]
+        address: String,
+        #[tabled(rename = "Weight")]
+        weight: String,
+        #[tabled(rename = "Open")]
+        open_movements: usize,
+        #[tabled(rename = "Open exposure")]
+        open_exposure: String,
+        #[tabled(rename = "Realized PnL")]
+        realized_pnl: String,
+    }
+    let rows: Vec<Row> = leaders
+        .iter()
+        .map(|l| Row {
+            address: l.address.clone(),
+            weight: l.weight.to_string(),
+            open_movements: l.open_movements,
+            open_exposure: format_decimal(l.open_exposure),
+            realized_pnl: format_decimal(l.realized_pnl),
+        })
+        .collect();
+    let pnls: Vec<Decimal> = leaders.iter().map(|l| l.realized_pnl).collect();
+    let mut table = Table::new(rows);
+    table.with(super::table_style());
+    color_pnl_column(&mut table, 4, &pnls);
+    table.to_string()
+}
+
+fn print_series(series: &[(String, Decimal)], chart: DashboardChart) {
+    let values: Vec<Decimal> = series.iter().map(|(_, v)| *v).collect();
+    for ((day, pnl), cell) in series.iter().zip(chart_cells(&values, chart)) {
+        let value = paint_pnl(&format_decimal(*pnl), *pnl);
+        if cell.is_empty() {
+            println!("{day} {value}");
+        } else {
+            println!("{day} {cell} {value}");
+        }
+    }
+}
+
+/// The chart column for each point of a series; empty strings for `None`.
+fn chart_cells(values: &[Decimal], chart: DashboardChart) -> Vec<String> {
+    match chart {
+        DashboardChart::Sparkline => sparkline(values).chars().map(String::from).collect(),
+        DashboardChart::Bars => values.iter().map(|v| bar(*v)).collect(),
+        DashboardChart::None => vec![String::new(); values.len()],
+    }
+}
+
+fn print_summary(summary: &DashboardSummary) {
+    let values: Vec<Decimal> = summary.last_7_days_pnl.iter().map(|(_, v)| *v).collect();
+    let week: Decimal = values.iter().sum();
+    crate::output::print_detail_table(vec![
+        ["Total copied".into(), format_decimal(summary.total_copied)],
+        ["Open movements".into(), summary.open_movements.to_string()],
+        [
+            "Settled movements".into(),
+            summary.settled_movements.to_string(),
+        ],
+        ["Realized PnL".into(), format_decimal(summary.realized_pnl)],
+        [
+            "Net PnL (after fees)".into(),
+            format_decimal(summary.net_pnl),
+        ],
+        [
+            "Current equity".into(),
+            summary
+                .current_equity
+                .map_or_else(|| "N/A".to_string(), format_decimal),
+        ],
+        ["Win rate".into(), format_rate(summary.win_rate)],
+        [
+            "Last 7 days".into(),
+            format!("{} {}", sparkline(&values), format_decimal(week)),
+        ],
+    ]);
+}
+
 fn bar(v: Decimal) -> String {
     let abs = v.abs().to_i32().unwrap_or(0).clamp(0, 40) as usize;
     if v.is_sign_negative() {
@@ -144,3 +769,136 @@ impl ToI32 for Decimal {
         self.trunc().to_string().parse().ok()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn settled(ts: &str, pnl: Decimal) -> MovementRecord {
+        MovementRecord {
+            movement_id: ts.into(),
+            leader: String::new(),
+            paper: false,
+            market: "m".into(),
+            asset: String::new(),
+            timestamp: ts.into(),
+            leader_value: dec!(10),
+            leader_price: dec!(0.5),
+            copied_value: dec!(10),
+            simulated_copy_price: dec!(0.5),
+            quantity: dec!(20),
+            copy_side: "BUY".into(),
+            outcome: "Yes".into(),
+            resolved_outcome: String::new(),
+            diff_pct: Decimal::ZERO,
+            estimated_total_fee_usd: dec!(0.5),
+            settled: true,
+            pnl,
+            remaining_quantity: None,
+            last_partial_close_ts: None,
+        }
+    }
+
+    #[test]
+    fn summary_reports_win_rate_and_week_buckets() {
+        let mut state = CopyState {
+            movements: vec![
+                settled("2025-03-09T10:00:00Z", dec!(4)),
+                settled("2025-03-10T11:00:00Z", dec!(-2)),
+                settled("2025-03-10T15:00:00Z", dec!(3)),
+                settled("2025-02-01T00:00:00Z", dec!(1)),
+            ],
+        };
+        state.movements[3].settled = false;
+        let today = chrono::NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        let s = dashboard_summary(&state, Some(dec!(100)), today);
+
+        assert_eq!(s.open_movements, 1);
+        assert_eq!(s.settled_movements, 3);
+        assert_eq!(s.total_copied, dec!(40));
+        assert_eq!(s.realized_pnl, dec!(5));
+        assert_eq!(s.net_pnl, dec!(3.5));
+        assert_eq!(s.current_equity, Some(dec!(103.5)));
+        assert_eq!(s.win_rate.unwrap().round_dp(4), dec!(0.6667));
+        assert_eq!(s.last_7_days_pnl.len(), 7);
+        assert_eq!(s.last_7_days_pnl[0].0, "2025-03-04");
+        assert_eq!(s.last_7_days_pnl[5], ("2025-03-09".into(), dec!(3.5)));
+        assert_eq!(s.last_7_days_pnl[6], ("2025-03-10".into(), dec!(0)));
+    }
+
+    #[test]
+    fn win_rate_is_none_without_settled_movements() {
+        assert_eq!(win_rate(&[]), None);
+        assert_eq!(format_rate(None), "N/A");
+        assert_eq!(format_rate(Some(dec!(0.5))), "50.00%");
+    }
+
+    #[test]
+    fn avg_return_uses_pnl_over_copied_value() {
+        let movements = vec![
+            settled("2025-03-09T10:00:00Z", dec!(2)),
+            settled("2025-03-10T11:00:00Z", dec!(-1)),
+        ];
+        // (+20% + -10%) / 2
+        assert_eq!(avg_return_pct(&movements), Some(dec!(5)));
+        assert_eq!(avg_return_pct(&[]), None);
+        assert_eq!(format_movement_pnl(None), "N/A");
+        let best = MovementPnl::from(&movements[0]);
+        assert_eq!(
+            format_movement_pnl(Some(&best)),
+            "$2.00 (m, 2025-03-09T10:00:00Z)"
+        );
+    }
+
+    #[test]
+    fn chart_cells_follow_the_selected_style() {
+        let values = [dec!(-2), dec!(0), dec!(100)];
+        assert_eq!(
+            chart_cells(&values, DashboardChart::Sparkline),
+            ["▁", "▁", "█"]
+        );
+        assert_eq!(
+            chart_cells(&values, DashboardChart::Bars)[2],
+            format!("|{}", "+".repeat(40))
+        );
+        assert_eq!(chart_cells(&values, DashboardChart::None), ["", "", ""]);
+    }
+
+    #[test]
+    fn sparkline_spans_min_to_max() {
+        assert_eq!(sparkline(&[dec!(-1), dec!(0), dec!(1)]), "▁▅█");
+        assert_eq!(sparkline(&[dec!(2), dec!(2)]), "▁▁");
+        assert_eq!(sparkline(&[]), "");
+    }
+
+    #[test]
+    fn leader_statuses_attribute_untagged_movements_to_the_primary_leader() {
+        let config: CopyConfig = serde_json::from_value(serde_json::json!({
+            "leaders": [{"address": "0xA", "weight": "2"}, {"address": "0xB"}],
+            "allocated_funds": "100",
+            "max_trade_pct": "5",
+            "max_total_exposure_pct": "70",
+            "min_copy_usd": "1",
+            "poll_interval_secs": 2,
+            "risk_level": "balanced",
+            "execute_orders": false,
+        }))
+        .unwrap();
+        let mut open_b = settled("2026-03-02T10:00:00Z", Decimal::ZERO);
+        open_b.leader = "0xb".into();
+        open_b.settled = false;
+        let mut old = settled("2026-03-03T10:00:00Z", dec!(1));
+        old.leader = "0xC".into();
+        let movements = vec![settled("2026-03-01T10:00:00Z", dec!(3)), open_b, old];
+
+        let rows = leader_statuses(&config, &movements);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].address, "0xA");
+        assert_eq!(rows[0].realized_pnl, dec!(3));
+        assert_eq!(rows[1].open_movements, 1);
+        assert_eq!(rows[1].open_exposure, dec!(10));
+        assert_eq!(rows[2].address, "0xC");
+        assert_eq!(rows[2].weight, Decimal::ZERO);
+    }
+}
@@ -1,5 +1,7 @@
-use alloy::primitives::{B256, U256};
+use alloy::primitives::{Address, B256, U256};
 use anyhow::Result;
+use rust_decimal::Decimal;
+use tabled::{Table, Tabled};
 
 use super::{OutputFormat, print_detail_table};
 
@@ -10,14 +12,14 @@ pub fn print_tx_result(
     output: &OutputFormat,
 ) -> Result<()> {
     match output {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let json = serde_json::json!({
                 "operation": operation,
                 "transaction_hash": format!("{tx_hash}"),
                 "block_number": block_number,
                 "polygonscan": format!("https://polygonscan.com/tx/{tx_hash}"),
             });
-            println!("{}", serde_json::to_string_pretty(&json)?);
+            super::print_json(&json)?;
             Ok(())
         }
         OutputFormat::Table => {
@@ -36,13 +38,139 @@ pub fn print_tx_result(
     }
 }
 
+/// One condition's outcome in `ctf redeem-batch`: the transaction hash and
+/// block on success, the error otherwise.
+pub type RedeemOutcome = std::result::Result<(B256, u64), String>;
+
+pub fn print_redeem_batch(results: &[(B256, RedeemOutcome)], output: &OutputFormat) -> Result<()> {
+    match output {
+        OutputFormat::Json | OutputFormat::Yaml => {
+            let data: Vec<_> = results
+                .iter()
+                .map(|(condition_id, result)| match result {
+                    Ok((tx_hash, block_number)) => serde_json::json!({
+                        "condition_id": format!("{condition_id}"),
+                        "status": "redeemed",
+                        "transaction_hash": format!("{tx_hash}"),
+                        "block_number": block_number,
+                    }),
+                    Err(e) => serde_json::json!({
+                        "condition_id": format!("{condition_id}"),
+                        "status": "failed",
+                        "error": e,
+                    }),
+                })
+                .collect();
+            super::print_json(&data)
+        }
+        OutputFormat::Table => {
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Condition")]
+                condition_id: String,
+                #[tabled(rename = "Status")]
+                status: String,
+                #[tabled(rename = "Tx Hash / Error")]
+                detail: String,
+            }
+            let rows: Vec<Row> = results
+                .iter()
+                .map(|(condition_id, result)| Row {
+                    condition_id: format!("{condition_id}"),
+                    status: if result.is_ok() { "redeemed" } else { "failed" }.to_string(),
+                    detail: match result {
+                        Ok((tx_hash, _)) => format!("{tx_hash}"),
+                        Err(e) => super::truncate(e, 80),
+                    },
+                })
+                .collect();
+            let table = Table::new(rows).with(super::table_style()).to_string();
+            println!("{table}");
+            let ok = results.iter().filter(|(_, r)| r.is_ok()).count();
+            println!("{ok} of {} redeemed.", results.len());
+            Ok(())
+        }
+    }
+}
+
+/// One outcome token held by the wallet, as shown by `ctf positions`.
+pub struct CtfPosition {
+    pub token_id: U256,
+    pub condition_id: B256,
+    pub outcome_index: u32,
+    /// Outcome name when the Data API reported it.
+    pub outcome: Option<String>,
+    /// Balance in shares (base units / 10^6).
+    pub balance: Decimal,
+}
+
+pub fn print_positions(
+    owner: Address,
+    positions: &[CtfPosition],
+    output: &OutputFormat,
+) -> Result<()> {
+    match output {
+        OutputFormat::Json | OutputFormat::Yaml => {
+            let data: Vec<_> = positions
+                .iter()
+                .map(|p| {
+                    serde_json::json!({
+                        "token_id": p.token_id.to_string(),
+                        "condition_id": format!("{}", p.condition_id),
+                        "outcome_index": p.outcome_index,
+                        "outcome": p.outcome,
+                        "balance": p.balance.normalize().to_string(),
+                    })
+                })
+                .collect();
+            super::print_json(&serde_json::json!({
+                "wallet": owner.to_string(),
+                "positions": data,
+            }))
+        }
+        OutputFormat::Table => {
+            if positions.is_empty() {
+                println!("No CTF positions for {owner}.");
+                return Ok(());
+            }
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Token ID")]
+                token_id: String,
+                #[tabled(rename = "Condition")]
+                condition_id: String,
+                #[tabled(rename = "Outcome")]
+                outcome: String,
+                #[tabled(rename = "Balance")]
+                balance: String,
+            }
+            let rows: Vec<Row> = positions
+                .iter()
+                .map(|p| Row {
+                    token_id: p.token_id.to_string(),
+                    condition_id: format!("{}", p.condition_id),
+                    outcome: match &p.outcome {
+                        Some(name) => format!("{} ({name})", p.outcome_index),
+                        None => p.outcome_index.to_string(),
+                    },
+                    balance: p.balance.normalize().to_string(),
+                })
+                .collect();
+            let table = Table::new(rows).with(super::table_style()).to_string();
+            println!("Wallet: {owner}");
+            println!("{table}");
+            Ok(())
+        }
+    }
+}
+
 pub fn print_condition_id(condition_id: B256, output: &OutputFormat) -> Result<()> {
     match output {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let json = serde_json::json!({
                 "condition_id": format!("{condition_id}"),
             });
-            println!("{}", serde_json::to_string_pretty(&json)?);
+            super::print_json(&json)?;
             Ok(())
         }
         OutputFormat::Table => {
@@ -54,11 +182,11 @@ pub fn print_condition_id(condition_id: B256, output: &OutputFormat) -> Result<(
 
 pub fn print_collection_id(collection_id: B256, output: &OutputFormat) -> Result<()> {
     match output {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let json = serde_json::json!({
                 "collection_id": format!("{collection_id}"),
             });
-            println!("{}", serde_json::to_string_pretty(&json)?);
+            super::print_json(&json)?;
             Ok(())
         }
         OutputFormat::Table => {
@@ -70,11 +198,11 @@ pub fn print_collection_id(collection_id: B256, output: &OutputFormat) -> Result
 
 pub fn print_position_id(position_id: U256, output: &OutputFormat) -> Result<()> {
     match output {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let json = serde_json::json!({
                 "position_id": position_id.to_string(),
             });
-            println!("{}", serde_json::to_string_pretty(&json)?);
+            super::print_json(&json)?;
             Ok(())
         }
         OutputFormat::Table => {
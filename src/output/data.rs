@@ -4,11 +4,12 @@ use polymarket_client_sdk::data::types::response::{
     Activity, BuilderLeaderboardEntry, BuilderVolumeEntry, ClosedPosition, LiveVolume, Market,
     MetaHolder, OpenInterest, Position, Trade, Traded, TraderLeaderboardEntry, Value,
 };
+use schemars::JsonSchema;
+use serde::Serialize;
 use serde_json::json;
-use tabled::settings::Style;
 use tabled::{Table, Tabled};
 
-use super::{OutputFormat, format_decimal, truncate};
+use super::{OutputFormat, color_pnl_column, format_decimal, truncate};
 
 fn format_market(m: &Market) -> String {
     match m {
@@ -18,6 +19,84 @@ fn format_market(m: &Market) -> String {
     }
 }
 
+/// JSON shape of one `data positions` row.
+#[derive(Serialize, JsonSchema)]
+pub(crate) struct PositionView {
+    title: String,
+    slug: String,
+    outcome: String,
+    outcome_index: i64,
+    size: String,
+    avg_price: String,
+    initial_value: String,
+    current_value: String,
+    cash_pnl: String,
+    percent_pnl: String,
+    realized_pnl: String,
+    cur_price: String,
+    condition_id: String,
+    proxy_wallet: String,
+    redeemable: bool,
+    mergeable: bool,
+}
+
+impl From<&Position> for PositionView {
+    fn from(p: &Position) -> Self {
+        Self {
+            title: p.title.clone(),
+            slug: p.slug.clone(),
+            outcome: p.outcome.clone(),
+            outcome_index: i64::from(p.outcome_index),
+            size: p.size.to_string(),
+            avg_price: p.avg_price.to_string(),
+            initial_value: p.initial_value.to_string(),
+            current_value: p.current_value.to_string(),
+            cash_pnl: p.cash_pnl.to_string(),
+            percent_pnl: p.percent_pnl.to_string(),
+            realized_pnl: p.realized_pnl.to_string(),
+            cur_price: p.cur_price.to_string(),
+            condition_id: p.condition_id.to_string(),
+            proxy_wallet: p.proxy_wallet.to_string(),
+            redeemable: p.redeemable,
+            mergeable: p.mergeable,
+        }
+    }
+}
+
+/// JSON shape of one `data closed-positions` row.
+#[derive(Serialize, JsonSchema)]
+pub(crate) struct ClosedPositionView {
+    title: String,
+    slug: String,
+    outcome: String,
+    outcome_index: i64,
+    avg_price: String,
+    total_bought: String,
+    realized_pnl: String,
+    cur_price: String,
+    condition_id: String,
+    proxy_wallet: String,
+    timestamp: i64,
+}
+
+impl From<&ClosedPosition> for ClosedPositionView {
+    fn from(p: &ClosedPosition) -> Self {
+        Self {
+            title: p.title.clone(),
+            slug: p.slug.clone(),
+            outcome: p.outcome.clone(),
+            outcome_index: i64::from(p.outcome_index),
+            avg_price: p.avg_price.to_string(),
+            total_bought: p.total_bought.to_string(),
+            realized_pnl: p.realized_pnl.to_string(),
+            cur_price: p.cur_price.to_string(),
+            condition_id: p.condition_id.to_string(),
+            proxy_wallet: p.proxy_wallet.to_string(),
+            timestamp: p.timestamp,
+        }
+    }
+}
+
 pub fn print_positions(positions: &[Position], output: &OutputFormat) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => {
@@ -51,34 +130,15 @@ pub fn print_positions(positions: &[Position], output: &OutputFormat) -> anyhow:
                     pnl: format!("{:.2}", p.cash_pnl),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let pnls: Vec<_> = positions.iter().map(|p| p.cash_pnl).collect();
+            let mut table = Table::new(rows);
+            table.with(super::table_style());
+            color_pnl_column(&mut table, 5, &pnls);
             println!("{table}");
         }
-        OutputFormat::Json => {
-            let data: Vec<_> = positions
-                .iter()
-                .map(|p| {
-                    json!({
-                        "title": p.title,
-                        "slug": p.slug,
-                        "outcome": p.outcome,
-                        "outcome_index": p.outcome_index,
-                        "size": p.size.to_string(),
-                        "avg_price": p.avg_price.to_string(),
-                        "initial_value": p.initial_value.to_string(),
-                        "current_value": p.current_value.to_string(),
-                        "cash_pnl": p.cash_pnl.to_string(),
-                        "percent_pnl": p.percent_pnl.to_string(),
-                        "realized_pnl": p.realized_pnl.to_string(),
-                        "cur_price": p.cur_price.to_string(),
-                        "condition_id": p.condition_id.to_string(),
-                        "proxy_wallet": p.proxy_wallet.to_string(),
-                        "redeemable": p.redeemable,
-                        "mergeable": p.mergeable,
-                    })
-                })
-                .collect();
-            super::print_json(&data)?;
+        OutputFormat::Json | OutputFormat::Yaml => {
+            let data: Vec<_> = positions.iter().map(PositionView::from).collect();
+            super::print_json_list(&data)?;
         }
     }
     Ok(())
@@ -114,29 +174,15 @@ pub fn print_closed_positions(
                     realized_pnl: format!("{:.2}", p.realized_pnl),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let pnls: Vec<_> = positions.iter().map(|p| p.realized_pnl).collect();
+            let mut table = Table::new(rows);
+            table.with(super::table_style());
+            color_pnl_column(&mut table, 3, &pnls);
             println!("{table}");
         }
-        OutputFormat::Json => {
-            let data: Vec<_> = positions
-                .iter()
-                .map(|p| {
-                    json!({
-                        "title": p.title,
-                        "slug": p.slug,
-                        "outcome": p.outcome,
-                        "outcome_index": p.outcome_index,
-                        "avg_price": p.avg_price.to_string(),
-                        "total_bought": p.total_bought.to_string(),
-                        "realized_pnl": p.realized_pnl.to_string(),
-                        "cur_price": p.cur_price.to_string(),
-                        "condition_id": p.condition_id.to_string(),
-                        "proxy_wallet": p.proxy_wallet.to_string(),
-                        "timestamp": p.timestamp,
-                    })
-                })
-                .collect();
-            super::print_json(&data)?;
+        OutputFormat::Json | OutputFormat::Yaml => {
+            let data: Vec<_> = positions.iter().map(ClosedPositionView::from).collect();
+            super::print_json_list(&data)?;
         }
     }
     Ok(())
@@ -163,15 +209,15 @@ pub fn print_value(values: &[Value], output: &OutputFormat) -> anyhow::Result<()
                     value: format_decimal(v.value),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = values
                 .iter()
                 .map(|v| json!({"user": v.user.to_string(), "value": v.value.to_string()}))
                 .collect();
-            super::print_json(&data)?;
+            super::print_json_list(&data)?;
         }
     }
     Ok(())
@@ -180,7 +226,7 @@ pub fn print_value(values: &[Value], output: &OutputFormat) -> anyhow::Result<()
 pub fn print_traded(t: &Traded, output: &OutputFormat) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => println!("{}: {} markets traded", t.user, t.traded),
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             super::print_json(&json!({
                 "user": t.user.to_string(),
                 "traded": t.traded,
@@ -220,30 +266,52 @@ pub fn print_trades(trades: &[Trade], output: &OutputFormat) -> anyhow::Result<(
                     price: format!("{:.4}", t.price),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
-            let data: Vec<_> = trades
-                .iter()
-                .map(|t| {
-                    json!({
-                        "title": t.title,
-                        "slug": t.slug,
-                        "side": t.side.to_string(),
-                        "outcome": t.outcome,
-                        "outcome_index": t.outcome_index,
-                        "size": t.size.to_string(),
-                        "price": t.price.to_string(),
-                        "timestamp": t.timestamp,
-                        "condition_id": t.condition_id.to_string(),
-                        "proxy_wallet": t.proxy_wallet.to_string(),
-                        "transaction_hash": t.transaction_hash.to_string(),
-                    })
-                })
-                .collect();
-            super::print_json(&data)?;
+        OutputFormat::Json | OutputFormat::Yaml => {
+            let data: Vec<_> = trades.iter().map(trade_json).collect();
+            super::print_json_list(&data)?;
+        }
+    }
+    Ok(())
+}
+
+fn trade_json(t: &Trade) -> serde_json::Value {
+    json!({
+        "title": t.title,
+        "slug": t.slug,
+        "side": t.side.to_string(),
+        "outcome": t.outcome,
+        "outcome_index": t.outcome_index,
+        "size": t.size.to_string(),
+        "price": t.price.to_string(),
+        "timestamp": t.timestamp,
+        "condition_id": t.condition_id.to_string(),
+        "proxy_wallet": t.proxy_wallet.to_string(),
+        "transaction_hash": t.transaction_hash.to_string(),
+    })
+}
+
+/// One trade per line for `data trades --follow`: a plain line in table mode,
+/// an NDJSON frame in json mode.
+pub fn print_trade_line(t: &Trade, output: &OutputFormat) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            let when = chrono::DateTime::from_timestamp(t.timestamp, 0).map_or_else(
+                || t.timestamp.to_string(),
+                |d| d.format("%Y-%m-%d %H:%M:%S").to_string(),
+            );
+            println!(
+                "{when}  {:<4} {:>10.2} {} @ {:.4}  {}",
+                t.side.to_string(),
+                t.size,
+                t.outcome,
+                t.price,
+                truncate(&t.title, 60),
+            );
         }
+        OutputFormat::Json | OutputFormat::Yaml => super::print_json_line(&trade_json(t))?,
     }
     Ok(())
 }
@@ -278,10 +346,10 @@ pub fn print_activity(activity: &[Activity], output: &OutputFormat) -> anyhow::R
                     tx: truncate(&a.transaction_hash.to_string(), 14),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = activity
                 .iter()
                 .map(|a| {
@@ -296,7 +364,7 @@ pub fn print_activity(activity: &[Activity], output: &OutputFormat) -> anyhow::R
                     })
                 })
                 .collect();
-            super::print_json(&data)?;
+            super::print_json_list(&data)?;
         }
     }
     Ok(())
@@ -336,10 +404,10 @@ pub fn print_holders(meta_holders: &[MetaHolder], output: &OutputFormat) -> anyh
                     })
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = meta_holders
                 .iter()
                 .map(|mh| {
@@ -359,7 +427,7 @@ pub fn print_holders(meta_holders: &[MetaHolder], output: &OutputFormat) -> anyh
                     json!({"token": mh.token.to_string(), "holders": holders})
                 })
                 .collect();
-            super::print_json(&data)?;
+            super::print_json_list(&data)?;
         }
     }
     Ok(())
@@ -386,15 +454,15 @@ pub fn print_open_interest(oi: &[OpenInterest], output: &OutputFormat) -> anyhow
                     value: format_decimal(o.value),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = oi
                 .iter()
                 .map(|o| json!({"market": format_market(&o.market), "value": o.value.to_string()}))
                 .collect();
-            super::print_json(&data)?;
+            super::print_json_list(&data)?;
         }
     }
     Ok(())
@@ -424,11 +492,11 @@ pub fn print_live_volume(volume: &[LiveVolume], output: &OutputFormat) -> anyhow
                         value: format_decimal(mv.value),
                     })
                     .collect();
-                let table = Table::new(rows).with(Style::rounded()).to_string();
+                let table = Table::new(rows).with(super::table_style()).to_string();
                 println!("{table}");
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = volume
                 .iter()
                 .map(|v| {
@@ -440,14 +508,80 @@ pub fn print_live_volume(volume: &[LiveVolume], output: &OutputFormat) -> anyhow
                     json!({"total": v.total.to_string(), "markets": markets})
                 })
                 .collect();
-            super::print_json(&data)?;
+            super::print_json_list(&data)?;
         }
     }
     Ok(())
 }
 
+/// Columns of `data leaderboard`, named after their JSON keys.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum LeaderboardColumn {
+    Rank,
+    #[value(name = "user_name")]
+    UserName,
+    #[value(name = "proxy_wallet")]
+    ProxyWallet,
+    Pnl,
+    Volume,
+}
+
+impl LeaderboardColumn {
+    const TABLE_DEFAULT: &'static [Self] = &[Self::Rank, Self::UserName, Self::Pnl, Self::Volume];
+    const JSON_DEFAULT: &'static [Self] = &[
+        Self::Rank,
+        Self::ProxyWallet,
+        Self::UserName,
+        Self::Pnl,
+        Self::Volume,
+    ];
+
+    fn header(self) -> &'static str {
+        match self {
+            Self::Rank => "#",
+            Self::UserName => "Trader",
+            Self::ProxyWallet => "Wallet",
+            Self::Pnl => "PnL",
+            Self::Volume => "Volume",
+        }
+    }
+
+    fn key(self) -> &'static str {
+        match self {
+            Self::Rank => "rank",
+            Self::UserName => "user_name",
+            Self::ProxyWallet => "proxy_wallet",
+            Self::Pnl => "pnl",
+            Self::Volume => "volume",
+        }
+    }
+
+    fn cell(self, e: &TraderLeaderboardEntry) -> String {
+        match self {
+            Self::Rank => e.rank.to_string(),
+            Self::UserName => truncate(e.user_name.as_deref().unwrap_or("—"), 20),
+            Self::ProxyWallet => e.proxy_wallet.to_string(),
+            Self::Pnl => format_decimal(e.pnl),
+            Self::Volume => format_decimal(e.vol),
+        }
+    }
+
+    fn value(self, e: &TraderLeaderboardEntry) -> serde_json::Value {
+        match self {
+            Self::Rank => json!(e.rank),
+            Self::UserName => json!(e.user_name),
+            Self::ProxyWallet => json!(e.proxy_wallet.to_string()),
+            Self::Pnl => json!(e.pnl.to_string()),
+            Self::Volume => json!(e.vol.to_string()),
+        }
+    }
+}
+
+/// Prints the trader leaderboard; `columns` picks and orders the fields shown
+/// (both formats), `None` keeps each format's default set.
 pub fn print_leaderboard(
     entries: &[TraderLeaderboardEntry],
+    columns: Option<&[LeaderboardColumn]>,
     output: &OutputFormat,
 ) -> anyhow::Result<()> {
     match output {
@@ -456,43 +590,32 @@ pub fn print_leaderboard(
                 println!("No leaderboard entries found.");
                 return Ok(());
             }
-            #[derive(Tabled)]
-            struct Row {
-                #[tabled(rename = "#")]
-                rank: String,
-                #[tabled(rename = "Trader")]
-                trader: String,
-                #[tabled(rename = "PnL")]
-                pnl: String,
-                #[tabled(rename = "Volume")]
-                volume: String,
+            let columns = columns.unwrap_or(LeaderboardColumn::TABLE_DEFAULT);
+            let mut builder = tabled::builder::Builder::default();
+            builder.push_record(columns.iter().map(|c| c.header().to_string()));
+            for e in entries {
+                builder.push_record(columns.iter().map(|c| c.cell(e)));
+            }
+            let mut table = builder.build();
+            table.with(super::table_style());
+            if let Some(col) = columns.iter().position(|c| *c == LeaderboardColumn::Pnl) {
+                let pnls: Vec<_> = entries.iter().map(|e| e.pnl).collect();
+                color_pnl_column(&mut table, col, &pnls);
             }
-            let rows: Vec<Row> = entries
-                .iter()
-                .map(|e| Row {
-                    rank: e.rank.to_string(),
-                    trader: truncate(e.user_name.as_deref().unwrap_or("—"), 20),
-                    pnl: format_decimal(e.pnl),
-                    volume: format_decimal(e.vol),
-                })
-                .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
+            let columns = columns.unwrap_or(LeaderboardColumn::JSON_DEFAULT);
             let data: Vec<_> = entries
                 .iter()
                 .map(|e| {
-                    json!({
-                        "rank": e.rank,
-                        "proxy_wallet": e.proxy_wallet.to_string(),
-                        "user_name": e.user_name,
-                        "pnl": e.pnl.to_string(),
-                        "volume": e.vol.to_string(),
-                    })
+                    columns
+                        .iter()
+                        .map(|c| (c.key().to_string(), c.value(e)))
+                        .collect::<serde_json::Map<_, _>>()
                 })
                 .collect();
-            super::print_json(&data)?;
+            super::print_json_list(&data)?;
         }
     }
     Ok(())
@@ -528,10 +651,10 @@ pub fn print_builder_leaderboard(
                     active_users: e.active_users.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = entries
                 .iter()
                 .map(|e| {
@@ -544,7 +667,7 @@ pub fn print_builder_leaderboard(
                     })
                 })
                 .collect();
-            super::print_json(&data)?;
+            super::print_json_list(&data)?;
         }
     }
     Ok(())
@@ -583,10 +706,10 @@ pub fn print_builder_volume(
                     rank: e.rank.to_string(),
                 })
                 .collect();
-            let table = Table::new(rows).with(Style::rounded()).to_string();
+            let table = Table::new(rows).with(super::table_style()).to_string();
             println!("{table}");
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Yaml => {
             let data: Vec<_> = entries
                 .iter()
                 .map(|e| {
@@ -600,7 +723,7 @@ pub fn print_builder_volume(
                     })
                 })
                 .collect();
-            super::print_json(&data)?;
+            super::print_json_list(&data)?;
         }
     }
     Ok(())
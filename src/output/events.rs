@@ -1,8 +1,10 @@
-use polymarket_client_sdk::gamma::types::response::Event;
-use tabled::settings::Style;
+use polymarket_client_sdk::gamma::types::response::{Event, Market};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use serde_json::json;
 use tabled::{Table, Tabled};
 
-use super::{detail_field, format_decimal, print_detail_table, truncate};
+use super::{OutputFormat, detail_field, format_decimal, print_detail_table, print_json, truncate};
 
 #[derive(Tabled)]
 struct EventRow {
@@ -50,7 +52,7 @@ pub fn print_events_table(events: &[Event]) {
         return;
     }
     let rows: Vec<EventRow> = events.iter().map(event_to_row).collect();
-    let table = Table::new(rows).with(Style::rounded()).to_string();
+    let table = Table::new(rows).with(super::table_style()).to_string();
     println!("{table}");
 }
 
@@ -169,6 +171,160 @@ pub fn print_event_detail(e: &Event) {
     print_detail_table(rows);
 }
 
+/// Current price of a child market: its first (Yes) outcome price.
+fn market_price(m: &Market) -> Option<Decimal> {
+    m.outcome_prices.as_ref().and_then(|p| p.first()).copied()
+}
+
+/// The event line followed by one indented line per child market.
+fn event_tree_lines(e: &Event) -> Vec<String> {
+    let markets = e.markets.as_deref().unwrap_or_default();
+    let mut lines = vec![e.title.clone().unwrap_or_else(|| e.id.clone())];
+    for (i, m) in markets.iter().enumerate() {
+        let branch = if i + 1 == markets.len() {
+            "└─"
+        } else {
+            "├─"
+        };
+        let question = m.question.as_deref().unwrap_or(&m.id);
+        let price = market_price(m)
+            .map_or_else(|| "—".into(), |p| format!("{:.2}¢", p * Decimal::from(100)));
+        lines.push(format!("  {branch} {} · {price}", truncate(question, 70)));
+    }
+    lines
+}
+
+/// The event object with `markets` replaced by `{id, slug, question, price}`
+/// children; an event without markets gets an empty array.
+fn event_tree_json(e: &Event) -> anyhow::Result<serde_json::Value> {
+    let mut event = serde_json::to_value(e)?;
+    let markets: Vec<serde_json::Value> = e
+        .markets
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .map(|m| {
+            json!({
+                "id": m.id,
+                "slug": m.slug,
+                "question": m.question,
+                "price": market_price(m),
+            })
+        })
+        .collect();
+    if let Some(obj) = event.as_object_mut() {
+        obj.insert("markets".into(), markets.into());
+    }
+    Ok(event)
+}
+
+pub fn print_event_tree(e: &Event, output: OutputFormat) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            print_event_detail(e);
+            if e.markets.as_ref().is_some_and(|m| !m.is_empty()) {
+                println!();
+                for line in event_tree_lines(e) {
+                    println!("{line}");
+                }
+            }
+        }
+        OutputFormat::Json | OutputFormat::Yaml => print_json(&event_tree_json(e)?)?,
+    }
+    Ok(())
+}
+
+#[derive(Serialize)]
+pub struct VolumeBreakdown {
+    pub total_volume: Decimal,
+    pub markets: Vec<MarketVolumeShare>,
+}
+
+#[derive(Serialize)]
+pub struct MarketVolumeShare {
+    pub slug: String,
+    pub volume: Decimal,
+    pub share_pct: Decimal,
+}
+
+/// Splits an event's volume across its child markets, largest first. Shares
+/// are computed against the sum of the child volumes so they add up to 100%.
+pub fn volume_breakdown(e: &Event) -> VolumeBreakdown {
+    let mut markets: Vec<(String, Decimal)> = e
+        .markets
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .map(|m| {
+            let slug = m
+                .slug
+                .clone()
+                .or_else(|| m.question.clone())
+                .unwrap_or_else(|| m.id.clone());
+            (slug, m.volume_num.unwrap_or_default())
+        })
+        .collect();
+    markets.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    let total_volume: Decimal = markets.iter().map(|(_, v)| *v).sum();
+    let markets = markets
+        .into_iter()
+        .map(|(slug, volume)| {
+            let share_pct = if total_volume.is_zero() {
+                Decimal::ZERO
+            } else {
+                (volume / total_volume * Decimal::ONE_HUNDRED).round_dp(2)
+            };
+            MarketVolumeShare {
+                slug,
+                volume,
+                share_pct,
+            }
+        })
+        .collect();
+
+    VolumeBreakdown {
+        total_volume,
+        markets,
+    }
+}
+
+#[derive(Tabled)]
+struct VolumeShareRow {
+    #[tabled(rename = "Market")]
+    slug: String,
+    #[tabled(rename = "Volume")]
+    volume: String,
+    #[tabled(rename = "Share")]
+    share: String,
+}
+
+pub fn print_volume_breakdown(e: &Event, output: OutputFormat) -> anyhow::Result<()> {
+    let breakdown = volume_breakdown(e);
+    match output {
+        OutputFormat::Json | OutputFormat::Yaml => print_json(&breakdown)?,
+        OutputFormat::Table => {
+            if breakdown.markets.is_empty() {
+                println!("No markets found.");
+                return Ok(());
+            }
+            let rows: Vec<VolumeShareRow> = breakdown
+                .markets
+                .iter()
+                .map(|m| VolumeShareRow {
+                    slug: truncate(&m.slug, 60),
+                    volume: format_decimal(m.volume),
+                    share: format!("{:.2}%", m.share_pct),
+                })
+                .collect();
+            let table = Table::new(rows).with(super::table_style()).to_string();
+            println!("{table}");
+            println!("Total volume: {}", format_decimal(breakdown.total_volume));
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -239,4 +395,62 @@ mod tests {
         let e = make_event(json!({"id": "1", "volume": "2500000"}));
         assert_eq!(event_to_row(&e).volume, "$2.5M");
     }
+
+    #[test]
+    fn volume_breakdown_sorts_and_shares() {
+        let e = make_event(json!({
+            "id": "1",
+            "markets": [
+                {"id": "m1", "slug": "small", "volumeNum": "250"},
+                {"id": "m2", "slug": "big", "volumeNum": "750"},
+                {"id": "m3", "slug": "none"}
+            ]
+        }));
+        let b = volume_breakdown(&e);
+        assert_eq!(b.total_volume, Decimal::from(1000));
+        let slugs: Vec<&str> = b.markets.iter().map(|m| m.slug.as_str()).collect();
+        assert_eq!(slugs, ["big", "small", "none"]);
+        assert_eq!(b.markets[0].share_pct, Decimal::from(75));
+        assert_eq!(b.markets[1].share_pct, Decimal::from(25));
+        assert_eq!(b.markets[2].share_pct, Decimal::ZERO);
+    }
+
+    #[test]
+    fn volume_breakdown_without_volume_has_zero_shares() {
+        let e = make_event(json!({"id": "1", "markets": [{"id": "m1", "slug": "a"}]}));
+        let b = volume_breakdown(&e);
+        assert!(b.total_volume.is_zero());
+        assert!(b.markets[0].share_pct.is_zero());
+    }
+
+    #[test]
+    fn tree_nests_markets_with_prices() {
+        let e = make_event(json!({
+            "id": "1",
+            "title": "Election",
+            "markets": [
+                {"id": "m1", "question": "Will A win?", "outcomePrices": "[\"0.625\", \"0.375\"]"},
+                {"id": "m2", "question": "Will B win?"}
+            ]
+        }));
+        assert_eq!(
+            event_tree_lines(&e),
+            [
+                "Election",
+                "  ├─ Will A win? · 62.50¢",
+                "  └─ Will B win? · —"
+            ]
+        );
+        let tree = event_tree_json(&e).unwrap();
+        assert_eq!(tree["markets"][0]["question"], "Will A win?");
+        assert_eq!(tree["markets"][0]["price"], json!(Decimal::new(625, 3)));
+        assert!(tree["markets"][1]["price"].is_null());
+    }
+
+    #[test]
+    fn tree_without_markets_is_just_the_event() {
+        let e = make_event(json!({"id": "1", "title": "Empty"}));
+        assert_eq!(event_tree_lines(&e), ["Empty"]);
+        assert_eq!(event_tree_json(&e).unwrap()["markets"], json!([]));
+    }
 }
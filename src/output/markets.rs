@@ -1,9 +1,11 @@
+use polymarket_client_sdk::clob::types::response::OrderBookSummaryResponse;
 use polymarket_client_sdk::gamma::types::response::Market;
-use polymarket_client_sdk::types::Decimal;
-use tabled::settings::Style;
+use polymarket_client_sdk::types::{Decimal, U256};
+use serde_json::json;
 use tabled::{Table, Tabled};
 
-use super::{detail_field, format_decimal, print_detail_table, truncate};
+use super::{OutputFormat, detail_field, format_decimal, print_detail_table, truncate};
+use crate::commands::markets::WatchTick;
 
 #[derive(Tabled)]
 struct MarketRow {
@@ -52,7 +54,7 @@ pub fn print_markets_table(markets: &[Market]) {
         return;
     }
     let rows: Vec<MarketRow> = markets.iter().map(market_to_row).collect();
-    let table = Table::new(rows).with(Style::rounded()).to_string();
+    let table = Table::new(rows).with(super::table_style()).to_string();
     println!("{table}");
 }
 
@@ -161,15 +163,120 @@ pub fn print_market_detail(m: &Market) {
     print_detail_table(rows);
 }
 
+/// The live CLOB book of one outcome token, for `markets get --with-book`.
+/// A failed fetch keeps its error so the other tokens still print.
+pub struct TokenBook {
+    pub token_id: U256,
+    pub outcome: Option<String>,
+    pub book: Result<OrderBookSummaryResponse, String>,
+}
+
+pub fn print_market_with_books(
+    m: &Market,
+    books: &[TokenBook],
+    output: &OutputFormat,
+) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            print_market_detail(m);
+            if books.is_empty() {
+                println!();
+                println!("No CLOB tokens for this market (not tradable yet); order book omitted.");
+            }
+            for tb in books {
+                println!();
+                println!(
+                    "Outcome: {} (token {})",
+                    tb.outcome.as_deref().unwrap_or("—"),
+                    tb.token_id
+                );
+                match &tb.book {
+                    Ok(book) => super::clob::print_order_book(book, output)?,
+                    Err(e) => println!("Order book unavailable: {e}"),
+                }
+            }
+        }
+        OutputFormat::Json | OutputFormat::Yaml => {
+            super::print_json(&market_with_books_json(serde_json::to_value(m)?, books))?;
+        }
+    }
+    Ok(())
+}
+
+/// Adds `books: {token_id: {outcome, book, error}}` to the market object;
+/// left out entirely when the market has no CLOB tokens.
+fn market_with_books_json(mut market: serde_json::Value, books: &[TokenBook]) -> serde_json::Value {
+    if books.is_empty() {
+        return market;
+    }
+    let by_token: serde_json::Map<_, _> = books
+        .iter()
+        .map(|tb| {
+            (
+                tb.token_id.to_string(),
+                json!({
+                    "outcome": tb.outcome,
+                    "book": tb.book.as_ref().ok().map(super::clob::order_book_to_json),
+                    "error": tb.book.as_ref().err(),
+                }),
+            )
+        })
+        .collect();
+    if let Some(obj) = market.as_object_mut() {
+        obj.insert("books".into(), serde_json::Value::Object(by_token));
+    }
+    market
+}
+
+/// One line per tick in table mode; one NDJSON record per tick in JSON mode.
+pub fn print_watch_tick(tick: &WatchTick, output: &OutputFormat) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            use std::io::Write;
+            println!("{}", watch_line(tick));
+            std::io::stdout().flush()?;
+        }
+        OutputFormat::Json | OutputFormat::Yaml => super::print_json_line(tick)?,
+    }
+    Ok(())
+}
+
+fn watch_line(tick: &WatchTick) -> String {
+    let px = |v: Option<Decimal>| v.map_or_else(|| "—".to_string(), |d| format!("{d:.4}"));
+    format!(
+        "{}  mid {}  bid {}  ask {}  spread {}",
+        tick.timestamp
+            .with_timezone(&super::display_timezone())
+            .format("%H:%M:%S"),
+        px(tick.midpoint),
+        px(tick.best_bid),
+        px(tick.best_ask),
+        px(tick.spread),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use serde_json::json;
 
     fn make_market(val: serde_json::Value) -> Market {
         serde_json::from_value(val).unwrap()
     }
 
+    #[test]
+    fn watch_line_shows_dashes_for_empty_sides() {
+        let tick = WatchTick {
+            timestamp: chrono::Utc::now(),
+            token_id: "1".into(),
+            midpoint: Some(Decimal::new(515, 3)),
+            best_bid: Some(Decimal::new(51, 2)),
+            best_ask: None,
+            spread: None,
+        };
+        let line = watch_line(&tick);
+        assert!(line.contains("mid 0.5150  bid 0.5100  ask —  spread —"));
+    }
+
     #[test]
     fn status_closed_overrides_active() {
         let m = make_market(json!({"id": "1", "closed": true, "active": true}));
@@ -234,4 +341,21 @@ mod tests {
         let m = make_market(json!({"id": "1", "active": true}));
         assert_eq!(market_to_row(&m).status, "Active");
     }
+
+    #[test]
+    fn books_nest_under_token_id_and_are_omitted_without_tokens() {
+        let market = json!({"id": "1", "question": "Q?"});
+        assert_eq!(market_with_books_json(market.clone(), &[]), market);
+
+        let books = [TokenBook {
+            token_id: U256::from(42u64),
+            outcome: Some("Yes".into()),
+            book: Err("No orderbook exists".into()),
+        }];
+        let v = market_with_books_json(market, &books);
+        assert_eq!(v["question"], "Q?");
+        assert_eq!(v["books"]["42"]["outcome"], "Yes");
+        assert!(v["books"]["42"]["book"].is_null());
+        assert_eq!(v["books"]["42"]["error"], "No orderbook exists");
+    }
 }
@@ -10,18 +10,39 @@ pub mod markets;
 pub mod profiles;
 pub mod series;
 pub mod sports;
+pub mod status;
 pub mod tags;
 
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use polymarket_client_sdk::types::Decimal;
-use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::RoundingStrategy;
 use tabled::Table;
-use tabled::settings::object::Columns;
-use tabled::settings::{Modify, Style, Width};
+use tabled::settings::object::{Cell, Columns};
+use tabled::settings::themes::Theme;
+use tabled::settings::{Color, Modify, Style, Width};
 
 #[derive(Clone, Copy, Debug, clap::ValueEnum)]
 pub enum OutputFormat {
     Table,
     Json,
+    Yaml,
+}
+
+/// Renders `data` as a terminal QR code using half-block characters, dark
+/// modules drawn light so it scans on the usual dark terminal background.
+pub fn render_qr(data: &str) -> anyhow::Result<String> {
+    use qrcode::QrCode;
+    use qrcode::render::unicode::Dense1x2;
+
+    let code = QrCode::new(data.as_bytes())?;
+    Ok(code
+        .render::<Dense1x2>()
+        .dark_color(Dense1x2::Light)
+        .light_color(Dense1x2::Dark)
+        .quiet_zone(true)
+        .build())
 }
 
 pub fn truncate(s: &str, max: usize) -> String {
@@ -33,31 +54,321 @@ pub fn truncate(s: &str, max: usize) -> String {
     truncated
 }
 
+/// Display-only currency for dollar amounts in table output: symbol and the
+/// number of display units per USD. Unset means plain USD.
+static DISPLAY_CURRENCY: OnceLock<(&'static str, Decimal)> = OnceLock::new();
+
+pub fn set_display_currency(symbol: &'static str, usd_rate: Decimal) {
+    let _ = DISPLAY_CURRENCY.set((symbol, usd_rate));
+}
+
+/// Timezone for day buckets and displayed timestamps. Unset means UTC, with
+/// stored timestamps shown as-is.
+static DISPLAY_TIMEZONE: OnceLock<chrono_tz::Tz> = OnceLock::new();
+
+pub fn set_display_timezone(tz: chrono_tz::Tz) {
+    let _ = DISPLAY_TIMEZONE.set(tz);
+}
+
+pub fn display_timezone() -> chrono_tz::Tz {
+    DISPLAY_TIMEZONE.get().copied().unwrap_or(chrono_tz::UTC)
+}
+
+/// Shows an RFC 3339 timestamp in the `--timezone` zone; unparseable input,
+/// or no zone configured, is returned unchanged.
+pub fn format_timestamp(ts: &str) -> String {
+    match (
+        DISPLAY_TIMEZONE.get(),
+        chrono::DateTime::parse_from_rfc3339(ts),
+    ) {
+        (Some(tz), Ok(t)) => t
+            .with_timezone(tz)
+            .format("%Y-%m-%d %H:%M:%S %Z")
+            .to_string(),
+        _ => ts.to_string(),
+    }
+}
+
 pub fn format_decimal(n: Decimal) -> String {
-    let f = n.to_f64().unwrap_or(0.0);
-    if f >= 1_000_000.0 {
-        format!("${:.1}M", f / 1_000_000.0)
-    } else if f >= 1_000.0 {
-        format!("${:.1}K", f / 1_000.0)
+    match DISPLAY_CURRENCY.get() {
+        Some(&(symbol, rate)) => format_amount(n * rate, symbol),
+        None => format_amount(n, "$"),
+    }
+}
+
+/// Buckets on the magnitude as it will be shown, so an amount that rounds up
+/// to the next unit (999_950 is 1000.0K) moves to that unit instead. Losses
+/// carry the sign before the symbol: `-$1.5M`.
+fn format_amount(n: Decimal, symbol: &str) -> String {
+    let round =
+        |v: Decimal, dp| v.round_dp_with_strategy(dp, RoundingStrategy::MidpointAwayFromZero);
+    let thousand = Decimal::from(1_000);
+    let magnitude = n.abs();
+    let cents = round(magnitude, 2);
+    // Anything that rounds to zero is shown as $0.00, never -$0.00.
+    let sign = if n.is_sign_negative() && !cents.is_zero() {
+        "-"
+    } else {
+        ""
+    };
+    if cents < thousand {
+        return format!("{sign}{symbol}{cents:.2}");
+    }
+    let thousands = round(magnitude / thousand, 1);
+    if thousands < thousand {
+        format!("{sign}{symbol}{thousands:.1}K")
+    } else {
+        let millions = round(magnitude / Decimal::from(1_000_000), 1);
+        format!("{sign}{symbol}{millions:.1}M")
+    }
+}
+
+static COLOR_ENABLED: OnceLock<bool> = OnceLock::new();
+
+pub fn set_color_enabled(enabled: bool) {
+    let _ = COLOR_ENABLED.set(enabled);
+}
+
+fn color_enabled() -> bool {
+    COLOR_ENABLED.get().copied().unwrap_or(false)
+}
+
+/// When to use ANSI color and box-drawing table borders.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ColorChoice {
+    /// Only when stdout is a terminal and `NO_COLOR` is unset
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    pub fn resolve(self, no_color_env: bool, is_terminal: bool) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => !no_color_env && is_terminal,
+        }
+    }
+}
+
+/// Rounded box-drawing borders with color on; plain ASCII otherwise, so
+/// tables captured in CI logs or files stay readable.
+pub fn table_style() -> Theme {
+    if color_enabled() {
+        Theme::from_style(Style::rounded())
+    } else {
+        Theme::from_style(Style::ascii())
+    }
+}
+
+fn pnl_color(v: Decimal) -> Option<Color> {
+    if v > Decimal::ZERO {
+        Some(Color::FG_GREEN)
+    } else if v < Decimal::ZERO {
+        Some(Color::FG_RED)
     } else {
-        format!("${f:.2}")
+        None
     }
 }
 
+/// Colors each body cell of `column` green/red by the sign of the matching
+/// entry in `values` (one per data row). No-op when color is disabled.
+pub fn color_pnl_column(table: &mut Table, column: usize, values: &[Decimal]) {
+    apply_pnl_colors(table, column, values, color_enabled());
+}
+
+fn apply_pnl_colors(table: &mut Table, column: usize, values: &[Decimal], enabled: bool) {
+    if !enabled {
+        return;
+    }
+    for (i, v) in values.iter().enumerate() {
+        if let Some(color) = pnl_color(*v) {
+            table.with(Modify::new(Cell::new(i + 1, column)).with(color));
+        }
+    }
+}
+
+/// Wraps plain (non-table) PnL text in the same green/red as table cells.
+pub fn paint_pnl(text: &str, v: Decimal) -> String {
+    paint_pnl_with(text, v, color_enabled())
+}
+
+fn paint_pnl_with(text: &str, v: Decimal, enabled: bool) -> String {
+    if !enabled || v.is_zero() {
+        return text.to_string();
+    }
+    let code = if v > Decimal::ZERO { 32 } else { 31 };
+    format!("\u{1b}[{code}m{text}\u{1b}[0m")
+}
+
+/// `--ndjson`: json output becomes one compact object per line instead of a
+/// pretty-printed document. Atomic rather than `OnceLock` because the shell
+/// sets it per command.
+static NDJSON: AtomicBool = AtomicBool::new(false);
+
+pub fn set_ndjson(enabled: bool) {
+    NDJSON.store(enabled, Ordering::Relaxed);
+}
+
+pub fn ndjson() -> bool {
+    NDJSON.load(Ordering::Relaxed)
+}
+
+/// `-o yaml` shares every json code path; only the final serialization differs.
+/// Set per command like `NDJSON`.
+static YAML: AtomicBool = AtomicBool::new(false);
+
+pub fn set_yaml(enabled: bool) {
+    YAML.store(enabled, Ordering::Relaxed);
+}
+
+fn yaml() -> bool {
+    YAML.load(Ordering::Relaxed)
+}
+
 pub fn print_json(data: &impl serde::Serialize) -> anyhow::Result<()> {
+    if ndjson() {
+        return print_json_line(data);
+    }
+    if yaml() {
+        print!("{}", serde_yaml::to_string(data)?);
+        return Ok(());
+    }
     println!("{}", serde_json::to_string_pretty(data)?);
     Ok(())
 }
 
+/// Prints a list: a pretty array normally, one record per line under `--ndjson`.
+pub fn print_json_list<T: serde::Serialize>(items: &[T]) -> anyhow::Result<()> {
+    if !ndjson() {
+        return print_json(&items);
+    }
+    for item in items {
+        print_json_line(item)?;
+    }
+    Ok(())
+}
+
+/// Writes one compact JSON line and flushes it, so consumers see each record
+/// as soon as it's printed. A closed pipe (`| head`) ends the process quietly
+/// instead of panicking like `println!` would.
+pub fn print_json_line(data: &impl serde::Serialize) -> anyhow::Result<()> {
+    use std::io::Write;
+
+    // Streamed records become separate YAML documents.
+    let line = if yaml() {
+        format!("---\n{}", serde_yaml::to_string(data)?.trim_end())
+    } else {
+        serde_json::to_string(data)?
+    };
+    let mut stdout = std::io::stdout().lock();
+    match writeln!(stdout, "{line}").and_then(|()| stdout.flush()) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::BrokenPipe => std::process::exit(0),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// A failure whose details the command already printed as its json/yaml
+/// result; it still exits non-zero, but without a second `error` document.
+#[derive(Debug)]
+pub struct ReportedFailure(pub String);
+
+impl std::fmt::Display for ReportedFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ReportedFailure {}
+
+/// The `error` object printed for json/yaml, or `None` for a [`ReportedFailure`].
+fn error_document(e: &anyhow::Error) -> Option<serde_json::Value> {
+    (!e.is::<ReportedFailure>()).then(|| serde_json::json!({"error": e.to_string()}))
+}
+
+/// Reports a failed command: an `error` object on stdout for json/yaml (so
+/// scripts always get parseable output), `Error: ...` on stderr for tables.
+pub fn print_error(e: &anyhow::Error, output: OutputFormat) {
+    let Some(body) = error_document(e) else {
+        if matches!(output, OutputFormat::Table) {
+            eprintln!("Error: {e}");
+        }
+        return;
+    };
+    match output {
+        OutputFormat::Json => println!("{body}"),
+        OutputFormat::Yaml => match serde_yaml::to_string(&body) {
+            Ok(doc) => print!("{doc}"),
+            Err(_) => println!("{body}"),
+        },
+        OutputFormat::Table => eprintln!("Error: {e}"),
+    }
+}
+
 pub fn print_detail_table(rows: Vec<[String; 2]>) {
     let table = Table::from_iter(rows)
-        .with(Style::rounded())
+        .with(table_style())
         .with(Modify::new(Columns::first()).with(Width::wrap(20)))
         .with(Modify::new(Columns::last()).with(Width::wrap(80)))
         .to_string();
     println!("{table}");
 }
 
+/// Quotes a CSV field per RFC 4180: wrapped in double quotes (with inner quotes
+/// doubled) only when it contains a comma, quote or line break.
+pub fn csv_field(s: &str) -> std::borrow::Cow<'_, str> {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\"")).into()
+    } else {
+        s.into()
+    }
+}
+
+/// One CSV record, without the trailing newline.
+pub fn csv_line<S: AsRef<str>>(fields: &[S]) -> String {
+    fields
+        .iter()
+        .map(|f| csv_field(f.as_ref()))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Parses CSV written by `csv_line` back into records. Quoted fields may hold
+/// commas, doubled quotes and line breaks; a trailing newline is optional.
+pub fn csv_records(text: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (in_quotes, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            (true, '"') => in_quotes = false,
+            (true, _) => field.push(c),
+            (false, '"') => in_quotes = true,
+            (false, ',') => record.push(std::mem::take(&mut field)),
+            (false, '\n') => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            (false, '\r') => {}
+            (false, _) => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records
+}
+
 macro_rules! detail_field {
     ($rows:expr, $label:expr, $val:expr) => {
         $rows.push([$label.into(), $val]);
@@ -71,6 +382,57 @@ mod tests {
     use super::*;
     use rust_decimal_macros::dec;
 
+    #[test]
+    fn reported_failures_get_no_second_error_document() {
+        let reported = anyhow::Error::new(ReportedFailure("1 of 2 redemption(s) failed".into()));
+        assert!(error_document(&reported).is_none());
+        assert_eq!(reported.to_string(), "1 of 2 redemption(s) failed");
+        let plain = anyhow::anyhow!("boom");
+        assert_eq!(error_document(&plain).unwrap()["error"], "boom");
+    }
+
+    #[test]
+    fn color_choice_auto_needs_a_terminal_and_no_no_color() {
+        assert!(ColorChoice::Auto.resolve(false, true));
+        assert!(!ColorChoice::Auto.resolve(true, true));
+        assert!(!ColorChoice::Auto.resolve(false, false));
+        assert!(ColorChoice::Always.resolve(true, false));
+        assert!(!ColorChoice::Never.resolve(false, true));
+    }
+
+    #[test]
+    fn csv_line_quotes_only_when_needed() {
+        assert_eq!(csv_line(&["a", "b c", ""]), "a,b c,");
+        assert_eq!(
+            csv_line(&["Will it rain, today?", "say \"hi\"", "x\ny"]),
+            "\"Will it rain, today?\",\"say \"\"hi\"\"\",\"x\ny\""
+        );
+    }
+
+    #[test]
+    fn csv_records_reads_back_csv_line() {
+        let fields = ["Will it rain, today?", "say \"hi\"", "x\ny", ""];
+        let text = format!(
+            "{}\r\n{}",
+            csv_line(&["a", "b", "c", "d"]),
+            csv_line(&fields)
+        );
+        let records = csv_records(&text);
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0], ["a", "b", "c", "d"]);
+        assert_eq!(records[1], fields);
+        assert!(csv_records("").is_empty());
+    }
+
+    #[test]
+    fn render_qr_draws_a_square_block() {
+        let qr = render_qr("0x0000000000000000000000000000000000000001").unwrap();
+        let lines: Vec<&str> = qr.lines().collect();
+        assert!(lines.len() > 10);
+        let width = lines[0].chars().count();
+        assert!(lines.iter().all(|l| l.chars().count() == width));
+    }
+
     #[test]
     fn truncate_shorter_than_max_unchanged() {
         assert_eq!(truncate("hello", 10), "hello");
@@ -144,11 +506,71 @@ mod tests {
 
     #[test]
     fn format_decimal_negative() {
-        assert_eq!(format_decimal(dec!(-500)), "$-500.00");
+        assert_eq!(format_decimal(dec!(-500)), "-$500.00");
+    }
+
+    #[test]
+    fn format_decimal_negative_buckets_on_magnitude() {
+        assert_eq!(format_decimal(dec!(-1_500_000)), "-$1.5M");
+        assert_eq!(format_decimal(dec!(-1_500)), "-$1.5K");
+        assert_eq!(format_decimal(dec!(-0.5)), "-$0.50");
+        assert_eq!(format_decimal(dec!(-0.001)), "$0.00");
+    }
+
+    #[test]
+    fn format_amount_uses_currency_symbol() {
+        assert_eq!(format_amount(dec!(1_500), "€"), "€1.5K");
+        assert_eq!(format_amount(dec!(0.5), "£"), "£0.50");
+    }
+
+    #[test]
+    fn pnl_colors_only_when_enabled_and_nonzero() {
+        assert_eq!(paint_pnl_with("0", dec!(0), true), "0");
+        assert_eq!(paint_pnl_with("1.5", dec!(1.5), false), "1.5");
+        assert!(paint_pnl_with("1.5", dec!(1.5), true).contains("\u{1b}[32m"));
+        assert!(paint_pnl_with("-2", dec!(-2), true).contains("\u{1b}[31m"));
+
+        #[derive(Clone, tabled::Tabled)]
+        struct Row {
+            #[tabled(rename = "Market")]
+            market: &'static str,
+            #[tabled(rename = "PnL")]
+            pnl: &'static str,
+        }
+        let rows = vec![
+            Row {
+                market: "a",
+                pnl: "1",
+            },
+            Row {
+                market: "b",
+                pnl: "0",
+            },
+        ];
+        let mut table = Table::new(rows.clone());
+        apply_pnl_colors(&mut table, 1, &[dec!(1), dec!(0)], true);
+        let colored = table.to_string();
+        let line = |needle: &str| colored.lines().find(|l| l.contains(needle)).unwrap();
+        assert!(!line("PnL").contains('\u{1b}'), "header stays plain");
+        assert!(line(" a ").contains("\u{1b}[32m"));
+        assert!(!line(" b ").contains('\u{1b}'));
+
+        let mut plain = Table::new(rows);
+        apply_pnl_colors(&mut plain, 1, &[dec!(1), dec!(-1)], false);
+        assert!(!plain.to_string().contains('\u{1b}'));
+    }
+
+    #[test]
+    fn format_decimal_rounding_up_to_a_million_uses_m() {
+        assert_eq!(format_decimal(dec!(999_999)), "$1.0M");
+        assert_eq!(format_decimal(dec!(999_950)), "$1.0M");
+        assert_eq!(format_decimal(dec!(1_049_999)), "$1.0M");
     }
 
     #[test]
-    fn format_decimal_just_below_million_uses_k() {
-        assert_eq!(format_decimal(dec!(999_999)), "$1000.0K");
+    fn format_decimal_just_below_million_rounding_uses_k() {
+        assert_eq!(format_decimal(dec!(999_949)), "$999.9K");
+        assert_eq!(format_decimal(dec!(999.995)), "$1.0K");
+        assert_eq!(format_decimal(dec!(999.994)), "$999.99");
     }
 }
@@ -1,6 +1,88 @@
+use chrono::{DateTime, Utc};
 use polymarket_client_sdk::gamma::types::response::PublicProfile;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use tabled::{Table, Tabled};
 
-use super::{detail_field, print_detail_table};
+use super::{OutputFormat, color_pnl_column, detail_field, format_decimal, print_detail_table};
+
+/// One point of `profiles pnl-history`.
+pub struct PnlPoint {
+    pub timestamp: DateTime<Utc>,
+    pub pnl: Decimal,
+    /// USDC traded since the previous point.
+    pub volume: Decimal,
+}
+
+/// JSON shape of one `profiles pnl-history` row. `drawdown` is the distance
+/// below the highest PnL seen so far in the range (0 at a new peak).
+#[derive(Serialize)]
+struct PnlHistoryRow {
+    timestamp: String,
+    pnl: Decimal,
+    drawdown: Decimal,
+    volume: Decimal,
+}
+
+fn pnl_history_rows(points: &[PnlPoint]) -> Vec<PnlHistoryRow> {
+    let mut peak: Option<Decimal> = None;
+    points
+        .iter()
+        .map(|p| {
+            let high = peak.map_or(p.pnl, |h| h.max(p.pnl));
+            peak = Some(high);
+            PnlHistoryRow {
+                timestamp: p.timestamp.to_rfc3339(),
+                pnl: p.pnl,
+                drawdown: p.pnl - high,
+                volume: p.volume,
+            }
+        })
+        .collect()
+}
+
+pub fn print_pnl_history(points: &[PnlPoint], output: &OutputFormat) -> anyhow::Result<()> {
+    let rows = pnl_history_rows(points);
+    match output {
+        OutputFormat::Table => {
+            if rows.is_empty() {
+                println!("No PnL history in this range.");
+                return Ok(());
+            }
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Time")]
+                timestamp: String,
+                #[tabled(rename = "PnL")]
+                pnl: String,
+                #[tabled(rename = "Drawdown")]
+                drawdown: String,
+                #[tabled(rename = "Volume")]
+                volume: String,
+            }
+            let table_rows: Vec<Row> = rows
+                .iter()
+                .map(|r| Row {
+                    timestamp: super::format_timestamp(&r.timestamp),
+                    pnl: format_decimal(r.pnl),
+                    drawdown: format_decimal(r.drawdown),
+                    volume: format_decimal(r.volume),
+                })
+                .collect();
+            let pnls: Vec<_> = rows.iter().map(|r| r.pnl).collect();
+            let mut table = Table::new(table_rows);
+            table.with(super::table_style());
+            color_pnl_column(&mut table, 1, &pnls);
+            println!("{table}");
+            let max_drawdown = rows.iter().map(|r| r.drawdown).min().unwrap_or_default();
+            let volume: Decimal = rows.iter().map(|r| r.volume).sum();
+            println!("Max drawdown: {}", format_decimal(max_drawdown));
+            println!("Volume:       {}", format_decimal(volume));
+        }
+        OutputFormat::Json | OutputFormat::Yaml => super::print_json_list(&rows)?,
+    }
+    Ok(())
+}
 
 pub fn print_profile_detail(p: &PublicProfile) {
     let mut rows: Vec<[String; 2]> = Vec::new();
@@ -39,3 +121,32 @@ pub fn print_profile_detail(p: &PublicProfile) {
 
     print_detail_table(rows);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(secs: i64, pnl: i64) -> PnlPoint {
+        PnlPoint {
+            timestamp: DateTime::from_timestamp(secs, 0).unwrap(),
+            pnl: Decimal::from(pnl),
+            volume: Decimal::ZERO,
+        }
+    }
+
+    #[test]
+    fn drawdown_tracks_running_peak() {
+        let rows = pnl_history_rows(&[point(0, 10), point(60, 40), point(120, 15), point(180, 50)]);
+        let drawdowns: Vec<_> = rows.iter().map(|r| r.drawdown).collect();
+        assert_eq!(
+            drawdowns,
+            vec![
+                Decimal::ZERO,
+                Decimal::ZERO,
+                Decimal::from(-25),
+                Decimal::ZERO
+            ]
+        );
+        assert_eq!(rows[0].timestamp, "1970-01-01T00:00:00+00:00");
+    }
+}
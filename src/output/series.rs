@@ -1,5 +1,4 @@
 use polymarket_client_sdk::gamma::types::response::Series;
-use tabled::settings::Style;
 use tabled::{Table, Tabled};
 
 use super::{detail_field, format_decimal, print_detail_table, truncate};
@@ -44,7 +43,7 @@ pub fn print_series_table(series: &[Series]) {
         return;
     }
     let rows: Vec<SeriesRow> = series.iter().map(series_to_row).collect();
-    let table = Table::new(rows).with(Style::rounded()).to_string();
+    let table = Table::new(rows).with(super::table_style()).to_string();
     println!("{table}");
 }
 
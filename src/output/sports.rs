@@ -1,10 +1,11 @@
+use chrono::NaiveDate;
 use polymarket_client_sdk::gamma::types::response::{
     SportsMarketTypesResponse, SportsMetadata, Team,
 };
-use tabled::settings::Style;
 use tabled::{Table, Tabled};
 
-use super::truncate;
+use super::{OutputFormat, print_json, truncate};
+use crate::commands::sports::Game;
 
 #[derive(Tabled)]
 struct SportRow {
@@ -33,7 +34,7 @@ pub fn print_sports_table(sports: &[SportsMetadata]) {
         return;
     }
     let rows: Vec<SportRow> = sports.iter().map(sport_to_row).collect();
-    let table = Table::new(rows).with(Style::rounded()).to_string();
+    let table = Table::new(rows).with(super::table_style()).to_string();
     println!("{table}");
 }
 
@@ -43,7 +44,9 @@ pub fn print_sport_types(types: &SportsMarketTypesResponse) {
         return;
     }
     let rows: Vec<[String; 1]> = types.market_types.iter().map(|t| [t.clone()]).collect();
-    let table = Table::from_iter(rows).with(Style::rounded()).to_string();
+    let table = Table::from_iter(rows)
+        .with(super::table_style())
+        .to_string();
     println!("{table}");
 }
 
@@ -77,6 +80,62 @@ pub fn print_teams_table(teams: &[Team]) {
         return;
     }
     let rows: Vec<TeamRow> = teams.iter().map(team_to_row).collect();
-    let table = Table::new(rows).with(Style::rounded()).to_string();
+    let table = Table::new(rows).with(super::table_style()).to_string();
     println!("{table}");
 }
+
+#[derive(Tabled)]
+struct GameRow {
+    #[tabled(rename = "Away")]
+    away: String,
+    #[tabled(rename = "Home")]
+    home: String,
+    #[tabled(rename = "Score")]
+    score: String,
+    #[tabled(rename = "Status")]
+    status: String,
+    #[tabled(rename = "Start")]
+    start: String,
+    #[tabled(rename = "Market")]
+    market: String,
+}
+
+fn game_to_row(g: &Game) -> GameRow {
+    let status = match g.status {
+        "live" => [Some("Live"), g.period.as_deref(), g.elapsed.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" "),
+        "final" => "Final".into(),
+        _ => "Scheduled".into(),
+    };
+    GameRow {
+        away: g.away.clone().unwrap_or_else(|| truncate(&g.title, 30)),
+        home: g.home.as_deref().unwrap_or("—").into(),
+        score: g.score.as_deref().unwrap_or("—").into(),
+        status,
+        start: g.start.as_deref().unwrap_or("—").into(),
+        market: g.market_slugs.first().unwrap_or(&g.event_slug).clone(),
+    }
+}
+
+pub fn print_games(
+    games: &[Game],
+    date: Option<NaiveDate>,
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Json | OutputFormat::Yaml => print_json(&games)?,
+        OutputFormat::Table if games.is_empty() => match date {
+            Some(day) => println!("No games on {day}."),
+            None => println!("No live or upcoming games."),
+        },
+        OutputFormat::Table => {
+            let rows: Vec<GameRow> = games.iter().map(game_to_row).collect();
+            let table = Table::new(rows).with(super::table_style()).to_string();
+            println!("{table}");
+        }
+    }
+    Ok(())
+}
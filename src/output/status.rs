@@ -0,0 +1,95 @@
+use serde::Serialize;
+use tabled::{Table, Tabled};
+
+use super::{OutputFormat, truncate};
+
+/// Health of one upstream API. `status` is the raw reply (or the error).
+#[derive(Serialize)]
+pub struct ServiceStatus {
+    pub service: &'static str,
+    pub up: bool,
+    pub latency_ms: u64,
+    pub status: String,
+}
+
+#[derive(Tabled)]
+struct Row {
+    #[tabled(rename = "Service")]
+    service: &'static str,
+    #[tabled(rename = "Status")]
+    status: String,
+    #[tabled(rename = "Latency (ms)")]
+    latency_ms: u64,
+}
+
+fn status_row(s: &ServiceStatus) -> Row {
+    Row {
+        service: s.service,
+        status: if s.up {
+            "up".to_string()
+        } else {
+            truncate(&format!("down: {}", s.status), 60)
+        },
+        latency_ms: s.latency_ms,
+    }
+}
+
+/// JSON is an object keyed by service so scripts can read e.g. `.clob.up`.
+fn statuses_json(statuses: &[ServiceStatus]) -> serde_json::Map<String, serde_json::Value> {
+    statuses
+        .iter()
+        .map(|s| (s.service.to_string(), serde_json::json!(s)))
+        .collect()
+}
+
+pub fn print_service_statuses(
+    statuses: &[ServiceStatus],
+    output: OutputFormat,
+) -> anyhow::Result<()> {
+    match output {
+        OutputFormat::Table => {
+            let rows: Vec<Row> = statuses.iter().map(status_row).collect();
+            let table = Table::new(rows).with(super::table_style()).to_string();
+            println!("{table}");
+        }
+        OutputFormat::Json | OutputFormat::Yaml => super::print_json(&statuses_json(statuses))?,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(service: &'static str, up: bool) -> ServiceStatus {
+        ServiceStatus {
+            service,
+            up,
+            latency_ms: 42,
+            status: if up { "OK".into() } else { "timeout".into() },
+        }
+    }
+
+    #[test]
+    fn json_shape_has_service_up_latency_and_status() {
+        let v: serde_json::Value = serde_json::to_value(sample("gamma", true)).unwrap();
+        assert_eq!(v["service"], "gamma");
+        assert_eq!(v["up"], true);
+        assert_eq!(v["latency_ms"], 42);
+        assert_eq!(v["status"], "OK");
+    }
+
+    #[test]
+    fn json_is_keyed_by_service() {
+        let v = statuses_json(&[sample("gamma", true), sample("clob", false)]);
+        assert_eq!(v["gamma"]["up"], true);
+        assert_eq!(v["clob"]["up"], false);
+        assert_eq!(v["clob"]["status"], "timeout");
+    }
+
+    #[test]
+    fn table_row_reports_error_only_when_down() {
+        assert_eq!(status_row(&sample("data", true)).status, "up");
+        assert_eq!(status_row(&sample("data", false)).status, "down: timeout");
+    }
+}
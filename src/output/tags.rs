@@ -1,5 +1,4 @@
 use polymarket_client_sdk::gamma::types::response::{RelatedTag, Tag};
-use tabled::settings::Style;
 use tabled::{Table, Tabled};
 
 use super::{detail_field, print_detail_table, truncate};
@@ -31,7 +30,7 @@ pub fn print_tags_table(tags: &[Tag]) {
         return;
     }
     let rows: Vec<TagRow> = tags.iter().map(tag_to_row).collect();
-    let table = Table::new(rows).with(Style::rounded()).to_string();
+    let table = Table::new(rows).with(super::table_style()).to_string();
     println!("{table}");
 }
 
@@ -62,7 +61,7 @@ pub fn print_related_tags_table(tags: &[RelatedTag]) {
         return;
     }
     let rows: Vec<RelatedTagRow> = tags.iter().map(related_tag_to_row).collect();
-    let table = Table::new(rows).with(Style::rounded()).to_string();
+    let table = Table::new(rows).with(super::table_style()).to_string();
     println!("{table}");
 }
 
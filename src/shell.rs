@@ -1,20 +1,68 @@
+use std::path::PathBuf;
+
 use clap::Parser;
+use rustyline::DefaultEditor;
+
+#[derive(clap::Args)]
+pub struct ShellArgs {
+    /// Keep at most this many lines of history in the config directory's `shell_history`
+    #[arg(long, default_value_t = 1000, value_parser = clap::value_parser!(u16).range(1..))]
+    history_size: u16,
+}
 
-use crate::output::OutputFormat;
+fn history_path() -> Option<PathBuf> {
+    crate::config::config_dir()
+        .ok()
+        .map(|dir| dir.join("shell_history"))
+}
+
+/// History outlives the session on disk, so lines that carry a private key
+/// are never recorded.
+fn should_record(line: &str) -> bool {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let sets_key = matches!(words.as_slice(), ["wallet", "import", ..]);
+    !sets_key && !line.contains("--private-key")
+}
+
+fn new_editor(history_size: u16) -> rustyline::Result<DefaultEditor> {
+    let config = rustyline::Config::builder()
+        .max_history_size(usize::from(history_size))?
+        .history_ignore_dups(true)?
+        .auto_add_history(false)
+        .build();
+    DefaultEditor::with_config(config)
+}
+
+fn save_history(rl: &mut DefaultEditor, path: Option<&PathBuf>) {
+    let Some(path) = path else { return };
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    if let Err(e) = rl.save_history(path) {
+        tracing::warn!("could not save shell history: {e}");
+    }
+}
 
-pub async fn run_shell() {
+pub async fn run_shell(args: ShellArgs) {
     println!();
     println!("  Polymarket CLI · Interactive Shell");
-    println!("  Type 'help' for commands, 'exit' to quit.");
+    println!("  Type 'help' for commands, 'exit' to quit. Ctrl-R searches history.");
     println!();
 
-    let mut rl = match rustyline::DefaultEditor::new() {
+    let mut rl = match new_editor(args.history_size) {
         Ok(rl) => rl,
         Err(e) => {
             eprintln!("Failed to initialize shell: {e}");
             return;
         }
     };
+    let history = history_path();
+    if let Some(path) = &history
+        && path.exists()
+        && let Err(e) = rl.load_history(path)
+    {
+        tracing::warn!("could not load shell history: {e}");
+    }
 
     loop {
         match rl.readline("polymarket> ") {
@@ -27,7 +75,9 @@ pub async fn run_shell() {
                     break;
                 }
 
-                let _ = rl.add_history_entry(line);
+                if should_record(line) && rl.add_history_entry(line).unwrap_or(false) {
+                    save_history(&mut rl, history.as_ref());
+                }
 
                 let args = split_args(line);
                 let mut full_args = vec!["polymarket".to_string()];
@@ -45,17 +95,16 @@ pub async fn run_shell() {
                 }
 
                 match crate::Cli::try_parse_from(&full_args) {
-                    Ok(cli) => {
+                    // The config dir is fixed when the process starts, so a
+                    // per-line value would be silently ignored.
+                    Ok(cli) if cli.config_dir.is_some() => {
+                        eprintln!("Error: {CONFIG_DIR_IN_SHELL}");
+                    }
+                    Ok(mut cli) => {
+                        crate::apply_output_mode(&mut cli);
                         let output = cli.output;
                         if let Err(e) = crate::run(cli).await {
-                            match output {
-                                OutputFormat::Json => {
-                                    println!("{}", serde_json::json!({"error": e.to_string()}));
-                                }
-                                OutputFormat::Table => {
-                                    eprintln!("Error: {e}");
-                                }
-                            }
+                            crate::output::print_error(&e, output);
                         }
                     }
                     Err(e) => {
@@ -75,6 +124,8 @@ pub async fn run_shell() {
     println!("Goodbye!");
 }
 
+const CONFIG_DIR_IN_SHELL: &str = "--config-dir applies to the whole session; start it with `polymarket --config-dir <dir> shell`";
+
 fn split_args(input: &str) -> Vec<String> {
     let mut args = Vec::new();
     let mut current = String::new();
@@ -96,3 +147,26 @@ fn split_args(input: &str) -> Vec<String> {
     }
     args
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_bearing_lines_are_not_recorded() {
+        assert!(should_record("clob book 12345"));
+        assert!(should_record("wallet show"));
+        assert!(!should_record("wallet import 0xabc"));
+        assert!(should_record("wallet rotate --yes"));
+        assert!(!should_record("clob balance --private-key 0xabc"));
+    }
+
+    #[test]
+    fn config_dir_is_parsed_per_line_so_the_shell_must_reject_it() {
+        use clap::Parser;
+        let mut args = vec!["polymarket".to_string()];
+        args.extend(split_args("--config-dir /tmp/other wallet show"));
+        let cli = crate::Cli::try_parse_from(&args).unwrap();
+        assert!(cli.config_dir.is_some());
+    }
+}
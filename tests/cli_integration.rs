@@ -29,7 +29,8 @@ fn help_lists_all_top_level_commands() {
             .and(predicate::str::contains("bridge"))
             .and(predicate::str::contains("wallet"))
             .and(predicate::str::contains("status"))
-            .and(predicate::str::contains("copy")),
+            .and(predicate::str::contains("copy"))
+            .and(predicate::str::contains("schema")),
     );
 }
 
@@ -80,10 +81,40 @@ fn wallet_help_lists_subcommands() {
                 .and(predicate::str::contains("import"))
                 .and(predicate::str::contains("address"))
                 .and(predicate::str::contains("show"))
+                .and(predicate::str::contains("rotate"))
                 .and(predicate::str::contains("reset")),
         );
 }
 
+#[test]
+fn wallet_rotate_help_explains_why_the_key_is_not_an_argument() {
+    polymarket()
+        .args(["wallet", "rotate", "--help"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("--new-key-file")
+                .and(predicate::str::contains("--new-keystore"))
+                .and(predicate::str::contains("shell history")),
+        );
+}
+
+#[test]
+fn wallet_rotate_reads_the_new_key_from_one_source_only() {
+    polymarket()
+        .args([
+            "wallet",
+            "rotate",
+            "--new-key-file",
+            "new.key",
+            "--new-keystore",
+            "new.json",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
 #[test]
 fn copy_help_lists_subcommands() {
     polymarket()
@@ -97,7 +128,9 @@ fn copy_help_lists_subcommands() {
                 .and(predicate::str::contains("record"))
                 .and(predicate::str::contains("settle"))
                 .and(predicate::str::contains("dashboard"))
-                .and(predicate::str::contains("ui")),
+                .and(predicate::str::contains("ui"))
+                .and(predicate::str::contains("monitor"))
+                .and(predicate::str::contains("preview")),
         );
 }
 
@@ -110,6 +143,127 @@ fn copy_status_requires_configuration() {
         .stderr(predicate::str::contains("Copy-trader is not configured"));
 }
 
+#[test]
+fn copy_settle_requires_movement_or_auto() {
+    polymarket()
+        .args(["copy", "settle"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--movement-id"));
+}
+
+#[test]
+fn copy_settle_auto_conflicts_with_manual_settle() {
+    polymarket()
+        .args([
+            "copy",
+            "settle",
+            "--auto",
+            "--movement-id",
+            "X",
+            "--pnl",
+            "1",
+        ])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn copy_record_execute_requires_order_details() {
+    polymarket()
+        .args([
+            "copy",
+            "record",
+            "--movement-id",
+            "X",
+            "--market",
+            "m",
+            "--leader-value",
+            "100",
+            "--copied-value",
+            "4",
+            "--execute",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--token-id"));
+}
+
+#[test]
+fn copy_settlements_rejects_bad_since_date() {
+    polymarket()
+        .args(["copy", "settlements", "--since", "03/01/2026"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("YYYY-MM-DD"));
+}
+
+#[test]
+fn copy_export_rejects_unknown_mode() {
+    polymarket()
+        .args(["copy", "export", "--mode", "paper"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("real"));
+}
+
+#[test]
+fn copy_import_missing_file_fails_clearly() {
+    polymarket()
+        .args(["copy", "import", "/nonexistent/copy_trader_real_db.jsonl"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to read"));
+}
+
+#[test]
+fn copy_reset_clears_history_but_keeps_config() {
+    let dir = std::env::temp_dir().join(format!("polymarket-cli-reset-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let run = |args: &[&str]| {
+        polymarket()
+            .args(["--config-dir", dir.to_str().unwrap()])
+            .args(args)
+            .assert()
+            .success()
+    };
+    run(&[
+        "copy",
+        "configure",
+        "--leader",
+        "0x0000000000000000000000000000000000000001",
+        "--allocated-funds",
+        "100",
+    ]);
+    run(&[
+        "copy",
+        "record",
+        "--movement-id",
+        "m1",
+        "--market",
+        "mkt",
+        "--leader-value",
+        "50",
+        "--copied-value",
+        "5",
+    ]);
+    run(&["-o", "json", "copy", "reset", "--mode", "all", "--yes"])
+        .stdout(predicate::str::contains("\"cleared\": 1"));
+
+    run(&["copy", "export"]).stdout(predicate::str::contains("m1").not());
+    run(&["copy", "status"]);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn copy_monitor_requires_configuration() {
+    polymarket()
+        .args(["copy", "monitor"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Copy-trader is not configured"));
+}
+
 #[test]
 fn no_args_shows_usage() {
     polymarket()
@@ -132,6 +286,15 @@ fn invalid_output_format_rejected() {
         .stderr(predicate::str::contains("invalid value"));
 }
 
+#[test]
+fn markets_list_help_shows_tag_id() {
+    polymarket()
+        .args(["markets", "list", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--tag-id"));
+}
+
 #[test]
 fn markets_search_requires_query() {
     polymarket().args(["markets", "search"]).assert().failure();
@@ -173,6 +336,34 @@ fn json_mode_error_is_valid_json_with_error_key() {
     );
 }
 
+#[test]
+fn wallet_balance_without_wallet_fails_clearly() {
+    let dir = std::env::temp_dir().join(format!("polymarket-cli-nowallet-{}", std::process::id()));
+    polymarket()
+        .args(["wallet", "balance", "--config-dir"])
+        .arg(&dir)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No wallet configured"));
+}
+
+#[test]
+fn wallet_show_yaml_has_configured_field() {
+    let output = polymarket()
+        .args(["-o", "yaml", "wallet", "show"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let parsed: serde_yaml::Value = serde_yaml::from_str(&stdout)
+        .unwrap_or_else(|e| panic!("stdout not valid YAML: {e}\nstdout: {stdout}"));
+    assert!(
+        parsed.get("configured").is_some(),
+        "missing 'configured' key: {stdout}"
+    );
+}
+
 #[test]
 fn table_mode_error_goes_to_stderr() {
     polymarket()
@@ -257,10 +448,20 @@ fn sports_help_lists_subcommands() {
         .stdout(
             predicate::str::contains("list")
                 .and(predicate::str::contains("market-types"))
-                .and(predicate::str::contains("teams")),
+                .and(predicate::str::contains("teams"))
+                .and(predicate::str::contains("scores")),
         );
 }
 
+#[test]
+fn sports_scores_rejects_malformed_date() {
+    polymarket()
+        .args(["sports", "scores", "--date", "13/09/2026"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("expected YYYY-MM-DD"));
+}
+
 #[test]
 fn clob_help_lists_subcommands() {
     polymarket()
@@ -276,6 +477,72 @@ fn clob_help_lists_subcommands() {
         );
 }
 
+#[test]
+fn clob_help_lists_order_management_subcommands() {
+    polymarket()
+        .args(["clob", "--help"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("orders")
+                .and(predicate::str::contains("cancel"))
+                .and(predicate::str::contains("cancel-all")),
+        );
+    polymarket()
+        .args(["clob", "orders", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--market").and(predicate::str::contains("--asset")));
+}
+
+#[test]
+fn clob_book_help_shows_ladder_flags() {
+    polymarket()
+        .args(["clob", "book", "--help"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("--depth")
+                .and(predicate::str::contains("--cumulative"))
+                .and(predicate::str::contains("--json-levels"))
+                .and(predicate::str::contains("--levels")),
+        );
+}
+
+#[test]
+fn unknown_timezone_rejected() {
+    polymarket()
+        .args(["--timezone", "Mars/Olympus", "status"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown timezone"));
+}
+
+#[test]
+fn clob_price_token_conflicts_with_tokens_file() {
+    polymarket()
+        .args([
+            "clob",
+            "price",
+            "123",
+            "--side",
+            "buy",
+            "--tokens-file",
+            "tokens.txt",
+        ])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn clob_fees_requires_token_or_market() {
+    polymarket()
+        .args(["clob", "fees"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--token").or(predicate::str::contains("--market")));
+}
+
 #[test]
 fn data_help_lists_subcommands() {
     polymarket()
@@ -289,6 +556,58 @@ fn data_help_lists_subcommands() {
         );
 }
 
+#[test]
+fn data_positions_rejects_bad_condition_filter() {
+    polymarket()
+        .args([
+            "data",
+            "positions",
+            "0x0000000000000000000000000000000000000001",
+            "--condition",
+            "not-a-condition",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid condition ID"));
+}
+
+#[test]
+fn data_positions_condition_conflicts_with_market() {
+    polymarket()
+        .args([
+            "data",
+            "positions",
+            "0x0000000000000000000000000000000000000001",
+            "--condition",
+            "0x01",
+            "--market",
+            "some-slug",
+        ])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn data_positions_market_filter_rejects_paging_flags() {
+    for (filter, value) in [("--condition", "0x01"), ("--market", "some-slug")] {
+        for paging in ["--limit", "--offset"] {
+            polymarket()
+                .args([
+                    "data",
+                    "positions",
+                    "0x0000000000000000000000000000000000000001",
+                    filter,
+                    value,
+                    paging,
+                    "5",
+                ])
+                .assert()
+                .failure()
+                .stderr(predicate::str::contains("cannot be used with"));
+        }
+    }
+}
+
 #[test]
 fn bridge_help_lists_subcommands() {
     polymarket()
@@ -302,11 +621,151 @@ fn bridge_help_lists_subcommands() {
         );
 }
 
+#[test]
+fn bridge_assets_help_shows_filters() {
+    polymarket()
+        .args(["bridge", "assets", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--chain").and(predicate::str::contains("--token")));
+}
+
+#[test]
+fn copy_configure_help_lists_presets() {
+    polymarket()
+        .args(["copy", "configure", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--preset").and(predicate::str::contains("scalper")));
+}
+
+#[test]
+fn copy_ui_socket_conflicts_with_port() {
+    polymarket()
+        .args([
+            "copy",
+            "ui",
+            "--socket",
+            "/tmp/pm-ui.sock",
+            "--port",
+            "9000",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--socket"));
+}
+
+#[test]
+fn copy_dashboard_help_shows_summary_only() {
+    polymarket()
+        .args(["copy", "dashboard", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--summary-only"));
+}
+
+#[test]
+fn copy_plan_help_shows_explain() {
+    polymarket()
+        .args(["copy", "plan", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--explain"));
+}
+
+#[test]
+fn leaderboard_rejects_unknown_column() {
+    polymarket()
+        .args(["data", "leaderboard", "--columns", "rank,roi"])
+        .assert()
+        .failure()
+        .stderr(
+            predicate::str::contains("roi")
+                .and(predicate::str::contains("proxy_wallet"))
+                .and(predicate::str::contains("user_name")),
+        );
+}
+
+#[test]
+fn schema_prints_copy_plan_contract() {
+    polymarket()
+        .args(["schema", "copy-plan"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("\"capped_size\"").and(predicate::str::contains("$schema")),
+        );
+}
+
+#[test]
+fn schema_wallet_show_lists_configured() {
+    polymarket()
+        .args(["schema", "wallet-show"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"configured\""));
+}
+
+#[test]
+fn cache_clear_on_empty_dir_removes_nothing() {
+    let dir = std::env::temp_dir().join(format!("polymarket-cli-cache-{}", std::process::id()));
+    polymarket()
+        .args(["-o", "json", "cache", "clear", "--config-dir"])
+        .arg(&dir)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"removed\": 0"));
+}
+
+#[test]
+fn schema_rejects_unknown_target() {
+    polymarket()
+        .args(["schema", "markets-nope"])
+        .assert()
+        .failure();
+}
+
 #[test]
 fn events_get_requires_id() {
     polymarket().args(["events", "get"]).assert().failure();
 }
 
+#[test]
+fn events_get_help_shows_volume_breakdown() {
+    polymarket()
+        .args(["events", "get", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--volume-breakdown"));
+}
+
+#[test]
+fn events_get_tree_conflicts_with_volume_breakdown() {
+    polymarket()
+        .args(["events", "get", "500", "--tree", "--volume-breakdown"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn data_trades_all_conflicts_with_follow() {
+    polymarket()
+        .args(["data", "trades", "0xabc", "--all", "--follow"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn max_pages_requires_all() {
+    polymarket()
+        .args(["markets", "list", "--max-pages", "3"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--all"));
+}
+
 #[test]
 fn tags_get_requires_id() {
     polymarket().args(["tags", "get"]).assert().failure();
@@ -335,21 +794,80 @@ fn profiles_get_requires_address() {
     polymarket().args(["profiles", "get"]).assert().failure();
 }
 
+#[test]
+fn profiles_pnl_history_rejects_reversed_range() {
+    polymarket()
+        .args([
+            "profiles",
+            "pnl-history",
+            "0x0000000000000000000000000000000000000001",
+            "--from",
+            "2025-03-01",
+            "--to",
+            "2025-01-01",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("must not be after --to"));
+}
+
+#[test]
+fn markets_watch_rejects_zero_interval() {
+    polymarket()
+        .args(["markets", "watch", "123", "--interval-secs", "0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--interval-secs"));
+}
+
 #[test]
 fn clob_book_requires_token() {
     polymarket().args(["clob", "book"]).assert().failure();
 }
 
+#[test]
+fn clob_cancel_requires_order_id() {
+    polymarket()
+        .args(["clob", "cancel"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("ORDER_ID"));
+}
+
 #[test]
 fn clob_price_requires_token() {
     polymarket().args(["clob", "price"]).assert().failure();
 }
 
+#[test]
+fn data_trades_interval_requires_follow() {
+    polymarket()
+        .args([
+            "data",
+            "trades",
+            "0x0000000000000000000000000000000000000000",
+            "--interval",
+            "3",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--follow"));
+}
+
 #[test]
 fn data_positions_requires_address() {
     polymarket().args(["data", "positions"]).assert().failure();
 }
 
+#[test]
+fn help_lists_ndjson_flag() {
+    polymarket()
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--ndjson"));
+}
+
 #[test]
 fn approve_help_lists_subcommands() {
     polymarket()
@@ -359,6 +877,15 @@ fn approve_help_lists_subcommands() {
         .stdout(predicate::str::contains("check").and(predicate::str::contains("set")));
 }
 
+#[test]
+fn approve_check_min_amount_requires_spender() {
+    polymarket()
+        .args(["approve", "check", "--min-amount", "100"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--spender"));
+}
+
 #[test]
 fn ctf_help_lists_subcommands() {
     polymarket()
@@ -372,10 +899,25 @@ fn ctf_help_lists_subcommands() {
                 .and(predicate::str::contains("redeem-neg-risk"))
                 .and(predicate::str::contains("condition-id"))
                 .and(predicate::str::contains("collection-id"))
-                .and(predicate::str::contains("position-id")),
+                .and(predicate::str::contains("position-id"))
+                .and(predicate::str::contains("positions")),
         );
 }
 
+#[test]
+fn ctf_positions_without_wallet_fails_clearly() {
+    let dir = std::env::temp_dir().join(format!(
+        "polymarket-cli-ctf-nowallet-{}",
+        std::process::id()
+    ));
+    polymarket()
+        .args(["ctf", "positions", "--config-dir"])
+        .arg(&dir)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No wallet configured"));
+}
+
 #[test]
 fn ctf_collection_id_requires_condition_and_index_set() {
     polymarket()
@@ -443,6 +985,23 @@ fn ctf_split_requires_amount() {
         .failure();
 }
 
+#[test]
+fn ctf_split_amount_conflicts_with_base_units() {
+    polymarket()
+        .args([
+            "ctf",
+            "split",
+            "--condition",
+            "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "--amount",
+            "10",
+            "--amount-base-units",
+            "10000000",
+        ])
+        .assert()
+        .failure();
+}
+
 #[test]
 fn ctf_merge_requires_condition_and_amount() {
     polymarket().args(["ctf", "merge"]).assert().failure();
@@ -489,6 +1048,64 @@ fn ctf_position_id_requires_collection() {
     polymarket().args(["ctf", "position-id"]).assert().failure();
 }
 
+#[test]
+fn private_key_file_conflicts_with_inline_key() {
+    polymarket()
+        .args([
+            "--private-key",
+            "0xabc",
+            "--private-key-file",
+            "key.txt",
+            "wallet",
+            "show",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn missing_private_key_file_is_reported() {
+    polymarket()
+        .args([
+            "--private-key-file",
+            "/nonexistent/polymarket.key",
+            "wallet",
+            "show",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to read private key file"));
+}
+
+#[test]
+fn private_key_file_is_only_read_by_commands_that_sign() {
+    polymarket()
+        .args([
+            "--private-key-file",
+            "/nonexistent/polymarket.key",
+            "schema",
+            "wallet-show",
+        ])
+        .assert()
+        .success();
+}
+
+#[test]
+fn copy_commands_that_sign_read_the_private_key_file() {
+    polymarket()
+        .args([
+            "--private-key-file",
+            "/nonexistent/polymarket.key",
+            "copy",
+            "settle",
+            "--auto",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to read private key file"));
+}
+
 #[test]
 fn json_flag_short_form_works() {
     polymarket()
@@ -506,6 +1123,32 @@ fn table_output_is_default() {
         .stdout(predicate::str::contains("Address").or(predicate::str::contains("configured")));
 }
 
+#[test]
+fn wallet_show_help_lists_qr_and_full() {
+    polymarket()
+        .args(["wallet", "show", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--qr").and(predicate::str::contains("--full")));
+}
+
+#[test]
+fn wallet_show_full_prints_the_signer_address() {
+    polymarket()
+        .args([
+            "--private-key",
+            "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "--signature-type",
+            "proxy",
+            "wallet",
+            "show",
+            "--full",
+        ])
+        .assert()
+        .success()
+        .stdout("0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf\n");
+}
+
 #[test]
 fn wallet_address_succeeds_or_fails_gracefully() {
     // If no wallet configured, should fail with error; if configured, should succeed
@@ -513,3 +1156,53 @@ fn wallet_address_succeeds_or_fails_gracefully() {
     // Either succeeds or fails with an error message — not a panic
     assert!(output.status.success() || !output.stderr.is_empty());
 }
+
+#[test]
+fn timeout_secs_must_be_positive() {
+    polymarket()
+        .args(["--timeout-secs", "0", "status"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--timeout-secs"));
+}
+
+#[test]
+fn quiet_status_prints_only_the_result() {
+    // Services may be down in CI: the table still lists them, and the only
+    // stderr allowed is the final error when every service is unreachable.
+    polymarket()
+        .args([
+            "--quiet",
+            "--max-retries",
+            "0",
+            "--timeout-secs",
+            "5",
+            "status",
+        ])
+        .assert()
+        .stdout(predicate::str::contains("gamma"))
+        .stderr(predicate::str::is_empty().or(predicate::str::starts_with("Error: ")));
+}
+
+#[test]
+fn quiet_conflicts_with_verbose() {
+    polymarket()
+        .args(["-q", "-v", "status"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn data_trades_export_append_requires_export() {
+    polymarket()
+        .args([
+            "data",
+            "trades",
+            "0x0000000000000000000000000000000000000001",
+            "--export-append",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--export"));
+}